@@ -0,0 +1,129 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use orx_v::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+type Cost = u32;
+
+const INF: Cost = Cost::MAX;
+
+fn random_costs(n: usize, connectivity: f64) -> Vec<(usize, usize, Cost)> {
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    let mut edges = vec![];
+    for i in 0..n {
+        for j in 0..n {
+            if i != j && rng.random_bool(connectivity) {
+                edges.push((i, j, rng.random_range(1..100)));
+            }
+        }
+    }
+    edges
+}
+
+fn initialize(edges: &[(usize, usize, Cost)], d: &mut impl MatrixMut<Cost>) {
+    d.reset_all(INF);
+    for i in 0..d.num_rows() {
+        d.set([i, i], 0);
+    }
+    for (i, j, cost) in edges.iter().copied() {
+        d.set([i, j], cost);
+    }
+}
+
+fn floyd_warshall_at(edges: &[(usize, usize, Cost)], d: &mut impl MatrixMut<Cost>) {
+    initialize(edges, d);
+    let n = d.num_rows();
+
+    for k in 0..n {
+        for i in 0..n {
+            let d_ik = d.at([i, k]);
+            if d_ik < INF {
+                for j in 0..n {
+                    let d_kj = d.at([k, j]);
+                    if d_kj < INF && d_ik + d_kj < d.at([i, j]) {
+                        *d.at_mut([i, j]) = d_ik + d_kj;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn floyd_warshall_unchecked(edges: &[(usize, usize, Cost)], d: &mut impl MatrixMut<Cost>) {
+    initialize(edges, d);
+    let n = d.num_rows();
+    assert_eq!(n, d.num_cols());
+
+    // SAFETY: i, j, k are in 0..n and the matrix is n x n
+    unsafe {
+        for k in 0..n {
+            for i in 0..n {
+                let d_ik = d.at_unchecked([i, k]);
+                if d_ik < INF {
+                    for j in 0..n {
+                        let d_kj = d.at_unchecked([k, j]);
+                        if d_kj < INF && d_ik + d_kj < d.at_unchecked([i, j]) {
+                            *d.at_mut_unchecked([i, j]) = d_ik + d_kj;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn bench_floyd_warshall(c: &mut Criterion) {
+    let treatments = [100, 250];
+
+    let mut group = c.benchmark_group("floyd_warshall");
+
+    for n in treatments {
+        let edges = random_costs(n, 0.1);
+
+        // sanity check
+        let mut expected = vec![0; n * n];
+        let mut result = vec![0; n * n];
+        floyd_warshall_at(&edges, &mut expected.v1_as_matrix_mut(n, n));
+        floyd_warshall_unchecked(&edges, &mut result.v1_as_matrix_mut(n, n));
+        assert_eq!(expected, result);
+
+        group.bench_with_input(BenchmarkId::new("d2-vec-at", n), &n, |b, _| {
+            let mut d = vec![vec![0; n]; n];
+            b.iter(|| floyd_warshall_at(black_box(&edges), &mut d.as_matrix_mut()))
+        });
+
+        group.bench_with_input(BenchmarkId::new("d2-vec-at_unchecked", n), &n, |b, _| {
+            let mut d = vec![vec![0; n]; n];
+            b.iter(|| floyd_warshall_unchecked(black_box(&edges), &mut d.as_matrix_mut()))
+        });
+
+        group.bench_with_input(BenchmarkId::new("v1-matrix-at", n), &n, |b, _| {
+            let mut d = vec![0; n * n];
+            b.iter(|| floyd_warshall_at(black_box(&edges), &mut d.v1_as_matrix_mut(n, n)))
+        });
+
+        group.bench_with_input(BenchmarkId::new("v1-matrix-at_unchecked", n), &n, |b, _| {
+            let mut d = vec![0; n * n];
+            b.iter(|| floyd_warshall_unchecked(black_box(&edges), &mut d.v1_as_matrix_mut(n, n)))
+        });
+
+        group.bench_with_input(BenchmarkId::new("flat-jagged-at", n), &n, |b, _| {
+            let mut d = vec![0; n * n].into_jagged_from_row_lengths(&vec![n; n]);
+            b.iter(|| floyd_warshall_at(black_box(&edges), &mut d.as_matrix_mut()))
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("flat-jagged-at_unchecked", n),
+            &n,
+            |b, _| {
+                let mut d = vec![0; n * n].into_jagged_from_row_lengths(&vec![n; n]);
+                b.iter(|| floyd_warshall_unchecked(black_box(&edges), &mut d.as_matrix_mut()))
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_floyd_warshall);
+criterion_main!(benches);
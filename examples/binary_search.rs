@@ -51,8 +51,8 @@ fn is_asc_arr<T: Ord>(arr: impl V1<T>) -> bool {
 
 fn main() {
     let arr = [1, 3, 8, 11];
-    assert_eq!(binary_search(&7, &arr), None);
-    assert_eq!(binary_search(&8, &arr), Some(2));
+    assert_eq!(binary_search(&7, arr), None);
+    assert_eq!(binary_search(&8, arr), Some(2));
 
     // with non-slice data types
 
@@ -75,10 +75,10 @@ fn main() {
     assert_eq!(binary_search(&12, &sparse), Some(1));
 
     let constant = V.d1().constant(42).bounded(6);
-    assert_eq!(binary_search(&8, &constant), None);
-    assert_eq!(binary_search(&42, &constant), Some(3)); // first 42 found in the mid
+    assert_eq!(binary_search(&8, constant), None);
+    assert_eq!(binary_search(&42, constant), Some(3)); // first 42 found in the mid
 
     let fun_vec = V.d1().fun(|[i]| 2 * i + 1).bounded(6); // => 1, 3, 5, 7, 9, 11
-    assert_eq!(binary_search(&8, &fun_vec), None);
-    assert_eq!(binary_search(&7, &fun_vec), Some(3));
+    assert_eq!(binary_search(&8, fun_vec), None);
+    assert_eq!(binary_search(&7, fun_vec), Some(3));
 }
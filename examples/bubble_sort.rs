@@ -71,7 +71,7 @@ fn main() {
     sort_and_validate(descending.clone(), &mut descending);
 
     let mut ascending = [1, 2, 3, 4, 5, 6];
-    sort_and_validate(ascending.clone(), &mut ascending);
+    sort_and_validate(ascending, ascending.as_mut_slice());
 
     let mut empty = Vec::<u64>::new();
     sort_and_validate(empty.clone(), &mut empty);
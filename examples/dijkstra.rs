@@ -177,7 +177,7 @@ fn with_edge_set_and_uniform_distances() {
 
 fn with_on_demand_cost_computation() {
     fn routing_service(from: usize, to: usize) -> Cost {
-        match (from + to) % 2 == 0 {
+        match (from + to).is_multiple_of(2) {
             true => from as Cost + 2 * to as Cost + 3,
             false => INF,
         }
@@ -199,7 +199,7 @@ fn with_on_demand_cost_computation() {
 
 fn with_cached_on_demand_cost_computation() {
     fn routing_service(from: usize, to: usize) -> Cost {
-        match (from + to) % 2 == 0 {
+        match (from + to).is_multiple_of(2) {
             true => from as Cost + 2 * to as Cost + 3,
             false => INF,
         }
@@ -24,5 +24,5 @@ fn main() {
 
     let v1 = vec![1, 2, 3, 4];
     let v2 = V.d1().constant(2).bounded(v1.card([]));
-    assert_eq!(dot_product(&v1, &v2), 20);
+    assert_eq!(dot_product(&v1, v2), 20);
 }
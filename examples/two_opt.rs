@@ -1,7 +1,6 @@
 use orx_v::*;
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
-use std::u32;
 
 fn apply_two_opt(mut tour: impl V1Mut<usize>, i: usize, j: usize) {
     let mut i = i + 1;
@@ -47,6 +46,7 @@ fn two_opt(distances: impl V2<u32>, mut tour: impl V1Mut<usize>) -> u32 {
     improvement
 }
 
+#[allow(clippy::needless_range_loop)]
 fn complete_distance_matrix_d2(n: usize) -> Vec<Vec<u32>> {
     let mut rng = ChaCha8Rng::seed_from_u64(75);
     let mut mat = vec![vec![0; n]; n];
@@ -145,14 +145,14 @@ fn main() {
         // Vec<Vec<u32>>
         let distances: Vec<Vec<u32>> = complete_distance_matrix_d2(n);
         let mut tour: Vec<_> = initial_tour(n);
-        let _improvement = two_opt(&distances, &mut tour);
+        let _improvement = two_opt(distances, &mut tour);
 
         #[cfg(feature = "ndarray")]
         {
             // ndarray::Array2
             let distances: ndarray::Array2<u32> = complete_ndarray_d2(n);
             let mut tour: Vec<_> = initial_tour(n);
-            let _improvement = two_opt(&distances, &mut tour);
+            let _improvement = two_opt(distances, &mut tour);
         }
     }
 
@@ -180,7 +180,7 @@ fn main() {
         let finite_distances: HashMap<[usize; 2], u32> = finite_distances_map(n);
         let distances = V.d2().sparse_from(finite_distances, 10000);
         let mut tour: Vec<_> = initial_tour(n);
-        let _improvement = two_opt(&distances, &mut tour);
+        let _improvement = two_opt(distances, &mut tour);
     }
 
     // functional matrix
@@ -189,7 +189,7 @@ fn main() {
         .d2()
         .fun(|[i, j]| euclidean_distance(&locations[i], &locations[j]));
     let mut tour: Vec<_> = initial_tour(n);
-    let _improvement = two_opt(&distances, &mut tour);
+    let _improvement = two_opt(distances, &mut tour);
 
     // functional matrix: ignore from-to depot (node 0) links
     let locations: Vec<Location> = get_locations(n);
@@ -199,7 +199,7 @@ fn main() {
         _ => euclidean_distance(&locations[i], &locations[j]),
     });
     let mut tour: Vec<_> = initial_tour(n);
-    let _improvement = two_opt(&distances, &mut tour);
+    let _improvement = two_opt(distances, &mut tour);
 
     // cached matrix
     let locations: Vec<Location> = get_locations(n);
@@ -208,10 +208,10 @@ fn main() {
         .fun(|[i, j]| routing_engine(&locations[i], &locations[j]))
         .into_cached();
     let mut tour: Vec<_> = initial_tour(n);
-    let _improvement = two_opt(&distances, &mut tour);
+    let _improvement = two_opt(distances, &mut tour);
 
     // uniform distances
     let distances = V.d2().constant(10);
     let mut tour: Vec<_> = initial_tour(n);
-    let _improvement = two_opt(&distances, &mut tour);
+    let _improvement = two_opt(distances, &mut tour);
 }
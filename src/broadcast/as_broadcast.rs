@@ -0,0 +1,216 @@
+use super::BroadcastVec;
+use crate::{Dim, NVec, D1, D2, D3, D4, D5, D6};
+
+/// Creates lazy views of rectangular vectors lifted to one higher dimension by
+/// repeating them along a new axis.
+pub trait Broadcast<D: Dim, T>: NVec<D::PrevDim, T> + Sized {
+    /// Converts the vector into a lazy view of dimension `D` by inserting a new `axis`
+    /// with `len` elements to this vector of dimension `D::PrevDim`.
+    ///
+    /// The element of the view at `idx` is the element of this vector at `idx`
+    /// with its `axis`-th element removed; i.e., this vector is repeated `len`
+    /// times along the new axis. The view has a rectangular cardinality, and it
+    /// does not copy the elements.
+    ///
+    /// See [`as_broadcast_axis`] to create the view over a reference of the vector.
+    ///
+    /// [`as_broadcast_axis`]: crate::Broadcast::as_broadcast_axis
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `axis` is not less than `D::dimension()`, or
+    /// * the vector is unbounded, or
+    /// * the vector is not rectangular; i.e., [`is_rectangular`] is false.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = V.d2().fun(|[i, j]| 10 * i + j).with_rectangular_bounds([2, 3]);
+    ///
+    /// let v3 = v2.into_broadcast_axis(0, 4);
+    /// assert_eq!(v3.shape(), [4, 2, 3]);
+    /// assert_eq!(v3.at([3, 1, 2]), 12);
+    /// ```
+    ///
+    /// Jagged vectors cannot be broadcast.
+    ///
+    /// ```should_panic
+    /// use orx_v::*;
+    ///
+    /// let v2 = vec![vec![0, 1], vec![2]];
+    /// let _ = v2.into_broadcast_axis(0, 2);
+    /// ```
+    fn into_broadcast_axis(self, axis: usize, len: usize) -> BroadcastVec<D, T, Self>;
+
+    /// Creates a lazy view of dimension `D` by inserting a new `axis` with `len`
+    /// elements to this vector of dimension `D::PrevDim`.
+    ///
+    /// The element of the view at `idx` is the element of this vector at `idx`
+    /// with its `axis`-th element removed; i.e., this vector is repeated `len`
+    /// times along the new axis. The view has a rectangular cardinality, and it
+    /// does not copy the elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `axis` is not less than `D::dimension()`, or
+    /// * the vector is unbounded, or
+    /// * the vector is not rectangular; i.e., [`is_rectangular`] is false.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = V.d2().fun(|[i, j]| 10 * i + j).with_rectangular_bounds([2, 3]);
+    ///
+    /// let v3 = v2.as_broadcast_axis(0, 4);
+    /// assert_eq!(v3.shape(), [4, 2, 3]);
+    /// assert_eq!(v3.at([3, 1, 2]), 12);
+    /// assert_eq!(v3.child(3).equality(&v2), Equality::Equal);
+    ///
+    /// let v3 = v2.as_broadcast_axis(2, 4);
+    /// assert_eq!(v3.shape(), [2, 3, 4]);
+    /// assert_eq!(v3.at([1, 2, 3]), 12);
+    /// assert_eq!(v3.child(1).child(2).equality(&[12, 12, 12, 12]), Equality::Equal);
+    /// ```
+    fn as_broadcast_axis(&self, axis: usize, len: usize) -> BroadcastVec<D, T, &Self>;
+}
+
+macro_rules! impl_broadcast {
+    ($dim:ty) => {
+        impl<T, V> Broadcast<$dim, T> for V
+        where
+            V: NVec<<$dim as Dim>::PrevDim, T>,
+        {
+            fn into_broadcast_axis(self, axis: usize, len: usize) -> BroadcastVec<$dim, T, Self> {
+                BroadcastVec::<$dim, T, Self>::new(self, axis, len)
+            }
+
+            fn as_broadcast_axis(&self, axis: usize, len: usize) -> BroadcastVec<$dim, T, &Self> {
+                BroadcastVec::<$dim, T, &Self>::new(self, axis, len)
+            }
+        }
+    };
+}
+
+impl_broadcast!(D2);
+impl_broadcast!(D3);
+impl_broadcast!(D4);
+impl_broadcast!(D5);
+impl_broadcast!(D6);
+
+/// Creates lazy `D2` views of bounded `D1` vectors by repeating them as rows or
+/// columns.
+pub trait V1Broadcast<T>: NVec<D1, T> + Sized {
+    /// Converts the vector into a lazy `D2` view with `num_cols` columns such that
+    /// the element at `[i, j]` is the `i`-th element of this vector; i.e., all
+    /// elements of the `i`-th row are equal to the `i`-th element.
+    ///
+    /// This is equivalent to `into_broadcast_axis(1, num_cols)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let weights = V.d1().fun(|[i]| i + 1).bounded(3);
+    ///
+    /// let w = weights.into_broadcast_rows(2);
+    /// assert_eq!(w.equality(&[[1, 1], [2, 2], [3, 3]]), Equality::Equal);
+    /// ```
+    fn into_broadcast_rows(self, num_cols: usize) -> BroadcastVec<D2, T, Self> {
+        BroadcastVec::<D2, T, Self>::new(self, 1, num_cols)
+    }
+
+    /// Creates a lazy `D2` view of the vector with `num_cols` columns such that
+    /// the element at `[i, j]` is the `i`-th element of this vector; i.e., all
+    /// elements of the `i`-th row are equal to the `i`-th element.
+    ///
+    /// This is equivalent to `as_broadcast_axis(1, num_cols)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let weights = vec![1, 2, 3];
+    ///
+    /// let w = weights.as_broadcast_rows(2);
+    /// assert_eq!(w.card([]), 3);
+    /// assert_eq!(w.card([0]), 2);
+    /// assert_eq!(w.at([2, 1]), 3);
+    /// assert_eq!(w.equality(&[[1, 1], [2, 2], [3, 3]]), Equality::Equal);
+    /// ```
+    fn as_broadcast_rows(&self, num_cols: usize) -> BroadcastVec<D2, T, &Self> {
+        BroadcastVec::<D2, T, &Self>::new(self, 1, num_cols)
+    }
+
+    /// Converts the vector into a lazy `D2` view with `num_rows` rows such that
+    /// the element at `[i, j]` is the `j`-th element of this vector; i.e., each
+    /// row is equal to this vector.
+    ///
+    /// This is equivalent to `into_broadcast_axis(0, num_rows)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let weights = V.d1().fun(|[i]| i + 1).bounded(3);
+    ///
+    /// let w = weights.into_broadcast_cols(2);
+    /// assert_eq!(w.equality(&[[1, 2, 3], [1, 2, 3]]), Equality::Equal);
+    /// ```
+    fn into_broadcast_cols(self, num_rows: usize) -> BroadcastVec<D2, T, Self> {
+        BroadcastVec::<D2, T, Self>::new(self, 0, num_rows)
+    }
+
+    /// Creates a lazy `D2` view of the vector with `num_rows` rows such that
+    /// the element at `[i, j]` is the `j`-th element of this vector; i.e., each
+    /// row is equal to this vector.
+    ///
+    /// This is equivalent to `as_broadcast_axis(0, num_rows)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let weights = vec![1, 2, 3];
+    ///
+    /// let w = weights.as_broadcast_cols(2);
+    /// assert_eq!(w.card([]), 2);
+    /// assert_eq!(w.card([0]), 3);
+    /// assert_eq!(w.at([1, 2]), 3);
+    /// assert_eq!(w.equality(&[[1, 2, 3], [1, 2, 3]]), Equality::Equal);
+    /// ```
+    fn as_broadcast_cols(&self, num_rows: usize) -> BroadcastVec<D2, T, &Self> {
+        BroadcastVec::<D2, T, &Self>::new(self, 0, num_rows)
+    }
+}
+
+impl<T, V: NVec<D1, T>> V1Broadcast<T> for V {}
@@ -0,0 +1,145 @@
+use crate::common_trait_helpers::debug::*;
+use crate::common_trait_helpers::shape::rectangular_shape;
+use crate::{dim::*, IntoDyn, NVec};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A lazy view of a rectangular vector of dimension `D::PrevDim` lifted to a vector
+/// of dimension `D` by repeating it along a new `axis`.
+///
+/// The element of the broadcast vector at `idx` is the element of the underlying
+/// vector at `idx` with its `axis`-th element removed. In other words, the
+/// broadcast vector does not depend on the index along the new axis.
+///
+/// It is created by the methods of the [`Broadcast`] and [`V1Broadcast`] traits.
+///
+/// [`Broadcast`]: crate::Broadcast
+/// [`V1Broadcast`]: crate::V1Broadcast
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let v2 = vec![vec![0, 1, 2], vec![3, 4, 5]];
+///
+/// let v3 = v2.into_broadcast_axis(1, 4);
+/// assert_eq!(v3.shape(), [2, 4, 3]);
+/// assert_eq!(v3.card([1, 3]), 3);
+/// assert_eq!(v3.at([1, 0, 2]), 5);
+/// assert_eq!(v3.at([1, 3, 2]), 5);
+/// assert_eq!(v3.child(1).child(2).equality(&[3, 4, 5]), Equality::Equal);
+/// ```
+pub struct BroadcastVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D::PrevDim, T>,
+{
+    pub(super) vec: V,
+    pub(super) axis: usize,
+    pub(super) shape: D::Idx,
+    phantom: PhantomData<T>,
+}
+
+impl<D, T, V> BroadcastVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D::PrevDim, T>,
+{
+    /// Destructs the broadcast view into the underlying vector.
+    pub fn into_inner(self) -> V {
+        self.vec
+    }
+
+    /// Returns the new axis along which the underlying vector is repeated.
+    pub fn axis(&self) -> usize {
+        self.axis
+    }
+
+    /// Returns the shape of the broadcast vector; i.e., number of elements along
+    /// each of its axes.
+    pub fn shape(&self) -> D::Idx {
+        self.shape
+    }
+}
+
+macro_rules! impl_new {
+    ($dim:ty, $n:expr) => {
+        impl<T, V> BroadcastVec<$dim, T, V>
+        where
+            V: NVec<<$dim as Dim>::PrevDim, T>,
+        {
+            pub(super) fn new(vec: V, axis: usize, len: usize) -> Self {
+                assert!(
+                    axis < $n,
+                    "Axis {} is out of bounds for a broadcast vector of dimension D{}",
+                    axis,
+                    $n
+                );
+                assert!(vec.is_bounded(), "An unbounded vector cannot be broadcast");
+                assert!(
+                    vec.is_rectangular(),
+                    "A jagged vector cannot be broadcast; vector must have rectangular cardinality."
+                );
+
+                let src_shape: [usize; $n - 1] = rectangular_shape(&vec.as_dyn());
+                let mut shape = [0; $n];
+                for q in 0..$n {
+                    shape[q] = match q {
+                        q if q < axis => src_shape[q],
+                        q if q == axis => len,
+                        q => src_shape[q - 1],
+                    };
+                }
+
+                Self {
+                    vec,
+                    axis,
+                    shape,
+                    phantom: PhantomData,
+                }
+            }
+
+            #[inline(always)]
+            pub(super) fn src_idx(&self, idx: [usize; $n]) -> [usize; $n - 1] {
+                let mut src = [0; $n - 1];
+                src[..self.axis].copy_from_slice(&idx[..self.axis]);
+                src[self.axis..].copy_from_slice(&idx[(self.axis + 1)..]);
+                src
+            }
+        }
+    };
+}
+
+impl_new!(D2, 2);
+impl_new!(D3, 3);
+impl_new!(D4, 4);
+impl_new!(D5, 5);
+impl_new!(D6, 6);
+
+macro_rules! impl_debug {
+    ($dim:ty, $dbg_fn:ident) => {
+        impl<T, V> Debug for BroadcastVec<$dim, T, V>
+        where
+            V: NVec<<$dim as Dim>::PrevDim, T>,
+            T: Debug,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{{ kind: BroadcastVec, dim: D{}, axis: {}, values: ",
+                    <$dim as Dim>::dimension(),
+                    self.axis,
+                )?;
+                $dbg_fn(f, self)?;
+                write!(f, " }}")
+            }
+        }
+    };
+}
+
+impl_debug!(D2, dbg_values_d2);
+impl_debug!(D3, dbg_values_d3);
+impl_debug!(D4, dbg_values_d4);
+impl_debug!(D5, dbg_values_d5);
+impl_debug!(D6, dbg_values_d6);
@@ -0,0 +1,13 @@
+use super::BroadcastVec;
+use crate::{Dim, IntoCached, NVec};
+use core::hash::Hash;
+
+impl<D, T, V> IntoCached<D, T> for BroadcastVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D::PrevDim, T>,
+    T: Copy,
+    D::Idx: Ord + Hash,
+    Self: NVec<D, T>,
+{
+}
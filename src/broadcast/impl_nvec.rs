@@ -0,0 +1,72 @@
+use super::BroadcastVec;
+use crate::children::{ChildD2D1, ChildD3D2, ChildD4D3, ChildD5D4, ChildD6D5};
+use crate::common_trait_helpers::indices::*;
+use crate::{dim::*, Card, NVec, NVecCoreSealed};
+use crate::{RectangularCardD2, RectangularCardD3, RectangularCardD4};
+use crate::{RectangularCardD5, RectangularCardD6};
+
+macro_rules! impl_broadcast {
+    ($dim:ty, $card:ident, $child:ident, $indices:ident) => {
+        impl<T, V> NVecCoreSealed<$dim, T> for BroadcastVec<$dim, T, V>
+        where
+            V: NVec<<$dim as Dim>::PrevDim, T>,
+        {
+            #[inline(always)]
+            fn core_num_children(&self) -> usize {
+                self.shape[0]
+            }
+
+            #[inline(always)]
+            fn core_card(&self, idx: impl Into<<$dim as Dim>::CardIdx>) -> usize {
+                $card::from(self.shape).cardinality_of(idx)
+            }
+
+            fn core_child(
+                &self,
+                i: <$dim as Dim>::ChildIdx,
+            ) -> impl NVecCoreSealed<<$dim as Dim>::PrevDim, T> {
+                $child::<_, T> {
+                    i,
+                    parent: self,
+                    phantom: Default::default(),
+                }
+            }
+
+            fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<$dim>, f: &mut F) -> O {
+                self.vec.core_map(self.src_idx(idx.into_idx()), f)
+            }
+
+            fn core_is_rectangular(&self) -> bool {
+                true
+            }
+        }
+
+        impl<T, V> NVec<$dim, T> for BroadcastVec<$dim, T, V>
+        where
+            V: NVec<<$dim as Dim>::PrevDim, T>,
+        {
+            #[inline(always)]
+            fn at(&self, idx: impl IntoIdx<$dim>) -> T {
+                self.vec.at(self.src_idx(idx.into_idx()))
+            }
+
+            fn child(&self, i: <$dim as Dim>::ChildIdx) -> impl NVec<<$dim as Dim>::PrevDim, T> {
+                $child {
+                    i,
+                    parent: self,
+                    phantom: Default::default(),
+                }
+            }
+
+            fn all(&self) -> impl Iterator<Item = T> {
+                $indices(self).map(|idx| self.at(idx))
+            }
+        }
+    };
+}
+
+impl_broadcast!(D2, RectangularCardD2, ChildD2D1, indices_d2);
+impl_broadcast!(D3, RectangularCardD3, ChildD3D2, indices_d3);
+impl_broadcast!(D4, RectangularCardD4, ChildD4D3, indices_d4);
+impl_broadcast!(D5, RectangularCardD5, ChildD5D4, indices_d5);
+impl_broadcast!(D6, RectangularCardD6, ChildD6D5, indices_d6);
@@ -0,0 +1,7 @@
+mod as_broadcast;
+mod broadcast_vec;
+mod impl_into_cached;
+mod impl_nvec;
+
+pub use as_broadcast::{Broadcast, V1Broadcast};
+pub use broadcast_vec::BroadcastVec;
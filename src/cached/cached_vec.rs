@@ -137,3 +137,5 @@ impl_debug!(D1, dbg_values_d1);
 impl_debug!(D2, dbg_values_d2);
 impl_debug!(D3, dbg_values_d3);
 impl_debug!(D4, dbg_values_d4);
+impl_debug!(D5, dbg_values_d5);
+impl_debug!(D6, dbg_values_d6);
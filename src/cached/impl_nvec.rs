@@ -1,5 +1,8 @@
 use super::{cache::Cache, cached_vec::CachedVec};
-use crate::{CardD1, Dim, FunVec, IntoIdx, NVec, VariableCardD2, VariableCardD3, D1, D2, D3, D4};
+use crate::{
+    CardD1, Dim, FunVec, IntoIdx, NVec, VariableCardD2, VariableCardD3, VariableCardD4,
+    VariableCardD5, D1, D2, D3, D4, D5, D6,
+};
 
 // D1
 
@@ -127,3 +130,111 @@ where
         })
     }
 }
+
+// D5
+
+impl<T, V, C> NVec<D5, T> for CachedVec<D5, T, V, C>
+where
+    V: NVec<D5, T>,
+    C: Cache<<D5 as Dim>::Idx, T>,
+    T: Copy,
+{
+    #[inline(always)]
+    fn at(&self, idx: impl IntoIdx<D5>) -> T {
+        *unsafe { self.entry_or_insert_with(idx) }
+    }
+
+    fn child(&self, i: <D5 as Dim>::ChildIdx) -> impl NVec<<D5 as Dim>::PrevDim, T> {
+        let fun = move |idx: <<D5 as Dim>::PrevDim as Dim>::Idx| {
+            let idx = D5::left_join_from_lower_dim(i, idx);
+            self.at(idx)
+        };
+
+        let card1 = FunVec::new(
+            move |[j]| self.vec.card([i, j]),
+            CardD1::from(self.card([i])),
+        );
+
+        let card2 = FunVec::new(
+            move |[j, k]| self.vec.card([i, j, k]),
+            VariableCardD2::from(card1),
+        );
+
+        let card3 = FunVec::new(
+            move |[j, k, l]| self.vec.card([i, j, k, l]),
+            VariableCardD3::from(card2),
+        );
+
+        FunVec::new(fun, VariableCardD4::from(card3))
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        (0..self.num_children()).flat_map(move |i| {
+            (0..self.card([i])).flat_map(move |j| {
+                (0..self.card([i, j])).flat_map(move |k| {
+                    (0..self.card([i, j, k])).flat_map(move |l| {
+                        (0..self.card([i, j, k, l])).map(move |m| self.at([i, j, k, l, m]))
+                    })
+                })
+            })
+        })
+    }
+}
+
+// D6
+
+impl<T, V, C> NVec<D6, T> for CachedVec<D6, T, V, C>
+where
+    V: NVec<D6, T>,
+    C: Cache<<D6 as Dim>::Idx, T>,
+    T: Copy,
+{
+    #[inline(always)]
+    fn at(&self, idx: impl IntoIdx<D6>) -> T {
+        *unsafe { self.entry_or_insert_with(idx) }
+    }
+
+    fn child(&self, i: <D6 as Dim>::ChildIdx) -> impl NVec<<D6 as Dim>::PrevDim, T> {
+        let fun = move |idx: <<D6 as Dim>::PrevDim as Dim>::Idx| {
+            let idx = D6::left_join_from_lower_dim(i, idx);
+            self.at(idx)
+        };
+
+        let card1 = FunVec::new(
+            move |[j]| self.vec.card([i, j]),
+            CardD1::from(self.card([i])),
+        );
+
+        let card2 = FunVec::new(
+            move |[j, k]| self.vec.card([i, j, k]),
+            VariableCardD2::from(card1),
+        );
+
+        let card3 = FunVec::new(
+            move |[j, k, l]| self.vec.card([i, j, k, l]),
+            VariableCardD3::from(card2),
+        );
+
+        let card4 = FunVec::new(
+            move |[j, k, l, m]| self.vec.card([i, j, k, l, m]),
+            VariableCardD4::from(card3),
+        );
+
+        FunVec::new(fun, VariableCardD5::from(card4))
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        (0..self.num_children()).flat_map(move |i| {
+            (0..self.card([i])).flat_map(move |j| {
+                (0..self.card([i, j])).flat_map(move |k| {
+                    (0..self.card([i, j, k])).flat_map(move |l| {
+                        (0..self.card([i, j, k, l])).flat_map(move |m| {
+                            (0..self.card([i, j, k, l, m]))
+                                .map(move |n| self.at([i, j, k, l, m, n]))
+                        })
+                    })
+                })
+            })
+        })
+    }
+}
@@ -1,6 +1,7 @@
 use super::{cache::Cache, cached_vec::CachedVec};
 use crate::{
-    CardD1, Dim, FunVec, NVec, NVecCoreSealed, VariableCardD2, VariableCardD3, D1, D2, D3, D4,
+    CardD1, Dim, FunVec, NVec, NVecCoreSealed, VariableCardD2, VariableCardD3, VariableCardD4,
+    VariableCardD5, D1, D2, D3, D4, D5, D6,
 };
 
 // D1
@@ -159,3 +160,112 @@ where
         self.vec.core_is_rectangular()
     }
 }
+
+// D5
+
+impl<T, V, C> NVecCoreSealed<D5, T> for CachedVec<D5, T, V, C>
+where
+    V: NVec<D5, T>,
+    C: Cache<<D5 as Dim>::Idx, T>,
+    T: Copy,
+{
+    #[inline(always)]
+    fn core_num_children(&self) -> usize {
+        self.vec.num_children()
+    }
+
+    #[inline(always)]
+    fn core_card(&self, idx: impl Into<<D5 as Dim>::CardIdx>) -> usize {
+        self.vec.card(idx)
+    }
+
+    fn core_child(&self, i: <D5 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D5 as Dim>::PrevDim, T> {
+        let fun = move |idx: <<D5 as Dim>::PrevDim as Dim>::Idx| {
+            let idx = D5::left_join_from_lower_dim(i, idx);
+            self.at(idx)
+        };
+
+        let card1 = FunVec::new(
+            move |[j]| self.vec.card([i, j]),
+            CardD1::from(self.card([i])),
+        );
+
+        let card2 = FunVec::new(
+            move |[j, k]| self.vec.card([i, j, k]),
+            VariableCardD2::from(card1),
+        );
+
+        let card3 = FunVec::new(
+            move |[j, k, l]| self.vec.card([i, j, k, l]),
+            VariableCardD3::from(card2),
+        );
+
+        FunVec::new(fun, VariableCardD4::from(card3))
+    }
+
+    #[inline(always)]
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl crate::IntoIdx<D5>, f: &mut F) -> O {
+        f(&*unsafe { self.entry_or_insert_with(idx) })
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        self.vec.core_is_rectangular()
+    }
+}
+
+// D6
+
+impl<T, V, C> NVecCoreSealed<D6, T> for CachedVec<D6, T, V, C>
+where
+    V: NVec<D6, T>,
+    C: Cache<<D6 as Dim>::Idx, T>,
+    T: Copy,
+{
+    #[inline(always)]
+    fn core_num_children(&self) -> usize {
+        self.vec.num_children()
+    }
+
+    #[inline(always)]
+    fn core_card(&self, idx: impl Into<<D6 as Dim>::CardIdx>) -> usize {
+        self.vec.card(idx)
+    }
+
+    fn core_child(&self, i: <D6 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D6 as Dim>::PrevDim, T> {
+        let fun = move |idx: <<D6 as Dim>::PrevDim as Dim>::Idx| {
+            let idx = D6::left_join_from_lower_dim(i, idx);
+            self.at(idx)
+        };
+
+        let card1 = FunVec::new(
+            move |[j]| self.vec.card([i, j]),
+            CardD1::from(self.card([i])),
+        );
+
+        let card2 = FunVec::new(
+            move |[j, k]| self.vec.card([i, j, k]),
+            VariableCardD2::from(card1),
+        );
+
+        let card3 = FunVec::new(
+            move |[j, k, l]| self.vec.card([i, j, k, l]),
+            VariableCardD3::from(card2),
+        );
+
+        let card4 = FunVec::new(
+            move |[j, k, l, m]| self.vec.card([i, j, k, l, m]),
+            VariableCardD4::from(card3),
+        );
+
+        FunVec::new(fun, VariableCardD5::from(card4))
+    }
+
+    #[inline(always)]
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl crate::IntoIdx<D6>, f: &mut F) -> O {
+        f(&*unsafe { self.entry_or_insert_with(idx) })
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        self.vec.core_is_rectangular()
+    }
+}
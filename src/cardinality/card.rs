@@ -122,7 +122,7 @@ where
 pub(crate) fn panic_on_all_when_udd(is_udd: bool) {
     if is_udd {
         panic!(
-            "Called `all` or `enumerate_all` on an unbounded vector. \
+            "Called `all`, `enumerate_all` or `indices` on an unbounded vector. \
         You may use `all_in` to iterate over explicit indices. \
         Alternatively, you may transform the vector into a bounded vector by \
         calling `bounded`, `with_rectangular_bounds` or `with_variable_bounds` \
@@ -3,6 +3,7 @@ use crate::{Dim, NVec, D1};
 
 /// A cardinality of dimension `D1` vectors, which is simply a length.
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CardD1(pub(super) usize);
 
 impl From<usize> for CardD1 {
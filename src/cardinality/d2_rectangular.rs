@@ -8,6 +8,7 @@ use crate::{Dim, IdxLeqD1, NVec, D2};
 /// A rectangular cardinality of dimension `D2` vectors, which is equal to two
 /// lengths across each dimensions.
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RectangularCardD2 {
     pub(super) card_idx0: usize,
     pub(super) card_idx1: usize,
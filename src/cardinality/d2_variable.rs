@@ -7,6 +7,7 @@ use crate::{cardinality::d1::CardD1, Dim, IdxLeqD1, NVec, D2, V1};
 /// A variable cardinality of dimension `D2` vectors such that every child
 /// in every lower dimension can have different numbers of children.
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VariableCardD2<V: V1<usize>>(pub(super) V);
 
 impl<V: V1<usize>> From<V> for VariableCardD2<V> {
@@ -7,6 +7,7 @@ use crate::{cardinality::d2_rectangular::RectangularCardD2, Dim, IdxLeqD2, NVec,
 /// A rectangular cardinality of dimension `D3` vectors, which is equal to three
 /// lengths across each dimensions.
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RectangularCardD3 {
     pub(super) card_idx0: usize,
     pub(super) card_idx1: usize,
@@ -9,6 +9,7 @@ use crate::{
 /// A variable cardinality of dimension `D3` vectors such that every child
 /// in every lower dimension can have different numbers of children.
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VariableCardD3<V: V2<usize>>(pub(super) V);
 
 impl<V: V2<usize>> From<V> for VariableCardD3<V> {
@@ -7,11 +7,12 @@ use crate::{cardinality::d3_rectangular::RectangularCardD3, Dim, IdxLeqD3, NVec,
 /// A rectangular cardinality of dimension `D4` vectors, which is equal to four
 /// lengths across each dimensions.
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RectangularCardD4 {
-    card_idx0: usize,
-    card_idx1: usize,
-    card_idx2: usize,
-    card_idx3: usize,
+    pub(super) card_idx0: usize,
+    pub(super) card_idx1: usize,
+    pub(super) card_idx2: usize,
+    pub(super) card_idx3: usize,
 }
 
 impl From<[usize; 4]> for RectangularCardD4 {
@@ -7,7 +7,8 @@ use crate::{cardinality::d3_variable::VariableCardD3, Dim, FunVec, IdxLeqD3, NVe
 /// A variable cardinality of dimension `D4` vectors such that every child
 /// in every lower dimension can have different numbers of children.
 #[derive(Clone, Copy)]
-pub struct VariableCardD4<V: V3<usize>>(V);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VariableCardD4<V: V3<usize>>(pub(super) V);
 
 impl<V: V3<usize>> From<V> for VariableCardD4<V> {
     fn from(value: V) -> Self {
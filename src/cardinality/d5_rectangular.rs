@@ -0,0 +1,131 @@
+use super::{
+    card::{child_fun_unchecked, Card},
+    panic_d1, panic_d2, panic_d3, panic_d4,
+};
+use crate::{cardinality::d4_rectangular::RectangularCardD4, Dim, IdxLeqD4, NVec, D5};
+
+/// A rectangular cardinality of dimension `D5` vectors, which is equal to five
+/// lengths across each dimensions.
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RectangularCardD5 {
+    pub(super) card_idx0: usize,
+    pub(super) card_idx1: usize,
+    pub(super) card_idx2: usize,
+    pub(super) card_idx3: usize,
+    pub(super) card_idx4: usize,
+}
+
+impl From<[usize; 5]> for RectangularCardD5 {
+    fn from(value: [usize; 5]) -> Self {
+        Self {
+            card_idx0: value[0],
+            card_idx1: value[1],
+            card_idx2: value[2],
+            card_idx3: value[3],
+            card_idx4: value[4],
+        }
+    }
+}
+
+impl Card<D5> for RectangularCardD5 {
+    fn is_rectangular(&self) -> bool {
+        true
+    }
+
+    fn cardinality_of(&self, idx: impl Into<<D5 as Dim>::CardIdx>) -> usize {
+        match idx.into() {
+            IdxLeqD4::IdxD0([]) => self.card_idx0,
+            IdxLeqD4::IdxD1([i]) => match i < self.card_idx0 {
+                true => self.card_idx1,
+                false => panic_d1(i, self.card_idx0),
+            },
+            IdxLeqD4::IdxD2([i, j]) => match i < self.card_idx0 {
+                true => match j < self.card_idx1 {
+                    true => self.card_idx2,
+                    false => panic_d2(i, j, self.card_idx1),
+                },
+                false => panic_d1(i, self.card_idx0),
+            },
+            IdxLeqD4::IdxD3([i, j, k]) => match i < self.card_idx0 {
+                true => match j < self.card_idx1 {
+                    true => match k < self.card_idx2 {
+                        true => self.card_idx3,
+                        false => panic_d3(i, j, k, self.card_idx2),
+                    },
+                    false => panic_d2(i, j, self.card_idx1),
+                },
+                false => panic_d1(i, self.card_idx0),
+            },
+            IdxLeqD4::IdxD4([i, j, k, l]) => match i < self.card_idx0 {
+                true => match j < self.card_idx1 {
+                    true => match k < self.card_idx2 {
+                        true => match l < self.card_idx3 {
+                            true => self.card_idx4,
+                            false => panic_d4(i, j, k, l, self.card_idx3),
+                        },
+                        false => panic_d3(i, j, k, self.card_idx2),
+                    },
+                    false => panic_d2(i, j, self.card_idx1),
+                },
+                false => panic_d1(i, self.card_idx0),
+            },
+        }
+    }
+
+    fn child_card(&self, i: usize) -> impl Card<<D5 as Dim>::PrevDim> {
+        match i < self.card_idx0 {
+            true => RectangularCardD4 {
+                card_idx0: self.card_idx1,
+                card_idx1: self.card_idx2,
+                card_idx2: self.card_idx3,
+                card_idx3: self.card_idx4,
+            },
+            false => panic_d1(i, self.card_idx0),
+        }
+    }
+
+    fn child_fun<T, F>(&self, i: usize, fun: F) -> impl Fn(<<D5 as Dim>::PrevDim as Dim>::Idx) -> T
+    where
+        F: Fn(<D5 as Dim>::Idx) -> T,
+    {
+        match i < self.card_idx0 {
+            true => child_fun_unchecked::<D5, _, _>(i, fun),
+            false => panic_d1(i, self.card_idx0),
+        }
+    }
+
+    fn vec_all<'a, T, N>(&'a self, vec: &'a N) -> impl Iterator<Item = T>
+    where
+        N: NVec<D5, T> + 'a,
+    {
+        (0..self.cardinality_of([])).flat_map(move |i| {
+            (0..self.cardinality_of([i])).flat_map(move |j| {
+                (0..self.cardinality_of([i, j])).flat_map(move |k| {
+                    (0..self.cardinality_of([i, j, k])).flat_map(move |l| {
+                        (0..self.cardinality_of([i, j, k, l])).map(move |m| vec.at([i, j, k, l, m]))
+                    })
+                })
+            })
+        })
+    }
+
+    fn vec_enumerate_all<'a, T, N>(
+        &'a self,
+        vec: &'a N,
+    ) -> impl Iterator<Item = (<D5 as Dim>::Idx, T)>
+    where
+        N: NVec<D5, T> + 'a,
+    {
+        (0..self.cardinality_of([])).flat_map(move |i| {
+            (0..self.cardinality_of([i])).flat_map(move |j| {
+                (0..self.cardinality_of([i, j])).flat_map(move |k| {
+                    (0..self.cardinality_of([i, j, k])).flat_map(move |l| {
+                        (0..self.cardinality_of([i, j, k, l]))
+                            .map(move |m| ([i, j, k, l, m], vec.at([i, j, k, l, m])))
+                    })
+                })
+            })
+        })
+    }
+}
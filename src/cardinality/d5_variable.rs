@@ -0,0 +1,170 @@
+use super::{
+    card::{child_fun_unchecked, Card},
+    panic_d1, panic_d2, panic_d3, panic_d4, CardD1, VariableCardD2, VariableCardD3,
+};
+use crate::{cardinality::d4_variable::VariableCardD4, Dim, FunVec, IdxLeqD4, NVec, D5, V4};
+
+/// A variable cardinality of dimension `D5` vectors such that every child
+/// in every lower dimension can have different numbers of children.
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VariableCardD5<V: V4<usize>>(pub(super) V);
+
+impl<V: V4<usize>> From<V> for VariableCardD5<V> {
+    fn from(value: V) -> Self {
+        Self(value)
+    }
+}
+
+impl<V: V4<usize>> Card<D5> for VariableCardD5<V> {
+    fn is_rectangular(&self) -> bool {
+        let n0 = self.0.card([]);
+        let n1 = match n0 {
+            0 => 0,
+            _ => self.0.card([0]),
+        };
+        let n2 = match n1 {
+            0 => 0,
+            _ => self.0.card([0, 0]),
+        };
+        let n3 = match n2 {
+            0 => 0,
+            _ => self.0.card([0, 0, 0]),
+        };
+        let n4 = match n3 {
+            0 => 0,
+            _ => self.0.at([0, 0, 0, 0]),
+        };
+
+        for i in 0..n0 {
+            if self.0.card([i]) != n1 {
+                return false;
+            }
+
+            for j in 0..n1 {
+                if self.0.card([i, j]) != n2 {
+                    return false;
+                }
+
+                for k in 0..n2 {
+                    if self.0.card([i, j, k]) != n3 {
+                        return false;
+                    }
+
+                    for l in 0..n3 {
+                        if self.0.at([i, j, k, l]) != n4 {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    fn cardinality_of(&self, idx: impl Into<<D5 as Dim>::CardIdx>) -> usize {
+        match idx.into() {
+            IdxLeqD4::IdxD0([]) => self.0.card([]),
+            IdxLeqD4::IdxD1([i]) => match i < self.0.card([]) {
+                true => self.0.card([i]),
+                false => panic_d1(i, self.0.card([])),
+            },
+            IdxLeqD4::IdxD2([i, j]) => match i < self.0.card([]) {
+                true => match j < self.0.card([i]) {
+                    true => self.0.card([i, j]),
+                    false => panic_d2(i, j, self.0.card([i])),
+                },
+                false => panic_d1(i, self.0.card([])),
+            },
+            IdxLeqD4::IdxD3([i, j, k]) => match i < self.0.card([]) {
+                true => match j < self.0.card([i]) {
+                    true => match k < self.0.card([i, j]) {
+                        true => self.0.card([i, j, k]),
+                        false => panic_d3(i, j, k, self.0.card([i, j])),
+                    },
+                    false => panic_d2(i, j, self.0.card([i])),
+                },
+                false => panic_d1(i, self.0.card([])),
+            },
+            IdxLeqD4::IdxD4([i, j, k, l]) => match i < self.0.card([]) {
+                true => match j < self.0.card([i]) {
+                    true => match k < self.0.card([i, j]) {
+                        true => match l < self.0.card([i, j, k]) {
+                            true => self.0.at([i, j, k, l]),
+                            false => panic_d4(i, j, k, l, self.0.card([i, j, k])),
+                        },
+                        false => panic_d3(i, j, k, self.0.card([i, j])),
+                    },
+                    false => panic_d2(i, j, self.0.card([i])),
+                },
+                false => panic_d1(i, self.0.card([])),
+            },
+        }
+    }
+
+    fn child_card(&self, i: usize) -> impl Card<<D5 as Dim>::PrevDim> {
+        match i < self.0.card([]) {
+            true => {
+                let card_idx0 = FunVec::new(
+                    move |[j]: [usize; 1]| self.0.card([i, j]),
+                    CardD1::from(self.0.card([i])),
+                );
+                let card_idx1 = FunVec::new(
+                    move |[j, k]: [usize; 2]| self.0.card([i, j, k]),
+                    VariableCardD2::from(card_idx0),
+                );
+                let card_idx2 = FunVec::new(
+                    move |[j, k, l]: [usize; 3]| self.0.at([i, j, k, l]),
+                    VariableCardD3::from(card_idx1),
+                );
+                VariableCardD4(card_idx2)
+            }
+            false => panic_d1(i, self.0.card([])),
+        }
+    }
+
+    fn child_fun<T, F>(&self, i: usize, fun: F) -> impl Fn(<<D5 as Dim>::PrevDim as Dim>::Idx) -> T
+    where
+        F: Fn(<D5 as Dim>::Idx) -> T,
+    {
+        match i < self.0.card([]) {
+            true => child_fun_unchecked::<D5, _, _>(i, fun),
+            false => panic_d1(i, self.0.card([])),
+        }
+    }
+
+    fn vec_all<'a, T, N>(&'a self, vec: &'a N) -> impl Iterator<Item = T>
+    where
+        N: NVec<D5, T> + 'a,
+    {
+        (0..self.cardinality_of([])).flat_map(move |i| {
+            (0..self.cardinality_of([i])).flat_map(move |j| {
+                (0..self.cardinality_of([i, j])).flat_map(move |k| {
+                    (0..self.cardinality_of([i, j, k])).flat_map(move |l| {
+                        (0..self.cardinality_of([i, j, k, l])).map(move |m| vec.at([i, j, k, l, m]))
+                    })
+                })
+            })
+        })
+    }
+
+    fn vec_enumerate_all<'a, T, N>(
+        &'a self,
+        vec: &'a N,
+    ) -> impl Iterator<Item = (<D5 as Dim>::Idx, T)>
+    where
+        N: NVec<D5, T> + 'a,
+    {
+        (0..self.cardinality_of([])).flat_map(move |i| {
+            (0..self.cardinality_of([i])).flat_map(move |j| {
+                (0..self.cardinality_of([i, j])).flat_map(move |k| {
+                    (0..self.cardinality_of([i, j, k])).flat_map(move |l| {
+                        (0..self.cardinality_of([i, j, k, l]))
+                            .map(move |m| ([i, j, k, l, m], vec.at([i, j, k, l, m])))
+                    })
+                })
+            })
+        })
+    }
+}
@@ -13,7 +13,9 @@ use core::{fmt::Debug, marker::PhantomData};
 /// This cardinality is useful in providing a shorthand to create empty or default vectors
 /// of any type.
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EmptyCard<D: Dim> {
+    #[cfg_attr(feature = "serde", serde(skip))]
     phantom: PhantomData<D>,
 }
 
@@ -1,6 +1,8 @@
-use crate::{Dim, NVecCore, D1, D2, D3, D4};
+use crate::{Dim, NVecCore, D1, D2, D3, D4, D5, D6};
 
+/// Determines whether or not a vector of the dimension is rectangular.
 pub trait IsRectangular: Dim {
+    /// Returns whether or not the `vec` is rectangular.
     fn is_rectangular<T>(vec: &impl NVecCore<Self, T>) -> bool;
 }
 
@@ -113,3 +115,158 @@ impl IsRectangular for D4 {
         }
     }
 }
+
+impl IsRectangular for D5 {
+    fn is_rectangular<T>(vec: &impl NVecCore<Self, T>) -> bool {
+        let mut equal_n3 = None;
+        let mut equal_n4 = None;
+        let mut equal_n5 = None;
+        match vec.core_card([]) {
+            0 => true,
+            n1 => {
+                let n2 = vec.core_card([0]);
+                for i1 in 0..n1 {
+                    if vec.core_card([i1]) != n2 {
+                        return false;
+                    }
+
+                    if n2 > 0 {
+                        let n3 = match equal_n3 {
+                            None => {
+                                let n3 = vec.core_card([i1, 0]);
+                                equal_n3 = Some(n3);
+                                n3
+                            }
+                            Some(n3) => n3,
+                        };
+                        for i2 in 0..n2 {
+                            if vec.core_card([i1, i2]) != n3 {
+                                return false;
+                            }
+
+                            if n3 > 0 {
+                                let n4 = match equal_n4 {
+                                    None => {
+                                        let n4 = vec.core_card([i1, i2, 0]);
+                                        equal_n4 = Some(n4);
+                                        n4
+                                    }
+                                    Some(n4) => n4,
+                                };
+                                for i3 in 0..n3 {
+                                    if vec.core_card([i1, i2, i3]) != n4 {
+                                        return false;
+                                    }
+
+                                    if n4 > 0 {
+                                        let n5 = match equal_n5 {
+                                            None => {
+                                                let n5 = vec.core_card([i1, i2, i3, 0]);
+                                                equal_n5 = Some(n5);
+                                                n5
+                                            }
+                                            Some(n5) => n5,
+                                        };
+
+                                        for i4 in 0..n4 {
+                                            if vec.core_card([i1, i2, i3, i4]) != n5 {
+                                                return false;
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                true
+            }
+        }
+    }
+}
+
+impl IsRectangular for D6 {
+    fn is_rectangular<T>(vec: &impl NVecCore<Self, T>) -> bool {
+        let mut equal_n3 = None;
+        let mut equal_n4 = None;
+        let mut equal_n5 = None;
+        let mut equal_n6 = None;
+        match vec.core_card([]) {
+            0 => true,
+            n1 => {
+                let n2 = vec.core_card([0]);
+                for i1 in 0..n1 {
+                    if vec.core_card([i1]) != n2 {
+                        return false;
+                    }
+
+                    if n2 > 0 {
+                        let n3 = match equal_n3 {
+                            None => {
+                                let n3 = vec.core_card([i1, 0]);
+                                equal_n3 = Some(n3);
+                                n3
+                            }
+                            Some(n3) => n3,
+                        };
+                        for i2 in 0..n2 {
+                            if vec.core_card([i1, i2]) != n3 {
+                                return false;
+                            }
+
+                            if n3 > 0 {
+                                let n4 = match equal_n4 {
+                                    None => {
+                                        let n4 = vec.core_card([i1, i2, 0]);
+                                        equal_n4 = Some(n4);
+                                        n4
+                                    }
+                                    Some(n4) => n4,
+                                };
+                                for i3 in 0..n3 {
+                                    if vec.core_card([i1, i2, i3]) != n4 {
+                                        return false;
+                                    }
+
+                                    if n4 > 0 {
+                                        let n5 = match equal_n5 {
+                                            None => {
+                                                let n5 = vec.core_card([i1, i2, i3, 0]);
+                                                equal_n5 = Some(n5);
+                                                n5
+                                            }
+                                            Some(n5) => n5,
+                                        };
+                                        for i4 in 0..n4 {
+                                            if vec.core_card([i1, i2, i3, i4]) != n5 {
+                                                return false;
+                                            }
+
+                                            if n5 > 0 {
+                                                let n6 = match equal_n6 {
+                                                    None => {
+                                                        let n6 = vec.core_card([i1, i2, i3, i4, 0]);
+                                                        equal_n6 = Some(n6);
+                                                        n6
+                                                    }
+                                                    Some(n6) => n6,
+                                                };
+
+                                                for i5 in 0..n5 {
+                                                    if vec.core_card([i1, i2, i3, i4, i5]) != n6 {
+                                                        return false;
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                true
+            }
+        }
+    }
+}
@@ -6,6 +6,10 @@ mod d3_rectangular;
 mod d3_variable;
 mod d4_rectangular;
 mod d4_variable;
+mod d5_rectangular;
+mod d5_variable;
+mod d6_rectangular;
+mod d6_variable;
 mod empty_card;
 mod is_rectangular;
 mod udd;
@@ -35,6 +39,22 @@ fn panic_d3(i: usize, j: usize, k: usize, card_idx2: usize) -> ! {
     )
 }
 
+#[allow(clippy::panic)]
+fn panic_d4(i: usize, j: usize, k: usize, l: usize, card_idx3: usize) -> ! {
+    panic!(
+        "Required bound condition l < vec.card([i, j, k]) fails for l={} and vec.card([{}, {}, {}])={}",
+        l, i, j, k, card_idx3
+    )
+}
+
+#[allow(clippy::panic)]
+fn panic_d5(i: usize, j: usize, k: usize, l: usize, m: usize, card_idx4: usize) -> ! {
+    panic!(
+        "Required bound condition m < vec.card([i, j, k, l]) fails for m={} and vec.card([{}, {}, {}, {}])={}",
+        m, i, j, k, l, card_idx4
+    )
+}
+
 pub(crate) use card::panic_on_all_when_udd;
 pub(crate) use is_rectangular::IsRectangular;
 
@@ -46,5 +66,9 @@ pub use d3_rectangular::RectangularCardD3;
 pub use d3_variable::VariableCardD3;
 pub use d4_rectangular::RectangularCardD4;
 pub use d4_variable::VariableCardD4;
+pub use d5_rectangular::RectangularCardD5;
+pub use d5_variable::VariableCardD5;
+pub use d6_rectangular::RectangularCardD6;
+pub use d6_variable::VariableCardD6;
 pub use empty_card::EmptyCard;
 pub use udd::UnboundedCard;
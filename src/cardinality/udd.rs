@@ -32,7 +32,9 @@ use core::marker::PhantomData;
 /// [`sparse_from`]: crate::v::NewV1::sparse_from
 /// [`constant`]: crate::v::NewV1::constant
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnboundedCard<D: Dim> {
+    #[cfg_attr(feature = "serde", serde(skip))]
     phantom: PhantomData<D>,
 }
 
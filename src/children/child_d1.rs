@@ -1,5 +1,5 @@
 use crate::{cardinality::panic_on_all_when_udd, Dim, IntoIdx, NVec, D1, D2};
-use crate::{NVecCore, NVecCoreSealed, NVecMut, D3, D4};
+use crate::{NVecCore, NVecCoreSealed, NVecMut, D3, D4, D5, D6};
 use core::fmt::Debug;
 use core::marker::PhantomData;
 
@@ -340,3 +340,236 @@ where
         self.mut_all(|x| *x = value);
     }
 }
+
+// D5 -> D1
+pub struct ChildD5D1<V, T>
+where
+    V: NVecCore<D5, T>,
+{
+    pub(crate) parent: V,
+    pub(crate) i: usize,
+    pub(crate) j: usize,
+    pub(crate) k: usize,
+    pub(crate) l: usize,
+    pub(crate) phantom: PhantomData<T>,
+}
+
+impl<V, T> Debug for ChildD5D1<V, T>
+where
+    T: Debug,
+    V: NVecCore<D5, T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{{ kind: VecChild, dim: D1, is_bounded: {}, values: ",
+            self.parent.core_is_bounded(),
+        )?;
+        crate::common_trait_helpers::debug::dbg_values_d1(f, self)?;
+        write!(f, " }}")
+    }
+}
+
+impl<V, T> NVecCoreSealed<D1, T> for ChildD5D1<V, T>
+where
+    V: NVecCore<D5, T>,
+{
+    fn core_num_children(&self) -> usize {
+        self.parent.core_card([self.i, self.j, self.k, self.l])
+    }
+
+    #[inline(always)]
+    fn core_card(&self, _: impl Into<<D1 as Dim>::CardIdx>) -> usize {
+        self.parent.core_card([self.i, self.j, self.k, self.l])
+    }
+
+    fn core_child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D1 as Dim>::PrevDim, T> {
+        self
+    }
+
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D1>, f: &mut F) -> O {
+        let [m] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, self.l, m];
+        self.parent.core_map(idx, f)
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        true
+    }
+}
+
+impl<V, T> NVec<D1, T> for ChildD5D1<V, T>
+where
+    V: NVec<D5, T>,
+{
+    fn at(&self, idx: impl IntoIdx<D1>) -> T {
+        let [m] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, self.l, m];
+        self.parent.at(idx)
+    }
+
+    fn child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVec<<D1 as Dim>::PrevDim, T> {
+        self
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        panic_on_all_when_udd(!self.parent.core_is_bounded());
+        (0..self.core_num_children())
+            .map(move |m| self.parent.at([self.i, self.j, self.k, self.l, m]))
+    }
+}
+
+impl<V, T> NVecMut<D1, T> for ChildD5D1<V, T>
+where
+    V: NVecMut<D5, T>,
+{
+    fn at_mut<Idx: IntoIdx<D1>>(&mut self, idx: Idx) -> &mut T {
+        let [m] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, self.l, m];
+        self.parent.at_mut(idx)
+    }
+
+    fn set<Idx: IntoIdx<D1>>(&mut self, idx: Idx, value: T) {
+        let [m] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, self.l, m];
+        self.parent.set(idx, value);
+    }
+
+    fn child_mut(&mut self, _: <D1 as Dim>::ChildIdx) -> impl NVecMut<<D1 as Dim>::PrevDim, T> {
+        self
+    }
+
+    fn mut_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for m in 0..self.num_children() {
+            f(self.at_mut(m));
+        }
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        self.mut_all(|x| *x = value);
+    }
+}
+
+// D6 -> D1
+pub struct ChildD6D1<V, T>
+where
+    V: NVecCore<D6, T>,
+{
+    pub(crate) parent: V,
+    pub(crate) i: usize,
+    pub(crate) j: usize,
+    pub(crate) k: usize,
+    pub(crate) l: usize,
+    pub(crate) m: usize,
+    pub(crate) phantom: PhantomData<T>,
+}
+
+impl<V, T> Debug for ChildD6D1<V, T>
+where
+    T: Debug,
+    V: NVecCore<D6, T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{{ kind: VecChild, dim: D1, is_bounded: {}, values: ",
+            self.parent.core_is_bounded(),
+        )?;
+        crate::common_trait_helpers::debug::dbg_values_d1(f, self)?;
+        write!(f, " }}")
+    }
+}
+
+impl<V, T> NVecCoreSealed<D1, T> for ChildD6D1<V, T>
+where
+    V: NVecCore<D6, T>,
+{
+    fn core_num_children(&self) -> usize {
+        self.parent
+            .core_card([self.i, self.j, self.k, self.l, self.m])
+    }
+
+    #[inline(always)]
+    fn core_card(&self, _: impl Into<<D1 as Dim>::CardIdx>) -> usize {
+        self.parent
+            .core_card([self.i, self.j, self.k, self.l, self.m])
+    }
+
+    fn core_child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D1 as Dim>::PrevDim, T> {
+        self
+    }
+
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D1>, f: &mut F) -> O {
+        let [n] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, self.l, self.m, n];
+        self.parent.core_map(idx, f)
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        true
+    }
+}
+
+impl<V, T> NVec<D1, T> for ChildD6D1<V, T>
+where
+    V: NVec<D6, T>,
+{
+    fn at(&self, idx: impl IntoIdx<D1>) -> T {
+        let [n] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, self.l, self.m, n];
+        self.parent.at(idx)
+    }
+
+    fn child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVec<<D1 as Dim>::PrevDim, T> {
+        self
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        panic_on_all_when_udd(!self.parent.core_is_bounded());
+        (0..self.core_num_children())
+            .map(move |n| self.parent.at([self.i, self.j, self.k, self.l, self.m, n]))
+    }
+}
+
+impl<V, T> NVecMut<D1, T> for ChildD6D1<V, T>
+where
+    V: NVecMut<D6, T>,
+{
+    fn at_mut<Idx: IntoIdx<D1>>(&mut self, idx: Idx) -> &mut T {
+        let [n] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, self.l, self.m, n];
+        self.parent.at_mut(idx)
+    }
+
+    fn set<Idx: IntoIdx<D1>>(&mut self, idx: Idx, value: T) {
+        let [n] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, self.l, self.m, n];
+        self.parent.set(idx, value);
+    }
+
+    fn child_mut(&mut self, _: <D1 as Dim>::ChildIdx) -> impl NVecMut<<D1 as Dim>::PrevDim, T> {
+        self
+    }
+
+    fn mut_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for n in 0..self.num_children() {
+            f(self.at_mut(n));
+        }
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        self.mut_all(|x| *x = value);
+    }
+}
@@ -1,5 +1,6 @@
-use super::child_d1::{ChildD3D1, ChildD4D1};
-use crate::{cardinality::panic_on_all_when_udd, Dim, IdxLeqD1, IntoIdx, NVec, D2, D3, D4};
+use super::child_d1::{ChildD3D1, ChildD4D1, ChildD5D1, ChildD6D1};
+use crate::cardinality::{panic_on_all_when_udd, IsRectangular};
+use crate::{Dim, IdxLeqD1, IntoIdx, NVec, D2, D3, D4, D5, D6};
 use crate::{NVecCore, NVecCoreSealed, NVecMut};
 use core::fmt::Debug;
 use core::marker::PhantomData;
@@ -294,3 +295,289 @@ where
         self.mut_all(|x| *x = value);
     }
 }
+
+// D5 -> D2
+pub struct ChildD5D2<V, T>
+where
+    V: NVecCore<D5, T>,
+{
+    pub(crate) parent: V,
+    pub(crate) i: usize,
+    pub(crate) j: usize,
+    pub(crate) k: usize,
+    pub(crate) phantom: PhantomData<T>,
+}
+
+impl<V, T> Debug for ChildD5D2<V, T>
+where
+    T: Debug,
+    V: NVecCore<D5, T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{{ kind: VecChild, dim: D2, is_bounded: {}, values: ",
+            self.parent.core_is_bounded(),
+        )?;
+        crate::common_trait_helpers::debug::dbg_values_d2(f, self)?;
+        write!(f, " }}")
+    }
+}
+
+impl<V, T> NVecCoreSealed<D2, T> for ChildD5D2<V, T>
+where
+    V: NVecCore<D5, T>,
+{
+    fn core_num_children(&self) -> usize {
+        self.parent.core_card([self.i, self.j, self.k])
+    }
+
+    fn core_card(&self, idx: impl Into<<D2 as Dim>::CardIdx>) -> usize {
+        match idx.into() {
+            IdxLeqD1::IdxD0(_) => self.parent.core_card([self.i, self.j, self.k]),
+            IdxLeqD1::IdxD1([l]) => self.parent.core_card([self.i, self.j, self.k, l]),
+        }
+    }
+
+    fn core_child(&self, l: <D2 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D2 as Dim>::PrevDim, T> {
+        ChildD5D1::<_, T> {
+            i: self.i,
+            j: self.j,
+            k: self.k,
+            l,
+            parent: &self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D2>, f: &mut F) -> O {
+        let [l, m] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, l, m];
+        self.parent.core_map(idx, f)
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        <D2 as IsRectangular>::is_rectangular(self)
+    }
+}
+
+impl<V, T> NVec<D2, T> for ChildD5D2<V, T>
+where
+    V: NVec<D5, T>,
+{
+    fn at(&self, idx: impl IntoIdx<D2>) -> T {
+        let [l, m] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, l, m];
+        self.parent.at(idx)
+    }
+
+    fn child(&self, l: <D2 as Dim>::ChildIdx) -> impl NVec<<D2 as Dim>::PrevDim, T> {
+        ChildD5D1 {
+            i: self.i,
+            j: self.j,
+            k: self.k,
+            l,
+            parent: &self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        panic_on_all_when_udd(self.core_num_children() == usize::MAX);
+        (0..self.core_num_children()).flat_map(move |l| {
+            (0..self.parent.core_card([self.i, self.j, self.k, l]))
+                .map(move |m| self.parent.at([self.i, self.j, self.k, l, m]))
+        })
+    }
+}
+
+impl<V, T> NVecMut<D2, T> for ChildD5D2<V, T>
+where
+    V: NVecMut<D5, T>,
+{
+    fn at_mut<Idx: IntoIdx<D2>>(&mut self, idx: Idx) -> &mut T {
+        let [l, m] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, l, m];
+        self.parent.at_mut(idx)
+    }
+
+    fn set<Idx: IntoIdx<D2>>(&mut self, idx: Idx, value: T) {
+        let [l, m] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, l, m];
+        self.parent.set(idx, value);
+    }
+
+    fn child_mut(&mut self, l: <D2 as Dim>::ChildIdx) -> impl NVecMut<<D2 as Dim>::PrevDim, T> {
+        ChildD5D1 {
+            i: self.i,
+            j: self.j,
+            k: self.k,
+            l,
+            parent: &mut self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn mut_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for l in 0..self.num_children() {
+            for m in 0..self.card([l]) {
+                f(self.at_mut([l, m]));
+            }
+        }
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        self.mut_all(|x| *x = value);
+    }
+}
+
+// D6 -> D2
+pub struct ChildD6D2<V, T>
+where
+    V: NVecCore<D6, T>,
+{
+    pub(crate) parent: V,
+    pub(crate) i: usize,
+    pub(crate) j: usize,
+    pub(crate) k: usize,
+    pub(crate) l: usize,
+    pub(crate) phantom: PhantomData<T>,
+}
+
+impl<V, T> Debug for ChildD6D2<V, T>
+where
+    T: Debug,
+    V: NVecCore<D6, T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{{ kind: VecChild, dim: D2, is_bounded: {}, values: ",
+            self.parent.core_is_bounded(),
+        )?;
+        crate::common_trait_helpers::debug::dbg_values_d2(f, self)?;
+        write!(f, " }}")
+    }
+}
+
+impl<V, T> NVecCoreSealed<D2, T> for ChildD6D2<V, T>
+where
+    V: NVecCore<D6, T>,
+{
+    fn core_num_children(&self) -> usize {
+        self.parent.core_card([self.i, self.j, self.k, self.l])
+    }
+
+    fn core_card(&self, idx: impl Into<<D2 as Dim>::CardIdx>) -> usize {
+        match idx.into() {
+            IdxLeqD1::IdxD0(_) => self.parent.core_card([self.i, self.j, self.k, self.l]),
+            IdxLeqD1::IdxD1([m]) => self.parent.core_card([self.i, self.j, self.k, self.l, m]),
+        }
+    }
+
+    fn core_child(&self, m: <D2 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D2 as Dim>::PrevDim, T> {
+        ChildD6D1::<_, T> {
+            i: self.i,
+            j: self.j,
+            k: self.k,
+            l: self.l,
+            m,
+            parent: &self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D2>, f: &mut F) -> O {
+        let [m, n] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, self.l, m, n];
+        self.parent.core_map(idx, f)
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        <D2 as IsRectangular>::is_rectangular(self)
+    }
+}
+
+impl<V, T> NVec<D2, T> for ChildD6D2<V, T>
+where
+    V: NVec<D6, T>,
+{
+    fn at(&self, idx: impl IntoIdx<D2>) -> T {
+        let [m, n] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, self.l, m, n];
+        self.parent.at(idx)
+    }
+
+    fn child(&self, m: <D2 as Dim>::ChildIdx) -> impl NVec<<D2 as Dim>::PrevDim, T> {
+        ChildD6D1 {
+            i: self.i,
+            j: self.j,
+            k: self.k,
+            l: self.l,
+            m,
+            parent: &self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        panic_on_all_when_udd(self.core_num_children() == usize::MAX);
+        (0..self.core_num_children()).flat_map(move |m| {
+            (0..self.parent.core_card([self.i, self.j, self.k, self.l, m]))
+                .map(move |n| self.parent.at([self.i, self.j, self.k, self.l, m, n]))
+        })
+    }
+}
+
+impl<V, T> NVecMut<D2, T> for ChildD6D2<V, T>
+where
+    V: NVecMut<D6, T>,
+{
+    fn at_mut<Idx: IntoIdx<D2>>(&mut self, idx: Idx) -> &mut T {
+        let [m, n] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, self.l, m, n];
+        self.parent.at_mut(idx)
+    }
+
+    fn set<Idx: IntoIdx<D2>>(&mut self, idx: Idx, value: T) {
+        let [m, n] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, self.l, m, n];
+        self.parent.set(idx, value);
+    }
+
+    fn child_mut(&mut self, m: <D2 as Dim>::ChildIdx) -> impl NVecMut<<D2 as Dim>::PrevDim, T> {
+        ChildD6D1 {
+            i: self.i,
+            j: self.j,
+            k: self.k,
+            l: self.l,
+            m,
+            parent: &mut self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn mut_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for m in 0..self.num_children() {
+            for n in 0..self.card([m]) {
+                f(self.at_mut([m, n]));
+            }
+        }
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        self.mut_all(|x| *x = value);
+    }
+}
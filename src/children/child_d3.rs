@@ -1,5 +1,6 @@
-use super::child_d2::ChildD4D2;
-use crate::{cardinality::panic_on_all_when_udd, Dim, IdxLeqD2, IntoIdx, NVec, D3, D4};
+use super::child_d2::{ChildD4D2, ChildD5D2, ChildD6D2};
+use crate::cardinality::{panic_on_all_when_udd, IsRectangular};
+use crate::{Dim, IdxLeqD2, IntoIdx, NVec, D3, D4, D5, D6};
 use crate::{NVecCore, NVecCoreSealed, NVecMut};
 use core::fmt::Debug;
 use core::marker::PhantomData;
@@ -152,3 +153,291 @@ where
         self.mut_all(|x| *x = value);
     }
 }
+
+// D5 -> D3
+pub struct ChildD5D3<V, T>
+where
+    V: NVecCore<D5, T>,
+{
+    pub(crate) parent: V,
+    pub(crate) i: usize,
+    pub(crate) j: usize,
+    pub(crate) phantom: PhantomData<T>,
+}
+
+impl<V, T> Debug for ChildD5D3<V, T>
+where
+    T: Debug,
+    V: NVecCore<D5, T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{{ kind: VecChild, dim: D3, is_bounded: {}, values: ",
+            self.parent.core_is_bounded(),
+        )?;
+        crate::common_trait_helpers::debug::dbg_values_d3(f, self)?;
+        write!(f, " }}")
+    }
+}
+
+impl<V, T> NVecCoreSealed<D3, T> for ChildD5D3<V, T>
+where
+    V: NVecCore<D5, T>,
+{
+    fn core_num_children(&self) -> usize {
+        self.parent.core_card([self.i, self.j])
+    }
+
+    fn core_card(&self, idx: impl Into<<D3 as Dim>::CardIdx>) -> usize {
+        match idx.into() {
+            IdxLeqD2::IdxD0(_) => self.parent.core_card([self.i, self.j]),
+            IdxLeqD2::IdxD1([k]) => self.parent.core_card([self.i, self.j, k]),
+            IdxLeqD2::IdxD2([k, l]) => self.parent.core_card([self.i, self.j, k, l]),
+        }
+    }
+
+    fn core_child(&self, k: <D3 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D3 as Dim>::PrevDim, T> {
+        ChildD5D2::<_, T> {
+            i: self.i,
+            j: self.j,
+            k,
+            parent: &self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D3>, f: &mut F) -> O {
+        let [k, l, m] = idx.into_idx();
+        let idx = [self.i, self.j, k, l, m];
+        self.parent.core_map(idx, f)
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        <D3 as IsRectangular>::is_rectangular(self)
+    }
+}
+
+impl<V, T> NVec<D3, T> for ChildD5D3<V, T>
+where
+    V: NVec<D5, T>,
+{
+    fn at(&self, idx: impl IntoIdx<D3>) -> T {
+        let [k, l, m] = idx.into_idx();
+        let idx = [self.i, self.j, k, l, m];
+        self.parent.at(idx)
+    }
+
+    fn child(&self, k: <D3 as Dim>::ChildIdx) -> impl NVec<<D3 as Dim>::PrevDim, T> {
+        ChildD5D2 {
+            i: self.i,
+            j: self.j,
+            k,
+            parent: &self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        panic_on_all_when_udd(self.core_num_children() == usize::MAX);
+        (0..self.core_num_children()).flat_map(move |k| {
+            (0..self.parent.core_card([self.i, self.j, k])).flat_map(move |l| {
+                (0..self.parent.core_card([self.i, self.j, k, l]))
+                    .map(move |m| self.parent.at([self.i, self.j, k, l, m]))
+            })
+        })
+    }
+}
+
+impl<V, T> NVecMut<D3, T> for ChildD5D3<V, T>
+where
+    V: NVecMut<D5, T>,
+{
+    fn at_mut<Idx: IntoIdx<D3>>(&mut self, idx: Idx) -> &mut T {
+        let [k, l, m] = idx.into_idx();
+        let idx = [self.i, self.j, k, l, m];
+        self.parent.at_mut(idx)
+    }
+
+    fn set<Idx: IntoIdx<D3>>(&mut self, idx: Idx, value: T) {
+        let [k, l, m] = idx.into_idx();
+        let idx = [self.i, self.j, k, l, m];
+        self.parent.set(idx, value);
+    }
+
+    fn child_mut(&mut self, k: <D3 as Dim>::ChildIdx) -> impl NVecMut<<D3 as Dim>::PrevDim, T> {
+        ChildD5D2 {
+            i: self.i,
+            j: self.j,
+            k,
+            parent: &mut self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn mut_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for k in 0..self.num_children() {
+            for l in 0..self.card([k]) {
+                for m in 0..self.card([k, l]) {
+                    f(self.at_mut([k, l, m]));
+                }
+            }
+        }
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        self.mut_all(|x| *x = value);
+    }
+}
+
+// D6 -> D3
+pub struct ChildD6D3<V, T>
+where
+    V: NVecCore<D6, T>,
+{
+    pub(crate) parent: V,
+    pub(crate) i: usize,
+    pub(crate) j: usize,
+    pub(crate) k: usize,
+    pub(crate) phantom: PhantomData<T>,
+}
+
+impl<V, T> Debug for ChildD6D3<V, T>
+where
+    T: Debug,
+    V: NVecCore<D6, T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{{ kind: VecChild, dim: D3, is_bounded: {}, values: ",
+            self.parent.core_is_bounded(),
+        )?;
+        crate::common_trait_helpers::debug::dbg_values_d3(f, self)?;
+        write!(f, " }}")
+    }
+}
+
+impl<V, T> NVecCoreSealed<D3, T> for ChildD6D3<V, T>
+where
+    V: NVecCore<D6, T>,
+{
+    fn core_num_children(&self) -> usize {
+        self.parent.core_card([self.i, self.j, self.k])
+    }
+
+    fn core_card(&self, idx: impl Into<<D3 as Dim>::CardIdx>) -> usize {
+        match idx.into() {
+            IdxLeqD2::IdxD0(_) => self.parent.core_card([self.i, self.j, self.k]),
+            IdxLeqD2::IdxD1([l]) => self.parent.core_card([self.i, self.j, self.k, l]),
+            IdxLeqD2::IdxD2([l, m]) => self.parent.core_card([self.i, self.j, self.k, l, m]),
+        }
+    }
+
+    fn core_child(&self, l: <D3 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D3 as Dim>::PrevDim, T> {
+        ChildD6D2::<_, T> {
+            i: self.i,
+            j: self.j,
+            k: self.k,
+            l,
+            parent: &self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D3>, f: &mut F) -> O {
+        let [l, m, n] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, l, m, n];
+        self.parent.core_map(idx, f)
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        <D3 as IsRectangular>::is_rectangular(self)
+    }
+}
+
+impl<V, T> NVec<D3, T> for ChildD6D3<V, T>
+where
+    V: NVec<D6, T>,
+{
+    fn at(&self, idx: impl IntoIdx<D3>) -> T {
+        let [l, m, n] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, l, m, n];
+        self.parent.at(idx)
+    }
+
+    fn child(&self, l: <D3 as Dim>::ChildIdx) -> impl NVec<<D3 as Dim>::PrevDim, T> {
+        ChildD6D2 {
+            i: self.i,
+            j: self.j,
+            k: self.k,
+            l,
+            parent: &self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        panic_on_all_when_udd(self.core_num_children() == usize::MAX);
+        (0..self.core_num_children()).flat_map(move |l| {
+            (0..self.parent.core_card([self.i, self.j, self.k, l])).flat_map(move |m| {
+                (0..self.parent.core_card([self.i, self.j, self.k, l, m]))
+                    .map(move |n| self.parent.at([self.i, self.j, self.k, l, m, n]))
+            })
+        })
+    }
+}
+
+impl<V, T> NVecMut<D3, T> for ChildD6D3<V, T>
+where
+    V: NVecMut<D6, T>,
+{
+    fn at_mut<Idx: IntoIdx<D3>>(&mut self, idx: Idx) -> &mut T {
+        let [l, m, n] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, l, m, n];
+        self.parent.at_mut(idx)
+    }
+
+    fn set<Idx: IntoIdx<D3>>(&mut self, idx: Idx, value: T) {
+        let [l, m, n] = idx.into_idx();
+        let idx = [self.i, self.j, self.k, l, m, n];
+        self.parent.set(idx, value);
+    }
+
+    fn child_mut(&mut self, l: <D3 as Dim>::ChildIdx) -> impl NVecMut<<D3 as Dim>::PrevDim, T> {
+        ChildD6D2 {
+            i: self.i,
+            j: self.j,
+            k: self.k,
+            l,
+            parent: &mut self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn mut_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for l in 0..self.num_children() {
+            for m in 0..self.card([l]) {
+                for n in 0..self.card([l, m]) {
+                    f(self.at_mut([l, m, n]));
+                }
+            }
+        }
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        self.mut_all(|x| *x = value);
+    }
+}
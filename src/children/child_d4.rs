@@ -0,0 +1,296 @@
+use super::child_d3::{ChildD5D3, ChildD6D3};
+use crate::cardinality::{panic_on_all_when_udd, IsRectangular};
+use crate::{Dim, IdxLeqD3, IntoIdx, NVec, D4, D5, D6};
+use crate::{NVecCore, NVecCoreSealed, NVecMut};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+// D5 -> D4
+pub struct ChildD5D4<V, T>
+where
+    V: NVecCore<D5, T>,
+{
+    pub(crate) parent: V,
+    pub(crate) i: usize,
+    pub(crate) phantom: PhantomData<T>,
+}
+
+impl<V, T> Debug for ChildD5D4<V, T>
+where
+    T: Debug,
+    V: NVecCore<D5, T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{{ kind: VecChild, dim: D4, is_bounded: {}, values: ",
+            self.parent.core_is_bounded(),
+        )?;
+        crate::common_trait_helpers::debug::dbg_values_d4(f, self)?;
+        write!(f, " }}")
+    }
+}
+
+impl<V, T> NVecCoreSealed<D4, T> for ChildD5D4<V, T>
+where
+    V: NVecCore<D5, T>,
+{
+    fn core_num_children(&self) -> usize {
+        self.parent.core_card([self.i])
+    }
+
+    fn core_card(&self, idx: impl Into<<D4 as Dim>::CardIdx>) -> usize {
+        match idx.into() {
+            IdxLeqD3::IdxD0(_) => self.parent.core_card([self.i]),
+            IdxLeqD3::IdxD1([j]) => self.parent.core_card([self.i, j]),
+            IdxLeqD3::IdxD2([j, k]) => self.parent.core_card([self.i, j, k]),
+            IdxLeqD3::IdxD3([j, k, l]) => self.parent.core_card([self.i, j, k, l]),
+        }
+    }
+
+    fn core_child(&self, j: <D4 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D4 as Dim>::PrevDim, T> {
+        ChildD5D3::<_, T> {
+            i: self.i,
+            j,
+            parent: &self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D4>, f: &mut F) -> O {
+        let [j, k, l, m] = idx.into_idx();
+        let idx = [self.i, j, k, l, m];
+        self.parent.core_map(idx, f)
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        <D4 as IsRectangular>::is_rectangular(self)
+    }
+}
+
+impl<V, T> NVec<D4, T> for ChildD5D4<V, T>
+where
+    V: NVec<D5, T>,
+{
+    fn at(&self, idx: impl IntoIdx<D4>) -> T {
+        let [j, k, l, m] = idx.into_idx();
+        let idx = [self.i, j, k, l, m];
+        self.parent.at(idx)
+    }
+
+    fn child(&self, j: <D4 as Dim>::ChildIdx) -> impl NVec<<D4 as Dim>::PrevDim, T> {
+        ChildD5D3 {
+            i: self.i,
+            j,
+            parent: &self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        panic_on_all_when_udd(self.core_num_children() == usize::MAX);
+        (0..self.core_num_children()).flat_map(move |j| {
+            (0..self.parent.core_card([self.i, j])).flat_map(move |k| {
+                (0..self.parent.core_card([self.i, j, k])).flat_map(move |l| {
+                    (0..self.parent.core_card([self.i, j, k, l]))
+                        .map(move |m| self.parent.at([self.i, j, k, l, m]))
+                })
+            })
+        })
+    }
+}
+
+impl<V, T> NVecMut<D4, T> for ChildD5D4<V, T>
+where
+    V: NVecMut<D5, T>,
+{
+    fn at_mut<Idx: IntoIdx<D4>>(&mut self, idx: Idx) -> &mut T {
+        let [j, k, l, m] = idx.into_idx();
+        let idx = [self.i, j, k, l, m];
+        self.parent.at_mut(idx)
+    }
+
+    fn set<Idx: IntoIdx<D4>>(&mut self, idx: Idx, value: T) {
+        let [j, k, l, m] = idx.into_idx();
+        let idx = [self.i, j, k, l, m];
+        self.parent.set(idx, value);
+    }
+
+    fn child_mut(&mut self, j: <D4 as Dim>::ChildIdx) -> impl NVecMut<<D4 as Dim>::PrevDim, T> {
+        ChildD5D3 {
+            i: self.i,
+            j,
+            parent: &mut self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn mut_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for j in 0..self.num_children() {
+            for k in 0..self.card([j]) {
+                for l in 0..self.card([j, k]) {
+                    for m in 0..self.card([j, k, l]) {
+                        f(self.at_mut([j, k, l, m]));
+                    }
+                }
+            }
+        }
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        self.mut_all(|x| *x = value);
+    }
+}
+
+// D6 -> D4
+pub struct ChildD6D4<V, T>
+where
+    V: NVecCore<D6, T>,
+{
+    pub(crate) parent: V,
+    pub(crate) i: usize,
+    pub(crate) j: usize,
+    pub(crate) phantom: PhantomData<T>,
+}
+
+impl<V, T> Debug for ChildD6D4<V, T>
+where
+    T: Debug,
+    V: NVecCore<D6, T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{{ kind: VecChild, dim: D4, is_bounded: {}, values: ",
+            self.parent.core_is_bounded(),
+        )?;
+        crate::common_trait_helpers::debug::dbg_values_d4(f, self)?;
+        write!(f, " }}")
+    }
+}
+
+impl<V, T> NVecCoreSealed<D4, T> for ChildD6D4<V, T>
+where
+    V: NVecCore<D6, T>,
+{
+    fn core_num_children(&self) -> usize {
+        self.parent.core_card([self.i, self.j])
+    }
+
+    fn core_card(&self, idx: impl Into<<D4 as Dim>::CardIdx>) -> usize {
+        match idx.into() {
+            IdxLeqD3::IdxD0(_) => self.parent.core_card([self.i, self.j]),
+            IdxLeqD3::IdxD1([k]) => self.parent.core_card([self.i, self.j, k]),
+            IdxLeqD3::IdxD2([k, l]) => self.parent.core_card([self.i, self.j, k, l]),
+            IdxLeqD3::IdxD3([k, l, m]) => self.parent.core_card([self.i, self.j, k, l, m]),
+        }
+    }
+
+    fn core_child(&self, k: <D4 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D4 as Dim>::PrevDim, T> {
+        ChildD6D3::<_, T> {
+            i: self.i,
+            j: self.j,
+            k,
+            parent: &self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D4>, f: &mut F) -> O {
+        let [k, l, m, n] = idx.into_idx();
+        let idx = [self.i, self.j, k, l, m, n];
+        self.parent.core_map(idx, f)
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        <D4 as IsRectangular>::is_rectangular(self)
+    }
+}
+
+impl<V, T> NVec<D4, T> for ChildD6D4<V, T>
+where
+    V: NVec<D6, T>,
+{
+    fn at(&self, idx: impl IntoIdx<D4>) -> T {
+        let [k, l, m, n] = idx.into_idx();
+        let idx = [self.i, self.j, k, l, m, n];
+        self.parent.at(idx)
+    }
+
+    fn child(&self, k: <D4 as Dim>::ChildIdx) -> impl NVec<<D4 as Dim>::PrevDim, T> {
+        ChildD6D3 {
+            i: self.i,
+            j: self.j,
+            k,
+            parent: &self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        panic_on_all_when_udd(self.core_num_children() == usize::MAX);
+        (0..self.core_num_children()).flat_map(move |k| {
+            (0..self.parent.core_card([self.i, self.j, k])).flat_map(move |l| {
+                (0..self.parent.core_card([self.i, self.j, k, l])).flat_map(move |m| {
+                    (0..self.parent.core_card([self.i, self.j, k, l, m]))
+                        .map(move |n| self.parent.at([self.i, self.j, k, l, m, n]))
+                })
+            })
+        })
+    }
+}
+
+impl<V, T> NVecMut<D4, T> for ChildD6D4<V, T>
+where
+    V: NVecMut<D6, T>,
+{
+    fn at_mut<Idx: IntoIdx<D4>>(&mut self, idx: Idx) -> &mut T {
+        let [k, l, m, n] = idx.into_idx();
+        let idx = [self.i, self.j, k, l, m, n];
+        self.parent.at_mut(idx)
+    }
+
+    fn set<Idx: IntoIdx<D4>>(&mut self, idx: Idx, value: T) {
+        let [k, l, m, n] = idx.into_idx();
+        let idx = [self.i, self.j, k, l, m, n];
+        self.parent.set(idx, value);
+    }
+
+    fn child_mut(&mut self, k: <D4 as Dim>::ChildIdx) -> impl NVecMut<<D4 as Dim>::PrevDim, T> {
+        ChildD6D3 {
+            i: self.i,
+            j: self.j,
+            k,
+            parent: &mut self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn mut_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for k in 0..self.num_children() {
+            for l in 0..self.card([k]) {
+                for m in 0..self.card([k, l]) {
+                    for n in 0..self.card([k, l, m]) {
+                        f(self.at_mut([k, l, m, n]));
+                    }
+                }
+            }
+        }
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        self.mut_all(|x| *x = value);
+    }
+}
@@ -0,0 +1,154 @@
+use super::child_d4::ChildD6D4;
+use crate::cardinality::{panic_on_all_when_udd, IsRectangular};
+use crate::{Dim, IdxLeqD4, IntoIdx, NVec, D5, D6};
+use crate::{NVecCore, NVecCoreSealed, NVecMut};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+// D6 -> D5
+pub struct ChildD6D5<V, T>
+where
+    V: NVecCore<D6, T>,
+{
+    pub(crate) parent: V,
+    pub(crate) i: usize,
+    pub(crate) phantom: PhantomData<T>,
+}
+
+impl<V, T> Debug for ChildD6D5<V, T>
+where
+    T: Debug,
+    V: NVecCore<D6, T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{{ kind: VecChild, dim: D5, is_bounded: {}, values: ",
+            self.parent.core_is_bounded(),
+        )?;
+        crate::common_trait_helpers::debug::dbg_values_d5(f, self)?;
+        write!(f, " }}")
+    }
+}
+
+impl<V, T> NVecCoreSealed<D5, T> for ChildD6D5<V, T>
+where
+    V: NVecCore<D6, T>,
+{
+    fn core_num_children(&self) -> usize {
+        self.parent.core_card([self.i])
+    }
+
+    fn core_card(&self, idx: impl Into<<D5 as Dim>::CardIdx>) -> usize {
+        match idx.into() {
+            IdxLeqD4::IdxD0(_) => self.parent.core_card([self.i]),
+            IdxLeqD4::IdxD1([j]) => self.parent.core_card([self.i, j]),
+            IdxLeqD4::IdxD2([j, k]) => self.parent.core_card([self.i, j, k]),
+            IdxLeqD4::IdxD3([j, k, l]) => self.parent.core_card([self.i, j, k, l]),
+            IdxLeqD4::IdxD4([j, k, l, m]) => self.parent.core_card([self.i, j, k, l, m]),
+        }
+    }
+
+    fn core_child(&self, j: <D5 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D5 as Dim>::PrevDim, T> {
+        ChildD6D4::<_, T> {
+            i: self.i,
+            j,
+            parent: &self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D5>, f: &mut F) -> O {
+        let [j, k, l, m, n] = idx.into_idx();
+        let idx = [self.i, j, k, l, m, n];
+        self.parent.core_map(idx, f)
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        <D5 as IsRectangular>::is_rectangular(self)
+    }
+}
+
+impl<V, T> NVec<D5, T> for ChildD6D5<V, T>
+where
+    V: NVec<D6, T>,
+{
+    fn at(&self, idx: impl IntoIdx<D5>) -> T {
+        let [j, k, l, m, n] = idx.into_idx();
+        let idx = [self.i, j, k, l, m, n];
+        self.parent.at(idx)
+    }
+
+    fn child(&self, j: <D5 as Dim>::ChildIdx) -> impl NVec<<D5 as Dim>::PrevDim, T> {
+        ChildD6D4 {
+            i: self.i,
+            j,
+            parent: &self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        panic_on_all_when_udd(self.core_num_children() == usize::MAX);
+        (0..self.core_num_children()).flat_map(move |j| {
+            (0..self.parent.core_card([self.i, j])).flat_map(move |k| {
+                (0..self.parent.core_card([self.i, j, k])).flat_map(move |l| {
+                    (0..self.parent.core_card([self.i, j, k, l])).flat_map(move |m| {
+                        (0..self.parent.core_card([self.i, j, k, l, m]))
+                            .map(move |n| self.parent.at([self.i, j, k, l, m, n]))
+                    })
+                })
+            })
+        })
+    }
+}
+
+impl<V, T> NVecMut<D5, T> for ChildD6D5<V, T>
+where
+    V: NVecMut<D6, T>,
+{
+    fn at_mut<Idx: IntoIdx<D5>>(&mut self, idx: Idx) -> &mut T {
+        let [j, k, l, m, n] = idx.into_idx();
+        let idx = [self.i, j, k, l, m, n];
+        self.parent.at_mut(idx)
+    }
+
+    fn set<Idx: IntoIdx<D5>>(&mut self, idx: Idx, value: T) {
+        let [j, k, l, m, n] = idx.into_idx();
+        let idx = [self.i, j, k, l, m, n];
+        self.parent.set(idx, value);
+    }
+
+    fn child_mut(&mut self, j: <D5 as Dim>::ChildIdx) -> impl NVecMut<<D5 as Dim>::PrevDim, T> {
+        ChildD6D4 {
+            i: self.i,
+            j,
+            parent: &mut self.parent,
+            phantom: Default::default(),
+        }
+    }
+
+    fn mut_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for j in 0..self.num_children() {
+            for k in 0..self.card([j]) {
+                for l in 0..self.card([j, k]) {
+                    for m in 0..self.card([j, k, l]) {
+                        for n in 0..self.card([j, k, l, m]) {
+                            f(self.at_mut([j, k, l, m, n]));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        self.mut_all(|x| *x = value);
+    }
+}
@@ -1,7 +1,11 @@
 mod child_d1;
 mod child_d2;
 mod child_d3;
+mod child_d4;
+mod child_d5;
 
 pub(crate) use child_d1::ChildD2D1;
 pub(crate) use child_d2::ChildD3D2;
 pub(crate) use child_d3::ChildD4D3;
+pub(crate) use child_d4::ChildD5D4;
+pub(crate) use child_d5::ChildD6D5;
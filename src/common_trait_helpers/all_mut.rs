@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+
+/// Picks the mutable references yielded by `source` as `(idx, element)` pairs whose
+/// indices are among the requested `indices`, and returns them in the order of the
+/// `indices`.
+///
+/// The `source` can yield the pairs in any order. It is consumed only until all
+/// requested indices are found; and only the picked references are stored.
+///
+/// # Panics
+///
+/// Panics if any of the `indices` is absent in the `source` or requested more than once.
+pub(crate) fn take_in_order<'a, I, T>(
+    source: impl Iterator<Item = (I, &'a mut T)>,
+    indices: Vec<I>,
+) -> impl Iterator<Item = &'a mut T>
+where
+    I: Ord + Copy + core::fmt::Debug + 'a,
+    T: 'a,
+{
+    let mut requested: Vec<_> = indices.iter().copied().zip(0..).collect();
+    requested.sort_unstable();
+    if let Some(w) = requested.windows(2).find(|w| w[0].0 == w[1].0) {
+        panic_not_unique(w[0].0);
+    }
+
+    let mut slots: Vec<Option<&'a mut T>> = indices.iter().map(|_| None).collect();
+    let mut num_remaining = slots.len();
+    let mut source = source;
+    while num_remaining > 0 {
+        let Some((idx, x)) = source.next() else {
+            break;
+        };
+        if let Ok(r) = requested.binary_search_by(|(i, _)| i.cmp(&idx)) {
+            slots[requested[r].1] = Some(x);
+            num_remaining -= 1;
+        }
+    }
+
+    if let Some(p) = slots.iter().position(|x| x.is_none()) {
+        panic_out_of_bounds(indices[p]);
+    }
+
+    slots.into_iter().flatten()
+}
+
+#[allow(clippy::panic)]
+fn panic_not_unique<I: core::fmt::Debug>(idx: I) -> ! {
+    panic!(
+        "Element at index {:?} is referenced more than once; hence, mutable references to all requested elements cannot be created",
+        idx
+    )
+}
+
+#[allow(clippy::panic)]
+pub(crate) fn panic_out_of_bounds<I: core::fmt::Debug>(idx: I) -> ! {
+    panic!("Index {:?} is out of bounds of the vector", idx)
+}
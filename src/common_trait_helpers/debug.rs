@@ -1,4 +1,4 @@
-use crate::{NVecCore, D1, D2, D3, D4};
+use crate::{NVecCore, D1, D2, D3, D4, D5, D6};
 use core::fmt::Debug;
 
 // impl
@@ -11,6 +11,46 @@ fn view_len(n: usize) -> usize {
     }
 }
 
+pub(crate) fn dbg_values_d6<T: Debug, V: NVecCore<D6, T>>(
+    f: &mut core::fmt::Formatter<'_>,
+    vec: &V,
+) -> core::fmt::Result {
+    let n = vec.core_card([]);
+
+    write!(f, "[")?;
+    if n > 0 {
+        dbg_values_d5(f, &vec.core_child(0))?;
+    }
+    for i in 1..view_len(n) {
+        write!(f, ", ")?;
+        dbg_values_d5(f, &vec.core_child(i))?;
+    }
+    if n == usize::MAX {
+        write!(f, ", ..]")?;
+    }
+    write!(f, "]")
+}
+
+pub(crate) fn dbg_values_d5<T: Debug, V: NVecCore<D5, T>>(
+    f: &mut core::fmt::Formatter<'_>,
+    vec: &V,
+) -> core::fmt::Result {
+    let n = vec.core_card([]);
+
+    write!(f, "[")?;
+    if n > 0 {
+        dbg_values_d4(f, &vec.core_child(0))?;
+    }
+    for i in 1..view_len(n) {
+        write!(f, ", ")?;
+        dbg_values_d4(f, &vec.core_child(i))?;
+    }
+    if n == usize::MAX {
+        write!(f, ", ..]")?;
+    }
+    write!(f, "]")
+}
+
 pub(crate) fn dbg_values_d4<T: Debug, V: NVecCore<D4, T>>(
     f: &mut core::fmt::Formatter<'_>,
     vec: &V,
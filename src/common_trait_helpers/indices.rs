@@ -0,0 +1,97 @@
+use crate::{Dim, NVecCore, D1, D2, D3, D4, D5, D6};
+
+pub(crate) fn indices_d6<'a, T, V: NVecCore<D6, T>>(
+    vec: &'a V,
+) -> impl Iterator<Item = [usize; 6]> + 'a {
+    (0..vec.core_card([])).flat_map(move |i| {
+        (0..vec.core_card([i])).flat_map(move |j| {
+            (0..vec.core_card([i, j])).flat_map(move |k| {
+                (0..vec.core_card([i, j, k])).flat_map(move |l| {
+                    (0..vec.core_card([i, j, k, l])).flat_map(move |m| {
+                        (0..vec.core_card([i, j, k, l, m])).map(move |n| [i, j, k, l, m, n])
+                    })
+                })
+            })
+        })
+    })
+}
+
+pub(crate) fn indices_d5<'a, T, V: NVecCore<D5, T>>(
+    vec: &'a V,
+) -> impl Iterator<Item = [usize; 5]> + 'a {
+    (0..vec.core_card([])).flat_map(move |i| {
+        (0..vec.core_card([i])).flat_map(move |j| {
+            (0..vec.core_card([i, j])).flat_map(move |k| {
+                (0..vec.core_card([i, j, k])).flat_map(move |l| {
+                    (0..vec.core_card([i, j, k, l])).map(move |m| [i, j, k, l, m])
+                })
+            })
+        })
+    })
+}
+
+pub(crate) fn indices_d4<'a, T, V: NVecCore<D4, T>>(
+    vec: &'a V,
+) -> impl Iterator<Item = [usize; 4]> + 'a {
+    (0..vec.core_card([])).flat_map(move |i| {
+        (0..vec.core_card([i])).flat_map(move |j| {
+            (0..vec.core_card([i, j]))
+                .flat_map(move |k| (0..vec.core_card([i, j, k])).map(move |l| [i, j, k, l]))
+        })
+    })
+}
+
+pub(crate) fn indices_d3<'a, T, V: NVecCore<D3, T>>(
+    vec: &'a V,
+) -> impl Iterator<Item = [usize; 3]> + 'a {
+    (0..vec.core_card([])).flat_map(move |i| {
+        (0..vec.core_card([i]))
+            .flat_map(move |j| (0..vec.core_card([i, j])).map(move |k| [i, j, k]))
+    })
+}
+
+pub(crate) fn indices_d2<'a, T, V: NVecCore<D2, T>>(
+    vec: &'a V,
+) -> impl Iterator<Item = [usize; 2]> + 'a {
+    (0..vec.core_card([])).flat_map(move |i| (0..vec.core_card([i])).map(move |j| [i, j]))
+}
+
+pub(crate) fn indices_d1<'a, T, V: NVecCore<D1, T>>(
+    vec: &'a V,
+) -> impl Iterator<Item = [usize; 1]> + 'a {
+    (0..vec.core_card([])).map(move |i| [i])
+}
+
+/// Enumerates the indices of vectors of a particular dimension.
+///
+/// This is a sealed super trait of [`Dim`] which is only implemented for the
+/// dimensions of this crate; and hence, is not a part of the public api.
+pub trait DimIndices: Sized {
+    /// Returns an iterator of all indices of the given `vec` in lexicographic order.
+    ///
+    /// Note that the iterator never ends if the `vec` is unbounded.
+    fn indices<'a, T>(
+        vec: &'a impl NVecCore<Self, T>,
+    ) -> impl Iterator<Item = <Self as Dim>::Idx> + 'a
+    where
+        Self: Dim;
+}
+
+macro_rules! impl_dim_indices {
+    ($dim:tt, $indices:ident) => {
+        impl DimIndices for $dim {
+            fn indices<'a, T>(
+                vec: &'a impl NVecCore<Self, T>,
+            ) -> impl Iterator<Item = <Self as Dim>::Idx> + 'a {
+                $indices(vec)
+            }
+        }
+    };
+}
+
+impl_dim_indices!(D1, indices_d1);
+impl_dim_indices!(D2, indices_d2);
+impl_dim_indices!(D3, indices_d3);
+impl_dim_indices!(D4, indices_d4);
+impl_dim_indices!(D5, indices_d5);
+impl_dim_indices!(D6, indices_d6);
@@ -1 +1,8 @@
+pub(crate) mod all_mut;
 pub(crate) mod debug;
+pub(crate) mod indices;
+#[cfg(feature = "rayon")]
+pub(crate) mod par;
+pub(crate) mod shape;
+pub(crate) mod split_mut;
+pub(crate) mod unchecked;
@@ -0,0 +1,54 @@
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+/// Parallel access to the elements or children of one-dimensional storages;
+/// used to implement [`NVecPar`] of storage-backed vectors.
+///
+/// [`NVecPar`]: crate::NVecPar
+pub(crate) trait ParStorage: Sync {
+    type Item: Send + Sync;
+
+    /// Returns a parallel iterator over references to the elements of the storage.
+    fn par_storage(&self) -> impl IndexedParallelIterator<Item = &Self::Item>;
+}
+
+/// Parallel mutable access to the elements or children of one-dimensional storages;
+/// used to implement [`NVecParMut`] of storage-backed vectors.
+///
+/// [`NVecParMut`]: crate::NVecParMut
+pub(crate) trait ParStorageMut: ParStorage {
+    /// Returns a parallel iterator over mutable references to the elements of the storage.
+    fn par_storage_mut(&mut self) -> impl ParallelIterator<Item = &mut Self::Item>;
+}
+
+/// Implements `ParStorage` for a storage which dereferences to a slice of `$Item`.
+macro_rules! impl_par_storage {
+    ([$($impl_generics:tt)*], $V:ty, $Item:ty, [$($where:tt)*]) => {
+        impl<$($impl_generics)*> $crate::common_trait_helpers::par::ParStorage for $V
+        where
+            $($where)*
+        {
+            type Item = $Item;
+
+            fn par_storage(&self) -> impl rayon::iter::IndexedParallelIterator<Item = &Self::Item> {
+                <[$Item] as rayon::iter::IntoParallelRefIterator>::par_iter(self)
+            }
+        }
+    };
+}
+
+/// Implements `ParStorageMut` for a storage which mutably dereferences to a slice of `$Item`.
+macro_rules! impl_par_storage_mut {
+    ([$($impl_generics:tt)*], $V:ty, $Item:ty, [$($where:tt)*]) => {
+        impl<$($impl_generics)*> $crate::common_trait_helpers::par::ParStorageMut for $V
+        where
+            $($where)*
+        {
+            fn par_storage_mut(&mut self) -> impl rayon::iter::ParallelIterator<Item = &mut Self::Item> {
+                <[$Item] as rayon::iter::IntoParallelRefMutIterator>::par_iter_mut(self)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_par_storage;
+pub(crate) use impl_par_storage_mut;
@@ -0,0 +1,15 @@
+use crate::DynNVec;
+
+/// Returns the number of elements along each dimension of the rectangular `vec`
+/// with rank `N`; dimensions following an empty dimension are zero.
+pub(crate) fn rectangular_shape<const N: usize, T>(vec: &impl DynNVec<T>) -> [usize; N] {
+    let zeros = [0; N];
+    let mut shape = [0; N];
+    for m in 0..N {
+        shape[m] = match m > 0 && shape[m - 1] == 0 {
+            true => 0,
+            false => vec.card(&zeros[..m]),
+        };
+    }
+    shape
+}
@@ -0,0 +1,15 @@
+/// Splits the `data` into consecutive disjoint mutable slices ending at the given
+/// non-decreasing `ends`.
+pub(crate) fn split_at_ends_mut<'a, T>(
+    data: &'a mut [T],
+    ends: impl Iterator<Item = usize> + 'a,
+) -> impl Iterator<Item = &'a mut [T]> + 'a {
+    let mut rest = data;
+    let mut begin = 0;
+    ends.map(move |end| {
+        let (row, tail) = core::mem::take(&mut rest).split_at_mut(end - begin);
+        rest = tail;
+        begin = end;
+        row
+    })
+}
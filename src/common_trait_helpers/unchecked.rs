@@ -0,0 +1,62 @@
+/// Access to the elements or children of one-dimensional storages without bounds checks;
+/// used to implement [`at_unchecked`] of storage-backed vectors.
+///
+/// [`at_unchecked`]: crate::NVec::at_unchecked
+pub(crate) trait UncheckedStorage {
+    type Item;
+
+    /// Returns a reference to the `i`-th element of the storage.
+    ///
+    /// # Safety
+    ///
+    /// `i` must be less than the length of the storage.
+    unsafe fn unchecked_child(&self, i: usize) -> &Self::Item;
+}
+
+/// Mutable access to the elements or children of one-dimensional storages without bounds
+/// checks; used to implement [`at_mut_unchecked`] of storage-backed vectors.
+///
+/// [`at_mut_unchecked`]: crate::NVecMut::at_mut_unchecked
+pub(crate) trait UncheckedStorageMut: UncheckedStorage {
+    /// Returns a mutable reference to the `i`-th element of the storage.
+    ///
+    /// # Safety
+    ///
+    /// `i` must be less than the length of the storage.
+    unsafe fn unchecked_child_mut(&mut self, i: usize) -> &mut Self::Item;
+}
+
+/// Implements `UncheckedStorage` for a storage which dereferences to a slice of `$Item`.
+macro_rules! impl_unchecked_storage {
+    ([$($impl_generics:tt)*], $V:ty, $Item:ty, [$($where:tt)*]) => {
+        impl<$($impl_generics)*> $crate::common_trait_helpers::unchecked::UncheckedStorage for $V
+        where
+            $($where)*
+        {
+            type Item = $Item;
+
+            #[inline(always)]
+            unsafe fn unchecked_child(&self, i: usize) -> &Self::Item {
+                unsafe { <[$Item]>::get_unchecked(self, i) }
+            }
+        }
+    };
+}
+
+/// Implements `UncheckedStorageMut` for a storage which mutably dereferences to a slice of `$Item`.
+macro_rules! impl_unchecked_storage_mut {
+    ([$($impl_generics:tt)*], $V:ty, $Item:ty, [$($where:tt)*]) => {
+        impl<$($impl_generics)*> $crate::common_trait_helpers::unchecked::UncheckedStorageMut for $V
+        where
+            $($where)*
+        {
+            #[inline(always)]
+            unsafe fn unchecked_child_mut(&mut self, i: usize) -> &mut Self::Item {
+                unsafe { <[$Item]>::get_unchecked_mut(self, i) }
+            }
+        }
+    };
+}
+
+pub(crate) use impl_unchecked_storage;
+pub(crate) use impl_unchecked_storage_mut;
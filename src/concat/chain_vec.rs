@@ -0,0 +1,106 @@
+use crate::common_trait_helpers::debug::*;
+use crate::{NVec, D1};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// Creates a `D1` vector which is the concatenation of vectors `a` and `b`
+/// without copying their elements.
+///
+/// The chained vector has `a.card([]) + b.card([])` elements. Its `i`-th element
+/// is the `i`-th element of `a` if `i < a.card([])`; and the `(i - a.card([]))`-th
+/// element of `b` otherwise.
+///
+/// The chained vector is an [`NVecMut`] when both `a` and `b` are mutable.
+///
+/// [`NVecMut`]: crate::NVecMut
+///
+/// # Panics
+///
+/// Panics if `a` is unbounded.
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let depots = vec![0, 1];
+/// let customers = [7, 8, 9];
+///
+/// let nodes = chain(&depots, &customers);
+/// assert_eq!(nodes.card([]), 5);
+/// assert_eq!(nodes.at(3), 8);
+/// assert_eq!(nodes.all().collect::<Vec<_>>(), vec![0, 1, 7, 8, 9]);
+///
+/// // chains can be chained
+/// let nodes = chain(nodes, 100..102);
+/// assert_eq!(nodes.equality(&[0, 1, 7, 8, 9, 100, 101]), Equality::Equal);
+///
+/// // mutable chain
+/// let mut a = vec![0, 0];
+/// let mut b = vec![0];
+/// let mut ab = chain(&mut a, &mut b);
+/// ab.set(2, 3);
+/// ab.mut_all(|x| *x += 1);
+/// assert_eq!((a, b), (vec![1, 1], vec![4]));
+/// ```
+pub fn chain<T, A, B>(a: A, b: B) -> ChainVec<T, A, B>
+where
+    A: NVec<D1, T>,
+    B: NVec<D1, T>,
+{
+    ChainVec::new(a, b)
+}
+
+/// A `D1` vector which is the concatenation of two `D1` vectors.
+///
+/// It is created by the [`chain`] function.
+///
+/// [`chain`]: crate::chain
+pub struct ChainVec<T, A, B>
+where
+    A: NVec<D1, T>,
+    B: NVec<D1, T>,
+{
+    pub(super) a: A,
+    pub(super) b: B,
+    pub(super) len_a: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T, A, B> ChainVec<T, A, B>
+where
+    A: NVec<D1, T>,
+    B: NVec<D1, T>,
+{
+    fn new(a: A, b: B) -> Self {
+        assert!(
+            a.is_bounded(),
+            "The first vector of the chain must be bounded"
+        );
+        let len_a = a.card([]);
+        Self {
+            a,
+            b,
+            len_a,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Destructs the chained vector into its two parts.
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+}
+
+impl<T, A, B> Debug for ChainVec<T, A, B>
+where
+    A: NVec<D1, T>,
+    B: NVec<D1, T>,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{{ kind: ChainVec, dim: D1, values: ")?;
+        dbg_values_d1(f, self)?;
+        write!(f, " }}")
+    }
+}
@@ -0,0 +1,22 @@
+use super::{ChainVec, StackVec};
+use crate::{Dim, IntoCached, NVec, D1};
+use core::hash::Hash;
+
+impl<T, A, B> IntoCached<D1, T> for ChainVec<T, A, B>
+where
+    A: NVec<D1, T>,
+    B: NVec<D1, T>,
+    T: Copy,
+{
+}
+
+impl<D, E, T, V> IntoCached<E, T> for StackVec<D, T, V>
+where
+    D: Dim,
+    E: Dim,
+    V: NVec<D, T>,
+    T: Copy,
+    E::Idx: Ord + Hash,
+    Self: NVec<E, T>,
+{
+}
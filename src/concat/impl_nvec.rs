@@ -0,0 +1,145 @@
+use super::{ChainVec, StackVec};
+use crate::{dim::*, NVec, NVecAllMut, NVecCoreSealed, NVecMut};
+use crate::{impl_vn, impl_vn_card, impl_vn_mut};
+
+// chain
+
+impl<T, A, B> NVecCoreSealed<D1, T> for ChainVec<T, A, B>
+where
+    A: NVec<D1, T>,
+    B: NVec<D1, T>,
+{
+    #[inline(always)]
+    fn core_num_children(&self) -> usize {
+        self.len_a.saturating_add(self.b.card([]))
+    }
+
+    #[inline(always)]
+    fn core_card(&self, _: impl Into<<D1 as Dim>::CardIdx>) -> usize {
+        self.core_num_children()
+    }
+
+    fn core_child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D1 as Dim>::PrevDim, T> {
+        self
+    }
+
+    #[inline(always)]
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D1>, f: &mut F) -> O {
+        let [i] = idx.into_idx();
+        match i < self.len_a {
+            true => self.a.core_map([i], f),
+            false => self.b.core_map([i - self.len_a], f),
+        }
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        true
+    }
+}
+
+impl<T, A, B> NVec<D1, T> for ChainVec<T, A, B>
+where
+    A: NVec<D1, T>,
+    B: NVec<D1, T>,
+{
+    #[inline(always)]
+    fn at(&self, idx: impl IntoIdx<D1>) -> T {
+        let [i] = idx.into_idx();
+        match i < self.len_a {
+            true => self.a.at([i]),
+            false => self.b.at([i - self.len_a]),
+        }
+    }
+
+    fn child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVec<<D1 as Dim>::PrevDim, T> {
+        self
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        self.a.all().chain(self.b.all())
+    }
+}
+
+impl<T, A, B> NVecMut<D1, T> for ChainVec<T, A, B>
+where
+    A: NVecMut<D1, T>,
+    B: NVecMut<D1, T>,
+{
+    #[inline(always)]
+    fn at_mut<Idx: IntoIdx<D1>>(&mut self, idx: Idx) -> &mut T {
+        let [i] = idx.into_idx();
+        match i < self.len_a {
+            true => self.a.at_mut([i]),
+            false => self.b.at_mut([i - self.len_a]),
+        }
+    }
+
+    #[inline(always)]
+    fn set<Idx: IntoIdx<D1>>(&mut self, idx: Idx, value: T) {
+        let [i] = idx.into_idx();
+        match i < self.len_a {
+            true => self.a.set([i], value),
+            false => self.b.set([i - self.len_a], value),
+        }
+    }
+
+    fn child_mut(&mut self, _: <D1 as Dim>::ChildIdx) -> impl NVecMut<<D1 as Dim>::PrevDim, T> {
+        self
+    }
+
+    fn mut_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.a.mut_all(&mut f);
+        self.b.mut_all(f);
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        self.a.reset_all(value);
+        self.b.reset_all(value);
+    }
+}
+
+impl<T, A, B> NVecAllMut<D1, T> for ChainVec<T, A, B>
+where
+    A: NVecAllMut<D1, T>,
+    B: NVecAllMut<D1, T>,
+{
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        self.a.all_mut().chain(self.b.all_mut())
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D1 as Dim>::Idx, &'a mut T)>
+    where
+        T: 'a,
+    {
+        self.all_mut().enumerate().map(|(i, x)| ([i], x))
+    }
+}
+
+// stack
+
+impl_vn_card!(D2, [C, T], StackVec<D1, T, C>, [C: NVec<D1, T>]);
+impl_vn_card!(D3, [C, T], StackVec<D2, T, C>, [C: NVec<D2, T>]);
+impl_vn_card!(D4, [C, T], StackVec<D3, T, C>, [C: NVec<D3, T>]);
+impl_vn_card!(D5, [C, T], StackVec<D4, T, C>, [C: NVec<D4, T>]);
+impl_vn_card!(D6, [C, T], StackVec<D5, T, C>, [C: NVec<D5, T>]);
+
+impl_vn!(D2, [T, C], StackVec<D1, T, C>, [C: NVec<D1, T>]);
+impl_vn!(D3, [T, C], StackVec<D2, T, C>, [C: NVec<D2, T>]);
+impl_vn!(D4, [T, C], StackVec<D3, T, C>, [C: NVec<D3, T>]);
+impl_vn!(D5, [T, C], StackVec<D4, T, C>, [C: NVec<D4, T>]);
+impl_vn!(D6, [T, C], StackVec<D5, T, C>, [C: NVec<D5, T>]);
+
+impl_vn_mut!(D2, [T, C], StackVec<D1, T, C>, [C: NVecMut<D1, T>]);
+impl_vn_mut!(D3, [T, C], StackVec<D2, T, C>, [C: NVecMut<D2, T>]);
+impl_vn_mut!(D4, [T, C], StackVec<D3, T, C>, [C: NVecMut<D3, T>]);
+impl_vn_mut!(D5, [T, C], StackVec<D4, T, C>, [C: NVecMut<D4, T>]);
+impl_vn_mut!(D6, [T, C], StackVec<D5, T, C>, [C: NVecMut<D5, T>]);
@@ -0,0 +1,7 @@
+mod chain_vec;
+mod impl_into_cached;
+mod impl_nvec;
+mod stack_vec;
+
+pub use chain_vec::{chain, ChainVec};
+pub use stack_vec::{stack, StackVec};
@@ -0,0 +1,178 @@
+use crate::common_trait_helpers::debug::*;
+use crate::common_trait_helpers::unchecked::{UncheckedStorage, UncheckedStorageMut};
+use crate::{Dim, NVec, D1, D2, D3, D4, D5};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
+
+/// Creates a vector of one higher dimension by stacking the given `vecs` so that
+/// the `i`-th child of the result is the `i`-th input vector.
+///
+/// The elements of the inputs are not copied; the stacked vector only holds the
+/// inputs, which are often references to vectors. Therefore, stacking `D1` vectors
+/// results in a `D2` vector, stacking `D2` vectors results in a `D3` vector, and
+/// so on.
+///
+/// The stacked vector is an [`NVecMut`] when all inputs are mutable.
+///
+/// [`NVecMut`]: crate::NVecMut
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let from_file = vec![3, 4, 5];
+/// let from_db = [1, 2];
+///
+/// let nodes = stack([from_file.as_slice(), &from_db]);
+/// assert_eq!(nodes.card([]), 2);
+/// assert_eq!(nodes.card([0]), 3);
+/// assert_eq!(nodes.card([1]), 2);
+/// assert!(!nodes.is_rectangular());
+/// assert_eq!(nodes.at([1, 0]), 1);
+/// assert_eq!(nodes.all().sum::<i32>(), 15);
+///
+/// let mut a = vec![vec![0, 1], vec![2]];
+/// let mut b = vec![vec![3]];
+/// let mut v3 = stack([&mut a, &mut b]);
+/// assert_eq!(v3.card([0, 1]), 1);
+/// v3.set([1, 0, 0], 30);
+/// v3.child_mut(0).set([1, 0], 20);
+/// assert_eq!(a, vec![vec![0, 1], vec![20]]);
+/// assert_eq!(b, vec![vec![30]]);
+/// ```
+pub fn stack<D, T, V, I>(vecs: I) -> StackVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    I: IntoIterator<Item = V>,
+{
+    StackVec {
+        vecs: vecs.into_iter().collect(),
+        phantom: PhantomData,
+    }
+}
+
+/// A vector obtained by stacking vectors of dimension `D` such that its `i`-th
+/// child is the `i`-th stacked vector; hence, the stacked vector has one higher
+/// dimension than `D`.
+///
+/// It is created by the [`stack`] function.
+///
+/// [`stack`]: crate::stack
+pub struct StackVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D, T>,
+{
+    vecs: Vec<V>,
+    phantom: PhantomData<(D, T)>,
+}
+
+impl<D, T, V> StackVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D, T>,
+{
+    /// Number of stacked vectors.
+    pub fn len(&self) -> usize {
+        self.vecs.len()
+    }
+
+    /// Returns true if there are no stacked vectors.
+    pub fn is_empty(&self) -> bool {
+        self.vecs.is_empty()
+    }
+
+    /// Appends the vector `vec` to the end of the stack.
+    pub fn push(&mut self, vec: V) {
+        self.vecs.push(vec);
+    }
+
+    /// Destructs the stacked vector into the stacked vectors.
+    pub fn into_inner(self) -> Vec<V> {
+        self.vecs
+    }
+
+    pub(super) fn iter(&self) -> impl Iterator<Item = &V> {
+        self.vecs.iter()
+    }
+
+    pub(super) fn iter_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.vecs.iter_mut()
+    }
+}
+
+impl<D, T, V> Index<usize> for StackVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D, T>,
+{
+    type Output = V;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.vecs[index]
+    }
+}
+
+impl<D, T, V> IndexMut<usize> for StackVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D, T>,
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.vecs[index]
+    }
+}
+
+impl<D, T, V> UncheckedStorage for StackVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D, T>,
+{
+    type Item = V;
+
+    #[inline(always)]
+    unsafe fn unchecked_child(&self, i: usize) -> &Self::Item {
+        unsafe { self.vecs.get_unchecked(i) }
+    }
+}
+
+impl<D, T, V> UncheckedStorageMut for StackVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D, T>,
+{
+    #[inline(always)]
+    unsafe fn unchecked_child_mut(&mut self, i: usize) -> &mut Self::Item {
+        unsafe { self.vecs.get_unchecked_mut(i) }
+    }
+}
+
+macro_rules! impl_debug {
+    ($dim:ty, $dbg_fn:ident) => {
+        impl<T, V> Debug for StackVec<$dim, T, V>
+        where
+            V: NVec<$dim, T>,
+            T: Debug,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{{ kind: StackVec, dim: D{}, values: ",
+                    <$dim as Dim>::dimension() + 1
+                )?;
+                $dbg_fn(f, self)?;
+                write!(f, " }}")
+            }
+        }
+    };
+}
+
+impl_debug!(D1, dbg_values_d2);
+impl_debug!(D2, dbg_values_d3);
+impl_debug!(D3, dbg_values_d4);
+impl_debug!(D4, dbg_values_d5);
+impl_debug!(D5, dbg_values_d6);
@@ -1,7 +1,8 @@
 use crate::common_trait_helpers::debug::*;
 use crate::{
     dim::*, Card, CardD1, IntoIdx, NVec, NVecCoreSealed, RectangularCardD2, RectangularCardD3,
-    RectangularCardD4, UnboundedCard, VariableCardD2, VariableCardD3, VariableCardD4, V1, V2, V3,
+    RectangularCardD4, RectangularCardD5, RectangularCardD6, UnboundedCard, VariableCardD2,
+    VariableCardD3, VariableCardD4, VariableCardD5, VariableCardD6, V1, V2, V3, V4, V5,
 };
 use core::fmt::Debug;
 use core::marker::PhantomData;
@@ -30,6 +31,7 @@ use core::marker::PhantomData;
 /// );
 /// ```
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstantVec<D, T, C = UnboundedCard<D>>
 where
     D: Dim,
@@ -38,6 +40,7 @@ where
 {
     value: T,
     card: C,
+    #[cfg_attr(feature = "serde", serde(skip))]
     phantom: PhantomData<D>,
 }
 
@@ -66,6 +69,8 @@ impl_debug!(D1, dbg_values_d1);
 impl_debug!(D2, dbg_values_d2);
 impl_debug!(D3, dbg_values_d3);
 impl_debug!(D4, dbg_values_d4);
+impl_debug!(D5, dbg_values_d5);
+impl_debug!(D6, dbg_values_d6);
 
 impl<D, T, C> ConstantVec<D, T, C>
 where
@@ -464,3 +469,136 @@ where
         ConstantVec::new(self.value, cardinality.into())
     }
 }
+
+// D5
+
+impl<T> ConstantVec<D5, T, UnboundedCard<D5>>
+where
+    T: Copy,
+{
+    /// Transforms an unbounded constant vector into one with rectangular bounds as in
+    /// multi-dimensional matrices. The matrix has
+    /// `dimensions[0]` x `dimensions[1]` x `dimensions[2]` x `dimensions[3]` x `dimensions[4]` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v5 = V.d5().constant(42).with_rectangular_bounds([2, 1, 3, 1, 2]);
+    ///
+    /// assert!(v5.is_rectangular());
+    /// assert_eq!(v5.card([]), 2);
+    /// assert_eq!(v5.card([0, 0, 0, 0]), 2);
+    /// assert_eq!(v5.all().count(), 12);
+    /// assert_eq!(v5.at([0, 0, 0, 0, 0]), 42);
+    ///
+    /// assert_eq!(v5.try_at([7, 7, 7, 7, 7]), None);
+    /// ```
+    pub fn with_rectangular_bounds(
+        self,
+        dimensions: [usize; 5],
+    ) -> ConstantVec<D5, T, RectangularCardD5> {
+        ConstantVec::new(self.value, dimensions.into())
+    }
+
+    /// Transforms an unbounded constant vector into one with variable bounds as in
+    /// jagged arrays:
+    /// * the vector has `cardinality.card([])` children, and
+    /// * `[i]`-th child has `cardinality.card([i])` children, and
+    /// * `[i, j]`-th child has `cardinality.card([i, j])` children, and
+    /// * `[i, j, k]`-th child has `cardinality.card([i, j, k])` children, and
+    /// * `[i, j, k, l]`-th child has `cardinality.at([i, j, k, l])` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let cardinality = vec![vec![vec![vec![2, 3]]], vec![vec![vec![1]]]];
+    /// let v5 = V.d5().constant(42).with_variable_bounds(&cardinality);
+    ///
+    /// assert!(!v5.is_rectangular());
+    /// assert_eq!(v5.card([]), 2);
+    /// assert_eq!(v5.card([0, 0, 0]), 2);
+    /// assert_eq!(v5.card([0, 0, 0, 1]), 3);
+    /// assert_eq!(v5.card([1, 0, 0, 0]), 1);
+    /// assert_eq!(v5.all().count(), 6);
+    /// assert_eq!(v5.at([0, 0, 0, 0, 0]), 42);
+    ///
+    /// assert_eq!(v5.try_at([7, 7, 7, 7, 7]), None);
+    /// ```
+    pub fn with_variable_bounds<C>(self, cardinality: C) -> ConstantVec<D5, T, VariableCardD5<C>>
+    where
+        C: V4<usize>,
+    {
+        ConstantVec::new(self.value, cardinality.into())
+    }
+}
+
+// D6
+
+impl<T> ConstantVec<D6, T, UnboundedCard<D6>>
+where
+    T: Copy,
+{
+    /// Transforms an unbounded constant vector into one with rectangular bounds as in
+    /// multi-dimensional matrices. The matrix has
+    /// `dimensions[0]` x `dimensions[1]` x `dimensions[2]` x `dimensions[3]` x `dimensions[4]` x `dimensions[5]` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v6 = V.d6().constant(42).with_rectangular_bounds([2, 1, 3, 1, 2, 2]);
+    ///
+    /// assert!(v6.is_rectangular());
+    /// assert_eq!(v6.card([]), 2);
+    /// assert_eq!(v6.card([0, 0, 0, 0, 0]), 2);
+    /// assert_eq!(v6.all().count(), 24);
+    /// assert_eq!(v6.at([0, 0, 0, 0, 0, 0]), 42);
+    ///
+    /// assert_eq!(v6.try_at([7, 7, 7, 7, 7, 7]), None);
+    /// ```
+    pub fn with_rectangular_bounds(
+        self,
+        dimensions: [usize; 6],
+    ) -> ConstantVec<D6, T, RectangularCardD6> {
+        ConstantVec::new(self.value, dimensions.into())
+    }
+
+    /// Transforms an unbounded constant vector into one with variable bounds as in
+    /// jagged arrays:
+    /// * the vector has `cardinality.card([])` children, and
+    /// * `[i]`-th child has `cardinality.card([i])` children, and
+    /// * `[i, j]`-th child has `cardinality.card([i, j])` children, and
+    /// * `[i, j, k]`-th child has `cardinality.card([i, j, k])` children, and
+    /// * `[i, j, k, l]`-th child has `cardinality.card([i, j, k, l])` children, and
+    /// * `[i, j, k, l, m]`-th child has `cardinality.at([i, j, k, l, m])` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let cardinality = vec![vec![vec![vec![vec![2, 3]]]], vec![vec![vec![vec![1]]]]];
+    /// let v6 = V.d6().constant(42).with_variable_bounds(&cardinality);
+    ///
+    /// assert!(!v6.is_rectangular());
+    /// assert_eq!(v6.card([]), 2);
+    /// assert_eq!(v6.card([0, 0, 0, 0]), 2);
+    /// assert_eq!(v6.card([0, 0, 0, 0, 1]), 3);
+    /// assert_eq!(v6.card([1, 0, 0, 0, 0]), 1);
+    /// assert_eq!(v6.all().count(), 6);
+    /// assert_eq!(v6.at([0, 0, 0, 0, 0, 0]), 42);
+    ///
+    /// assert_eq!(v6.try_at([7, 7, 7, 7, 7, 7]), None);
+    /// ```
+    pub fn with_variable_bounds<C>(self, cardinality: C) -> ConstantVec<D6, T, VariableCardD6<C>>
+    where
+        C: V5<usize>,
+    {
+        ConstantVec::new(self.value, cardinality.into())
+    }
+}
@@ -0,0 +1,341 @@
+use crate::cardinality::IsRectangular;
+use crate::{CardIdx, Dim, IntoIdx, NVec, NVecCoreSealed, NVecMut, D1, D2, D3, D4, D5, D6};
+
+/// Extension point that allows types defined outside of this crate to become
+/// `D` dimensional vectors.
+///
+/// [`NVec`] requires the core functionalities which are sealed in this crate.
+/// Instead of implementing them directly, a custom type implements `CustomNVec<D, T>`
+/// and is wrapped in a [`CustomVec`], which implements [`NVec<D, T>`]. The core
+/// functionalities, such as `card`, `is_rectangular` or `equality`, are all derived
+/// from the `num_children`, `child` and `at` methods of this trait.
+///
+/// Further, if the custom type implements [`CustomNVecMut<D, T>`], the wrapper
+/// implements [`NVecMut<D, T>`].
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// /// A one dimensional vector defined by a list of chunks.
+/// struct Chunks {
+///     chunks: Vec<Vec<i32>>,
+///     chunk_len: usize,
+/// }
+///
+/// impl CustomNVec<D1, i32> for Chunks {
+///     fn custom_num_children(&self) -> usize {
+///         self.chunks.iter().map(|x| x.len()).sum()
+///     }
+///
+///     fn custom_at(&self, idx: impl IntoIdx<D1>) -> i32 {
+///         let [i] = idx.into_idx();
+///         self.chunks[i / self.chunk_len][i % self.chunk_len]
+///     }
+///
+///     fn custom_child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVec<<D1 as Dim>::PrevDim, i32> {
+///         CustomVec::new(self)
+///     }
+///
+///     fn custom_all(&self) -> impl Iterator<Item = i32> {
+///         self.chunks.iter().flat_map(|x| x.iter().copied())
+///     }
+/// }
+///
+/// let chunks = Chunks { chunks: vec![vec![0, 1, 2], vec![3, 4]], chunk_len: 3 };
+/// let chunks = CustomVec::new(chunks);
+///
+/// assert_eq!(chunks.card([]), 5);
+/// assert_eq!(chunks.at(3), 3);
+/// assert!(chunks.is_rectangular());
+/// assert_eq!(chunks.equality(&[0, 1, 2, 3, 4]), Equality::Equal);
+/// assert_eq!(chunks.try_at(5), None);
+/// ```
+///
+/// [`NVec`]: crate::NVec
+/// [`NVec<D, T>`]: crate::NVec
+/// [`NVecMut<D, T>`]: crate::NVecMut
+/// [`CustomVec`]: crate::CustomVec
+/// [`CustomNVecMut<D, T>`]: crate::CustomNVecMut
+pub trait CustomNVec<D: Dim, T> {
+    /// Returns the number of children of the vector; i.e., number of
+    /// elements of the one lower dimension.
+    ///
+    /// Cardinalities of all lower dimensions of the vector are derived from
+    /// this method and the cardinalities of the children.
+    fn custom_num_children(&self) -> usize;
+
+    /// Returns the element at the `idx`-th position of the vector.
+    fn custom_at(&self, idx: impl IntoIdx<D>) -> T;
+
+    /// Returns the `i`-th child of the vector.
+    ///
+    /// Note that the `D1` vectors never have a child; hence, the method is never called
+    /// and it is sufficient to return `CustomVec::new(self)`.
+    fn custom_child(&self, i: D::ChildIdx) -> impl NVec<D::PrevDim, T>;
+
+    /// Returns a flattened iterator over all scalar (D0) elements of the vector.
+    fn custom_all(&self) -> impl Iterator<Item = T>;
+}
+
+/// Extension point that allows types defined outside of this crate to become
+/// `D` dimensional mutable vectors.
+///
+/// A custom type implementing `CustomNVecMut<D, T>` and wrapped in a [`CustomVec`]
+/// implements [`NVecMut<D, T>`].
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// /// A two dimensional vector storing its rows in a single vec.
+/// struct Rows<T> {
+///     data: Vec<T>,
+///     num_cols: usize,
+/// }
+///
+/// impl<T: Copy> CustomNVec<D2, T> for Rows<T> {
+///     fn custom_num_children(&self) -> usize {
+///         self.data.len() / self.num_cols
+///     }
+///
+///     fn custom_at(&self, idx: impl IntoIdx<D2>) -> T {
+///         let [i, j] = idx.into_idx();
+///         self.data[i * self.num_cols + j]
+///     }
+///
+///     fn custom_child(&self, i: <D2 as Dim>::ChildIdx) -> impl NVec<<D2 as Dim>::PrevDim, T> {
+///         &self.data[i * self.num_cols..(i + 1) * self.num_cols]
+///     }
+///
+///     fn custom_all(&self) -> impl Iterator<Item = T> {
+///         self.data.iter().copied()
+///     }
+/// }
+///
+/// impl<T: Copy> CustomNVecMut<D2, T> for Rows<T> {
+///     fn custom_at_mut(&mut self, idx: impl IntoIdx<D2>) -> &mut T {
+///         let [i, j] = idx.into_idx();
+///         &mut self.data[i * self.num_cols + j]
+///     }
+///
+///     fn custom_child_mut(
+///         &mut self,
+///         i: <D2 as Dim>::ChildIdx,
+///     ) -> impl NVecMut<<D2 as Dim>::PrevDim, T> {
+///         &mut self.data[i * self.num_cols..(i + 1) * self.num_cols]
+///     }
+///
+///     fn custom_mut_all<F: FnMut(&mut T)>(&mut self, f: F) {
+///         self.data.iter_mut().for_each(f);
+///     }
+/// }
+///
+/// let mut rows = CustomVec::new(Rows { data: vec![0, 1, 2, 3, 4, 5], num_cols: 3 });
+/// assert_eq!(rows.card([]), 2);
+/// assert_eq!(rows.card([1]), 3);
+/// assert_eq!(rows.equality(&[[0, 1, 2], [3, 4, 5]]), Equality::Equal);
+///
+/// rows.set([1, 0], 42);
+/// rows.child_mut(0).set(2, 7);
+/// assert_eq!(rows.into_inner().data, vec![0, 1, 7, 42, 4, 5]);
+/// ```
+///
+/// [`CustomVec`]: crate::CustomVec
+/// [`NVecMut<D, T>`]: crate::NVecMut
+pub trait CustomNVecMut<D: Dim, T>: CustomNVec<D, T> {
+    /// Returns a mutable reference to the element at the `idx`-th position of the vector.
+    fn custom_at_mut(&mut self, idx: impl IntoIdx<D>) -> &mut T;
+
+    /// Returns the `i`-th child of the vector as a mutable vector.
+    ///
+    /// Note that the `D1` vectors never have a child; hence, the method is never called
+    /// and it is sufficient to return `CustomVec::new(self)`.
+    fn custom_child_mut(&mut self, i: D::ChildIdx) -> impl NVecMut<D::PrevDim, T>;
+
+    /// Applies the mutating function `f` over all scalar elements of the vector.
+    fn custom_mut_all<F: FnMut(&mut T)>(&mut self, f: F);
+}
+
+// &V and &mut V auto impl
+
+impl<D: Dim, T, V: CustomNVec<D, T>> CustomNVec<D, T> for &V {
+    fn custom_num_children(&self) -> usize {
+        <V as CustomNVec<D, T>>::custom_num_children(self)
+    }
+
+    fn custom_at(&self, idx: impl IntoIdx<D>) -> T {
+        <V as CustomNVec<D, T>>::custom_at(self, idx)
+    }
+
+    fn custom_child(&self, i: D::ChildIdx) -> impl NVec<D::PrevDim, T> {
+        <V as CustomNVec<D, T>>::custom_child(self, i)
+    }
+
+    fn custom_all(&self) -> impl Iterator<Item = T> {
+        <V as CustomNVec<D, T>>::custom_all(self)
+    }
+}
+
+impl<D: Dim, T, V: CustomNVec<D, T>> CustomNVec<D, T> for &mut V {
+    fn custom_num_children(&self) -> usize {
+        <V as CustomNVec<D, T>>::custom_num_children(self)
+    }
+
+    fn custom_at(&self, idx: impl IntoIdx<D>) -> T {
+        <V as CustomNVec<D, T>>::custom_at(self, idx)
+    }
+
+    fn custom_child(&self, i: D::ChildIdx) -> impl NVec<D::PrevDim, T> {
+        <V as CustomNVec<D, T>>::custom_child(self, i)
+    }
+
+    fn custom_all(&self) -> impl Iterator<Item = T> {
+        <V as CustomNVec<D, T>>::custom_all(self)
+    }
+}
+
+impl<D: Dim, T, V: CustomNVecMut<D, T>> CustomNVecMut<D, T> for &mut V {
+    fn custom_at_mut(&mut self, idx: impl IntoIdx<D>) -> &mut T {
+        <V as CustomNVecMut<D, T>>::custom_at_mut(self, idx)
+    }
+
+    fn custom_child_mut(&mut self, i: D::ChildIdx) -> impl NVecMut<D::PrevDim, T> {
+        <V as CustomNVecMut<D, T>>::custom_child_mut(self, i)
+    }
+
+    fn custom_mut_all<F: FnMut(&mut T)>(&mut self, f: F) {
+        <V as CustomNVecMut<D, T>>::custom_mut_all(self, f);
+    }
+}
+
+/// A vector wrapping a custom type `V` which implements [`CustomNVec<D, T>`],
+/// and hence, implementing [`NVec<D, T>`] for all such dimensions `D`.
+///
+/// Similarly, it implements [`NVecMut<D, T>`] whenever `V` implements
+/// [`CustomNVecMut<D, T>`].
+///
+/// See [`CustomNVec`] for an example.
+///
+/// [`CustomNVec`]: crate::CustomNVec
+/// [`CustomNVec<D, T>`]: crate::CustomNVec
+/// [`CustomNVecMut<D, T>`]: crate::CustomNVecMut
+/// [`NVec<D, T>`]: crate::NVec
+/// [`NVecMut<D, T>`]: crate::NVecMut
+#[derive(Clone, Copy, Debug)]
+pub struct CustomVec<V>(V);
+
+impl<V> CustomVec<V> {
+    /// Wraps the custom `vec` so that it can be used as a vector.
+    pub fn new(vec: V) -> Self {
+        Self(vec)
+    }
+
+    /// Returns a reference to the wrapped custom vector.
+    pub fn inner(&self) -> &V {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the wrapped custom vector.
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.0
+    }
+
+    /// Destructs the wrapper into the custom vector.
+    pub fn into_inner(self) -> V {
+        self.0
+    }
+}
+
+macro_rules! impl_custom_vec {
+    ($dim:ty) => {
+        impl<T, V> NVecCoreSealed<$dim, T> for CustomVec<V>
+        where
+            V: CustomNVec<$dim, T>,
+        {
+            #[inline(always)]
+            fn core_num_children(&self) -> usize {
+                self.0.custom_num_children()
+            }
+
+            #[inline(always)]
+            fn core_card(&self, idx: impl Into<<$dim as Dim>::CardIdx>) -> usize {
+                idx.into().card(self)
+            }
+
+            fn core_child(
+                &self,
+                i: <$dim as Dim>::ChildIdx,
+            ) -> impl NVecCoreSealed<<$dim as Dim>::PrevDim, T> {
+                self.0.custom_child(i)
+            }
+
+            #[inline(always)]
+            fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<$dim>, f: &mut F) -> O {
+                f(&self.0.custom_at(idx))
+            }
+
+            fn core_is_rectangular(&self) -> bool {
+                <$dim as IsRectangular>::is_rectangular(self)
+            }
+        }
+
+        impl<T, V> NVec<$dim, T> for CustomVec<V>
+        where
+            V: CustomNVec<$dim, T>,
+        {
+            #[inline(always)]
+            fn at(&self, idx: impl IntoIdx<$dim>) -> T {
+                self.0.custom_at(idx)
+            }
+
+            fn child(&self, i: <$dim as Dim>::ChildIdx) -> impl NVec<<$dim as Dim>::PrevDim, T> {
+                self.0.custom_child(i)
+            }
+
+            fn all(&self) -> impl Iterator<Item = T> {
+                self.0.custom_all()
+            }
+        }
+
+        impl<T, V> NVecMut<$dim, T> for CustomVec<V>
+        where
+            V: CustomNVecMut<$dim, T>,
+        {
+            #[inline(always)]
+            fn at_mut<Idx: IntoIdx<$dim>>(&mut self, idx: Idx) -> &mut T {
+                self.0.custom_at_mut(idx)
+            }
+
+            fn child_mut(
+                &mut self,
+                i: <$dim as Dim>::ChildIdx,
+            ) -> impl NVecMut<<$dim as Dim>::PrevDim, T> {
+                self.0.custom_child_mut(i)
+            }
+
+            fn mut_all<F>(&mut self, f: F)
+            where
+                F: FnMut(&mut T),
+            {
+                self.0.custom_mut_all(f);
+            }
+
+            fn reset_all(&mut self, value: T)
+            where
+                T: PartialEq + Copy,
+            {
+                self.0.custom_mut_all(|x| *x = value);
+            }
+        }
+    };
+}
+
+impl_custom_vec!(D1);
+impl_custom_vec!(D2);
+impl_custom_vec!(D3);
+impl_custom_vec!(D4);
+impl_custom_vec!(D5);
+impl_custom_vec!(D6);
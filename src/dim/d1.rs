@@ -1,4 +1,4 @@
-use super::{dimension::Dim, index_sums::IdxLeqD0, IdxLeqD1, IntoIdx};
+use super::{dimension::Dim, index_sums::IdxLeqD0, IdxLeqD1, IndexError, IntoIdx};
 use crate::NVecCore;
 use core::fmt::Debug;
 
@@ -55,4 +55,15 @@ impl Dim for D1 {
     fn in_bounds<T>(idx: impl IntoIdx<Self>, vec: &impl NVecCore<Self, T>) -> bool {
         idx.into_idx()[0] < vec.core_num_children()
     }
+
+    fn check_bounds<T>(
+        idx: impl IntoIdx<Self>,
+        vec: &impl NVecCore<Self, T>,
+    ) -> Result<(), IndexError<Self>> {
+        let [i] = idx.into_idx();
+        match vec.core_num_children() {
+            n if i < n => Ok(()),
+            n => Err(IndexError::new([].into(), i, n)),
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use super::{dimension::Dim, IdxLeqD0, IdxLeqD1, IdxLeqD2, IntoIdx, D1};
+use super::{dimension::Dim, IdxLeqD0, IdxLeqD1, IdxLeqD2, IndexError, IntoIdx, SplitIdx, D1};
 use crate::{NVecCore, NVecCoreSealed};
 use core::fmt::Debug;
 
@@ -44,4 +44,16 @@ impl Dim for D2 {
         let [i, j] = idx.into_idx();
         i < vec.core_num_children() && j < vec.core_child(i).core_num_children()
     }
+
+    fn check_bounds<T>(
+        idx: impl IntoIdx<Self>,
+        vec: &impl NVecCore<Self, T>,
+    ) -> Result<(), IndexError<Self>> {
+        let (i, lesser_idx) = idx.into_idx().split_idx();
+        match vec.core_num_children() {
+            n if i < n => <Self::PrevDim as Dim>::check_bounds(lesser_idx, &vec.core_child(i))
+                .map_err(|e| IndexError::from_child(i, e)),
+            n => Err(IndexError::new([].into(), i, n)),
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use super::{dimension::Dim, IdxLeqD1, IdxLeqD2, IdxLeqD3, IntoIdx, D2};
+use super::{dimension::Dim, IdxLeqD1, IdxLeqD2, IdxLeqD3, IndexError, IntoIdx, SplitIdx, D2};
 use crate::{NVecCore, NVecCoreSealed};
 use core::fmt::Debug;
 
@@ -54,4 +54,16 @@ impl Dim for D3 {
         }
         false
     }
+
+    fn check_bounds<T>(
+        idx: impl IntoIdx<Self>,
+        vec: &impl NVecCore<Self, T>,
+    ) -> Result<(), IndexError<Self>> {
+        let (i, lesser_idx) = idx.into_idx().split_idx();
+        match vec.core_num_children() {
+            n if i < n => <Self::PrevDim as Dim>::check_bounds(lesser_idx, &vec.core_child(i))
+                .map_err(|e| IndexError::from_child(i, e)),
+            n => Err(IndexError::new([].into(), i, n)),
+        }
+    }
 }
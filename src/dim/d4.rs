@@ -1,4 +1,4 @@
-use super::{dimension::Dim, IdxLeqD2, IdxLeqD3, IdxLeqD4, IntoIdx, D3};
+use super::{dimension::Dim, IdxLeqD2, IdxLeqD3, IdxLeqD4, IndexError, IntoIdx, SplitIdx, D3};
 use crate::{NVecCore, NVecCoreSealed};
 use core::fmt::Debug;
 
@@ -58,4 +58,16 @@ impl Dim for D4 {
         }
         false
     }
+
+    fn check_bounds<T>(
+        idx: impl IntoIdx<Self>,
+        vec: &impl NVecCore<Self, T>,
+    ) -> Result<(), IndexError<Self>> {
+        let (i, lesser_idx) = idx.into_idx().split_idx();
+        match vec.core_num_children() {
+            n if i < n => <Self::PrevDim as Dim>::check_bounds(lesser_idx, &vec.core_child(i))
+                .map_err(|e| IndexError::from_child(i, e)),
+            n => Err(IndexError::new([].into(), i, n)),
+        }
+    }
 }
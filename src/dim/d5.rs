@@ -0,0 +1,83 @@
+use super::{dimension::Dim, IdxLeqD3, IdxLeqD4, IdxLeqD5, IndexError, IntoIdx, SplitIdx, D4};
+use crate::{NVecCore, NVecCoreSealed};
+use core::fmt::Debug;
+
+/// Five dimensions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct D5;
+
+impl Dim for D5 {
+    type Idx = [usize; 5];
+
+    type ChildIdx = usize;
+
+    type PrevDim = D4;
+
+    type CardIdx = IdxLeqD4;
+
+    type LeqIdx = IdxLeqD5;
+
+    fn dimension() -> usize {
+        5
+    }
+
+    #[inline(always)]
+    fn left_join_from_lower_dim(
+        left_most_idx: usize,
+        lower_idx: <Self::PrevDim as Dim>::Idx,
+    ) -> Self::Idx {
+        [
+            left_most_idx,
+            lower_idx[0],
+            lower_idx[1],
+            lower_idx[2],
+            lower_idx[3],
+        ]
+    }
+
+    #[inline(always)]
+    fn left_join_from_lower_card_idx(
+        left_most_idx: usize,
+        lower_idx: <Self::PrevDim as Dim>::CardIdx,
+    ) -> Self::CardIdx {
+        match lower_idx {
+            IdxLeqD3::IdxD0([]) => Self::CardIdx::IdxD1([left_most_idx]),
+            IdxLeqD3::IdxD1([j]) => Self::CardIdx::IdxD2([left_most_idx, j]),
+            IdxLeqD3::IdxD2([j, k]) => Self::CardIdx::IdxD3([left_most_idx, j, k]),
+            IdxLeqD3::IdxD3([j, k, l]) => Self::CardIdx::IdxD4([left_most_idx, j, k, l]),
+        }
+    }
+
+    #[inline(always)]
+    fn in_bounds<T>(idx: impl IntoIdx<Self>, vec: &impl NVecCore<Self, T>) -> bool {
+        let [i, j, k, l, m] = idx.into_idx();
+        if i < vec.core_num_children() {
+            let child = vec.core_child(i);
+            if j < child.core_num_children() {
+                let child = child.core_child(j);
+                if k < child.core_num_children() {
+                    let child = child.core_child(k);
+                    if l < child.core_num_children() {
+                        let child = child.core_child(l);
+                        if m < child.core_num_children() {
+                            return true;
+                        }
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    fn check_bounds<T>(
+        idx: impl IntoIdx<Self>,
+        vec: &impl NVecCore<Self, T>,
+    ) -> Result<(), IndexError<Self>> {
+        let (i, lesser_idx) = idx.into_idx().split_idx();
+        match vec.core_num_children() {
+            n if i < n => <Self::PrevDim as Dim>::check_bounds(lesser_idx, &vec.core_child(i))
+                .map_err(|e| IndexError::from_child(i, e)),
+            n => Err(IndexError::new([].into(), i, n)),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use super::{dimension::Dim, IdxLeqD4, IdxLeqD5, IdxLeqD6, IndexError, IntoIdx, SplitIdx, D5};
+use crate::{NVecCore, NVecCoreSealed};
+use core::fmt::Debug;
+
+/// Six dimensions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct D6;
+
+impl Dim for D6 {
+    type Idx = [usize; 6];
+
+    type ChildIdx = usize;
+
+    type PrevDim = D5;
+
+    type CardIdx = IdxLeqD5;
+
+    type LeqIdx = IdxLeqD6;
+
+    fn dimension() -> usize {
+        6
+    }
+
+    #[inline(always)]
+    fn left_join_from_lower_dim(
+        left_most_idx: usize,
+        lower_idx: <Self::PrevDim as Dim>::Idx,
+    ) -> Self::Idx {
+        [
+            left_most_idx,
+            lower_idx[0],
+            lower_idx[1],
+            lower_idx[2],
+            lower_idx[3],
+            lower_idx[4],
+        ]
+    }
+
+    #[inline(always)]
+    fn left_join_from_lower_card_idx(
+        left_most_idx: usize,
+        lower_idx: <Self::PrevDim as Dim>::CardIdx,
+    ) -> Self::CardIdx {
+        match lower_idx {
+            IdxLeqD4::IdxD0([]) => Self::CardIdx::IdxD1([left_most_idx]),
+            IdxLeqD4::IdxD1([j]) => Self::CardIdx::IdxD2([left_most_idx, j]),
+            IdxLeqD4::IdxD2([j, k]) => Self::CardIdx::IdxD3([left_most_idx, j, k]),
+            IdxLeqD4::IdxD3([j, k, l]) => Self::CardIdx::IdxD4([left_most_idx, j, k, l]),
+            IdxLeqD4::IdxD4([j, k, l, m]) => Self::CardIdx::IdxD5([left_most_idx, j, k, l, m]),
+        }
+    }
+
+    #[inline(always)]
+    fn in_bounds<T>(idx: impl IntoIdx<Self>, vec: &impl NVecCore<Self, T>) -> bool {
+        let [i, j, k, l, m, n] = idx.into_idx();
+        if i < vec.core_num_children() {
+            let child = vec.core_child(i);
+            if j < child.core_num_children() {
+                let child = child.core_child(j);
+                if k < child.core_num_children() {
+                    let child = child.core_child(k);
+                    if l < child.core_num_children() {
+                        let child = child.core_child(l);
+                        if m < child.core_num_children() {
+                            let child = child.core_child(m);
+                            if n < child.core_num_children() {
+                                return true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    fn check_bounds<T>(
+        idx: impl IntoIdx<Self>,
+        vec: &impl NVecCore<Self, T>,
+    ) -> Result<(), IndexError<Self>> {
+        let (i, lesser_idx) = idx.into_idx().split_idx();
+        match vec.core_num_children() {
+            n if i < n => <Self::PrevDim as Dim>::check_bounds(lesser_idx, &vec.core_child(i))
+                .map_err(|e| IndexError::from_child(i, e)),
+            n => Err(IndexError::new([].into(), i, n)),
+        }
+    }
+}
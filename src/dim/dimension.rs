@@ -1,10 +1,11 @@
-use super::{index_card::CardIdx, IntoIdx, LeqIdx};
+use super::{index_card::CardIdx, IndexError, IntoIdx, LeqIdx};
+use crate::common_trait_helpers::indices::DimIndices;
 use crate::NVecCore;
 use core::fmt::Debug;
 
 /// Dimensionality of a structure,
 /// such as D1 for one-dimensional or D2 for two-dimensional structures.
-pub trait Dim: Sized + Copy + Debug + PartialEq + 'static {
+pub trait Dim: Sized + Copy + Debug + PartialEq + DimIndices + 'static {
     /// Index for this dimensionality.
     type Idx: Copy + Debug;
 
@@ -49,4 +50,11 @@ pub trait Dim: Sized + Copy + Debug + PartialEq + 'static {
 
     /// Returns whether or not the `idx` is in bounds for the given `vec`.
     fn in_bounds<T>(idx: impl IntoIdx<Self>, vec: &impl NVecCore<Self, T>) -> bool;
+
+    /// Returns Ok if the `idx` is in bounds for the given `vec`; returns the [`IndexError`]
+    /// pointing to the first axis that is out of bounds otherwise.
+    fn check_bounds<T>(
+        idx: impl IntoIdx<Self>,
+        vec: &impl NVecCore<Self, T>,
+    ) -> Result<(), IndexError<Self>>;
 }
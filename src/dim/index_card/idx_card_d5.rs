@@ -0,0 +1,90 @@
+use super::CardIdx;
+use crate::{dim::*, NVec, NVecCore, NVecCoreSealed};
+use index_card::{CardEquality, Equality};
+use index_sums::{IdxLeqD3, IdxLeqD4};
+
+impl CardIdx<D5> for IdxLeqD4 {
+    fn is_d0(&self) -> bool {
+        matches!(self, Self::IdxD0(_))
+    }
+
+    fn card<T>(self, vec: &impl NVecCore<D5, T>) -> usize {
+        match self {
+            Self::IdxD0(_) => vec.core_num_children(),
+            Self::IdxD1([i]) => vec.core_child(i).core_num_children(),
+            Self::IdxD2([i, j]) => vec.core_child(i).core_child(j).core_num_children(),
+            Self::IdxD3([i, j, k]) => vec
+                .core_child(i)
+                .core_child(j)
+                .core_child(k)
+                .core_num_children(),
+            Self::IdxD4([i, j, k, l]) => vec
+                .core_child(i)
+                .core_child(j)
+                .core_child(k)
+                .core_child(l)
+                .core_num_children(),
+        }
+    }
+
+    fn card_equality<T>(a: &impl NVecCore<D5, T>, b: &impl NVecCore<D5, T>) -> CardEquality<D5> {
+        match (a.core_num_children(), b.core_num_children()) {
+            (x, y) if x == y => {
+                let unequal = (0..x)
+                    .map(|i| {
+                        (
+                            i,
+                            IdxLeqD3::card_equality(&a.core_child(i), &b.core_child(i)),
+                        )
+                    })
+                    .find(|x| x.1 != CardEquality::Equal);
+
+                if let Some((i, CardEquality::Unequal(idx, x, y))) = unequal {
+                    return match idx {
+                        IdxLeqD3::IdxD0(_) => CardEquality::Unequal(Self::IdxD1([i]), x, y),
+                        IdxLeqD3::IdxD1([j]) => CardEquality::Unequal(Self::IdxD2([i, j]), x, y),
+                        IdxLeqD3::IdxD2([j, k]) => {
+                            CardEquality::Unequal(Self::IdxD3([i, j, k]), x, y)
+                        }
+                        IdxLeqD3::IdxD3([j, k, l]) => {
+                            CardEquality::Unequal(Self::IdxD4([i, j, k, l]), x, y)
+                        }
+                    };
+                }
+                CardEquality::Equal
+            }
+            (x, y) => CardEquality::Unequal(Self::IdxD0([]), x, y),
+        }
+    }
+
+    fn equality<T: PartialEq>(a: &impl NVec<D5, T>, b: &impl NVec<D5, T>) -> Equality<D5> {
+        match (a.core_num_children(), b.core_num_children()) {
+            (x, y) if x == y => {
+                for i in 0..x {
+                    match IdxLeqD3::equality(&a.child(i), &b.child(i)) {
+                        Equality::Equal => {}
+                        Equality::UnequalCard(idx, x, y) => match idx {
+                            IdxLeqD3::IdxD0(_) => {
+                                return Equality::UnequalCard(IdxLeqD4::IdxD1([i]), x, y);
+                            }
+                            IdxLeqD3::IdxD1([j]) => {
+                                return Equality::UnequalCard(IdxLeqD4::IdxD2([i, j]), x, y);
+                            }
+                            IdxLeqD3::IdxD2([j, k]) => {
+                                return Equality::UnequalCard(IdxLeqD4::IdxD3([i, j, k]), x, y);
+                            }
+                            IdxLeqD3::IdxD3([j, k, l]) => {
+                                return Equality::UnequalCard(IdxLeqD4::IdxD4([i, j, k, l]), x, y);
+                            }
+                        },
+                        Equality::UnequalValue([j, k, l, m]) => {
+                            return Equality::UnequalValue([i, j, k, l, m]);
+                        }
+                    }
+                }
+                Equality::Equal
+            }
+            (x, y) => Equality::UnequalCard(Self::IdxD0([]), x, y),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use super::CardIdx;
+use crate::{dim::*, NVec, NVecCore, NVecCoreSealed};
+use index_card::{CardEquality, Equality};
+use index_sums::{IdxLeqD4, IdxLeqD5};
+
+impl CardIdx<D6> for IdxLeqD5 {
+    fn is_d0(&self) -> bool {
+        matches!(self, Self::IdxD0(_))
+    }
+
+    fn card<T>(self, vec: &impl NVecCore<D6, T>) -> usize {
+        match self {
+            Self::IdxD0(_) => vec.core_num_children(),
+            Self::IdxD1([i]) => vec.core_child(i).core_num_children(),
+            Self::IdxD2([i, j]) => vec.core_child(i).core_child(j).core_num_children(),
+            Self::IdxD3([i, j, k]) => vec
+                .core_child(i)
+                .core_child(j)
+                .core_child(k)
+                .core_num_children(),
+            Self::IdxD4([i, j, k, l]) => vec
+                .core_child(i)
+                .core_child(j)
+                .core_child(k)
+                .core_child(l)
+                .core_num_children(),
+            Self::IdxD5([i, j, k, l, m]) => vec
+                .core_child(i)
+                .core_child(j)
+                .core_child(k)
+                .core_child(l)
+                .core_child(m)
+                .core_num_children(),
+        }
+    }
+
+    fn card_equality<T>(a: &impl NVecCore<D6, T>, b: &impl NVecCore<D6, T>) -> CardEquality<D6> {
+        match (a.core_num_children(), b.core_num_children()) {
+            (x, y) if x == y => {
+                let unequal = (0..x)
+                    .map(|i| {
+                        (
+                            i,
+                            IdxLeqD4::card_equality(&a.core_child(i), &b.core_child(i)),
+                        )
+                    })
+                    .find(|x| x.1 != CardEquality::Equal);
+
+                if let Some((i, CardEquality::Unequal(idx, x, y))) = unequal {
+                    return match idx {
+                        IdxLeqD4::IdxD0(_) => CardEquality::Unequal(Self::IdxD1([i]), x, y),
+                        IdxLeqD4::IdxD1([j]) => CardEquality::Unequal(Self::IdxD2([i, j]), x, y),
+                        IdxLeqD4::IdxD2([j, k]) => {
+                            CardEquality::Unequal(Self::IdxD3([i, j, k]), x, y)
+                        }
+                        IdxLeqD4::IdxD3([j, k, l]) => {
+                            CardEquality::Unequal(Self::IdxD4([i, j, k, l]), x, y)
+                        }
+                        IdxLeqD4::IdxD4([j, k, l, m]) => {
+                            CardEquality::Unequal(Self::IdxD5([i, j, k, l, m]), x, y)
+                        }
+                    };
+                }
+                CardEquality::Equal
+            }
+            (x, y) => CardEquality::Unequal(Self::IdxD0([]), x, y),
+        }
+    }
+
+    fn equality<T: PartialEq>(a: &impl NVec<D6, T>, b: &impl NVec<D6, T>) -> Equality<D6> {
+        match (a.core_num_children(), b.core_num_children()) {
+            (x, y) if x == y => {
+                for i in 0..x {
+                    match IdxLeqD4::equality(&a.child(i), &b.child(i)) {
+                        Equality::Equal => {}
+                        Equality::UnequalCard(idx, x, y) => match idx {
+                            IdxLeqD4::IdxD0(_) => {
+                                return Equality::UnequalCard(IdxLeqD5::IdxD1([i]), x, y);
+                            }
+                            IdxLeqD4::IdxD1([j]) => {
+                                return Equality::UnequalCard(IdxLeqD5::IdxD2([i, j]), x, y);
+                            }
+                            IdxLeqD4::IdxD2([j, k]) => {
+                                return Equality::UnequalCard(IdxLeqD5::IdxD3([i, j, k]), x, y);
+                            }
+                            IdxLeqD4::IdxD3([j, k, l]) => {
+                                return Equality::UnequalCard(IdxLeqD5::IdxD4([i, j, k, l]), x, y);
+                            }
+                            IdxLeqD4::IdxD4([j, k, l, m]) => {
+                                return Equality::UnequalCard(
+                                    IdxLeqD5::IdxD5([i, j, k, l, m]),
+                                    x,
+                                    y,
+                                );
+                            }
+                        },
+                        Equality::UnequalValue([j, k, l, m, n]) => {
+                            return Equality::UnequalValue([i, j, k, l, m, n]);
+                        }
+                    }
+                }
+                Equality::Equal
+            }
+            (x, y) => Equality::UnequalCard(Self::IdxD0([]), x, y),
+        }
+    }
+}
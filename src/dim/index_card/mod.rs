@@ -5,6 +5,8 @@ mod idx_card_d1;
 mod idx_card_d2;
 mod idx_card_d3;
 mod idx_card_d4;
+mod idx_card_d5;
+mod idx_card_d6;
 
 pub use card_equality::CardEquality;
 pub use card_idx::CardIdx;
@@ -0,0 +1,88 @@
+use crate::dim::Dim;
+use core::fmt::{Debug, Display};
+
+/// Error returned by fallible indexing methods such as [`get`] and [`get_mut`] when the
+/// index is out of bounds.
+///
+/// The error points to the first axis at which the index is out of bounds:
+/// * `card_idx` is the position of the vector on which the index fails,
+///   such that `vec.card(card_idx)` is the cardinality that is exceeded;
+/// * `idx` is the attempted index on this axis;
+/// * `card` is the actual cardinality at `card_idx`, which is less than or equal to `idx`.
+///
+/// [`get`]: crate::NVec::get
+/// [`get_mut`]: crate::NVecMut::get_mut
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let vec = vec![
+///     vec![0, 1, 2],
+///     vec![3],
+///     vec![4, 5],
+/// ];
+///
+/// assert_eq!(vec.get([2, 1]), Ok(5));
+///
+/// let error = vec.get([1, 2]).unwrap_err();
+/// assert_eq!(error.card_idx, IdxLeqD1::IdxD1([1]));
+/// assert_eq!(error.idx, 2);
+/// assert_eq!(error.card, 1);
+///
+/// let error = vec.get([3, 0]).unwrap_err();
+/// assert_eq!(error.card_idx, IdxLeqD1::IdxD0([]));
+/// assert_eq!(error.idx, 3);
+/// assert_eq!(error.card, 3);
+/// ```
+#[derive(PartialEq, Clone, Copy)]
+pub struct IndexError<D: Dim> {
+    /// Position of the vector whose cardinality is exceeded by the index.
+    pub card_idx: D::CardIdx,
+    /// Attempted index on the axis that is out of bounds.
+    pub idx: usize,
+    /// Actual cardinality of the vector at `card_idx`.
+    pub card: usize,
+}
+
+impl<D: Dim> IndexError<D> {
+    pub(crate) fn new(card_idx: D::CardIdx, idx: usize, card: usize) -> Self {
+        Self {
+            card_idx,
+            idx,
+            card,
+        }
+    }
+
+    /// Converts the error of the `i`-th child of a vector into the error of the vector.
+    pub(crate) fn from_child(i: usize, child_error: IndexError<D::PrevDim>) -> Self {
+        Self::new(
+            D::left_join_from_lower_card_idx(i, child_error.card_idx),
+            child_error.idx,
+            child_error.card,
+        )
+    }
+}
+
+impl<D: Dim> Debug for IndexError<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "IndexError {{ card_idx: {:?}, idx: {}, card: {} }}",
+            self.card_idx, self.idx, self.card
+        )
+    }
+}
+
+impl<D: Dim> Display for IndexError<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "index {} is out of bounds at {:?} with cardinality {}",
+            self.idx, self.card_idx, self.card
+        )
+    }
+}
+
+impl<D: Dim> core::error::Error for IndexError<D> {}
@@ -0,0 +1,45 @@
+use super::LeqIdx;
+use crate::{IdxLeqD5, NVecCore, NVecCoreSealed, D5};
+
+impl LeqIdx<D5> for IdxLeqD5 {
+    fn in_leq_bounds<T>(self, vec: &impl NVecCore<D5, T>) -> bool {
+        match self {
+            Self::IdxD0(_) => true,
+            Self::IdxD1([i]) => i < vec.core_num_children(),
+            Self::IdxD2([i, j]) => {
+                i < vec.core_num_children() && j < vec.core_child(i).core_num_children()
+            }
+            Self::IdxD3([i, j, k]) => {
+                i < vec.core_num_children()
+                    && j < vec.core_child(i).core_num_children()
+                    && k < vec.core_child(i).core_child(j).core_num_children()
+            }
+            Self::IdxD4([i, j, k, l]) => {
+                i < vec.core_num_children()
+                    && j < vec.core_child(i).core_num_children()
+                    && k < vec.core_child(i).core_child(j).core_num_children()
+                    && l < vec
+                        .core_child(i)
+                        .core_child(j)
+                        .core_child(k)
+                        .core_num_children()
+            }
+            Self::IdxD5([i, j, k, l, m]) => {
+                i < vec.core_num_children()
+                    && j < vec.core_child(i).core_num_children()
+                    && k < vec.core_child(i).core_child(j).core_num_children()
+                    && l < vec
+                        .core_child(i)
+                        .core_child(j)
+                        .core_child(k)
+                        .core_num_children()
+                    && m < vec
+                        .core_child(i)
+                        .core_child(j)
+                        .core_child(k)
+                        .core_child(l)
+                        .core_num_children()
+            }
+        }
+    }
+}
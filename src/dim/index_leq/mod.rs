@@ -2,6 +2,8 @@ mod leq_d1;
 mod leq_d2;
 mod leq_d3;
 mod leq_d4;
+mod leq_d5;
+mod leq_d6;
 mod leq_idx;
 
 pub use leq_idx::LeqIdx;
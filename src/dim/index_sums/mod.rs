@@ -3,9 +3,31 @@ mod idx_leq_d1;
 mod idx_leq_d2;
 mod idx_leq_d3;
 mod idx_leq_d4;
+mod idx_leq_d5;
+mod idx_leq_d6;
 
 pub use idx_leq_d0::IdxLeqD0;
 pub use idx_leq_d1::IdxLeqD1;
 pub use idx_leq_d2::IdxLeqD2;
 pub use idx_leq_d3::IdxLeqD3;
 pub use idx_leq_d4::IdxLeqD4;
+pub use idx_leq_d5::IdxLeqD5;
+pub use idx_leq_d6::IdxLeqD6;
+
+macro_rules! impl_as_slice {
+    ($idx:ty, [$($variant:ident),*]) => {
+        impl $idx {
+            /// Returns the index as a slice of its elements.
+            pub(crate) fn as_slice(&self) -> &[usize] {
+                match self {
+                    $(Self::$variant(x) => x,)*
+                }
+            }
+        }
+    };
+}
+
+impl_as_slice!(IdxLeqD1, [IdxD0, IdxD1]);
+impl_as_slice!(IdxLeqD2, [IdxD0, IdxD1, IdxD2]);
+impl_as_slice!(IdxLeqD3, [IdxD0, IdxD1, IdxD2, IdxD3]);
+impl_as_slice!(IdxLeqD4, [IdxD0, IdxD1, IdxD2, IdxD3, IdxD4]);
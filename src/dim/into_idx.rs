@@ -45,3 +45,17 @@ impl IntoIdx<D4> for (usize, usize, usize, usize) {
         [self.0, self.1, self.2, self.3]
     }
 }
+
+impl IntoIdx<D5> for (usize, usize, usize, usize, usize) {
+    #[inline(always)]
+    fn into_idx(self) -> <D5 as Dim>::Idx {
+        [self.0, self.1, self.2, self.3, self.4]
+    }
+}
+
+impl IntoIdx<D6> for (usize, usize, usize, usize, usize, usize) {
+    #[inline(always)]
+    fn into_idx(self) -> <D6 as Dim>::Idx {
+        [self.0, self.1, self.2, self.3, self.4, self.5]
+    }
+}
@@ -2,8 +2,11 @@ mod d1;
 mod d2;
 mod d3;
 mod d4;
+mod d5;
+mod d6;
 mod dimension;
 mod index_card;
+mod index_error;
 mod index_leq;
 mod index_sums;
 mod into_idx;
@@ -13,9 +16,12 @@ pub use d1::{IdxNever, D1};
 pub use d2::D2;
 pub use d3::D3;
 pub use d4::D4;
+pub use d5::D5;
+pub use d6::D6;
 pub use dimension::Dim;
 pub use index_card::{CardEquality, CardIdx, Equality};
+pub use index_error::IndexError;
 pub use index_leq::LeqIdx;
-pub use index_sums::{IdxLeqD0, IdxLeqD1, IdxLeqD2, IdxLeqD3, IdxLeqD4};
+pub use index_sums::{IdxLeqD0, IdxLeqD1, IdxLeqD2, IdxLeqD3, IdxLeqD4, IdxLeqD5, IdxLeqD6};
 pub use into_idx::IntoIdx;
-pub(crate) use split::SplitIdx;
+pub(crate) use split::{SplitCardIdx, SplitIdx};
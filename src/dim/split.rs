@@ -37,3 +37,98 @@ impl SplitIdx for <D4 as Dim>::Idx {
         (self[0], [self[1], self[2], self[3]])
     }
 }
+
+impl SplitIdx for <D5 as Dim>::Idx {
+    type LesserIdx = <D4 as Dim>::Idx;
+    #[inline(always)]
+    fn split_idx(self) -> (usize, Self::LesserIdx) {
+        (self[0], [self[1], self[2], self[3], self[4]])
+    }
+}
+
+impl SplitIdx for <D6 as Dim>::Idx {
+    type LesserIdx = <D5 as Dim>::Idx;
+    #[inline(always)]
+    fn split_idx(self) -> (usize, Self::LesserIdx) {
+        (self[0], [self[1], self[2], self[3], self[4], self[5]])
+    }
+}
+
+pub trait SplitCardIdx {
+    type LesserIdx;
+
+    fn split_card_idx(self) -> Option<(usize, Self::LesserIdx)>;
+}
+
+impl SplitCardIdx for IdxLeqD0 {
+    type LesserIdx = IdxLeqD0;
+    #[inline(always)]
+    fn split_card_idx(self) -> Option<(usize, Self::LesserIdx)> {
+        None
+    }
+}
+
+impl SplitCardIdx for IdxLeqD1 {
+    type LesserIdx = IdxLeqD0;
+    #[inline(always)]
+    fn split_card_idx(self) -> Option<(usize, Self::LesserIdx)> {
+        match self {
+            Self::IdxD0(_) => None,
+            Self::IdxD1([i]) => Some((i, [].into())),
+        }
+    }
+}
+
+impl SplitCardIdx for IdxLeqD2 {
+    type LesserIdx = IdxLeqD1;
+    #[inline(always)]
+    fn split_card_idx(self) -> Option<(usize, Self::LesserIdx)> {
+        match self {
+            Self::IdxD0(_) => None,
+            Self::IdxD1([i]) => Some((i, [].into())),
+            Self::IdxD2([i, j]) => Some((i, [j].into())),
+        }
+    }
+}
+
+impl SplitCardIdx for IdxLeqD3 {
+    type LesserIdx = IdxLeqD2;
+    #[inline(always)]
+    fn split_card_idx(self) -> Option<(usize, Self::LesserIdx)> {
+        match self {
+            Self::IdxD0(_) => None,
+            Self::IdxD1([i]) => Some((i, [].into())),
+            Self::IdxD2([i, j]) => Some((i, [j].into())),
+            Self::IdxD3([i, j, k]) => Some((i, [j, k].into())),
+        }
+    }
+}
+
+impl SplitCardIdx for IdxLeqD4 {
+    type LesserIdx = IdxLeqD3;
+    #[inline(always)]
+    fn split_card_idx(self) -> Option<(usize, Self::LesserIdx)> {
+        match self {
+            Self::IdxD0(_) => None,
+            Self::IdxD1([i]) => Some((i, [].into())),
+            Self::IdxD2([i, j]) => Some((i, [j].into())),
+            Self::IdxD3([i, j, k]) => Some((i, [j, k].into())),
+            Self::IdxD4([i, j, k, l]) => Some((i, [j, k, l].into())),
+        }
+    }
+}
+
+impl SplitCardIdx for IdxLeqD5 {
+    type LesserIdx = IdxLeqD4;
+    #[inline(always)]
+    fn split_card_idx(self) -> Option<(usize, Self::LesserIdx)> {
+        match self {
+            Self::IdxD0(_) => None,
+            Self::IdxD1([i]) => Some((i, [].into())),
+            Self::IdxD2([i, j]) => Some((i, [j].into())),
+            Self::IdxD3([i, j, k]) => Some((i, [j, k].into())),
+            Self::IdxD4([i, j, k, l]) => Some((i, [j, k, l].into())),
+            Self::IdxD5([i, j, k, l, m]) => Some((i, [j, k, l, m].into())),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::Dim;
+
+/// Dimensionality of a structure which is known only at runtime.
+///
+/// Dimensions such as [`D1`] or [`D2`] fix the number of dimensions of a
+/// vector at compile time. This is not always possible; for instance,
+/// the rank of a tensor loaded from a file is known only after the file is read.
+/// Vectors with a runtime dimension implement [`DynNVec`] and are accessed by
+/// slice indices whose lengths are equal to the rank of the vector.
+///
+/// [`D1`]: crate::D1
+/// [`D2`]: crate::D2
+/// [`DynNVec`]: crate::DynNVec
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let dim = DynDim::new(2);
+/// assert_eq!(dim.rank(), 2);
+/// assert!(dim.is::<D2>());
+/// assert!(!dim.is::<D3>());
+///
+/// assert_eq!(DynDim::of::<D4>(), DynDim::new(4));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DynDim {
+    rank: usize,
+}
+
+impl DynDim {
+    /// Creates a runtime dimension with the given `rank`; i.e., number of dimensions.
+    pub fn new(rank: usize) -> Self {
+        Self { rank }
+    }
+
+    /// Creates the runtime dimension equivalent to the static dimension `D`.
+    pub fn of<D: Dim>() -> Self {
+        Self::new(D::dimension())
+    }
+
+    /// Rank, or the number of dimensions; such as 1 for one-dimensional or 2 for
+    /// two-dimensional structures.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Returns true if this runtime dimension is equal to the static dimension `D`.
+    pub fn is<D: Dim>(&self) -> bool {
+        self.rank == D::dimension()
+    }
+}
@@ -0,0 +1,129 @@
+use super::{idx_slice::IdxSlice, DynNVec};
+use crate::{Dim, IntoIdx, NVec, NVecCoreSealed, D1, D2, D3, D4, D5, D6};
+use core::marker::PhantomData;
+
+/// A view of a vector with runtime dimension as a vector with the static
+/// dimension `D`, which can only be created when the ranks match.
+pub(crate) struct DynAsNVec<D, T, V>
+where
+    D: Dim,
+    V: DynNVec<T>,
+{
+    vec: V,
+    phantom: PhantomData<(D, T)>,
+}
+
+impl<D, T, V> DynAsNVec<D, T, V>
+where
+    D: Dim,
+    V: DynNVec<T>,
+{
+    pub(crate) fn try_new(vec: V) -> Option<Self> {
+        match vec.rank() == D::dimension() {
+            true => Some(Self {
+                vec,
+                phantom: PhantomData,
+            }),
+            false => None,
+        }
+    }
+
+    fn new_unchecked(vec: V) -> Self {
+        debug_assert_eq!(vec.rank(), D::dimension());
+        Self {
+            vec,
+            phantom: PhantomData,
+        }
+    }
+}
+
+macro_rules! impl_core_and_nvec {
+    ($dim:ty, $child:ident) => {
+        impl<T, V> NVecCoreSealed<$dim, T> for DynAsNVec<$dim, T, V>
+        where
+            V: DynNVec<T>,
+        {
+            #[inline(always)]
+            fn core_num_children(&self) -> usize {
+                self.vec.num_children()
+            }
+
+            #[inline(always)]
+            fn core_card(&self, idx: impl Into<<$dim as Dim>::CardIdx>) -> usize {
+                self.vec.card(idx.into().idx_slice())
+            }
+
+            fn core_child(
+                &self,
+                i: <$dim as Dim>::ChildIdx,
+            ) -> impl NVecCoreSealed<<$dim as Dim>::PrevDim, T> {
+                self.$child(i)
+            }
+
+            #[inline(always)]
+            fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<$dim>, f: &mut F) -> O {
+                f(&self.vec.at(idx.into_idx()))
+            }
+
+            fn core_is_rectangular(&self) -> bool {
+                self.vec.is_rectangular()
+            }
+        }
+
+        impl<T, V> NVec<$dim, T> for DynAsNVec<$dim, T, V>
+        where
+            V: DynNVec<T>,
+        {
+            #[inline(always)]
+            fn at(&self, idx: impl IntoIdx<$dim>) -> T {
+                self.vec.at(idx.into_idx())
+            }
+
+            fn child(&self, i: <$dim as Dim>::ChildIdx) -> impl NVec<<$dim as Dim>::PrevDim, T> {
+                self.$child(i)
+            }
+
+            fn all(&self) -> impl Iterator<Item = T> {
+                self.vec.all()
+            }
+        }
+    };
+}
+
+impl<T, V> DynAsNVec<D1, T, V>
+where
+    V: DynNVec<T>,
+{
+    fn d1_child(&self, _: <D1 as Dim>::ChildIdx) -> &Self {
+        self
+    }
+}
+
+macro_rules! impl_child {
+    ($dim:ty) => {
+        impl<T, V> DynAsNVec<$dim, T, V>
+        where
+            V: DynNVec<T>,
+        {
+            fn dyn_child(
+                &self,
+                i: usize,
+            ) -> DynAsNVec<<$dim as Dim>::PrevDim, T, impl DynNVec<T> + '_> {
+                DynAsNVec::new_unchecked(self.vec.child(i))
+            }
+        }
+    };
+}
+
+impl_child!(D2);
+impl_child!(D3);
+impl_child!(D4);
+impl_child!(D5);
+impl_child!(D6);
+
+impl_core_and_nvec!(D1, d1_child);
+impl_core_and_nvec!(D2, dyn_child);
+impl_core_and_nvec!(D3, dyn_child);
+impl_core_and_nvec!(D4, dyn_child);
+impl_core_and_nvec!(D5, dyn_child);
+impl_core_and_nvec!(D6, dyn_child);
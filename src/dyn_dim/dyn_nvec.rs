@@ -0,0 +1,320 @@
+use super::{dyn_as_nvec::DynAsNVec, DynDim};
+use crate::{NVec, D1, D2, D3, D4, D5, D6};
+
+/// A vector whose dimension is known only at runtime.
+///
+/// Unlike [`NVec`] where the dimension is a type parameter, the rank of a dynamic
+/// vector is a runtime value, see [`DynDim`]. Elements and cardinalities are accessed
+/// by slice indices; and hence, an index might be an array, a slice, a `Vec<usize>`
+/// or a `SmallVec`, or any other type that can be referenced as `&[usize]`.
+///
+/// * `at(idx)` requires `idx.len()` to be equal to the rank of the vector.
+/// * `card(idx)` requires `idx.len()` to be less than the rank of the vector.
+/// * `child(i)` is a vector with one less rank, dispatching all calls to this
+///   vector with the prefix `i`.
+///
+/// When the rank matches, a dynamic vector can be viewed as a vector with static
+/// dimension by [`as_d1`], [`as_d2`], etc. Conversely, any vector with static
+/// dimension can be viewed as a dynamic vector by [`IntoDyn`] methods.
+///
+/// [`as_d1`]: crate::DynNVec::as_d1
+/// [`as_d2`]: crate::DynNVec::as_d2
+/// [`IntoDyn`]: crate::IntoDyn
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// fn sum(v: &impl DynNVec<i32>) -> i32 {
+///     v.all().sum()
+/// }
+///
+/// let v = DynVec::new(vec![2, 3], vec![0, 1, 2, 3, 4, 5]);
+/// assert_eq!(v.rank(), 2);
+/// assert_eq!(v.card([]), 2);
+/// assert_eq!(v.card([1]), 3);
+/// assert_eq!(v.at([1, 2]), 5);
+/// assert_eq!(v.child(1).at([2]), 5);
+/// assert_eq!(sum(&v), 15);
+///
+/// let v2 = vec![vec![1, 2], vec![3]];
+/// let v = v2.as_dyn();
+/// assert_eq!(v.rank(), 2);
+/// assert_eq!(v.card([1]), 1);
+/// assert_eq!(v.at(vec![0, 1]), 2);
+/// assert_eq!(sum(&v), 6);
+/// ```
+pub trait DynNVec<T> {
+    /// Rank, or the number of dimensions, of the vector.
+    fn rank(&self) -> usize;
+
+    /// Returns the cardinality of the child of the vector at the given `idx`;
+    /// where `idx.len()` must be less than the rank of the vector:
+    /// * `card([])` returns the number of children of the vector,
+    /// * `card([i])` returns the number of children of the `i`-th child, and so on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx.len()` is not less than the rank of the vector, or if `idx`
+    /// is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v = vec![vec![vec![1], vec![2, 3]], vec![]];
+    /// let v = v.as_dyn();
+    ///
+    /// assert_eq!(v.card([]), 2);
+    /// assert_eq!(v.card([0]), 2);
+    /// assert_eq!(v.card([1]), 0);
+    /// assert_eq!(v.card([0, 1]), 2);
+    /// ```
+    fn card(&self, idx: impl AsRef<[usize]>) -> usize;
+
+    /// Returns the element at the given `idx` where `idx.len()` must be equal to the
+    /// rank of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx.len()` is not equal to the rank of the vector, or if `idx` is
+    /// out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v = DynVec::new(vec![2, 1, 2], vec![0, 1, 2, 3]);
+    ///
+    /// assert_eq!(v.at([1, 0, 1]), 3);
+    /// assert_eq!(v.at(&[0, 0, 1]), 1);
+    /// assert_eq!(v.at(vec![1, 0, 0]), 2);
+    /// ```
+    fn at(&self, idx: impl AsRef<[usize]>) -> T;
+
+    /// Returns the `i`-th child of the vector, which is a vector of one less rank.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rank of the vector is 1, or if `i` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v = DynVec::new(vec![2, 1, 2], vec![0, 1, 2, 3]);
+    ///
+    /// let c = v.child(1);
+    /// assert_eq!(c.rank(), 2);
+    /// assert_eq!(c.at([0, 1]), 3);
+    /// assert_eq!(c.child(0).at([0]), 2);
+    /// ```
+    fn child(&self, i: usize) -> impl DynNVec<T>;
+
+    /// Returns an iterator of all elements of the vector in lexicographic order of
+    /// their indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v = vec![vec![vec![1], vec![2, 3]], vec![], vec![vec![4]]];
+    /// let v = v.as_dyn();
+    ///
+    /// assert_eq!(v.all().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    /// ```
+    fn all(&self) -> impl Iterator<Item = T>;
+
+    /// Returns whether or not the vector is rectangular; i.e., all children at
+    /// the same depth have the same cardinality.
+    fn is_rectangular(&self) -> bool;
+
+    // provided
+
+    /// Returns the runtime dimension of the vector.
+    fn dim(&self) -> DynDim {
+        DynDim::new(self.rank())
+    }
+
+    /// Returns the number of children of the vector; equivalently, `card([])`.
+    fn num_children(&self) -> usize {
+        self.card([])
+    }
+
+    /// Returns whether or not the given `idx` is in bounds; where `idx.len()` can be
+    /// less than or equal to the rank of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v = vec![vec![1], vec![2, 3]];
+    /// let v = v.as_dyn();
+    ///
+    /// assert!(v.in_bounds([]));
+    /// assert!(v.in_bounds([1]));
+    /// assert!(v.in_bounds([1, 1]));
+    /// assert!(!v.in_bounds([0, 1]));
+    /// assert!(!v.in_bounds([2]));
+    /// assert!(!v.in_bounds([0, 0, 0]));
+    /// ```
+    fn in_bounds(&self, idx: impl AsRef<[usize]>) -> bool {
+        let idx = idx.as_ref();
+        idx.len() <= self.rank()
+            && idx
+                .iter()
+                .enumerate()
+                .all(|(q, i)| *i < self.card(&idx[..q]))
+    }
+
+    /// Returns the element at the given `idx` if it is in bounds and its length is
+    /// equal to the rank of the vector; returns None otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v = DynVec::new(vec![2, 2], vec![0, 1, 2, 3]);
+    ///
+    /// assert_eq!(v.try_at([1, 0]), Some(2));
+    /// assert_eq!(v.try_at([1, 2]), None);
+    /// assert_eq!(v.try_at([1]), None);
+    /// ```
+    fn try_at(&self, idx: impl AsRef<[usize]>) -> Option<T> {
+        let idx = idx.as_ref();
+        match idx.len() == self.rank() && self.in_bounds(idx) {
+            true => Some(self.at(idx)),
+            false => None,
+        }
+    }
+
+    /// Creates a `D1` view of the vector if its rank is 1; returns None otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v = DynVec::new(vec![3], vec![0, 1, 2]);
+    ///
+    /// let v1 = v.as_d1().unwrap();
+    /// assert_eq!(v1.equality(&[0, 1, 2]), Equality::Equal);
+    ///
+    /// assert!(v.as_d2().is_none());
+    /// ```
+    fn as_d1(&self) -> Option<impl NVec<D1, T>>
+    where
+        Self: Sized,
+    {
+        DynAsNVec::<D1, T, _>::try_new(self)
+    }
+
+    /// Creates a `D2` view of the vector if its rank is 2; returns None otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v = DynVec::new(vec![2, 3], vec![0, 1, 2, 3, 4, 5]);
+    ///
+    /// let v2 = v.as_d2().unwrap();
+    /// assert_eq!(v2.at([1, 0]), 3);
+    /// assert_eq!(v2.equality(&[[0, 1, 2], [3, 4, 5]]), Equality::Equal);
+    ///
+    /// assert!(v.as_d1().is_none());
+    /// assert!(v.as_d3().is_none());
+    /// ```
+    fn as_d2(&self) -> Option<impl NVec<D2, T>>
+    where
+        Self: Sized,
+    {
+        DynAsNVec::<D2, T, _>::try_new(self)
+    }
+
+    /// Creates a `D3` view of the vector if its rank is 3; returns None otherwise.
+    ///
+    /// See [`as_d2`] for examples.
+    ///
+    /// [`as_d2`]: crate::DynNVec::as_d2
+    fn as_d3(&self) -> Option<impl NVec<D3, T>>
+    where
+        Self: Sized,
+    {
+        DynAsNVec::<D3, T, _>::try_new(self)
+    }
+
+    /// Creates a `D4` view of the vector if its rank is 4; returns None otherwise.
+    ///
+    /// See [`as_d2`] for examples.
+    ///
+    /// [`as_d2`]: crate::DynNVec::as_d2
+    fn as_d4(&self) -> Option<impl NVec<D4, T>>
+    where
+        Self: Sized,
+    {
+        DynAsNVec::<D4, T, _>::try_new(self)
+    }
+
+    /// Creates a `D5` view of the vector if its rank is 5; returns None otherwise.
+    ///
+    /// See [`as_d2`] for examples.
+    ///
+    /// [`as_d2`]: crate::DynNVec::as_d2
+    fn as_d5(&self) -> Option<impl NVec<D5, T>>
+    where
+        Self: Sized,
+    {
+        DynAsNVec::<D5, T, _>::try_new(self)
+    }
+
+    /// Creates a `D6` view of the vector if its rank is 6; returns None otherwise.
+    ///
+    /// See [`as_d2`] for examples.
+    ///
+    /// [`as_d2`]: crate::DynNVec::as_d2
+    fn as_d6(&self) -> Option<impl NVec<D6, T>>
+    where
+        Self: Sized,
+    {
+        DynAsNVec::<D6, T, _>::try_new(self)
+    }
+}
+
+// ref
+
+impl<T, V: DynNVec<T>> DynNVec<T> for &V {
+    #[inline(always)]
+    fn rank(&self) -> usize {
+        <V as DynNVec<T>>::rank(self)
+    }
+
+    #[inline(always)]
+    fn card(&self, idx: impl AsRef<[usize]>) -> usize {
+        <V as DynNVec<T>>::card(self, idx)
+    }
+
+    #[inline(always)]
+    fn at(&self, idx: impl AsRef<[usize]>) -> T {
+        <V as DynNVec<T>>::at(self, idx)
+    }
+
+    fn child(&self, i: usize) -> impl DynNVec<T> {
+        <V as DynNVec<T>>::child(self, i)
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        <V as DynNVec<T>>::all(self)
+    }
+
+    fn is_rectangular(&self) -> bool {
+        <V as DynNVec<T>>::is_rectangular(self)
+    }
+}
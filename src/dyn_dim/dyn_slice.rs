@@ -0,0 +1,90 @@
+use super::{panic_card_rank, panic_no_child, panic_rank, DynNVec};
+
+/// A borrowed view of a rectangular vector with runtime dimension, represented
+/// by its `shape` and the row-major flat `data`.
+pub(crate) struct DynSlice<'a, T> {
+    shape: &'a [usize],
+    data: &'a [T],
+}
+
+impl<'a, T> DynSlice<'a, T> {
+    pub(crate) fn new(shape: &'a [usize], data: &'a [T]) -> Self {
+        debug_assert_eq!(shape.iter().product::<usize>(), data.len());
+        Self { shape, data }
+    }
+
+    /// Number of elements in each child of this view.
+    fn child_len(&self) -> usize {
+        self.shape[1..].iter().product()
+    }
+
+    pub(crate) fn child_slice(&self, i: usize) -> DynSlice<'a, T> {
+        if self.shape.len() < 2 {
+            return panic_no_child();
+        }
+        assert!(
+            i < self.shape[0],
+            "Child index {} is out of bounds for the vector with {} children",
+            i,
+            self.shape[0]
+        );
+        let len = self.child_len();
+        DynSlice::new(&self.shape[1..], &self.data[(i * len)..((i + 1) * len)])
+    }
+
+    pub(crate) fn flat_idx(&self, idx: &[usize]) -> usize {
+        if idx.len() != self.shape.len() {
+            panic_rank(idx.len(), self.shape.len());
+        }
+
+        let mut flat_idx = 0;
+        for (i, n) in idx.iter().zip(self.shape) {
+            assert!(
+                i < n,
+                "Index {:?} is out of bounds for the vector of shape {:?}",
+                idx,
+                self.shape
+            );
+            flat_idx = flat_idx * n + i;
+        }
+        flat_idx
+    }
+}
+
+impl<T: Copy> DynNVec<T> for DynSlice<'_, T> {
+    #[inline(always)]
+    fn rank(&self) -> usize {
+        self.shape.len()
+    }
+
+    fn card(&self, idx: impl AsRef<[usize]>) -> usize {
+        let idx = idx.as_ref();
+        if idx.len() >= self.shape.len() {
+            panic_card_rank(idx.len(), self.shape.len());
+        }
+        assert!(
+            idx.iter().zip(self.shape).all(|(i, n)| i < n),
+            "Index {:?} is out of bounds for the vector of shape {:?}",
+            idx,
+            self.shape
+        );
+        self.shape[idx.len()]
+    }
+
+    #[inline(always)]
+    fn at(&self, idx: impl AsRef<[usize]>) -> T {
+        self.data[self.flat_idx(idx.as_ref())]
+    }
+
+    fn child(&self, i: usize) -> impl DynNVec<T> {
+        self.child_slice(i)
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        self.data.iter().copied()
+    }
+
+    fn is_rectangular(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,175 @@
+use super::{dyn_slice::DynSlice, DynNVec};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// A rectangular vector whose dimension is known only at runtime, represented by
+/// its `shape` and a flat storage of its elements in row-major order.
+///
+/// The rank of the vector is the length of its shape.
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// // say shape & data are read from a file
+/// let shape = vec![2, 3, 2];
+/// let data: Vec<_> = (0..12).collect();
+///
+/// let v = DynVec::new(shape, data);
+/// assert_eq!(v.rank(), 3);
+/// assert_eq!(v.card([]), 2);
+/// assert_eq!(v.card([1]), 3);
+/// assert_eq!(v.card([1, 2]), 2);
+/// assert_eq!(v.at([1, 2, 0]), 10);
+///
+/// // when the rank is known, it can be viewed as a static vector
+/// let v3 = v.as_d3().unwrap();
+/// assert_eq!(v3.at([1, 2, 0]), 10);
+/// assert_eq!(v3.child(0).child(1).at([1]), 3);
+/// ```
+pub struct DynVec<T> {
+    shape: Vec<usize>,
+    data: Vec<T>,
+}
+
+impl<T> DynVec<T> {
+    /// Creates a new vector with the given `shape` such that its elements are
+    /// stored in `data` in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `shape` is empty, or
+    /// * the product of `shape` is not equal to the length of `data`.
+    pub fn new(shape: Vec<usize>, data: Vec<T>) -> Self {
+        assert!(
+            !shape.is_empty(),
+            "Shape of a DynVec must have at least one dimension"
+        );
+        assert_eq!(
+            shape.iter().product::<usize>(),
+            data.len(),
+            "Number of elements of the shape {:?} must be equal to the length of data",
+            shape
+        );
+        Self { shape, data }
+    }
+
+    /// Creates a new vector by collecting the elements of the given `vec` if it is
+    /// rectangular; returns None otherwise.
+    ///
+    /// Note that any vector with static dimension can be converted into a dynamic
+    /// vector by [`as_dyn`] or [`into_dyn`].
+    ///
+    /// [`as_dyn`]: crate::IntoDyn::as_dyn
+    /// [`into_dyn`]: crate::IntoDyn::into_dyn
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = vec![vec![0, 1, 2], vec![3, 4, 5]];
+    /// let v = DynVec::from_rectangular(&v2.as_dyn()).unwrap();
+    /// assert_eq!(v.shape(), &[2, 3]);
+    /// assert_eq!(v.at([1, 1]), 4);
+    ///
+    /// let v2 = vec![vec![0, 1, 2], vec![3]];
+    /// assert!(DynVec::from_rectangular(&v2.as_dyn()).is_none());
+    /// ```
+    pub fn from_rectangular(vec: &impl DynNVec<T>) -> Option<Self> {
+        match vec.is_rectangular() {
+            false => None,
+            true => {
+                let mut shape = Vec::with_capacity(vec.rank());
+                let mut first_idx = Vec::with_capacity(vec.rank());
+                for _ in 0..vec.rank() {
+                    let n = match shape.last() {
+                        Some(0) => 0,
+                        _ => vec.card(&first_idx),
+                    };
+                    shape.push(n);
+                    first_idx.push(0);
+                }
+                Some(Self::new(shape, vec.all().collect()))
+            }
+        }
+    }
+
+    /// Shape of the vector; i.e., number of elements along each dimension.
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Destructs the vector into its shape and flat data.
+    pub fn into_inner(self) -> (Vec<usize>, Vec<T>) {
+        (self.shape, self.data)
+    }
+
+    /// Returns a mutable reference to the element at the given `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx.len()` is not equal to the rank of the vector, or if `idx` is
+    /// out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v = DynVec::new(vec![2, 2], vec![0, 1, 2, 3]);
+    /// *v.at_mut([1, 0]) = 42;
+    /// assert_eq!(v.at([1, 0]), 42);
+    /// ```
+    pub fn at_mut(&mut self, idx: impl AsRef<[usize]>) -> &mut T {
+        let flat_idx = DynSlice::new(&self.shape, &self.data).flat_idx(idx.as_ref());
+        &mut self.data[flat_idx]
+    }
+
+    fn as_dyn_slice(&self) -> DynSlice<'_, T> {
+        DynSlice::new(&self.shape, &self.data)
+    }
+}
+
+impl<T: Copy> DynNVec<T> for DynVec<T> {
+    #[inline(always)]
+    fn rank(&self) -> usize {
+        self.shape.len()
+    }
+
+    #[inline(always)]
+    fn card(&self, idx: impl AsRef<[usize]>) -> usize {
+        self.as_dyn_slice().card(idx)
+    }
+
+    #[inline(always)]
+    fn at(&self, idx: impl AsRef<[usize]>) -> T {
+        self.as_dyn_slice().at(idx)
+    }
+
+    fn child(&self, i: usize) -> impl DynNVec<T> {
+        self.as_dyn_slice().child_slice(i)
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        self.data.iter().copied()
+    }
+
+    fn is_rectangular(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Debug> Debug for DynVec<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{{ kind: DynVec, dim: D{}, shape: {:?}, values: {:?} }}",
+            self.shape.len(),
+            self.shape,
+            self.data
+        )
+    }
+}
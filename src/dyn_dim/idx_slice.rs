@@ -0,0 +1,70 @@
+use crate::{IdxLeqD0, IdxLeqD1, IdxLeqD2, IdxLeqD3, IdxLeqD4, IdxLeqD5};
+
+/// Cardinality indices of static dimensions which can be referenced as slice
+/// indices of dynamic vectors.
+pub(crate) trait IdxSlice {
+    fn idx_slice(&self) -> &[usize];
+}
+
+impl IdxSlice for IdxLeqD0 {
+    fn idx_slice(&self) -> &[usize] {
+        match self {
+            Self::IdxD0(x) => x,
+        }
+    }
+}
+
+impl IdxSlice for IdxLeqD1 {
+    fn idx_slice(&self) -> &[usize] {
+        match self {
+            Self::IdxD0(x) => x,
+            Self::IdxD1(x) => x,
+        }
+    }
+}
+
+impl IdxSlice for IdxLeqD2 {
+    fn idx_slice(&self) -> &[usize] {
+        match self {
+            Self::IdxD0(x) => x,
+            Self::IdxD1(x) => x,
+            Self::IdxD2(x) => x,
+        }
+    }
+}
+
+impl IdxSlice for IdxLeqD3 {
+    fn idx_slice(&self) -> &[usize] {
+        match self {
+            Self::IdxD0(x) => x,
+            Self::IdxD1(x) => x,
+            Self::IdxD2(x) => x,
+            Self::IdxD3(x) => x,
+        }
+    }
+}
+
+impl IdxSlice for IdxLeqD4 {
+    fn idx_slice(&self) -> &[usize] {
+        match self {
+            Self::IdxD0(x) => x,
+            Self::IdxD1(x) => x,
+            Self::IdxD2(x) => x,
+            Self::IdxD3(x) => x,
+            Self::IdxD4(x) => x,
+        }
+    }
+}
+
+impl IdxSlice for IdxLeqD5 {
+    fn idx_slice(&self) -> &[usize] {
+        match self {
+            Self::IdxD0(x) => x,
+            Self::IdxD1(x) => x,
+            Self::IdxD2(x) => x,
+            Self::IdxD3(x) => x,
+            Self::IdxD4(x) => x,
+            Self::IdxD5(x) => x,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use super::NVecAsDyn;
+use crate::{Dim, NVec};
+
+/// Transforms a vector with static dimension into a vector with runtime
+/// dimension via the `into_dyn` method; or alternatively, creates a view
+/// with runtime dimension by the `as_dyn` method.
+///
+/// The resulting vector implements [`DynNVec`] with a rank equal to
+/// the dimension of the source vector.
+///
+/// [`DynNVec`]: crate::DynNVec
+pub trait IntoDyn<D: Dim, T>: Sized + NVec<D, T> {
+    /// Converts the vector into a vector with runtime dimension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v3 = V.d3().fun(|[i, j, k]| i + j + k).with_rectangular_bounds([2, 3, 4]);
+    ///
+    /// let v = v3.into_dyn();
+    /// assert_eq!(v.dim(), DynDim::new(3));
+    /// assert_eq!(v.card([1, 2]), 4);
+    /// assert_eq!(v.at([1, 2, 3]), 6);
+    /// assert_eq!(v.child(1).child(2).at([3]), 6);
+    /// ```
+    fn into_dyn(self) -> NVecAsDyn<D, T, Self> {
+        NVecAsDyn::new(self)
+    }
+
+    /// Creates a view of the vector with runtime dimension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = vec![vec![0, 1], vec![2]];
+    ///
+    /// let v = v2.as_dyn();
+    /// assert_eq!(v.rank(), 2);
+    /// assert_eq!(v.card([0]), 2);
+    /// assert_eq!(v.try_at([1, 1]), None);
+    ///
+    /// // back to static dimension
+    /// let v2_again = v.as_d2().unwrap();
+    /// assert_eq!(v2_again.equality(&v2), Equality::Equal);
+    /// ```
+    fn as_dyn(&self) -> NVecAsDyn<D, T, &Self> {
+        NVecAsDyn::new(self)
+    }
+}
+
+impl<D: Dim, T, V: NVec<D, T>> IntoDyn<D, T> for V {}
@@ -0,0 +1,36 @@
+mod dimension;
+mod dyn_as_nvec;
+mod dyn_nvec;
+mod dyn_slice;
+mod dyn_vec;
+mod idx_slice;
+mod into_dyn;
+mod nvec_as_dyn;
+
+pub use dimension::DynDim;
+pub use dyn_nvec::DynNVec;
+pub use dyn_vec::DynVec;
+pub use into_dyn::IntoDyn;
+pub use nvec_as_dyn::NVecAsDyn;
+
+// out-of-bound errors
+#[allow(clippy::panic)]
+fn panic_rank(num_indices: usize, rank: usize) -> ! {
+    panic!(
+        "Index of length {} cannot be used to access an element of a vector of rank {}",
+        num_indices, rank
+    )
+}
+
+#[allow(clippy::panic)]
+fn panic_card_rank(num_indices: usize, rank: usize) -> ! {
+    panic!(
+        "Required rank condition idx.len() < rank fails for idx.len()={} and rank={} while querying cardinality",
+        num_indices, rank
+    )
+}
+
+#[allow(clippy::panic)]
+fn panic_no_child<S>() -> S {
+    panic!("A vector of rank 1 does not have children")
+}
@@ -0,0 +1,122 @@
+use super::{panic_card_rank, panic_no_child, panic_rank, DynNVec};
+use crate::{Dim, NVec, D1, D2, D3, D4, D5, D6};
+use core::marker::PhantomData;
+
+/// A vector with static dimension `D` viewed as a vector with runtime dimension.
+///
+/// It can be created by the [`into_dyn`] and [`as_dyn`] methods.
+///
+/// [`into_dyn`]: crate::IntoDyn::into_dyn
+/// [`as_dyn`]: crate::IntoDyn::as_dyn
+pub struct NVecAsDyn<D, T, V>
+where
+    D: Dim,
+    V: NVec<D, T>,
+{
+    vec: V,
+    phantom: PhantomData<(D, T)>,
+}
+
+impl<D, T, V> NVecAsDyn<D, T, V>
+where
+    D: Dim,
+    V: NVec<D, T>,
+{
+    pub(super) fn new(vec: V) -> Self {
+        Self {
+            vec,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Destructs the dynamic view into the underlying vector with static dimension.
+    pub fn into_inner(self) -> V {
+        self.vec
+    }
+}
+
+macro_rules! impl_dyn_nvec {
+    ($dim:ty) => {
+        impl<T, V> DynNVec<T> for NVecAsDyn<$dim, T, V>
+        where
+            V: NVec<$dim, T>,
+        {
+            #[inline(always)]
+            fn rank(&self) -> usize {
+                <$dim as Dim>::dimension()
+            }
+
+            fn card(&self, idx: impl AsRef<[usize]>) -> usize {
+                let idx = idx.as_ref();
+                match idx.split_first() {
+                    _ if idx.len() >= <$dim as Dim>::dimension() => {
+                        panic_card_rank(idx.len(), <$dim as Dim>::dimension())
+                    }
+                    None => self.vec.card([]),
+                    Some((i, idx)) => self.child(*i).card(idx),
+                }
+            }
+
+            #[inline(always)]
+            fn at(&self, idx: impl AsRef<[usize]>) -> T {
+                let idx = idx.as_ref();
+                let idx: <$dim as Dim>::Idx = match idx.try_into() {
+                    Ok(idx) => idx,
+                    Err(_) => panic_rank(idx.len(), <$dim as Dim>::dimension()),
+                };
+                self.vec.at(idx)
+            }
+
+            fn child(&self, i: usize) -> impl DynNVec<T> {
+                self.dyn_child(i)
+            }
+
+            fn all(&self) -> impl Iterator<Item = T> {
+                self.vec.all()
+            }
+
+            fn is_rectangular(&self) -> bool {
+                self.vec.is_rectangular()
+            }
+        }
+    };
+}
+
+impl<T, V> NVecAsDyn<D1, T, V>
+where
+    V: NVec<D1, T>,
+{
+    fn dyn_child(&self, _: usize) -> Self {
+        panic_no_child()
+    }
+}
+
+macro_rules! impl_child {
+    ($dim:ty) => {
+        impl<T, V> NVecAsDyn<$dim, T, V>
+        where
+            V: NVec<$dim, T>,
+        {
+            fn dyn_child(
+                &self,
+                i: usize,
+            ) -> NVecAsDyn<<$dim as Dim>::PrevDim, T, impl NVec<<$dim as Dim>::PrevDim, T> + '_>
+            {
+                NVecAsDyn::new(self.vec.child(i.into()))
+            }
+        }
+    };
+}
+
+impl_child!(D2);
+impl_child!(D3);
+impl_child!(D4);
+impl_child!(D5);
+impl_child!(D6);
+
+impl_dyn_nvec!(D1);
+impl_dyn_nvec!(D2);
+impl_dyn_nvec!(D3);
+impl_dyn_nvec!(D4);
+impl_dyn_nvec!(D5);
+impl_dyn_nvec!(D6);
@@ -1,4 +1,4 @@
-use crate::{CardIdx, Dim, EmptyCard, IntoIdx, NVec, NVecCoreSealed, NVecMut};
+use crate::{CardIdx, Dim, EmptyCard, IntoIdx, NVec, NVecAllMut, NVecCoreSealed, NVecMut};
 use core::fmt::Debug;
 use core::marker::PhantomData;
 
@@ -97,3 +97,19 @@ impl<D: Dim, T> NVecMut<D, T> for EmptyVec<D, T> {
     {
     }
 }
+
+impl<D: Dim, T> NVecAllMut<D, T> for EmptyVec<D, T> {
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        core::iter::empty()
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D as Dim>::Idx, &'a mut T)>
+    where
+        T: 'a,
+    {
+        core::iter::empty()
+    }
+}
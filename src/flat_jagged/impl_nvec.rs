@@ -15,6 +15,16 @@ where
         self.jagged.flat_vec.at(idx)
     }
 
+    #[inline(always)]
+    unsafe fn at_unchecked(&self, idx: impl IntoIdx<D1>) -> T {
+        debug_assert!(D1::in_bounds(idx, self), "index is out of bounds");
+        let [j] = idx.into_idx();
+        unsafe {
+            let idx = self.jagged.to_d1_idx_unchecked([self.i, j]);
+            self.jagged.flat_vec.at_unchecked(idx)
+        }
+    }
+
     fn child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVec<<D1 as Dim>::PrevDim, T> {
         self
     }
@@ -38,6 +48,15 @@ where
         self.flat_vec.at(idx)
     }
 
+    #[inline(always)]
+    unsafe fn at_unchecked(&self, idx: impl IntoIdx<D2>) -> T {
+        debug_assert!(D2::in_bounds(idx, self), "index is out of bounds");
+        unsafe {
+            let idx = self.to_d1_idx_unchecked(idx.into_idx());
+            self.flat_vec.at_unchecked(idx)
+        }
+    }
+
     fn child(&self, i: <D2 as Dim>::ChildIdx) -> impl NVec<<D2 as Dim>::PrevDim, T> {
         let (begin, end) = self.row_range(i);
         FunVec::new(move |[j]| self.at([i, j]), CardD1::from(end - begin))
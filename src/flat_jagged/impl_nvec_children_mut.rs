@@ -0,0 +1,44 @@
+use super::FlatJagged;
+use crate::common_trait_helpers::split_mut::split_at_ends_mut;
+use crate::{Dim, NVec, NVecChildrenMut, NVecMut, D1, D2, V};
+
+impl<V1, I, T> NVecChildrenMut<D2, T> for FlatJagged<V1, I, T>
+where
+    V1: NVecMut<D1, T> + AsMut<[T]>,
+    I: NVec<D1, usize>,
+    T: Copy,
+{
+    fn children_mut(&mut self) -> impl Iterator<Item = impl NVecMut<<D2 as Dim>::PrevDim, T>> {
+        let ends = &self.row_end_indices;
+        let ends = (0..ends.card([])).map(move |i| ends.at(i));
+        split_at_ends_mut(self.flat_vec.as_mut(), ends)
+    }
+
+    fn split_children_mut(&mut self, i: usize) -> (impl NVecMut<D2, T>, impl NVecMut<D2, T>) {
+        let num_rows = self.num_rows();
+        assert!(
+            i <= num_rows,
+            "FlatJagged cannot be split at row {}: jagged array has {} rows",
+            i,
+            num_rows
+        );
+
+        let ends = &self.row_end_indices;
+        let begin = match i {
+            0 => 0,
+            _ => ends.at(i - 1),
+        };
+        let (first, second) = self.flat_vec.as_mut().split_at_mut(begin);
+
+        let first_ends = V.d1().fun(move |[k]| ends.at(k)).bounded(i);
+        let second_ends = V
+            .d1()
+            .fun(move |[k]| ends.at(i + k) - begin)
+            .bounded(num_rows - i);
+
+        (
+            FlatJagged::new(first, first_ends),
+            FlatJagged::new(second, second_ends),
+        )
+    }
+}
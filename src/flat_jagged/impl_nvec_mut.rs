@@ -1,5 +1,5 @@
 use super::{FlatJagged, FlatJaggedRowMut};
-use crate::{Dim, IntoIdx, NVec, NVecMut, D1, D2};
+use crate::{Dim, IntoIdx, NVec, NVecAllMut, NVecMut, D1, D2};
 
 // row
 
@@ -14,6 +14,15 @@ where
         self.jagged.flat_vec.at_mut(idx)
     }
 
+    unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<D1>) -> &mut T {
+        debug_assert!(D1::in_bounds(idx, self), "index is out of bounds");
+        let [j] = idx.into_idx();
+        unsafe {
+            let idx = self.jagged.to_d1_idx_unchecked([self.i, j]);
+            self.jagged.flat_vec.at_mut_unchecked(idx)
+        }
+    }
+
     fn set<Idx: IntoIdx<D1>>(&mut self, idx: Idx, value: T) {
         let [j] = idx.into_idx();
         let idx = self.jagged.to_d1_idx([self.i, j]);
@@ -53,6 +62,14 @@ where
         self.flat_vec.at_mut(idx)
     }
 
+    unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<D2>) -> &mut T {
+        debug_assert!(D2::in_bounds(idx, self), "index is out of bounds");
+        unsafe {
+            let idx = self.to_d1_idx_unchecked(idx.into_idx());
+            self.flat_vec.at_mut_unchecked(idx)
+        }
+    }
+
     fn set<Idx: IntoIdx<D2>>(&mut self, idx: Idx, value: T) {
         let idx = self.to_d1_idx(idx.into_idx());
         self.flat_vec.set(idx, value);
@@ -76,3 +93,35 @@ where
         self.flat_vec.mut_all(|x| *x = value);
     }
 }
+
+impl<V, I, T> NVecAllMut<D2, T> for FlatJagged<V, I, T>
+where
+    V: NVecAllMut<D1, T>,
+    I: NVec<D1, usize>,
+{
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        let len = match self.num_rows() {
+            0 => 0,
+            n => self.row_end_indices.at(n - 1),
+        };
+        self.flat_vec.all_mut().take(len)
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D2 as Dim>::Idx, &'a mut T)>
+    where
+        T: 'a,
+    {
+        let ends = &self.row_end_indices;
+        let indices = (0..ends.card([])).flat_map(move |i| {
+            let begin = match i {
+                0 => 0,
+                _ => ends.at(i - 1),
+            };
+            (0..(ends.at(i) - begin)).map(move |j| [i, j])
+        });
+        indices.zip(self.flat_vec.all_mut())
+    }
+}
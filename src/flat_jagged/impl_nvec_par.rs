@@ -0,0 +1,49 @@
+use super::FlatJagged;
+use crate::{CardD1, Dim, FunVec, NVec, NVecPar, NVecParMut, D1, D2};
+use rayon::prelude::*;
+
+impl<V, I, T> NVecPar<D2, T> for FlatJagged<V, I, T>
+where
+    V: NVecPar<D1, T>,
+    I: NVec<D1, usize> + Sync,
+    T: Send + Sync,
+{
+    fn par_children(
+        &self,
+    ) -> impl IndexedParallelIterator<Item = impl NVec<<D2 as Dim>::PrevDim, T> + Send> {
+        (0..self.num_rows()).into_par_iter().map(move |i| {
+            let (begin, end) = self.row_range(i);
+            FunVec::new(
+                move |[j]| self.flat_vec.at(begin + j),
+                CardD1::from(end - begin),
+            )
+        })
+    }
+
+    fn par_all(&self) -> impl ParallelIterator<Item = T> {
+        self.flat_vec.par_all()
+    }
+
+    fn par_enumerate_all(&self) -> impl ParallelIterator<Item = (<D2 as Dim>::Idx, T)> {
+        (0..self.num_rows())
+            .into_par_iter()
+            .flat_map_iter(move |i| {
+                let (begin, end) = self.row_range(i);
+                (begin..end).map(move |k| ([i, k - begin], self.flat_vec.at(k)))
+            })
+    }
+}
+
+impl<V, I, T> NVecParMut<D2, T> for FlatJagged<V, I, T>
+where
+    V: NVecParMut<D1, T>,
+    I: NVec<D1, usize>,
+    T: Send,
+{
+    fn par_mut_all<F>(&mut self, f: F)
+    where
+        F: Fn(&mut T) + Sync + Send,
+    {
+        self.flat_vec.par_mut_all(f);
+    }
+}
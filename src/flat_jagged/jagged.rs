@@ -49,16 +49,64 @@ use core::marker::PhantomData;
 ///     Equality::Equal,
 /// );
 /// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "FlatJaggedFields<V, I>")
+)]
 pub struct FlatJagged<V, I, T>
 where
     V: NVec<D1, T>,
     I: NVec<D1, usize>,
 {
     pub(super) flat_vec: V,
-    row_end_indices: I,
+    pub(super) row_end_indices: I,
+    #[cfg_attr(feature = "serde", serde(skip))]
     phantom: PhantomData<T>,
 }
 
+/// Fields of a [`FlatJagged`] which are deserialized and validated before the jagged
+/// vector is created.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct FlatJaggedFields<V, I> {
+    flat_vec: V,
+    row_end_indices: I,
+}
+
+#[cfg(feature = "serde")]
+impl<V, I, T> TryFrom<FlatJaggedFields<V, I>> for FlatJagged<V, I, T>
+where
+    V: NVec<D1, T>,
+    I: NVec<D1, usize>,
+{
+    type Error = &'static str;
+
+    fn try_from(fields: FlatJaggedFields<V, I>) -> Result<Self, Self::Error> {
+        let FlatJaggedFields {
+            flat_vec,
+            row_end_indices,
+        } = fields;
+
+        if row_end_indices.is_unbounded() {
+            return Err("row end indices of the FlatJagged must be bounded");
+        }
+
+        let mut begin = 0;
+        for end in row_end_indices.all() {
+            if end < begin {
+                return Err("row end indices of the FlatJagged must be non-decreasing");
+            }
+            begin = end;
+        }
+
+        match begin <= flat_vec.card([]) {
+            true => Ok(Self::new(flat_vec, row_end_indices)),
+            false => Err("row end indices of the FlatJagged exceed the length of the flat vector"),
+        }
+    }
+}
+
 impl<V, I, T> FlatJagged<V, I, T>
 where
     V: NVec<D1, T>,
@@ -152,6 +200,19 @@ where
         }
     }
 
+    /// # Safety
+    ///
+    /// `idx` must be in bounds of the jagged array.
+    #[inline(always)]
+    pub(super) unsafe fn to_d1_idx_unchecked(&self, idx: <D2 as Dim>::Idx) -> usize {
+        let [i, j] = idx;
+        let begin = match i {
+            0 => 0,
+            _ => unsafe { self.row_end_indices.at_unchecked(i - 1) },
+        };
+        begin + j
+    }
+
     pub(super) fn row_range(&self, i: usize) -> (usize, usize) {
         let begin = match i {
             0 => 0,
@@ -164,7 +225,7 @@ where
         (begin, end)
     }
 
-    pub(super) fn row_mut(&mut self, i: usize) -> FlatJaggedRowMut<V, I, T> {
+    pub(super) fn row_mut(&mut self, i: usize) -> FlatJaggedRowMut<'_, V, I, T> {
         match i < self.num_rows() {
             true => FlatJaggedRowMut { jagged: self, i },
             false => panic_oob_i(i, self.num_rows()),
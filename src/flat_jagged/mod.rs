@@ -1,6 +1,9 @@
 mod impl_nvec;
+mod impl_nvec_children_mut;
 mod impl_nvec_core;
 mod impl_nvec_mut;
+#[cfg(feature = "rayon")]
+mod impl_nvec_par;
 mod into_jagged;
 mod jagged;
 mod jagged_row;
@@ -9,3 +12,4 @@ mod uniform_end_indices;
 pub use into_jagged::IntoJagged;
 pub use jagged::FlatJagged;
 pub use jagged_row::FlatJaggedRowMut;
+pub use uniform_end_indices::UniformEndIndices;
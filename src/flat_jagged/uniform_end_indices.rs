@@ -1,5 +1,14 @@
 use crate::{Dim, IntoIdx, NVec, NVecCoreSealed, D1};
 
+/// Row end indices of a jagged vector where each row has the same length, except that
+/// the last row might have fewer elements.
+///
+/// It is the `D1` vector of row end indices of the [`FlatJagged`] vectors created by
+/// [`into_jagged_with_uniform_lengths`] and its borrowing variants.
+///
+/// [`FlatJagged`]: crate::FlatJagged
+/// [`into_jagged_with_uniform_lengths`]: crate::IntoJagged::into_jagged_with_uniform_lengths
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UniformEndIndices {
     num_rows: usize,
     num_cols: usize,
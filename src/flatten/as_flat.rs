@@ -0,0 +1,145 @@
+use super::FlattenVec;
+use crate::{Dim, NVec, NVecMut, D2, D3, D4, D5, D6};
+
+/// Creates `D1` views of bounded multi-dimensional vectors.
+///
+/// This is the inverse of [`IntoJagged`] which creates `D2` views of `D1` vectors;
+/// however, it is available for any bounded vector, not only for flat-backed ones.
+///
+/// [`IntoJagged`]: crate::IntoJagged
+pub trait AsFlat<D: Dim, T>: NVec<D, T> + Sized {
+    /// Converts the vector into a `D1` view such that its elements are the elements of
+    /// this vector in the order yielded by [`all`]; i.e., in lexicographic order of
+    /// their indices.
+    ///
+    /// When the underlying vector is mutable, so is the view.
+    /// See [`as_flat`] for details, and [`as_flat_mut`] to create a mutable view over a
+    /// reference of the vector.
+    ///
+    /// [`all`]: crate::NVec::all
+    /// [`as_flat`]: crate::AsFlat::as_flat
+    /// [`as_flat_mut`]: crate::AsFlat::as_flat_mut
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is unbounded, or if any of its children is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = vec![vec![0, 1], vec![2], vec![3, 4]];
+    ///
+    /// let mut v1 = v2.into_flat();
+    /// v1.set(2, 20);
+    /// assert_eq!(v1.all().collect::<Vec<_>>(), vec![0, 1, 20, 3, 4]);
+    ///
+    /// let v2 = v1.into_inner();
+    /// assert_eq!(v2, vec![vec![0, 1], vec![20], vec![3, 4]]);
+    /// ```
+    fn into_flat(self) -> FlattenVec<D, T, Self>;
+
+    /// Creates a `D1` view of the vector such that its elements are the elements of
+    /// this vector in the order yielded by [`all`]; i.e., in lexicographic order of
+    /// their indices.
+    ///
+    /// Random access by [`at`] is:
+    /// * O(1) when the vector has a rectangular cardinality, and
+    /// * O(log n) by a binary search over the cumulative sizes of the lowest
+    ///   dimensional children, such as rows of a `D2` vector, when the cardinality
+    ///   is variable. Note that these cumulative sizes are computed once when the
+    ///   view is created.
+    ///
+    /// [`all`]: crate::NVec::all
+    /// [`at`]: crate::NVec::at
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is unbounded, or if any of its children is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// fn max_of(v: impl V1<i32>) -> Option<i32> {
+    ///     v.all().max()
+    /// }
+    ///
+    /// // jagged
+    /// let v3 = vec![vec![vec![1, 2], vec![]], vec![], vec![vec![7], vec![3, 4]]];
+    /// let v1 = v3.as_flat();
+    /// assert_eq!(v1.card([]), 5);
+    /// assert_eq!(v1.at(2), 7);
+    /// assert_eq!(v1.at(4), 4);
+    /// assert_eq!(max_of(&v1), Some(7));
+    ///
+    /// // rectangular
+    /// let v2 = V.d2().fun(|[i, j]| (10 * i + j) as i32).with_rectangular_bounds([3, 4]);
+    /// let v1 = v2.as_flat();
+    /// assert_eq!(v1.card([]), 12);
+    /// assert_eq!(v1.at(6), 12);
+    /// assert_eq!(max_of(&v1), Some(23));
+    /// ```
+    fn as_flat(&self) -> FlattenVec<D, T, &Self>;
+
+    /// Creates a mutable `D1` view of the vector such that its elements are the
+    /// elements of this vector in the order yielded by [`all`]; i.e., in
+    /// lexicographic order of their indices.
+    ///
+    /// See [`as_flat`] for details.
+    ///
+    /// [`all`]: crate::NVec::all
+    /// [`as_flat`]: crate::AsFlat::as_flat
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is unbounded, or if any of its children is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v2 = vec![vec![0, 1], vec![2], vec![3, 4]];
+    ///
+    /// let mut v1 = v2.as_flat_mut();
+    /// v1.set(2, 20);
+    /// *v1.at_mut(4) += 40;
+    /// assert_eq!(v2, vec![vec![0, 1], vec![20], vec![3, 44]]);
+    /// ```
+    fn as_flat_mut(&mut self) -> FlattenVec<D, T, &mut Self>
+    where
+        Self: NVecMut<D, T>;
+}
+
+macro_rules! impl_as_flat {
+    ($dim:ty) => {
+        impl<T, V> AsFlat<$dim, T> for V
+        where
+            V: NVec<$dim, T>,
+        {
+            fn into_flat(self) -> FlattenVec<$dim, T, Self> {
+                FlattenVec::<$dim, T, Self>::new(self)
+            }
+
+            fn as_flat(&self) -> FlattenVec<$dim, T, &Self> {
+                FlattenVec::<$dim, T, &Self>::new(self)
+            }
+
+            fn as_flat_mut(&mut self) -> FlattenVec<$dim, T, &mut Self>
+            where
+                Self: NVecMut<$dim, T>,
+            {
+                FlattenVec::<$dim, T, &mut Self>::new(self)
+            }
+        }
+    };
+}
+
+impl_as_flat!(D2);
+impl_as_flat!(D3);
+impl_as_flat!(D4);
+impl_as_flat!(D5);
+impl_as_flat!(D6);
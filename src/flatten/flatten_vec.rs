@@ -0,0 +1,198 @@
+use crate::common_trait_helpers::debug::*;
+use crate::common_trait_helpers::shape::rectangular_shape;
+use crate::{dim::*, DynNVec, IntoDyn, NVec};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A `D1` view of a bounded vector of dimension `D` such that its elements are the
+/// elements of the vector in the order yielded by its [`all`] method; i.e., in
+/// lexicographic order of their indices.
+///
+/// Random access by [`at`] is:
+/// * O(1) when the vector has a rectangular cardinality, and
+/// * O(log n) by a binary search over the cumulative sizes of the lowest dimensional
+///   children, such as rows of a `D2` vector, when the cardinality is variable.
+///
+/// It is created by the [`into_flat`], [`as_flat`] and [`as_flat_mut`] methods.
+///
+/// [`all`]: crate::NVec::all
+/// [`at`]: crate::NVec::at
+/// [`into_flat`]: crate::AsFlat::into_flat
+/// [`as_flat`]: crate::AsFlat::as_flat
+/// [`as_flat_mut`]: crate::AsFlat::as_flat_mut
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let v2 = vec![vec![0, 1], vec![], vec![2, 3, 4]];
+///
+/// let v1 = v2.as_flat();
+/// assert_eq!(v1.card([]), 5);
+/// assert_eq!(v1.at(3), 3);
+/// assert_eq!(v1.all().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+/// ```
+pub struct FlattenVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D, T>,
+{
+    pub(super) vec: V,
+    pub(super) shape: Option<D::Idx>,
+    pub(super) prefixes: Vec<<D::PrevDim as Dim>::Idx>,
+    pub(super) ends: Vec<usize>,
+    pub(super) len: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<D, T, V> FlattenVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D, T>,
+{
+    /// Destructs the flattened view into the underlying vector.
+    pub fn into_inner(self) -> V {
+        self.vec
+    }
+}
+
+macro_rules! impl_new {
+    ($dim:ty, $n:expr) => {
+        impl<T, V> FlattenVec<$dim, T, V>
+        where
+            V: NVec<$dim, T>,
+        {
+            pub(super) fn new(vec: V) -> Self {
+                assert!(vec.is_bounded(), "An unbounded vector cannot be flattened");
+                let dyn_vec = vec.as_dyn();
+
+                match vec.is_rectangular() {
+                    true => {
+                        let shape: [usize; $n] = rectangular_shape(&dyn_vec);
+                        let len = shape.iter().try_fold(1usize, |len, n| len.checked_mul(*n));
+                        let len = match len {
+                            Some(len) => len,
+                            None => panic_unbounded(),
+                        };
+                        Self {
+                            vec,
+                            shape: Some(shape),
+                            prefixes: Vec::new(),
+                            ends: Vec::new(),
+                            len,
+                            phantom: PhantomData,
+                        }
+                    }
+                    false => {
+                        let mut prefixes = Vec::new();
+                        let mut ends = Vec::new();
+                        let mut prefix = [0; $n - 1];
+                        push_leaves(&dyn_vec, &mut prefix, 0, &mut prefixes, &mut ends);
+                        let len = ends.last().copied().unwrap_or(0);
+                        Self {
+                            vec,
+                            shape: None,
+                            prefixes,
+                            ends,
+                            len,
+                            phantom: PhantomData,
+                        }
+                    }
+                }
+            }
+
+            #[inline(always)]
+            pub(super) fn src_idx(&self, k: usize) -> [usize; $n] {
+                if k >= self.len {
+                    panic_oob(k, self.len);
+                }
+
+                let mut idx = [0; $n];
+                match &self.shape {
+                    Some(shape) => {
+                        let mut k = k;
+                        for q in (0..$n).rev() {
+                            idx[q] = k % shape[q];
+                            k /= shape[q];
+                        }
+                    }
+                    None => {
+                        let l = self.ends.partition_point(|end| *end <= k);
+                        let begin = match l {
+                            0 => 0,
+                            _ => self.ends[l - 1],
+                        };
+                        idx[..($n - 1)].copy_from_slice(&self.prefixes[l]);
+                        idx[$n - 1] = k - begin;
+                    }
+                }
+                idx
+            }
+        }
+    };
+}
+
+impl_new!(D2, 2);
+impl_new!(D3, 3);
+impl_new!(D4, 4);
+impl_new!(D5, 5);
+impl_new!(D6, 6);
+
+/// Pushes indices of all lowest dimensional children of the `vec`, which are `D1`
+/// vectors, together with the cumulative number of their elements.
+fn push_leaves<T, const M: usize>(
+    vec: &impl DynNVec<T>,
+    prefix: &mut [usize; M],
+    q: usize,
+    prefixes: &mut Vec<[usize; M]>,
+    ends: &mut Vec<usize>,
+) {
+    match q == M {
+        true => {
+            let end = ends.last().copied().unwrap_or(0) + vec.card(&prefix[..]);
+            prefixes.push(*prefix);
+            ends.push(end);
+        }
+        false => {
+            for i in 0..vec.card(&prefix[..q]) {
+                prefix[q] = i;
+                push_leaves(vec, prefix, q + 1, prefixes, ends);
+            }
+        }
+    }
+}
+
+// errors
+
+#[allow(clippy::panic)]
+fn panic_oob(k: usize, len: usize) -> ! {
+    panic!(
+        "Flattened idx {} is out of bounds: flattened vector has {} elements",
+        k, len
+    );
+}
+
+#[allow(clippy::panic)]
+fn panic_unbounded() -> ! {
+    panic!("A vector with an unbounded child cannot be flattened");
+}
+
+impl<D, T, V> Debug for FlattenVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    T: Debug,
+    Self: NVec<D1, T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{{ kind: FlattenVec, dim: D1, source_dim: D{}, values: ",
+            D::dimension()
+        )?;
+        dbg_values_d1(f, self)?;
+        write!(f, " }}")
+    }
+}
@@ -0,0 +1,11 @@
+use super::FlattenVec;
+use crate::{Dim, IntoCached, NVec, D1};
+
+impl<D, T, V> IntoCached<D1, T> for FlattenVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    T: Copy,
+    Self: NVec<D1, T>,
+{
+}
@@ -0,0 +1,124 @@
+use super::FlattenVec;
+use crate::{dim::*, NVec, NVecAllMut, NVecCoreSealed, NVecMut};
+
+macro_rules! impl_flatten {
+    ($dim:ty) => {
+        impl<T, V> NVecCoreSealed<D1, T> for FlattenVec<$dim, T, V>
+        where
+            V: NVec<$dim, T>,
+        {
+            #[inline(always)]
+            fn core_num_children(&self) -> usize {
+                self.len
+            }
+
+            #[inline(always)]
+            fn core_card(&self, _: impl Into<<D1 as Dim>::CardIdx>) -> usize {
+                self.len
+            }
+
+            fn core_child(
+                &self,
+                _: <D1 as Dim>::ChildIdx,
+            ) -> impl NVecCoreSealed<<D1 as Dim>::PrevDim, T> {
+                self
+            }
+
+            #[inline(always)]
+            fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D1>, f: &mut F) -> O {
+                let [k] = idx.into_idx();
+                self.vec.core_map(self.src_idx(k), f)
+            }
+
+            fn core_is_rectangular(&self) -> bool {
+                true
+            }
+        }
+
+        impl<T, V> NVec<D1, T> for FlattenVec<$dim, T, V>
+        where
+            V: NVec<$dim, T>,
+        {
+            #[inline(always)]
+            fn at(&self, idx: impl IntoIdx<D1>) -> T {
+                let [k] = idx.into_idx();
+                self.vec.at(self.src_idx(k))
+            }
+
+            fn child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVec<<D1 as Dim>::PrevDim, T> {
+                self
+            }
+
+            fn all(&self) -> impl Iterator<Item = T> {
+                self.vec.all()
+            }
+        }
+
+        impl<T, V> NVecMut<D1, T> for FlattenVec<$dim, T, V>
+        where
+            V: NVecMut<$dim, T>,
+        {
+            #[inline(always)]
+            fn at_mut<Idx: IntoIdx<D1>>(&mut self, idx: Idx) -> &mut T {
+                let [k] = idx.into_idx();
+                let idx = self.src_idx(k);
+                self.vec.at_mut(idx)
+            }
+
+            #[inline(always)]
+            fn set<Idx: IntoIdx<D1>>(&mut self, idx: Idx, value: T) {
+                let [k] = idx.into_idx();
+                let idx = self.src_idx(k);
+                self.vec.set(idx, value);
+            }
+
+            fn child_mut(
+                &mut self,
+                _: <D1 as Dim>::ChildIdx,
+            ) -> impl NVecMut<<D1 as Dim>::PrevDim, T> {
+                self
+            }
+
+            fn mut_all<F>(&mut self, f: F)
+            where
+                F: FnMut(&mut T),
+            {
+                self.vec.mut_all(f);
+            }
+
+            fn reset_all(&mut self, value: T)
+            where
+                T: PartialEq + Copy,
+            {
+                self.vec.reset_all(value);
+            }
+        }
+
+        impl<T, V> NVecAllMut<D1, T> for FlattenVec<$dim, T, V>
+        where
+            V: NVecAllMut<$dim, T>,
+        {
+            fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+            where
+                T: 'a,
+            {
+                self.vec.all_mut()
+            }
+
+            fn enumerate_all_mut<'a>(
+                &'a mut self,
+            ) -> impl Iterator<Item = (<D1 as Dim>::Idx, &'a mut T)>
+            where
+                T: 'a,
+            {
+                self.vec.all_mut().enumerate().map(|(k, x)| ([k], x))
+            }
+        }
+    };
+}
+
+impl_flatten!(D2);
+impl_flatten!(D3);
+impl_flatten!(D4);
+impl_flatten!(D5);
+impl_flatten!(D6);
@@ -0,0 +1,7 @@
+mod as_flat;
+mod flatten_vec;
+mod impl_into_cached;
+mod impl_nvec;
+
+pub use as_flat::AsFlat;
+pub use flatten_vec::FlattenVec;
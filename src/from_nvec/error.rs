@@ -0,0 +1,33 @@
+use core::fmt::Display;
+
+/// Error returned when an [`NVec`] cannot be materialized into an owned storage.
+///
+/// [`NVec`]: crate::NVec
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FromNVecError {
+    /// The vector, or one of its children, is unbounded; and hence, it cannot be
+    /// collected into a finite storage.
+    ///
+    /// Finite domain of such vectors can be set by calling `bounded` for `D1` vectors,
+    /// or `with_rectangular_bounds` or `with_variable_bounds` for higher dimensional vectors.
+    Unbounded,
+    /// The target storage is rectangular, such as a matrix; however, the vector is jagged.
+    NotRectangular,
+}
+
+impl Display for FromNVecError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unbounded => write!(
+                f,
+                "Cannot collect an unbounded vector into an owned storage; consider setting its bounds by `bounded`, `with_rectangular_bounds` or `with_variable_bounds`"
+            ),
+            Self::NotRectangular => write!(
+                f,
+                "Cannot collect a jagged vector into a rectangular storage"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for FromNVecError {}
@@ -0,0 +1,23 @@
+use super::{FromNVec, FromNVecError};
+use crate::{FlatJagged, IntoJagged, NVec, D2};
+use alloc::vec::Vec;
+
+impl<T: Copy> FromNVec<D2, T> for FlatJagged<Vec<T>, Vec<usize>, T> {
+    fn from_nvec(vec: &impl NVec<D2, T>) -> Result<Self, FromNVecError> {
+        if vec.is_unbounded() {
+            return Err(FromNVecError::Unbounded);
+        }
+
+        let mut flat_vec = Vec::new();
+        let mut row_end_indices = Vec::with_capacity(vec.num_children());
+        for row in vec.children() {
+            if row.is_unbounded() {
+                return Err(FromNVecError::Unbounded);
+            }
+            flat_vec.extend(row.all());
+            row_end_indices.push(flat_vec.len());
+        }
+
+        Ok(flat_vec.into_jagged(row_end_indices))
+    }
+}
@@ -0,0 +1,47 @@
+use super::FromNVecError;
+use crate::{Dim, NVec};
+
+/// A storage that can be created by materializing, or collecting all elements of, a
+/// `D` dimensional vector of `T`.
+///
+/// Implemented by:
+/// * nested standard vectors such as `Vec<T>` for `D1` and `Vec<Vec<T>>` for `D2`;
+/// * [`FlatJagged`] with `Vec` storages for `D2`;
+/// * [`V1Matrix`] with `Vec` storage for rectangular `D2` vectors.
+///
+/// Implementing this trait allows to target a custom container with the same
+/// materialization logic.
+///
+/// [`FlatJagged`]: crate::FlatJagged
+/// [`V1Matrix`]: crate::matrices::V1Matrix
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// struct Bag(Vec<u64>);
+///
+/// impl FromNVec<D2, u64> for Bag {
+///     fn from_nvec(vec: &impl NVec<D2, u64>) -> Result<Self, FromNVecError> {
+///         match vec.is_unbounded() {
+///             true => Err(FromNVecError::Unbounded),
+///             false => Ok(Self(vec.all().collect())),
+///         }
+///     }
+/// }
+///
+/// let v2 = V.d2().fun(|[i, j]| (i + j) as u64).with_variable_bounds(vec![1, 2]);
+/// let bag = Bag::from_nvec(&v2).unwrap();
+/// assert_eq!(bag.0, vec![0, 1, 2]);
+///
+/// let v2 = V.d2().fun(|[i, j]| (i + j) as u64);
+/// assert_eq!(Bag::from_nvec(&v2).err(), Some(FromNVecError::Unbounded));
+/// ```
+pub trait FromNVec<D: Dim, T>: Sized {
+    /// Creates the storage by collecting all elements of the `vec`.
+    ///
+    /// Returns an error if the `vec` is unbounded, or if it does not satisfy the
+    /// shape requirements of the storage.
+    fn from_nvec(vec: &impl NVec<D, T>) -> Result<Self, FromNVecError>;
+}
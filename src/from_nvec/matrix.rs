@@ -0,0 +1,36 @@
+use super::{FromNVec, FromNVecError};
+use crate::common_trait_helpers::shape::rectangular_shape;
+use crate::{
+    matrices::{V1LayoutColMajor, V1LayoutRowMajor, V1Matrix},
+    IntoDyn, NVec, V1AsMatrix, D2,
+};
+use alloc::vec::Vec;
+
+fn matrix_shape<T>(vec: &impl NVec<D2, T>) -> Result<[usize; 2], FromNVecError> {
+    if vec.is_unbounded() || vec.children().any(|row| row.is_unbounded()) {
+        return Err(FromNVecError::Unbounded);
+    }
+
+    match vec.is_rectangular() {
+        true => Ok(rectangular_shape(&vec.as_dyn())),
+        false => Err(FromNVecError::NotRectangular),
+    }
+}
+
+impl<T: Copy> FromNVec<D2, T> for V1Matrix<T, Vec<T>, V1LayoutRowMajor> {
+    fn from_nvec(vec: &impl NVec<D2, T>) -> Result<Self, FromNVecError> {
+        let [num_rows, num_cols] = matrix_shape(vec)?;
+        let data: Vec<_> = vec.all().collect();
+        Ok(data.v1_into_matrix(num_rows, num_cols))
+    }
+}
+
+impl<T: Copy> FromNVec<D2, T> for V1Matrix<T, Vec<T>, V1LayoutColMajor> {
+    fn from_nvec(vec: &impl NVec<D2, T>) -> Result<Self, FromNVecError> {
+        let [num_rows, num_cols] = matrix_shape(vec)?;
+        let data: Vec<_> = (0..num_cols)
+            .flat_map(|j| (0..num_rows).map(move |i| vec.at([i, j])))
+            .collect();
+        Ok(data.v1_into_matrix_col_major(num_rows, num_cols))
+    }
+}
@@ -0,0 +1,12 @@
+mod error;
+mod flat_jagged;
+mod from_nvec_trait;
+mod matrix;
+mod nested_vec;
+mod to_nested_vec;
+mod v2_to_owned;
+
+pub use error::FromNVecError;
+pub use from_nvec_trait::FromNVec;
+pub use to_nested_vec::ToNestedVec;
+pub use v2_to_owned::V2ToOwned;
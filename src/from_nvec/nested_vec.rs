@@ -0,0 +1,36 @@
+use super::{FromNVec, FromNVecError};
+use crate::{Dim, NVec, D1, D2, D3, D4, D5, D6};
+use alloc::vec::Vec;
+
+impl<T> FromNVec<D1, T> for Vec<T> {
+    fn from_nvec(vec: &impl NVec<D1, T>) -> Result<Self, FromNVecError> {
+        match vec.is_unbounded() {
+            true => Err(FromNVecError::Unbounded),
+            false => Ok(vec.all().collect()),
+        }
+    }
+}
+
+macro_rules! impl_from_nvec_nested {
+    ($dim:ty) => {
+        impl<T, C> FromNVec<$dim, T> for Vec<C>
+        where
+            C: FromNVec<<$dim as Dim>::PrevDim, T>,
+        {
+            fn from_nvec(vec: &impl NVec<$dim, T>) -> Result<Self, FromNVecError> {
+                match vec.is_unbounded() {
+                    true => Err(FromNVecError::Unbounded),
+                    false => (0..vec.num_children())
+                        .map(|i| C::from_nvec(&vec.child(i)))
+                        .collect(),
+                }
+            }
+        }
+    };
+}
+
+impl_from_nvec_nested!(D2);
+impl_from_nvec_nested!(D3);
+impl_from_nvec_nested!(D4);
+impl_from_nvec_nested!(D5);
+impl_from_nvec_nested!(D6);
@@ -0,0 +1,39 @@
+use super::FromNVec;
+use crate::{Dim, D1, D2, D3, D4, D5, D6};
+use alloc::vec::Vec;
+
+/// A dimension which has a nested standard vector owning all elements of a vector
+/// of this dimension, such as `Vec<T>` for `D1` or `Vec<Vec<T>>` for `D2`.
+///
+/// Implemented for all dimensions `D1` to `D6`; it is the target of [`to_nested_vec`].
+///
+/// [`to_nested_vec`]: crate::NVec::to_nested_vec
+pub trait ToNestedVec: Dim {
+    /// Nested standard vector owning all elements of a vector of this dimension,
+    /// such as `Vec<T>` for `D1` or `Vec<Vec<T>>` for `D2`.
+    type NestedVec<T>: FromNVec<Self, T>;
+}
+
+impl ToNestedVec for D1 {
+    type NestedVec<T> = Vec<T>;
+}
+
+impl ToNestedVec for D2 {
+    type NestedVec<T> = Vec<Vec<T>>;
+}
+
+impl ToNestedVec for D3 {
+    type NestedVec<T> = Vec<Vec<Vec<T>>>;
+}
+
+impl ToNestedVec for D4 {
+    type NestedVec<T> = Vec<Vec<Vec<Vec<T>>>>;
+}
+
+impl ToNestedVec for D5 {
+    type NestedVec<T> = Vec<Vec<Vec<Vec<Vec<T>>>>>;
+}
+
+impl ToNestedVec for D6 {
+    type NestedVec<T> = Vec<Vec<Vec<Vec<Vec<Vec<T>>>>>>;
+}
@@ -0,0 +1,66 @@
+use super::{FromNVec, FromNVecError};
+use crate::{matrices::V1MatrixRowMajor, FlatJagged, NVec, D2};
+use alloc::vec::Vec;
+
+/// Materializes a `D2` vector into owned two-dimensional storages.
+pub trait V2ToOwned<T>: NVec<D2, T> {
+    /// Collects all elements of the `D2` vector into a [`FlatJagged`] vector where
+    /// both the flat storage and row end indices are standard vectors.
+    ///
+    /// Returns [`FromNVecError::Unbounded`] if the vector or any of its rows is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = V.d2().fun(|[i, j]| 10 * i + j).with_variable_bounds(vec![2, 0, 1]);
+    /// let jagged = v2.to_flat_jagged().unwrap();
+    /// assert_eq!(jagged.equality(&v2), Equality::Equal);
+    ///
+    /// let (flat_vec, row_end_indices) = jagged.into_inner();
+    /// assert_eq!(flat_vec, vec![0, 1, 20]);
+    /// assert_eq!(row_end_indices, vec![2, 2, 3]);
+    ///
+    /// let v2 = V.d2().fun(|[i, j]| 10 * i + j);
+    /// assert_eq!(v2.to_flat_jagged().err(), Some(FromNVecError::Unbounded));
+    /// ```
+    fn to_flat_jagged(&self) -> Result<FlatJagged<Vec<T>, Vec<usize>, T>, FromNVecError>
+    where
+        T: Copy,
+    {
+        FlatJagged::from_nvec(self)
+    }
+
+    /// Collects all elements of the rectangular `D2` vector into a row-major
+    /// [`V1Matrix`] backed by a standard vector.
+    ///
+    /// Returns
+    /// * [`FromNVecError::Unbounded`] if the vector or any of its rows is unbounded, and
+    /// * [`FromNVecError::NotRectangular`] if the rows do not have the same number of elements.
+    ///
+    /// [`V1Matrix`]: crate::matrices::V1Matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = V.d2().fun(|[i, j]| 10 * i + j).with_rectangular_bounds([2, 3]);
+    /// let mat = v2.to_matrix().unwrap();
+    /// assert_eq!(mat.num_rows(), 2);
+    /// assert_eq!(mat.num_cols(), 3);
+    /// assert_eq!(mat.at([1, 2]), 12);
+    ///
+    /// let v2 = vec![vec![1, 2], vec![3]];
+    /// assert_eq!(v2.to_matrix().err(), Some(FromNVecError::NotRectangular));
+    /// ```
+    fn to_matrix(&self) -> Result<V1MatrixRowMajor<T, Vec<T>>, FromNVecError>
+    where
+        T: Copy,
+    {
+        V1MatrixRowMajor::from_nvec(self)
+    }
+}
+
+impl<T, V> V2ToOwned<T> for V where V: NVec<D2, T> {}
@@ -1,7 +1,8 @@
 use super::FunVec;
 use crate::{
-    dim::*, CardD1, RectangularCardD2, RectangularCardD3, RectangularCardD4, UnboundedCard,
-    VariableCardD2, VariableCardD3, VariableCardD4, V1, V2, V3,
+    dim::*, CardD1, RectangularCardD2, RectangularCardD3, RectangularCardD4, RectangularCardD5,
+    RectangularCardD6, UnboundedCard, VariableCardD2, VariableCardD3, VariableCardD4,
+    VariableCardD5, VariableCardD6, V1, V2, V3, V4, V5,
 };
 
 // D1
@@ -461,3 +462,136 @@ where
         FunVec::new(self.fun, cardinality.into())
     }
 }
+
+// D5
+
+impl<T, F> FunVec<D5, T, F, UnboundedCard<D5>>
+where
+    F: Fn(<D5 as Dim>::Idx) -> T,
+{
+    /// Converts an unbounded functional vector into one with rectangular bounds as in
+    /// multi-dimensional matrices. The matrix has
+    /// `dimensions[0]` x `dimensions[1]` x `dimensions[2]` x `dimensions[3]` x `dimensions[4]` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v5 = V.d5().fun(|idx: [usize; 5]| idx.iter().sum::<usize>()).with_rectangular_bounds([2, 1, 3, 1, 2]);
+    ///
+    /// assert!(v5.is_rectangular());
+    /// assert_eq!(v5.card([]), 2);
+    /// assert_eq!(v5.card([0, 0, 0, 0]), 2);
+    /// assert_eq!(v5.all().count(), 12);
+    /// assert_eq!(v5.at([1, 1, 1, 1, 1]), 5);
+    ///
+    /// assert_eq!(v5.try_at([7, 7, 7, 7, 7]), None);
+    /// ```
+    pub fn with_rectangular_bounds(
+        self,
+        dimensions: [usize; 5],
+    ) -> FunVec<D5, T, F, RectangularCardD5> {
+        FunVec::new(self.fun, dimensions.into())
+    }
+
+    /// Converts an unbounded functional vector into one with variable bounds as in
+    /// jagged arrays:
+    /// * the vector has `cardinality.card([])` children, and
+    /// * `[i]`-th child has `cardinality.card([i])` children, and
+    /// * `[i, j]`-th child has `cardinality.card([i, j])` children, and
+    /// * `[i, j, k]`-th child has `cardinality.card([i, j, k])` children, and
+    /// * `[i, j, k, l]`-th child has `cardinality.at([i, j, k, l])` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let cardinality = vec![vec![vec![vec![2, 3]]], vec![vec![vec![1]]]];
+    /// let v5 = V.d5().fun(|idx: [usize; 5]| idx.iter().sum::<usize>()).with_variable_bounds(&cardinality);
+    ///
+    /// assert!(!v5.is_rectangular());
+    /// assert_eq!(v5.card([]), 2);
+    /// assert_eq!(v5.card([0, 0, 0]), 2);
+    /// assert_eq!(v5.card([0, 0, 0, 1]), 3);
+    /// assert_eq!(v5.card([1, 0, 0, 0]), 1);
+    /// assert_eq!(v5.all().count(), 6);
+    /// assert_eq!(v5.at([1, 1, 1, 1, 1]), 5);
+    ///
+    /// assert_eq!(v5.try_at([7, 7, 7, 7, 7]), None);
+    /// ```
+    pub fn with_variable_bounds<C>(self, cardinality: C) -> FunVec<D5, T, F, VariableCardD5<C>>
+    where
+        C: V4<usize>,
+    {
+        FunVec::new(self.fun, cardinality.into())
+    }
+}
+
+// D6
+
+impl<T, F> FunVec<D6, T, F, UnboundedCard<D6>>
+where
+    F: Fn(<D6 as Dim>::Idx) -> T,
+{
+    /// Converts an unbounded functional vector into one with rectangular bounds as in
+    /// multi-dimensional matrices. The matrix has
+    /// `dimensions[0]` x `dimensions[1]` x `dimensions[2]` x `dimensions[3]` x `dimensions[4]` x `dimensions[5]` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v6 = V.d6().fun(|idx: [usize; 6]| idx.iter().sum::<usize>()).with_rectangular_bounds([2, 1, 3, 1, 2, 2]);
+    ///
+    /// assert!(v6.is_rectangular());
+    /// assert_eq!(v6.card([]), 2);
+    /// assert_eq!(v6.card([0, 0, 0, 0, 0]), 2);
+    /// assert_eq!(v6.all().count(), 24);
+    /// assert_eq!(v6.at([1, 1, 1, 1, 1, 1]), 6);
+    ///
+    /// assert_eq!(v6.try_at([7, 7, 7, 7, 7, 7]), None);
+    /// ```
+    pub fn with_rectangular_bounds(
+        self,
+        dimensions: [usize; 6],
+    ) -> FunVec<D6, T, F, RectangularCardD6> {
+        FunVec::new(self.fun, dimensions.into())
+    }
+
+    /// Converts an unbounded functional vector into one with variable bounds as in
+    /// jagged arrays:
+    /// * the vector has `cardinality.card([])` children, and
+    /// * `[i]`-th child has `cardinality.card([i])` children, and
+    /// * `[i, j]`-th child has `cardinality.card([i, j])` children, and
+    /// * `[i, j, k]`-th child has `cardinality.card([i, j, k])` children, and
+    /// * `[i, j, k, l]`-th child has `cardinality.card([i, j, k, l])` children, and
+    /// * `[i, j, k, l, m]`-th child has `cardinality.at([i, j, k, l, m])` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let cardinality = vec![vec![vec![vec![vec![2, 3]]]], vec![vec![vec![vec![1]]]]];
+    /// let v6 = V.d6().fun(|idx: [usize; 6]| idx.iter().sum::<usize>()).with_variable_bounds(&cardinality);
+    ///
+    /// assert!(!v6.is_rectangular());
+    /// assert_eq!(v6.card([]), 2);
+    /// assert_eq!(v6.card([0, 0, 0, 0]), 2);
+    /// assert_eq!(v6.card([0, 0, 0, 0, 1]), 3);
+    /// assert_eq!(v6.card([1, 0, 0, 0, 0]), 1);
+    /// assert_eq!(v6.all().count(), 6);
+    /// assert_eq!(v6.at([1, 1, 1, 1, 1, 1]), 6);
+    ///
+    /// assert_eq!(v6.try_at([7, 7, 7, 7, 7, 7]), None);
+    /// ```
+    pub fn with_variable_bounds<C>(self, cardinality: C) -> FunVec<D6, T, F, VariableCardD6<C>>
+    where
+        C: V5<usize>,
+    {
+        FunVec::new(self.fun, cardinality.into())
+    }
+}
@@ -1,6 +1,11 @@
-use crate::{impl_v1, impl_vn, D2, D3, D4};
+use crate::common_trait_helpers::unchecked::impl_unchecked_storage;
+use crate::{impl_v1, impl_vn, D2, D3, D4, D5, D6};
+
+impl_unchecked_storage!([const N: usize, T], [T; N], T, []);
 
 impl_v1!(N, [T], [T; N], [T: Copy]);
 impl_vn!(D2, N, [C, T], [C; N], [C: NVec<<D2 as Dim>::PrevDim, T>]);
 impl_vn!(D3, N, [C, T], [C; N], [C: NVec<<D3 as Dim>::PrevDim, T>]);
 impl_vn!(D4, N, [C, T], [C; N], [C: NVec<<D4 as Dim>::PrevDim, T>]);
+impl_vn!(D5, N, [C, T], [C; N], [C: NVec<<D5 as Dim>::PrevDim, T>]);
+impl_vn!(D6, N, [C, T], [C; N], [C: NVec<<D6 as Dim>::PrevDim, T>]);
@@ -12,6 +12,13 @@ macro_rules! impl_v1 {
                 self[idx.into_idx()[0]]
             }
 
+            #[inline(always)]
+            unsafe fn at_unchecked(&self, idx: impl IntoIdx<D1>) -> T {
+                debug_assert!(D1::in_bounds(idx, self), "index is out of bounds");
+                let i = idx.into_idx()[0];
+                unsafe { *$crate::common_trait_helpers::unchecked::UncheckedStorage::unchecked_child(self, i) }
+            }
+
             fn child(&self, _: IdxNever) -> impl NVec<<D1 as Dim>::PrevDim, T> {
                 self
             }
@@ -31,6 +38,13 @@ macro_rules! impl_v1 {
                 self[idx.into_idx()[0]]
             }
 
+            #[inline(always)]
+            unsafe fn at_unchecked(&self, idx: impl IntoIdx<D1>) -> T {
+                debug_assert!(D1::in_bounds(idx, self), "index is out of bounds");
+                let i = idx.into_idx()[0];
+                unsafe { *$crate::common_trait_helpers::unchecked::UncheckedStorage::unchecked_child(self, i) }
+            }
+
             fn child(&self, _: IdxNever) -> impl NVec<<D1 as Dim>::PrevDim, T> {
                 self
             }
@@ -58,6 +72,16 @@ macro_rules! impl_vn {
                 self.child(i).at(c_idx)
             }
 
+            #[inline(always)]
+            unsafe fn at_unchecked(&self, idx: impl IntoIdx<$dim>) -> T {
+                debug_assert!(<$dim as Dim>::in_bounds(idx, self), "index is out of bounds");
+                let (i, c_idx) = idx.into_idx().split_idx();
+                unsafe {
+                    $crate::common_trait_helpers::unchecked::UncheckedStorage::unchecked_child(self, i)
+                        .at_unchecked(c_idx)
+                }
+            }
+
             fn child(&self, i: usize) -> impl NVec<<$dim as Dim>::PrevDim, T> {
                 &self[i]
             }
@@ -78,6 +102,16 @@ macro_rules! impl_vn {
                 self.child(i).at(c_idx)
             }
 
+            #[inline(always)]
+            unsafe fn at_unchecked(&self, idx: impl IntoIdx<$dim>) -> T {
+                debug_assert!(<$dim as Dim>::in_bounds(idx, self), "index is out of bounds");
+                let (i, c_idx) = idx.into_idx().split_idx();
+                unsafe {
+                    $crate::common_trait_helpers::unchecked::UncheckedStorage::unchecked_child(self, i)
+                        .at_unchecked(c_idx)
+                }
+            }
+
             fn child(&self, i: usize) -> impl NVec<<$dim as Dim>::PrevDim, T> {
                 &self[i]
             }
@@ -11,6 +11,12 @@ impl<T: Copy> NVec<D2, T> for Array<T, Ix2> {
         self[idx.into_idx()]
     }
 
+    #[inline(always)]
+    unsafe fn at_unchecked(&self, idx: impl IntoIdx<D2>) -> T {
+        debug_assert!(D2::in_bounds(idx, self), "index is out of bounds");
+        unsafe { *self.uget(idx.into_idx()) }
+    }
+
     fn child(&self, i: <D2 as Dim>::ChildIdx) -> impl NVec<<D2 as Dim>::PrevDim, T> {
         ChildD2D1 {
             i,
@@ -32,6 +38,12 @@ impl<T: Copy> NVec<D3, T> for Array<T, Ix3> {
         self[idx.into_idx()]
     }
 
+    #[inline(always)]
+    unsafe fn at_unchecked(&self, idx: impl IntoIdx<D3>) -> T {
+        debug_assert!(D3::in_bounds(idx, self), "index is out of bounds");
+        unsafe { *self.uget(idx.into_idx()) }
+    }
+
     fn child(&self, i: usize) -> impl NVec<<D3 as Dim>::PrevDim, T> {
         ChildD3D2 {
             i,
@@ -53,6 +65,12 @@ impl<T: Copy> NVec<D4, T> for Array<T, Ix4> {
         self[idx.into_idx()]
     }
 
+    #[inline(always)]
+    unsafe fn at_unchecked(&self, idx: impl IntoIdx<D4>) -> T {
+        debug_assert!(D4::in_bounds(idx, self), "index is out of bounds");
+        unsafe { *self.uget(idx.into_idx()) }
+    }
+
     fn child(&self, i: usize) -> impl NVec<<D4 as Dim>::PrevDim, T> {
         ChildD4D3 {
             i,
@@ -1,10 +1,22 @@
-use crate::{impl_v1, impl_vn, D2, D3, D4};
+use crate::common_trait_helpers::unchecked::UncheckedStorage;
+use crate::{impl_v1, impl_vn, D2, D3, D4, D5, D6};
 use ndarray::{Array, Ix1};
 
+impl<T> UncheckedStorage for Array<T, Ix1> {
+    type Item = T;
+
+    #[inline(always)]
+    unsafe fn unchecked_child(&self, i: usize) -> &Self::Item {
+        unsafe { self.uget(i) }
+    }
+}
+
 impl_v1!([T], Array<T, Ix1>, [T: Copy]);
 impl_vn!(D2, [T, C], Array<C, Ix1>, [C: NVec<<D2 as Dim>::PrevDim, T>]);
 impl_vn!(D3, [T, C], Array<C, Ix1>, [C: NVec<<D3 as Dim>::PrevDim, T>]);
 impl_vn!(D4, [T, C], Array<C, Ix1>, [C: NVec<<D4 as Dim>::PrevDim, T>]);
+impl_vn!(D5, [T, C], Array<C, Ix1>, [C: NVec<<D5 as Dim>::PrevDim, T>]);
+impl_vn!(D6, [T, C], Array<C, Ix1>, [C: NVec<<D6 as Dim>::PrevDim, T>]);
 
 #[cfg(test)]
 mod tests {
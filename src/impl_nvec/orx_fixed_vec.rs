@@ -1,10 +1,22 @@
-use crate::{impl_v1, impl_vn, D2, D3, D4};
+use crate::common_trait_helpers::unchecked::UncheckedStorage;
+use crate::{impl_v1, impl_vn, D2, D3, D4, D5, D6};
 use orx_fixed_vec::*;
 
+impl<T> UncheckedStorage for FixedVec<T> {
+    type Item = T;
+
+    #[inline(always)]
+    unsafe fn unchecked_child(&self, i: usize) -> &Self::Item {
+        unsafe { <Self as PinnedVec<T>>::get_unchecked(self, i) }
+    }
+}
+
 impl_v1!([T], FixedVec<T>, [T: Copy]);
 impl_vn!(D2, [T, C], FixedVec<C>, [C: NVec<<D2 as Dim>::PrevDim, T>]);
 impl_vn!(D3, [T, C], FixedVec<C>, [C: NVec<<D3 as Dim>::PrevDim, T>]);
 impl_vn!(D4, [T, C], FixedVec<C>, [C: NVec<<D4 as Dim>::PrevDim, T>]);
+impl_vn!(D5, [T, C], FixedVec<C>, [C: NVec<<D5 as Dim>::PrevDim, T>]);
+impl_vn!(D6, [T, C], FixedVec<C>, [C: NVec<<D6 as Dim>::PrevDim, T>]);
 
 #[cfg(all(test, feature = "orx-fixed-vec"))]
 mod tests {
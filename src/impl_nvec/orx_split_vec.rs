@@ -1,10 +1,22 @@
-use crate::{impl_v1, impl_vn, D2, D3, D4};
+use crate::common_trait_helpers::unchecked::UncheckedStorage;
+use crate::{impl_v1, impl_vn, D2, D3, D4, D5, D6};
 use orx_split_vec::*;
 
+impl<T, G: Growth> UncheckedStorage for SplitVec<T, G> {
+    type Item = T;
+
+    #[inline(always)]
+    unsafe fn unchecked_child(&self, i: usize) -> &Self::Item {
+        unsafe { <Self as PinnedVec<T>>::get_unchecked(self, i) }
+    }
+}
+
 impl_v1!([T, G], SplitVec<T, G>, [T: Copy, G: Growth]);
 impl_vn!(D2, [G, T, C], SplitVec<C, G>, [C: NVec<<D2 as Dim>::PrevDim, T>, G: Growth]);
 impl_vn!(D3, [G, T, C], SplitVec<C, G>, [C: NVec<<D3 as Dim>::PrevDim, T>, G: Growth]);
 impl_vn!(D4, [G, T, C], SplitVec<C, G>, [C: NVec<<D4 as Dim>::PrevDim, T>, G: Growth]);
+impl_vn!(D5, [G, T, C], SplitVec<C, G>, [C: NVec<<D5 as Dim>::PrevDim, T>, G: Growth]);
+impl_vn!(D6, [G, T, C], SplitVec<C, G>, [C: NVec<<D6 as Dim>::PrevDim, T>, G: Growth]);
 
 #[cfg(all(test, feature = "orx-split-vec"))]
 mod tests {
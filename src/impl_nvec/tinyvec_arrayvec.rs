@@ -1,10 +1,15 @@
-use crate::{impl_v1, impl_vn, D2, D3, D4};
+use crate::common_trait_helpers::unchecked::impl_unchecked_storage;
+use crate::{impl_v1, impl_vn, D2, D3, D4, D5, D6};
 use tinyvec::{Array, ArrayVec};
 
+impl_unchecked_storage!([A], ArrayVec<A>, A::Item, [A: Array]);
+
 impl_v1!(N, [T], ArrayVec<[T; N]>, [T: Copy, [T; N]: Array<Item = T>]);
 impl_vn!(D2, N, [C, T], ArrayVec<[C; N]>, [C: NVec<<D2 as Dim>::PrevDim, T>, [C; N]: Array<Item = C>]);
 impl_vn!(D3, N, [C, T], ArrayVec<[C; N]>, [C: NVec<<D3 as Dim>::PrevDim, T>, [C; N]: Array<Item = C>]);
 impl_vn!(D4, N, [C, T], ArrayVec<[C; N]>, [C: NVec<<D4 as Dim>::PrevDim, T>, [C; N]: Array<Item = C>]);
+impl_vn!(D5, N, [C, T], ArrayVec<[C; N]>, [C: NVec<<D5 as Dim>::PrevDim, T>, [C; N]: Array<Item = C>]);
+impl_vn!(D6, N, [C, T], ArrayVec<[C; N]>, [C: NVec<<D6 as Dim>::PrevDim, T>, [C; N]: Array<Item = C>]);
 
 #[cfg(all(test, feature = "tinyvec"))]
 mod tests {
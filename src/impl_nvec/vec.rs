@@ -1,10 +1,15 @@
-use crate::{impl_v1, impl_vn, D2, D3, D4};
+use crate::common_trait_helpers::unchecked::impl_unchecked_storage;
+use crate::{impl_v1, impl_vn, D2, D3, D4, D5, D6};
 use alloc::vec::Vec;
 
+impl_unchecked_storage!([T], Vec<T>, T, []);
+
 impl_v1!([T], Vec<T>, [T: Copy]);
 impl_vn!(D2, [T, C], Vec<C>, [C: NVec<<D2 as Dim>::PrevDim, T>]);
 impl_vn!(D3, [T, C], Vec<C>, [C: NVec<<D3 as Dim>::PrevDim, T>]);
 impl_vn!(D4, [T, C], Vec<C>, [C: NVec<<D4 as Dim>::PrevDim, T>]);
+impl_vn!(D5, [T, C], Vec<C>, [C: NVec<<D5 as Dim>::PrevDim, T>]);
+impl_vn!(D6, [T, C], Vec<C>, [C: NVec<<D6 as Dim>::PrevDim, T>]);
 
 #[cfg(test)]
 mod tests {
@@ -1,7 +1,9 @@
-use crate::{impl_v1_card, impl_vn_card, D2, D3, D4};
+use crate::{impl_v1_card, impl_vn_card, D2, D3, D4, D5, D6};
 use arrayvec::ArrayVec;
 
 impl_v1_card!(N, [T], ArrayVec<T, N>, []);
 impl_vn_card!(D2, N, [C, T], ArrayVec<C, N>, [C: NVecCoreSealed<<D2 as Dim>::PrevDim, T>]);
 impl_vn_card!(D3, N, [C, T], ArrayVec<C, N>, [C: NVecCoreSealed<<D3 as Dim>::PrevDim, T>]);
 impl_vn_card!(D4, N, [C, T], ArrayVec<C, N>, [C: NVecCoreSealed<<D4 as Dim>::PrevDim, T>]);
+impl_vn_card!(D5, N, [C, T], ArrayVec<C, N>, [C: NVecCoreSealed<<D5 as Dim>::PrevDim, T>]);
+impl_vn_card!(D6, N, [C, T], ArrayVec<C, N>, [C: NVecCoreSealed<<D6 as Dim>::PrevDim, T>]);
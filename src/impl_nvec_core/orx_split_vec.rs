@@ -1,7 +1,9 @@
-use crate::{impl_v1_card, impl_vn_card, D2, D3, D4};
+use crate::{impl_v1_card, impl_vn_card, D2, D3, D4, D5, D6};
 use orx_split_vec::*;
 
 impl_v1_card!([G, T], SplitVec<T, G>, [G: Growth]);
 impl_vn_card!(D2, [G, C, T], SplitVec<C, G>, [G: Growth, C: NVecCoreSealed<<D2 as Dim>::PrevDim, T>]);
 impl_vn_card!(D3, [G, C, T], SplitVec<C, G>, [G: Growth, C: NVecCoreSealed<<D3 as Dim>::PrevDim, T>]);
 impl_vn_card!(D4, [G, C, T], SplitVec<C, G>, [G: Growth, C: NVecCoreSealed<<D4 as Dim>::PrevDim, T>]);
+impl_vn_card!(D5, [G, C, T], SplitVec<C, G>, [G: Growth, C: NVecCoreSealed<<D5 as Dim>::PrevDim, T>]);
+impl_vn_card!(D6, [G, C, T], SplitVec<C, G>, [G: Growth, C: NVecCoreSealed<<D6 as Dim>::PrevDim, T>]);
@@ -1,10 +1,12 @@
-use crate::{impl_v1_card, impl_vn_card, D2, D3, D4};
+use crate::{impl_v1_card, impl_vn_card, D2, D3, D4, D5, D6};
 use alloc::vec::Vec;
 
 impl_v1_card!([T], Vec<T>, []);
 impl_vn_card!(D2, [C, T], Vec<C>, [C: NVecCoreSealed<<D2 as Dim>::PrevDim, T>]);
 impl_vn_card!(D3, [C, T], Vec<C>, [C: NVecCoreSealed<<D3 as Dim>::PrevDim, T>]);
 impl_vn_card!(D4, [C, T], Vec<C>, [C: NVecCoreSealed<<D4 as Dim>::PrevDim, T>]);
+impl_vn_card!(D5, [C, T], Vec<C>, [C: NVecCoreSealed<<D5 as Dim>::PrevDim, T>]);
+impl_vn_card!(D6, [C, T], Vec<C>, [C: NVecCoreSealed<<D6 as Dim>::PrevDim, T>]);
 
 // impl<C, T> NVecCoreSealed<D2, T> for Vec<C>
 // where
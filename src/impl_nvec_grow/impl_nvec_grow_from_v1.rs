@@ -0,0 +1,143 @@
+/// Implements `NVecGrow<D1, T>` for a growable 1-dimensional vector, such as the
+/// standard `Vec<T>`, which implements `GrowStorage<Item = T>`.
+///
+/// Requires `GrowStorage` to be in scope.
+macro_rules! impl_v1_grow {
+    ($N:ident, [$($impl_generics:tt)*], $V:ty, [$($where:tt)*]) => {
+        impl_v1_grow!([const $N: usize, $($impl_generics)*], $V, [$($where)*]);
+    };
+
+    ([$($impl_generics:tt)*], $V:ty, [$($where:tt)*]) => {
+        #[allow(unused_imports)]
+        use $crate::*;
+        #[allow(unused_imports)]
+        use $crate::grow::*;
+
+        impl<$($impl_generics)*> NVecGrow<D1, T> for $V where $($where)* {
+            type Child = T;
+
+            fn push_child(&mut self, child: Self::Child) -> Result<(), GrowError> {
+                self.try_grow_push(child)
+            }
+
+            fn push(&mut self, _: impl Into<<D1 as Dim>::CardIdx>, value: T) -> Result<(), GrowError> {
+                self.try_grow_push(value)
+            }
+
+            fn extend(
+                &mut self,
+                _: impl Into<<D1 as Dim>::CardIdx>,
+                values: impl IntoIterator<Item = T>,
+            ) -> Result<(), GrowError> {
+                for value in values {
+                    self.try_grow_push(value)?;
+                }
+                Ok(())
+            }
+
+            fn insert(
+                &mut self,
+                _: impl Into<<D1 as Dim>::CardIdx>,
+                position: usize,
+                value: T,
+            ) -> Result<(), GrowError> {
+                self.try_grow_insert(position, value)
+            }
+
+            fn truncate(&mut self, _: impl Into<<D1 as Dim>::CardIdx>, len: usize) {
+                self.grow_truncate(len);
+            }
+
+            fn resize(
+                &mut self,
+                _: impl Into<<D1 as Dim>::CardIdx>,
+                len: usize,
+                value: T,
+            ) -> Result<(), GrowError> {
+                self.try_grow_resize(len, value)
+            }
+
+            fn clear(&mut self) {
+                self.grow_truncate(0);
+            }
+        }
+    };
+}
+
+/// Implements `NVecGrow<D, T>` for a growable vector of growable `D::PrevDim` dimensional
+/// vectors, such as `Vec<C>`, which implements `GrowStorage<Item = C>`.
+///
+/// Requires `GrowStorage` and `SplitCardIdx` to be in scope.
+macro_rules! impl_vn_grow {
+    ($dim:tt, $N:ident, [$($impl_generics:tt)*], $V:ty, [$($where:tt)*]) => {
+        impl_vn_grow!($dim, [const $N: usize, $($impl_generics)*], $V, [$($where)*]);
+    };
+
+    ($dim:tt, [$($impl_generics:tt)*], $V:ty, [$($where:tt)*]) => {
+        #[allow(unused_imports)]
+        use $crate::*;
+        #[allow(unused_imports)]
+        use $crate::grow::*;
+
+        impl<$($impl_generics)*> NVecGrow<$dim, T> for $V where $($where)* {
+            type Child = C;
+
+            fn push_child(&mut self, child: Self::Child) -> Result<(), GrowError> {
+                self.try_grow_push(child)
+            }
+
+            fn push(&mut self, idx: impl Into<<$dim as Dim>::CardIdx>, value: T) -> Result<(), GrowError> {
+                match idx.into().split_card_idx() {
+                    Some((i, idx)) => self.grow_child_mut(i).push(idx, value),
+                    None => Err(GrowError::NotLeaf),
+                }
+            }
+
+            fn extend(
+                &mut self,
+                idx: impl Into<<$dim as Dim>::CardIdx>,
+                values: impl IntoIterator<Item = T>,
+            ) -> Result<(), GrowError> {
+                match idx.into().split_card_idx() {
+                    Some((i, idx)) => self.grow_child_mut(i).extend(idx, values),
+                    None => Err(GrowError::NotLeaf),
+                }
+            }
+
+            fn insert(
+                &mut self,
+                idx: impl Into<<$dim as Dim>::CardIdx>,
+                position: usize,
+                value: T,
+            ) -> Result<(), GrowError> {
+                match idx.into().split_card_idx() {
+                    Some((i, idx)) => self.grow_child_mut(i).insert(idx, position, value),
+                    None => Err(GrowError::NotLeaf),
+                }
+            }
+
+            fn truncate(&mut self, idx: impl Into<<$dim as Dim>::CardIdx>, len: usize) {
+                match idx.into().split_card_idx() {
+                    Some((i, idx)) => self.grow_child_mut(i).truncate(idx, len),
+                    None => self.grow_truncate(len),
+                }
+            }
+
+            fn resize(
+                &mut self,
+                idx: impl Into<<$dim as Dim>::CardIdx>,
+                len: usize,
+                value: T,
+            ) -> Result<(), GrowError> {
+                match idx.into().split_card_idx() {
+                    Some((i, idx)) => self.grow_child_mut(i).resize(idx, len, value),
+                    None => Err(GrowError::NotLeaf),
+                }
+            }
+
+            fn clear(&mut self) {
+                self.grow_truncate(0);
+            }
+        }
+    };
+}
@@ -0,0 +1,85 @@
+#[macro_use]
+mod impl_nvec_grow_from_v1;
+mod vec;
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+
+#[cfg(feature = "orx-fixed-vec")]
+mod orx_fixed_vec;
+
+#[cfg(feature = "orx-split-vec")]
+mod orx_split_vec;
+
+#[cfg(feature = "smallvec")]
+mod smallvec;
+
+#[cfg(feature = "tinyvec")]
+mod tinyvec_arrayvec;
+
+#[cfg(feature = "tinyvec")]
+mod tinyvec_tinyvec;
+
+use crate::grow::GrowError;
+
+/// Common growing operations of the one-dimensional storages backing [`NVecGrow`] implementations.
+///
+/// [`NVecGrow`]: crate::grow::NVecGrow
+pub(crate) trait GrowStorage {
+    type Item;
+
+    /// Fixed capacity of the storage; None if the storage can grow unboundedly.
+    fn grow_capacity(&self) -> Option<usize>;
+
+    fn grow_len(&self) -> usize;
+
+    /// Pushes the `value`; called only when the storage has room for it.
+    fn grow_push(&mut self, value: Self::Item);
+
+    /// Inserts the `value` at the given `position`; called only when the storage has room for it.
+    fn grow_insert(&mut self, position: usize, value: Self::Item);
+
+    fn grow_truncate(&mut self, len: usize);
+
+    fn grow_child_mut(&mut self, i: usize) -> &mut Self::Item;
+
+    fn try_grow_push(&mut self, value: Self::Item) -> Result<(), GrowError> {
+        match self.grow_capacity() {
+            Some(capacity) if self.grow_len() >= capacity => {
+                Err(GrowError::CapacityExceeded { capacity })
+            }
+            _ => {
+                self.grow_push(value);
+                Ok(())
+            }
+        }
+    }
+
+    fn try_grow_insert(&mut self, position: usize, value: Self::Item) -> Result<(), GrowError> {
+        match self.grow_capacity() {
+            Some(capacity) if self.grow_len() >= capacity => {
+                Err(GrowError::CapacityExceeded { capacity })
+            }
+            _ => {
+                self.grow_insert(position, value);
+                Ok(())
+            }
+        }
+    }
+
+    fn try_grow_resize(&mut self, len: usize, value: Self::Item) -> Result<(), GrowError>
+    where
+        Self::Item: Clone,
+    {
+        match self.grow_capacity() {
+            Some(capacity) if len > capacity => Err(GrowError::CapacityExceeded { capacity }),
+            _ => {
+                self.grow_truncate(len);
+                while self.grow_len() < len {
+                    self.grow_push(value.clone());
+                }
+                Ok(())
+            }
+        }
+    }
+}
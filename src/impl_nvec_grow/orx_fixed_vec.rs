@@ -0,0 +1,38 @@
+use super::GrowStorage;
+use crate::{dim::SplitCardIdx, D2, D3, D4, D5, D6};
+use orx_fixed_vec::*;
+
+impl<T> GrowStorage for FixedVec<T> {
+    type Item = T;
+
+    fn grow_capacity(&self) -> Option<usize> {
+        Some(PinnedVec::capacity(self))
+    }
+
+    fn grow_len(&self) -> usize {
+        PinnedVec::len(self)
+    }
+
+    fn grow_push(&mut self, value: Self::Item) {
+        PinnedVec::push(self, value);
+    }
+
+    fn grow_insert(&mut self, position: usize, value: Self::Item) {
+        PinnedVec::insert(self, position, value);
+    }
+
+    fn grow_truncate(&mut self, len: usize) {
+        PinnedVec::truncate(self, len);
+    }
+
+    fn grow_child_mut(&mut self, i: usize) -> &mut Self::Item {
+        &mut self[i]
+    }
+}
+
+impl_v1_grow!([T], FixedVec<T>, [T: Copy]);
+impl_vn_grow!(D2, [T, C], FixedVec<C>, [C: NVecGrow<<D2 as Dim>::PrevDim, T>]);
+impl_vn_grow!(D3, [T, C], FixedVec<C>, [C: NVecGrow<<D3 as Dim>::PrevDim, T>]);
+impl_vn_grow!(D4, [T, C], FixedVec<C>, [C: NVecGrow<<D4 as Dim>::PrevDim, T>]);
+impl_vn_grow!(D5, [T, C], FixedVec<C>, [C: NVecGrow<<D5 as Dim>::PrevDim, T>]);
+impl_vn_grow!(D6, [T, C], FixedVec<C>, [C: NVecGrow<<D6 as Dim>::PrevDim, T>]);
@@ -0,0 +1,38 @@
+use super::GrowStorage;
+use crate::{dim::SplitCardIdx, D2, D3, D4, D5, D6};
+use smallvec::{Array, SmallVec};
+
+impl<A: Array> GrowStorage for SmallVec<A> {
+    type Item = A::Item;
+
+    fn grow_capacity(&self) -> Option<usize> {
+        None
+    }
+
+    fn grow_len(&self) -> usize {
+        self.len()
+    }
+
+    fn grow_push(&mut self, value: Self::Item) {
+        SmallVec::push(self, value);
+    }
+
+    fn grow_insert(&mut self, position: usize, value: Self::Item) {
+        SmallVec::insert(self, position, value);
+    }
+
+    fn grow_truncate(&mut self, len: usize) {
+        SmallVec::truncate(self, len);
+    }
+
+    fn grow_child_mut(&mut self, i: usize) -> &mut Self::Item {
+        &mut self[i]
+    }
+}
+
+impl_v1_grow!(N, [T], SmallVec<[T; N]>, [T: Copy, [T; N]: Array<Item = T>]);
+impl_vn_grow!(D2, N, [C, T], SmallVec<[C; N]>, [C: NVecGrow<<D2 as Dim>::PrevDim, T>, [C; N]: Array<Item = C>]);
+impl_vn_grow!(D3, N, [C, T], SmallVec<[C; N]>, [C: NVecGrow<<D3 as Dim>::PrevDim, T>, [C; N]: Array<Item = C>]);
+impl_vn_grow!(D4, N, [C, T], SmallVec<[C; N]>, [C: NVecGrow<<D4 as Dim>::PrevDim, T>, [C; N]: Array<Item = C>]);
+impl_vn_grow!(D5, N, [C, T], SmallVec<[C; N]>, [C: NVecGrow<<D5 as Dim>::PrevDim, T>, [C; N]: Array<Item = C>]);
+impl_vn_grow!(D6, N, [C, T], SmallVec<[C; N]>, [C: NVecGrow<<D6 as Dim>::PrevDim, T>, [C; N]: Array<Item = C>]);
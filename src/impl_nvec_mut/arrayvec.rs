@@ -1,10 +1,15 @@
-use crate::{impl_v1_mut, impl_vn_mut, D2, D3, D4};
+use crate::common_trait_helpers::unchecked::impl_unchecked_storage_mut;
+use crate::{impl_v1_mut, impl_vn_mut, D2, D3, D4, D5, D6};
 use arrayvec::ArrayVec;
 
+impl_unchecked_storage_mut!([const N: usize, T], ArrayVec<T, N>, T, []);
+
 impl_v1_mut!(N, [T], ArrayVec<T, N>, [T: Copy]);
 impl_vn_mut!(D2, N, [C, T], ArrayVec<C, N>, [C: NVecMut<<D2 as Dim>::PrevDim, T>]);
 impl_vn_mut!(D3, N, [C, T], ArrayVec<C, N>, [C: NVecMut<<D3 as Dim>::PrevDim, T>]);
 impl_vn_mut!(D4, N, [C, T], ArrayVec<C, N>, [C: NVecMut<<D4 as Dim>::PrevDim, T>]);
+impl_vn_mut!(D5, N, [C, T], ArrayVec<C, N>, [C: NVecMut<<D5 as Dim>::PrevDim, T>]);
+impl_vn_mut!(D6, N, [C, T], ArrayVec<C, N>, [C: NVecMut<<D6 as Dim>::PrevDim, T>]);
 
 #[cfg(all(test, feature = "arrayvec"))]
 mod tests {
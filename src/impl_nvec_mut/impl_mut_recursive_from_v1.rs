@@ -19,7 +19,9 @@ macro_rules! impl_v1mut_for_v1 {
             fn child_mut(&mut self, _: <D1 as Dim>::ChildIdx) -> impl NVecMut<<D1 as Dim>::PrevDim, T> {
                 self
             }
+        }
 
+        impl<T: Copy> NVecAllMut<D1, T> for $v1 {
             fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
             where
                 T: 'a,
@@ -56,7 +58,12 @@ macro_rules! impl_v1mut_for_v1 {
             fn child_mut(&mut self, _: <D1 as Dim>::ChildIdx) -> impl NVecMut<<D1 as Dim>::PrevDim, T> {
                 self
             }
+        }
 
+        impl<T: Copy, $arg> NVecAllMut<D1, T> for $v1
+        where
+            $($where)*
+        {
             fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
             where
                 T: 'a,
@@ -93,7 +100,12 @@ macro_rules! impl_v1mut_for_v1 {
             fn child_mut(&mut self, _: <D1 as Dim>::ChildIdx) -> impl NVecMut<<D1 as Dim>::PrevDim, T> {
                 self
             }
+        }
 
+        impl<const $const_arg: usize, T: Copy> NVecAllMut<D1, T> for $v1
+        where
+            $($where)*
+        {
             fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
             where
                 T: 'a,
@@ -140,7 +152,12 @@ macro_rules! impl_vmut_recursive_for_v1 {
             fn child_mut(&mut self, i: <$dim as Dim>::ChildIdx) -> impl NVecMut<<$dim as Dim>::PrevDim, T> {
                 &mut self[i]
             }
+        }
 
+        impl<C, T> NVecAllMut<$dim, T> for $v1
+        where
+            C: NVecAllMut<<$dim as Dim>::PrevDim, T>,
+        {
             fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
             where
                 T: 'a,
@@ -186,7 +203,13 @@ macro_rules! impl_vmut_recursive_for_v1 {
             fn child_mut(&mut self, i: <$dim as Dim>::ChildIdx) -> impl NVecMut<<$dim as Dim>::PrevDim, T> {
                 &mut self[i]
             }
+        }
 
+        impl<C, T, $arg> NVecAllMut<$dim, T> for $v1
+        where
+            C: NVecAllMut<<$dim as Dim>::PrevDim, T>,
+            $($where)*
+        {
             fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
             where
                 T: 'a,
@@ -232,7 +255,13 @@ macro_rules! impl_vmut_recursive_for_v1 {
             fn child_mut(&mut self, i: <$dim as Dim>::ChildIdx) -> impl NVecMut<<$dim as Dim>::PrevDim, T> {
                 &mut self[i]
             }
+        }
 
+        impl<const $const_arg: usize, C, T> NVecAllMut<$dim, T> for $v1
+        where
+            C: NVecAllMut<<$dim as Dim>::PrevDim, T>,
+            $($where)*
+        {
             fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
             where
                 T: 'a,
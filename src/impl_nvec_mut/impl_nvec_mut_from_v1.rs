@@ -12,6 +12,13 @@ macro_rules! impl_v1_mut {
                 &mut self[idx.into_idx()[0]]
             }
 
+            #[inline(always)]
+            unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<D1>) -> &mut T {
+                debug_assert!(D1::in_bounds(idx, self), "index is out of bounds");
+                let i = idx.into_idx()[0];
+                unsafe { $crate::common_trait_helpers::unchecked::UncheckedStorageMut::unchecked_child_mut(self, i) }
+            }
+
             #[inline(always)]
             fn set<Idx: IntoIdx<D1>>(&mut self, idx: Idx, value: T) {
                 self[idx.into_idx()[0]] = value;
@@ -37,6 +44,22 @@ macro_rules! impl_v1_mut {
                 self.mut_all(|x| *x = value);
             }
         }
+
+        impl<$($impl_generics)*> NVecAllMut<D1, T> for $V where $($where)* {
+            fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+            where
+                T: 'a,
+            {
+                self.iter_mut()
+            }
+
+            fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D1 as Dim>::Idx, &'a mut T)>
+            where
+                T: 'a,
+            {
+                self.iter_mut().enumerate().map(|(i, x)| ([i], x))
+            }
+        }
     };
     ($const_arg:tt, [$($impl_generics:tt)*], $V:ty, [$($where:tt)*]) => {
         #[allow(unused_imports)]
@@ -48,6 +71,13 @@ macro_rules! impl_v1_mut {
                 &mut self[idx.into_idx()[0]]
             }
 
+            #[inline(always)]
+            unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<D1>) -> &mut T {
+                debug_assert!(D1::in_bounds(idx, self), "index is out of bounds");
+                let i = idx.into_idx()[0];
+                unsafe { $crate::common_trait_helpers::unchecked::UncheckedStorageMut::unchecked_child_mut(self, i) }
+            }
+
             #[inline(always)]
             fn set<Idx: IntoIdx<D1>>(&mut self, idx: Idx, value: T) {
                 self[idx.into_idx()[0]] = value;
@@ -73,6 +103,22 @@ macro_rules! impl_v1_mut {
                 self.mut_all(|x| *x = value);
             }
         }
+
+        impl<const $const_arg: usize, $($impl_generics)*> NVecAllMut<D1, T> for $V where $($where)* {
+            fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+            where
+                T: 'a,
+            {
+                self.iter_mut()
+            }
+
+            fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D1 as Dim>::Idx, &'a mut T)>
+            where
+                T: 'a,
+            {
+                self.iter_mut().enumerate().map(|(i, x)| ([i], x))
+            }
+        }
     };
 }
 
@@ -92,6 +138,16 @@ macro_rules! impl_vn_mut {
                 self[i].at_mut(c_idx)
             }
 
+            #[inline(always)]
+            unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<$dim>) -> &mut T {
+                debug_assert!(<$dim as Dim>::in_bounds(idx, self), "index is out of bounds");
+                let (i, c_idx) = idx.into_idx().split_idx();
+                unsafe {
+                    $crate::common_trait_helpers::unchecked::UncheckedStorageMut::unchecked_child_mut(self, i)
+                        .at_mut_unchecked(c_idx)
+                }
+            }
+
             #[inline(always)]
             fn set<Idx: IntoIdx<$dim>>(&mut self, idx: Idx, value: T) {
                 let (i, c_idx) = idx.into_idx().split_idx();
@@ -118,6 +174,25 @@ macro_rules! impl_vn_mut {
                 self.mut_all(|x| *x = value);
             }
         }
+
+        impl<$($impl_generics)*> NVecAllMut<$dim, T> for $V where C: NVecAllMut<<$dim as Dim>::PrevDim, T>, $($where)* {
+            fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+            where
+                T: 'a,
+            {
+                self.iter_mut().flat_map(|x| x.all_mut())
+            }
+
+            fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<$dim as Dim>::Idx, &'a mut T)>
+            where
+                T: 'a,
+            {
+                self.iter_mut().enumerate().flat_map(|(i, x)| {
+                    x.enumerate_all_mut()
+                        .map(move |(idx, y)| (<$dim as Dim>::left_join_from_lower_dim(i, idx), y))
+                })
+            }
+        }
     };
     ($dim:ty, $const_arg:tt, [$($impl_generics:tt)*], $V:ty, [$($where:tt)*]) => {
         #[allow(unused_imports)]
@@ -130,6 +205,16 @@ macro_rules! impl_vn_mut {
                 self[i].at_mut(c_idx)
             }
 
+            #[inline(always)]
+            unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<$dim>) -> &mut T {
+                debug_assert!(<$dim as Dim>::in_bounds(idx, self), "index is out of bounds");
+                let (i, c_idx) = idx.into_idx().split_idx();
+                unsafe {
+                    $crate::common_trait_helpers::unchecked::UncheckedStorageMut::unchecked_child_mut(self, i)
+                        .at_mut_unchecked(c_idx)
+                }
+            }
+
             #[inline(always)]
             fn set<Idx: IntoIdx<$dim>>(&mut self, idx: Idx, value: T) {
                 let (i, c_idx) = idx.into_idx().split_idx();
@@ -156,5 +241,24 @@ macro_rules! impl_vn_mut {
                 self.mut_all(|x| *x = value);
             }
         }
+
+        impl<const $const_arg: usize, $($impl_generics)*> NVecAllMut<$dim, T> for $V where C: NVecAllMut<<$dim as Dim>::PrevDim, T>, $($where)* {
+            fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+            where
+                T: 'a,
+            {
+                self.iter_mut().flat_map(|x| x.all_mut())
+            }
+
+            fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<$dim as Dim>::Idx, &'a mut T)>
+            where
+                T: 'a,
+            {
+                self.iter_mut().enumerate().flat_map(|(i, x)| {
+                    x.enumerate_all_mut()
+                        .map(move |(idx, y)| (<$dim as Dim>::left_join_from_lower_dim(i, idx), y))
+                })
+            }
+        }
     };
 }
@@ -1,5 +1,5 @@
 use crate::children::{ChildD2D1, ChildD3D2, ChildD4D3};
-use crate::{dim::*, NVecMut};
+use crate::{dim::*, NVecAllMut, NVecMut};
 use ndarray::{Array, Ix2, Ix3, Ix4};
 
 // d2 - full-indexed
@@ -9,6 +9,11 @@ impl<T: Copy> NVecMut<D2, T> for Array<T, Ix2> {
         &mut self[idx.into_idx()]
     }
 
+    unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<D2>) -> &mut T {
+        debug_assert!(D2::in_bounds(idx, self), "index is out of bounds");
+        unsafe { self.uget_mut(idx.into_idx()) }
+    }
+
     fn set<Idx: IntoIdx<D2>>(&mut self, idx: Idx, value: T) {
         self[idx.into_idx()] = value
     }
@@ -40,6 +45,22 @@ impl<T: Copy> NVecMut<D2, T> for Array<T, Ix2> {
     }
 }
 
+impl<T: Copy> NVecAllMut<D2, T> for Array<T, Ix2> {
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        self.iter_mut()
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D2 as Dim>::Idx, &'a mut T)>
+    where
+        T: 'a,
+    {
+        self.indexed_iter_mut().map(|((i, j), x)| ([i, j], x))
+    }
+}
+
 // d3 - full-indexed
 
 impl<T: Copy> NVecMut<D3, T> for Array<T, Ix3> {
@@ -47,6 +68,11 @@ impl<T: Copy> NVecMut<D3, T> for Array<T, Ix3> {
         &mut self[idx.into_idx()]
     }
 
+    unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<D3>) -> &mut T {
+        debug_assert!(D3::in_bounds(idx, self), "index is out of bounds");
+        unsafe { self.uget_mut(idx.into_idx()) }
+    }
+
     fn set<Idx: IntoIdx<D3>>(&mut self, idx: Idx, value: T) {
         self[idx.into_idx()] = value
     }
@@ -78,6 +104,22 @@ impl<T: Copy> NVecMut<D3, T> for Array<T, Ix3> {
     }
 }
 
+impl<T: Copy> NVecAllMut<D3, T> for Array<T, Ix3> {
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        self.iter_mut()
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D3 as Dim>::Idx, &'a mut T)>
+    where
+        T: 'a,
+    {
+        self.indexed_iter_mut().map(|((i, j, k), x)| ([i, j, k], x))
+    }
+}
+
 // d4 - full-indexed
 
 impl<T: Copy> NVecMut<D4, T> for Array<T, Ix4> {
@@ -85,6 +127,11 @@ impl<T: Copy> NVecMut<D4, T> for Array<T, Ix4> {
         &mut self[idx.into_idx()]
     }
 
+    unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<D4>) -> &mut T {
+        debug_assert!(D4::in_bounds(idx, self), "index is out of bounds");
+        unsafe { self.uget_mut(idx.into_idx()) }
+    }
+
     fn set<Idx: IntoIdx<D4>>(&mut self, idx: Idx, value: T) {
         self[idx.into_idx()] = value
     }
@@ -116,6 +163,23 @@ impl<T: Copy> NVecMut<D4, T> for Array<T, Ix4> {
     }
 }
 
+impl<T: Copy> NVecAllMut<D4, T> for Array<T, Ix4> {
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        self.iter_mut()
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D4 as Dim>::Idx, &'a mut T)>
+    where
+        T: 'a,
+    {
+        self.indexed_iter_mut()
+            .map(|((i, j, k, l), x)| ([i, j, k, l], x))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -1,7 +1,17 @@
-use crate::{impl_v1_mut, impl_vn_mut, D2, D3, D4};
+use crate::common_trait_helpers::unchecked::UncheckedStorageMut;
+use crate::{impl_v1_mut, impl_vn_mut, D2, D3, D4, D5, D6};
 use ndarray::{Array, Ix1};
 
+impl<T> UncheckedStorageMut for Array<T, Ix1> {
+    #[inline(always)]
+    unsafe fn unchecked_child_mut(&mut self, i: usize) -> &mut Self::Item {
+        unsafe { self.uget_mut(i) }
+    }
+}
+
 impl_v1_mut!([T], Array<T, Ix1>, [T: Copy]);
 impl_vn_mut!(D2, [T, C], Array<C, Ix1>, [C: NVecMut<<D2 as Dim>::PrevDim, T>]);
 impl_vn_mut!(D3, [T, C], Array<C, Ix1>, [C: NVecMut<<D3 as Dim>::PrevDim, T>]);
 impl_vn_mut!(D4, [T, C], Array<C, Ix1>, [C: NVecMut<<D4 as Dim>::PrevDim, T>]);
+impl_vn_mut!(D5, [T, C], Array<C, Ix1>, [C: NVecMut<<D5 as Dim>::PrevDim, T>]);
+impl_vn_mut!(D6, [T, C], Array<C, Ix1>, [C: NVecMut<<D6 as Dim>::PrevDim, T>]);
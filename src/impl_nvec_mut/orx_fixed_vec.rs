@@ -1,11 +1,21 @@
-use crate::{impl_v1_mut, impl_vn_mut, D2, D3, D4};
+use crate::common_trait_helpers::unchecked::UncheckedStorageMut;
+use crate::{impl_v1_mut, impl_vn_mut, D2, D3, D4, D5, D6};
 use orx_fixed_vec::*;
 use orx_iterable::CollectionMut;
 
+impl<T> UncheckedStorageMut for FixedVec<T> {
+    #[inline(always)]
+    unsafe fn unchecked_child_mut(&mut self, i: usize) -> &mut Self::Item {
+        unsafe { <Self as PinnedVec<T>>::get_unchecked_mut(self, i) }
+    }
+}
+
 impl_v1_mut!([T], FixedVec<T>, [T: Copy]);
 impl_vn_mut!(D2, [T, C], FixedVec<C>, [C: NVecMut<<D2 as Dim>::PrevDim, T>]);
 impl_vn_mut!(D3, [T, C], FixedVec<C>, [C: NVecMut<<D3 as Dim>::PrevDim, T>]);
 impl_vn_mut!(D4, [T, C], FixedVec<C>, [C: NVecMut<<D4 as Dim>::PrevDim, T>]);
+impl_vn_mut!(D5, [T, C], FixedVec<C>, [C: NVecMut<<D5 as Dim>::PrevDim, T>]);
+impl_vn_mut!(D6, [T, C], FixedVec<C>, [C: NVecMut<<D6 as Dim>::PrevDim, T>]);
 
 #[cfg(all(test, feature = "orx-fixed-vec"))]
 mod tests {
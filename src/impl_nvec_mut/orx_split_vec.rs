@@ -1,10 +1,20 @@
-use crate::{impl_v1_mut, impl_vn_mut, D2, D3, D4};
+use crate::common_trait_helpers::unchecked::UncheckedStorageMut;
+use crate::{impl_v1_mut, impl_vn_mut, D2, D3, D4, D5, D6};
 use orx_split_vec::*;
 
+impl<T, G: Growth> UncheckedStorageMut for SplitVec<T, G> {
+    #[inline(always)]
+    unsafe fn unchecked_child_mut(&mut self, i: usize) -> &mut Self::Item {
+        unsafe { <Self as PinnedVec<T>>::get_unchecked_mut(self, i) }
+    }
+}
+
 impl_v1_mut!([T, G], SplitVec<T, G>, [T: Copy, G: Growth]);
 impl_vn_mut!(D2, [G, T, C], SplitVec<C, G>, [C: NVecMut<<D2 as Dim>::PrevDim, T>, G: Growth]);
 impl_vn_mut!(D3, [G, T, C], SplitVec<C, G>, [C: NVecMut<<D3 as Dim>::PrevDim, T>, G: Growth]);
 impl_vn_mut!(D4, [G, T, C], SplitVec<C, G>, [C: NVecMut<<D4 as Dim>::PrevDim, T>, G: Growth]);
+impl_vn_mut!(D5, [G, T, C], SplitVec<C, G>, [C: NVecMut<<D5 as Dim>::PrevDim, T>, G: Growth]);
+impl_vn_mut!(D6, [G, T, C], SplitVec<C, G>, [C: NVecMut<<D6 as Dim>::PrevDim, T>, G: Growth]);
 
 #[cfg(all(test, feature = "orx-split-vec"))]
 mod tests {
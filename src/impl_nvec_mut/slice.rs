@@ -1,6 +1,11 @@
-use crate::{impl_v1_mut, impl_vn_mut, D2, D3, D4};
+use crate::common_trait_helpers::unchecked::impl_unchecked_storage_mut;
+use crate::{impl_v1_mut, impl_vn_mut, D2, D3, D4, D5, D6};
 
-impl_v1_mut!(['a, T], &'a mut [T], [T: Copy]);
-impl_vn_mut!(D2, ['a, C, T], &'a mut [C], [C: NVecMut<<D2 as Dim>::PrevDim, T>]);
-impl_vn_mut!(D3, ['a, C, T], &'a mut [C], [C: NVecMut<<D3 as Dim>::PrevDim, T>]);
-impl_vn_mut!(D4, ['a, C, T], &'a mut [C], [C: NVecMut<<D4 as Dim>::PrevDim, T>]);
+impl_unchecked_storage_mut!(['a, T], &'a mut [T], T, []);
+
+impl_v1_mut!(['v, T], &'v mut [T], [T: Copy]);
+impl_vn_mut!(D2, ['v, C, T], &'v mut [C], [C: NVecMut<<D2 as Dim>::PrevDim, T>]);
+impl_vn_mut!(D3, ['v, C, T], &'v mut [C], [C: NVecMut<<D3 as Dim>::PrevDim, T>]);
+impl_vn_mut!(D4, ['v, C, T], &'v mut [C], [C: NVecMut<<D4 as Dim>::PrevDim, T>]);
+impl_vn_mut!(D5, ['v, C, T], &'v mut [C], [C: NVecMut<<D5 as Dim>::PrevDim, T>]);
+impl_vn_mut!(D6, ['v, C, T], &'v mut [C], [C: NVecMut<<D6 as Dim>::PrevDim, T>]);
@@ -1,10 +1,15 @@
-use crate::{impl_v1_mut, impl_vn_mut, D2, D3, D4};
+use crate::common_trait_helpers::unchecked::impl_unchecked_storage_mut;
+use crate::{impl_v1_mut, impl_vn_mut, D2, D3, D4, D5, D6};
 use smallvec::{Array, SmallVec};
 
+impl_unchecked_storage_mut!([A], SmallVec<A>, A::Item, [A: Array]);
+
 impl_v1_mut!(N, [T], SmallVec<[T; N]>, [T: Copy, [T; N]: Array<Item = T>]);
 impl_vn_mut!(D2, N, [C, T], SmallVec<[C; N]>, [C: NVecMut<<D2 as Dim>::PrevDim, T>, [C; N]: Array<Item = C>]);
 impl_vn_mut!(D3, N, [C, T], SmallVec<[C; N]>, [C: NVecMut<<D3 as Dim>::PrevDim, T>, [C; N]: Array<Item = C>]);
 impl_vn_mut!(D4, N, [C, T], SmallVec<[C; N]>, [C: NVecMut<<D4 as Dim>::PrevDim, T>, [C; N]: Array<Item = C>]);
+impl_vn_mut!(D5, N, [C, T], SmallVec<[C; N]>, [C: NVecMut<<D5 as Dim>::PrevDim, T>, [C; N]: Array<Item = C>]);
+impl_vn_mut!(D6, N, [C, T], SmallVec<[C; N]>, [C: NVecMut<<D6 as Dim>::PrevDim, T>, [C; N]: Array<Item = C>]);
 
 #[cfg(all(test, feature = "smallvec"))]
 mod tests {
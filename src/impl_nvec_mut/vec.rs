@@ -1,10 +1,15 @@
-use crate::{impl_v1_mut, impl_vn_mut, D2, D3, D4};
+use crate::common_trait_helpers::unchecked::impl_unchecked_storage_mut;
+use crate::{impl_v1_mut, impl_vn_mut, D2, D3, D4, D5, D6};
 use alloc::vec::Vec;
 
+impl_unchecked_storage_mut!([T], Vec<T>, T, []);
+
 impl_v1_mut!([T], Vec<T>, [T: Copy]);
 impl_vn_mut!(D2, [T, C], Vec<C>, [C: NVecMut<<D2 as Dim>::PrevDim, T>]);
 impl_vn_mut!(D3, [T, C], Vec<C>, [C: NVecMut<<D3 as Dim>::PrevDim, T>]);
 impl_vn_mut!(D4, [T, C], Vec<C>, [C: NVecMut<<D4 as Dim>::PrevDim, T>]);
+impl_vn_mut!(D5, [T, C], Vec<C>, [C: NVecMut<<D5 as Dim>::PrevDim, T>]);
+impl_vn_mut!(D6, [T, C], Vec<C>, [C: NVecMut<<D6 as Dim>::PrevDim, T>]);
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,20 @@
+use super::impl_nvec_par_from_v1::*;
+use crate::common_trait_helpers::par::{impl_par_storage, impl_par_storage_mut};
+use crate::{D2, D3, D4, D5, D6};
+
+impl_par_storage!([const N: usize, T], [T; N], T, [T: Send + Sync]);
+impl_par_storage_mut!([const N: usize, T], [T; N], T, [T: Send + Sync]);
+
+impl_v1_par!([const N: usize, T], [T; N], [T: Copy + Send + Sync]);
+impl_vn_par!(D2, [const N: usize, T, C], [C; N], [T: Send, C: NVec<<D2 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D3, [const N: usize, T, C], [C; N], [T: Send, C: NVec<<D3 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D4, [const N: usize, T, C], [C; N], [T: Send, C: NVec<<D4 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D5, [const N: usize, T, C], [C; N], [T: Send, C: NVec<<D5 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D6, [const N: usize, T, C], [C; N], [T: Send, C: NVec<<D6 as Dim>::PrevDim, T> + Send + Sync]);
+
+impl_v1_par_mut!([const N: usize, T], [T; N], [T: Copy + Send + Sync]);
+impl_vn_par_mut!(D2, [const N: usize, T, C], [C; N], [T: Send, C: NVecMut<<D2 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D3, [const N: usize, T, C], [C; N], [T: Send, C: NVecMut<<D3 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D4, [const N: usize, T, C], [C; N], [T: Send, C: NVecMut<<D4 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D5, [const N: usize, T, C], [C; N], [T: Send, C: NVecMut<<D5 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D6, [const N: usize, T, C], [C; N], [T: Send, C: NVecMut<<D6 as Dim>::PrevDim, T> + Send + Sync]);
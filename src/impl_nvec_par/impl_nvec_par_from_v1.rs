@@ -0,0 +1,111 @@
+/// Implements `NVecPar<D1, T>` for a struct which is sort of like a 1-dimensional
+/// vector and implements `ParStorage`, such as the standard `Vec<T>`.
+macro_rules! impl_v1_par {
+    ([$($impl_generics:tt)*], $V:ty, [$($where:tt)*]) => {
+        #[allow(unused_imports)]
+        use $crate::*;
+        #[allow(unused_imports)]
+        use $crate::common_trait_helpers::par::*;
+        #[allow(unused_imports)]
+        use rayon::prelude::*;
+
+        impl<$($impl_generics)*> NVecPar<D1, T> for $V where $($where)* {
+            fn par_children(
+                &self,
+            ) -> impl IndexedParallelIterator<Item = impl NVec<<D1 as Dim>::PrevDim, T> + Send> {
+                rayon::iter::empty::<&Self>()
+            }
+
+            fn par_all(&self) -> impl ParallelIterator<Item = T> {
+                self.par_storage().copied()
+            }
+
+            fn par_enumerate_all(&self) -> impl ParallelIterator<Item = (<D1 as Dim>::Idx, T)> {
+                self.par_storage().copied().enumerate().map(|(i, x)| ([i], x))
+            }
+        }
+    };
+}
+
+/// Implements `NVecPar<D, T>` for a struct which is sort of like a 1-dimensional
+/// vector and implements `ParStorage`, such as the standard `Vec<T>`, and a dimension
+/// `D` which is at least 2-dimensional.
+macro_rules! impl_vn_par {
+    ($dim:ty, [$($impl_generics:tt)*], $V:ty, [$($where:tt)*]) => {
+        #[allow(unused_imports)]
+        use $crate::*;
+        #[allow(unused_imports)]
+        use $crate::common_trait_helpers::par::*;
+        #[allow(unused_imports)]
+        use rayon::prelude::*;
+
+        impl<$($impl_generics)*> NVecPar<$dim, T> for $V where $($where)* {
+            fn par_children(
+                &self,
+            ) -> impl IndexedParallelIterator<Item = impl NVec<<$dim as Dim>::PrevDim, T> + Send> {
+                self.par_storage()
+            }
+
+            fn par_all(&self) -> impl ParallelIterator<Item = T> {
+                self.par_storage().flat_map_iter(|c| c.all())
+            }
+
+            fn par_enumerate_all(&self) -> impl ParallelIterator<Item = (<$dim as Dim>::Idx, T)> {
+                self.par_storage().enumerate().flat_map_iter(|(i, c)| {
+                    c.enumerate_all()
+                        .map(move |(c_idx, x)| (<$dim as Dim>::left_join_from_lower_dim(i, c_idx), x))
+                })
+            }
+        }
+    };
+}
+
+/// Implements `NVecParMut<D1, T>` for a struct which is sort of like a 1-dimensional
+/// vector and implements `ParStorageMut`, such as the standard `Vec<T>`.
+macro_rules! impl_v1_par_mut {
+    ([$($impl_generics:tt)*], $V:ty, [$($where:tt)*]) => {
+        #[allow(unused_imports)]
+        use $crate::*;
+        #[allow(unused_imports)]
+        use $crate::common_trait_helpers::par::*;
+        #[allow(unused_imports)]
+        use rayon::prelude::*;
+
+        impl<$($impl_generics)*> NVecParMut<D1, T> for $V where $($where)* {
+            fn par_mut_all<F>(&mut self, f: F)
+            where
+                F: Fn(&mut T) + Sync + Send,
+            {
+                self.par_storage_mut().for_each(f)
+            }
+        }
+    };
+}
+
+/// Implements `NVecParMut<D, T>` for a struct which is sort of like a 1-dimensional
+/// vector and implements `ParStorageMut`, such as the standard `Vec<T>`, and a dimension
+/// `D` which is at least 2-dimensional.
+macro_rules! impl_vn_par_mut {
+    ($dim:ty, [$($impl_generics:tt)*], $V:ty, [$($where:tt)*]) => {
+        #[allow(unused_imports)]
+        use $crate::*;
+        #[allow(unused_imports)]
+        use $crate::common_trait_helpers::par::*;
+        #[allow(unused_imports)]
+        use rayon::prelude::*;
+
+        impl<$($impl_generics)*> NVecParMut<$dim, T> for $V where $($where)* {
+            fn par_mut_all<F>(&mut self, f: F)
+            where
+                F: Fn(&mut T) + Sync + Send,
+            {
+                self.par_storage_mut().for_each(|c| c.mut_all(&f))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_v1_par;
+pub(crate) use impl_v1_par_mut;
+pub(crate) use impl_vn_par;
+pub(crate) use impl_vn_par_mut;
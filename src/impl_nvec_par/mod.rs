@@ -0,0 +1,13 @@
+mod array;
+mod impl_nvec_par_from_v1;
+mod slice;
+mod vec;
+
+#[cfg(feature = "ndarray")]
+mod ndarray_recursive;
+
+#[cfg(feature = "ndarray")]
+mod ndarray_multi_dim;
+
+#[cfg(feature = "orx-split-vec")]
+mod orx_split_vec;
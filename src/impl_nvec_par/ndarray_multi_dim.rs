@@ -0,0 +1,58 @@
+use crate::children::{ChildD2D1, ChildD3D2, ChildD4D3};
+use crate::dim::*;
+use crate::{NVec, NVecPar, NVecParMut};
+use ndarray::{Array, Axis, Ix2, Ix3, Ix4};
+use rayon::prelude::*;
+
+macro_rules! impl_ndarray_par {
+    ($dim:ty, $ix:ty, $child:ident, |$i:ident, $c_idx:pat_param| $idx:expr) => {
+        impl<T: Copy + Send + Sync> NVecPar<$dim, T> for Array<T, $ix> {
+            fn par_children(
+                &self,
+            ) -> impl IndexedParallelIterator<Item = impl NVec<<$dim as Dim>::PrevDim, T> + Send>
+            {
+                (0..self.len_of(Axis(0)))
+                    .into_par_iter()
+                    .map(move |i| $child {
+                        i,
+                        parent: self,
+                        phantom: Default::default(),
+                    })
+            }
+
+            fn par_all(&self) -> impl ParallelIterator<Item = T> {
+                (0..self.len_of(Axis(0)))
+                    .into_par_iter()
+                    .flat_map_iter(move |i| self.index_axis(Axis(0), i).into_iter().copied())
+            }
+
+            fn par_enumerate_all(&self) -> impl ParallelIterator<Item = (<$dim as Dim>::Idx, T)> {
+                (0..self.len_of(Axis(0)))
+                    .into_par_iter()
+                    .flat_map_iter(move |$i| {
+                        let child = self.index_axis(Axis(0), $i);
+                        let indices = ndarray::indices(child.raw_dim());
+                        indices
+                            .into_iter()
+                            .zip(child)
+                            .map(move |($c_idx, x)| ($idx, *x))
+                    })
+            }
+        }
+
+        impl<T: Copy + Send + Sync> NVecParMut<$dim, T> for Array<T, $ix> {
+            fn par_mut_all<F>(&mut self, f: F)
+            where
+                F: Fn(&mut T) + Sync + Send,
+            {
+                self.axis_iter_mut(Axis(0))
+                    .into_par_iter()
+                    .for_each(|mut child| child.map_inplace(&f));
+            }
+        }
+    };
+}
+
+impl_ndarray_par!(D2, Ix2, ChildD2D1, |i, j| [i, j]);
+impl_ndarray_par!(D3, Ix3, ChildD3D2, |i, (j, k)| [i, j, k]);
+impl_ndarray_par!(D4, Ix4, ChildD4D3, |i, (j, k, l)| [i, j, k, l]);
@@ -0,0 +1,33 @@
+use super::impl_nvec_par_from_v1::*;
+use crate::common_trait_helpers::par::{ParStorage, ParStorageMut};
+use crate::{D2, D3, D4, D5, D6};
+use ndarray::{Array, Ix1};
+use rayon::prelude::*;
+
+impl<T: Send + Sync> ParStorage for Array<T, Ix1> {
+    type Item = T;
+
+    fn par_storage(&self) -> impl IndexedParallelIterator<Item = &Self::Item> {
+        (0..self.len()).into_par_iter().map(move |i| &self[i])
+    }
+}
+
+impl<T: Send + Sync> ParStorageMut for Array<T, Ix1> {
+    fn par_storage_mut(&mut self) -> impl ParallelIterator<Item = &mut Self::Item> {
+        self.par_iter_mut()
+    }
+}
+
+impl_v1_par!([T], Array<T, Ix1>, [T: Copy + Send + Sync]);
+impl_vn_par!(D2, [T, C], Array<C, Ix1>, [T: Send, C: NVec<<D2 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D3, [T, C], Array<C, Ix1>, [T: Send, C: NVec<<D3 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D4, [T, C], Array<C, Ix1>, [T: Send, C: NVec<<D4 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D5, [T, C], Array<C, Ix1>, [T: Send, C: NVec<<D5 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D6, [T, C], Array<C, Ix1>, [T: Send, C: NVec<<D6 as Dim>::PrevDim, T> + Send + Sync]);
+
+impl_v1_par_mut!([T], Array<T, Ix1>, [T: Copy + Send + Sync]);
+impl_vn_par_mut!(D2, [T, C], Array<C, Ix1>, [T: Send, C: NVecMut<<D2 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D3, [T, C], Array<C, Ix1>, [T: Send, C: NVecMut<<D3 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D4, [T, C], Array<C, Ix1>, [T: Send, C: NVecMut<<D4 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D5, [T, C], Array<C, Ix1>, [T: Send, C: NVecMut<<D5 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D6, [T, C], Array<C, Ix1>, [T: Send, C: NVecMut<<D6 as Dim>::PrevDim, T> + Send + Sync]);
@@ -0,0 +1,35 @@
+use super::impl_nvec_par_from_v1::*;
+use crate::common_trait_helpers::par::{ParStorage, ParStorageMut};
+use crate::{D2, D3, D4, D5, D6};
+use orx_split_vec::*;
+use rayon::prelude::*;
+
+impl<T: Send + Sync, G: Growth + Sync> ParStorage for SplitVec<T, G> {
+    type Item = T;
+
+    fn par_storage(&self) -> impl IndexedParallelIterator<Item = &Self::Item> {
+        (0..self.len()).into_par_iter().map(move |i| &self[i])
+    }
+}
+
+impl<T: Send + Sync, G: Growth + Sync> ParStorageMut for SplitVec<T, G> {
+    fn par_storage_mut(&mut self) -> impl ParallelIterator<Item = &mut Self::Item> {
+        // SAFETY: only the elements are mutated; fragments and their lengths are not changed
+        let fragments = unsafe { self.fragments_mut() };
+        fragments.par_iter_mut().flat_map_iter(|f| f.iter_mut())
+    }
+}
+
+impl_v1_par!([T, G], SplitVec<T, G>, [T: Copy + Send + Sync, G: Growth + Sync]);
+impl_vn_par!(D2, [G, T, C], SplitVec<C, G>, [T: Send, C: NVec<<D2 as Dim>::PrevDim, T> + Send + Sync, G: Growth + Sync]);
+impl_vn_par!(D3, [G, T, C], SplitVec<C, G>, [T: Send, C: NVec<<D3 as Dim>::PrevDim, T> + Send + Sync, G: Growth + Sync]);
+impl_vn_par!(D4, [G, T, C], SplitVec<C, G>, [T: Send, C: NVec<<D4 as Dim>::PrevDim, T> + Send + Sync, G: Growth + Sync]);
+impl_vn_par!(D5, [G, T, C], SplitVec<C, G>, [T: Send, C: NVec<<D5 as Dim>::PrevDim, T> + Send + Sync, G: Growth + Sync]);
+impl_vn_par!(D6, [G, T, C], SplitVec<C, G>, [T: Send, C: NVec<<D6 as Dim>::PrevDim, T> + Send + Sync, G: Growth + Sync]);
+
+impl_v1_par_mut!([T, G], SplitVec<T, G>, [T: Copy + Send + Sync, G: Growth + Sync]);
+impl_vn_par_mut!(D2, [G, T, C], SplitVec<C, G>, [T: Send, C: NVecMut<<D2 as Dim>::PrevDim, T> + Send + Sync, G: Growth + Sync]);
+impl_vn_par_mut!(D3, [G, T, C], SplitVec<C, G>, [T: Send, C: NVecMut<<D3 as Dim>::PrevDim, T> + Send + Sync, G: Growth + Sync]);
+impl_vn_par_mut!(D4, [G, T, C], SplitVec<C, G>, [T: Send, C: NVecMut<<D4 as Dim>::PrevDim, T> + Send + Sync, G: Growth + Sync]);
+impl_vn_par_mut!(D5, [G, T, C], SplitVec<C, G>, [T: Send, C: NVecMut<<D5 as Dim>::PrevDim, T> + Send + Sync, G: Growth + Sync]);
+impl_vn_par_mut!(D6, [G, T, C], SplitVec<C, G>, [T: Send, C: NVecMut<<D6 as Dim>::PrevDim, T> + Send + Sync, G: Growth + Sync]);
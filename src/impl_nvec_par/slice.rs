@@ -0,0 +1,28 @@
+use super::impl_nvec_par_from_v1::*;
+use crate::common_trait_helpers::par::{impl_par_storage, impl_par_storage_mut};
+use crate::{D2, D3, D4, D5, D6};
+
+impl_par_storage!(['a, T], &'a [T], T, [T: Send + Sync]);
+impl_par_storage!(['a, T], &'a mut [T], T, [T: Send + Sync]);
+impl_par_storage_mut!(['a, T], &'a mut [T], T, [T: Send + Sync]);
+
+impl_v1_par!(['a, T], &'a [T], [T: Copy + Send + Sync]);
+impl_vn_par!(D2, ['a, T, C], &'a [C], [T: Send, C: NVec<<D2 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D3, ['a, T, C], &'a [C], [T: Send, C: NVec<<D3 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D4, ['a, T, C], &'a [C], [T: Send, C: NVec<<D4 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D5, ['a, T, C], &'a [C], [T: Send, C: NVec<<D5 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D6, ['a, T, C], &'a [C], [T: Send, C: NVec<<D6 as Dim>::PrevDim, T> + Send + Sync]);
+
+impl_v1_par!(['a, T], &'a mut [T], [T: Copy + Send + Sync]);
+impl_vn_par!(D2, ['a, T, C], &'a mut [C], [T: Send, C: NVec<<D2 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D3, ['a, T, C], &'a mut [C], [T: Send, C: NVec<<D3 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D4, ['a, T, C], &'a mut [C], [T: Send, C: NVec<<D4 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D5, ['a, T, C], &'a mut [C], [T: Send, C: NVec<<D5 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D6, ['a, T, C], &'a mut [C], [T: Send, C: NVec<<D6 as Dim>::PrevDim, T> + Send + Sync]);
+
+impl_v1_par_mut!(['a, T], &'a mut [T], [T: Copy + Send + Sync]);
+impl_vn_par_mut!(D2, ['a, T, C], &'a mut [C], [T: Send, C: NVecMut<<D2 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D3, ['a, T, C], &'a mut [C], [T: Send, C: NVecMut<<D3 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D4, ['a, T, C], &'a mut [C], [T: Send, C: NVecMut<<D4 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D5, ['a, T, C], &'a mut [C], [T: Send, C: NVecMut<<D5 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D6, ['a, T, C], &'a mut [C], [T: Send, C: NVecMut<<D6 as Dim>::PrevDim, T> + Send + Sync]);
@@ -0,0 +1,21 @@
+use super::impl_nvec_par_from_v1::*;
+use crate::common_trait_helpers::par::{impl_par_storage, impl_par_storage_mut};
+use crate::{D2, D3, D4, D5, D6};
+use alloc::vec::Vec;
+
+impl_par_storage!([T], Vec<T>, T, [T: Send + Sync]);
+impl_par_storage_mut!([T], Vec<T>, T, [T: Send + Sync]);
+
+impl_v1_par!([T], Vec<T>, [T: Copy + Send + Sync]);
+impl_vn_par!(D2, [T, C], Vec<C>, [T: Send, C: NVec<<D2 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D3, [T, C], Vec<C>, [T: Send, C: NVec<<D3 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D4, [T, C], Vec<C>, [T: Send, C: NVec<<D4 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D5, [T, C], Vec<C>, [T: Send, C: NVec<<D5 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par!(D6, [T, C], Vec<C>, [T: Send, C: NVec<<D6 as Dim>::PrevDim, T> + Send + Sync]);
+
+impl_v1_par_mut!([T], Vec<T>, [T: Copy + Send + Sync]);
+impl_vn_par_mut!(D2, [T, C], Vec<C>, [T: Send, C: NVecMut<<D2 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D3, [T, C], Vec<C>, [T: Send, C: NVecMut<<D3 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D4, [T, C], Vec<C>, [T: Send, C: NVecMut<<D4 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D5, [T, C], Vec<C>, [T: Send, C: NVecMut<<D5 as Dim>::PrevDim, T> + Send + Sync]);
+impl_vn_par_mut!(D6, [T, C], Vec<C>, [T: Send, C: NVecMut<<D6 as Dim>::PrevDim, T> + Send + Sync]);
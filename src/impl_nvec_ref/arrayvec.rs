@@ -0,0 +1,9 @@
+use crate::{impl_v1_ref, impl_vn_ref, D2, D3, D4, D5, D6};
+use arrayvec::ArrayVec;
+
+impl_v1_ref!(N, [T], ArrayVec<T, N>, []);
+impl_vn_ref!(D2, N, [C, T], ArrayVec<C, N>, [C: NVecRef<<D2 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D3, N, [C, T], ArrayVec<C, N>, [C: NVecRef<<D3 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D4, N, [C, T], ArrayVec<C, N>, [C: NVecRef<<D4 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D5, N, [C, T], ArrayVec<C, N>, [C: NVecRef<<D5 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D6, N, [C, T], ArrayVec<C, N>, [C: NVecRef<<D6 as Dim>::PrevDim, T>]);
@@ -0,0 +1,86 @@
+/// Implements `NVecRef<D1, T>` for a struct which is sort of like a 1-dimensional
+/// vector, such as the standard `Vec<T>`.
+#[macro_export]
+macro_rules! impl_v1_ref {
+    ([$($impl_generics:tt)*], $V:ty, [$($where:tt)*]) => {
+        #[allow(unused_imports)]
+        use $crate::*;
+
+        impl<$($impl_generics)*> NVecRef<D1, T> for $V where $($where)* {
+            #[inline(always)]
+            fn at_ref(&self, idx: impl IntoIdx<D1>) -> &T {
+                &self[idx.into_idx()[0]]
+            }
+
+            fn all_ref<'x>(&'x self) -> impl Iterator<Item = &'x T>
+            where
+                T: 'x,
+            {
+                self.iter()
+            }
+        }
+    };
+    ($const_arg:tt, [$($impl_generics:tt)*], $V:ty, [$($where:tt)*]) => {
+        #[allow(unused_imports)]
+        use $crate::*;
+
+        impl<const $const_arg: usize, $($impl_generics)*> NVecRef<D1, T> for $V where $($where)* {
+            #[inline(always)]
+            fn at_ref(&self, idx: impl IntoIdx<D1>) -> &T {
+                &self[idx.into_idx()[0]]
+            }
+
+            fn all_ref<'x>(&'x self) -> impl Iterator<Item = &'x T>
+            where
+                T: 'x,
+            {
+                self.iter()
+            }
+        }
+    };
+}
+
+/// Implements `NVecRef<D, T>` for a struct which is sort of like a 1-dimensional
+/// vector, such as the standard `Vec<T>`, and a dimension `D` which is at least
+/// 2-dimensional.
+#[macro_export]
+macro_rules! impl_vn_ref {
+    ($dim:ty, [$($impl_generics:tt)*], $V:ty, [$($where:tt)*]) => {
+        #[allow(unused_imports)]
+        use $crate::*;
+
+        impl<$($impl_generics)*> NVecRef<$dim, T> for $V where $($where)* {
+            #[inline(always)]
+            fn at_ref(&self, idx: impl IntoIdx<$dim>) -> &T {
+                let (i, c_idx) = idx.into_idx().split_idx();
+                self[i].at_ref(c_idx)
+            }
+
+            fn all_ref<'x>(&'x self) -> impl Iterator<Item = &'x T>
+            where
+                T: 'x,
+            {
+                self.iter().flat_map(|x| x.all_ref())
+            }
+        }
+    };
+    ($dim:ty, $const_arg:tt, [$($impl_generics:tt)*], $V:ty, [$($where:tt)*]) => {
+        #[allow(unused_imports)]
+        use $crate::*;
+
+        impl<const $const_arg: usize, $($impl_generics)*> NVecRef<$dim, T> for $V where $($where)* {
+            #[inline(always)]
+            fn at_ref(&self, idx: impl IntoIdx<$dim>) -> &T {
+                let (i, c_idx) = idx.into_idx().split_idx();
+                self[i].at_ref(c_idx)
+            }
+
+            fn all_ref<'x>(&'x self) -> impl Iterator<Item = &'x T>
+            where
+                T: 'x,
+            {
+                self.iter().flat_map(|x| x.all_ref())
+            }
+        }
+    };
+}
@@ -0,0 +1,25 @@
+use crate::dim::*;
+use crate::NVecRef;
+use ndarray::{Array, Ix2, Ix3, Ix4};
+
+macro_rules! impl_ndarray_ref {
+    ($dim:ty, $ix:ty) => {
+        impl<T> NVecRef<$dim, T> for Array<T, $ix> {
+            #[inline(always)]
+            fn at_ref(&self, idx: impl IntoIdx<$dim>) -> &T {
+                &self[idx.into_idx()]
+            }
+
+            fn all_ref<'a>(&'a self) -> impl Iterator<Item = &'a T>
+            where
+                T: 'a,
+            {
+                self.iter()
+            }
+        }
+    };
+}
+
+impl_ndarray_ref!(D2, Ix2);
+impl_ndarray_ref!(D3, Ix3);
+impl_ndarray_ref!(D4, Ix4);
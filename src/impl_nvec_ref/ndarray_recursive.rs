@@ -0,0 +1,9 @@
+use crate::{impl_v1_ref, impl_vn_ref, D2, D3, D4, D5, D6};
+use ndarray::{Array, Ix1};
+
+impl_v1_ref!([T], Array<T, Ix1>, []);
+impl_vn_ref!(D2, [T, C], Array<C, Ix1>, [C: NVecRef<<D2 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D3, [T, C], Array<C, Ix1>, [C: NVecRef<<D3 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D4, [T, C], Array<C, Ix1>, [C: NVecRef<<D4 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D5, [T, C], Array<C, Ix1>, [C: NVecRef<<D5 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D6, [T, C], Array<C, Ix1>, [C: NVecRef<<D6 as Dim>::PrevDim, T>]);
@@ -0,0 +1,9 @@
+use crate::{impl_v1_ref, impl_vn_ref, D2, D3, D4, D5, D6};
+use orx_split_vec::*;
+
+impl_v1_ref!([T, G], SplitVec<T, G>, [G: Growth]);
+impl_vn_ref!(D2, [G, T, C], SplitVec<C, G>, [C: NVecRef<<D2 as Dim>::PrevDim, T>, G: Growth]);
+impl_vn_ref!(D3, [G, T, C], SplitVec<C, G>, [C: NVecRef<<D3 as Dim>::PrevDim, T>, G: Growth]);
+impl_vn_ref!(D4, [G, T, C], SplitVec<C, G>, [C: NVecRef<<D4 as Dim>::PrevDim, T>, G: Growth]);
+impl_vn_ref!(D5, [G, T, C], SplitVec<C, G>, [C: NVecRef<<D5 as Dim>::PrevDim, T>, G: Growth]);
+impl_vn_ref!(D6, [G, T, C], SplitVec<C, G>, [C: NVecRef<<D6 as Dim>::PrevDim, T>, G: Growth]);
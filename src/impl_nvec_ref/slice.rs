@@ -0,0 +1,15 @@
+use crate::{impl_v1_ref, impl_vn_ref, D2, D3, D4, D5, D6};
+
+impl_v1_ref!(['a, T], &'a [T], []);
+impl_vn_ref!(D2, ['a, C, T], &'a [C], [C: NVecRef<<D2 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D3, ['a, C, T], &'a [C], [C: NVecRef<<D3 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D4, ['a, C, T], &'a [C], [C: NVecRef<<D4 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D5, ['a, C, T], &'a [C], [C: NVecRef<<D5 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D6, ['a, C, T], &'a [C], [C: NVecRef<<D6 as Dim>::PrevDim, T>]);
+
+impl_v1_ref!(['a, T], &'a mut [T], []);
+impl_vn_ref!(D2, ['a, C, T], &'a mut [C], [C: NVecRef<<D2 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D3, ['a, C, T], &'a mut [C], [C: NVecRef<<D3 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D4, ['a, C, T], &'a mut [C], [C: NVecRef<<D4 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D5, ['a, C, T], &'a mut [C], [C: NVecRef<<D5 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D6, ['a, C, T], &'a mut [C], [C: NVecRef<<D6 as Dim>::PrevDim, T>]);
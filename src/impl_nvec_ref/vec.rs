@@ -0,0 +1,9 @@
+use crate::{impl_v1_ref, impl_vn_ref, D2, D3, D4, D5, D6};
+use alloc::vec::Vec;
+
+impl_v1_ref!([T], Vec<T>, []);
+impl_vn_ref!(D2, [T, C], Vec<C>, [C: NVecRef<<D2 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D3, [T, C], Vec<C>, [C: NVecRef<<D3 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D4, [T, C], Vec<C>, [C: NVecRef<<D4 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D5, [T, C], Vec<C>, [C: NVecRef<<D5 as Dim>::PrevDim, T>]);
+impl_vn_ref!(D6, [T, C], Vec<C>, [C: NVecRef<<D6 as Dim>::PrevDim, T>]);
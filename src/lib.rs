@@ -17,52 +17,145 @@ extern crate alloc;
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
+mod broadcast;
 mod cached;
 mod cardinality;
 mod children;
 mod common_trait_helpers;
+mod concat;
 mod constant_vec;
+mod custom_nvec;
 mod dim;
+mod dyn_dim;
 mod empty_vec;
 mod flat_jagged;
+mod flatten;
+mod from_nvec;
 mod fun;
 mod impl_nvec;
 mod impl_nvec_core;
+mod impl_nvec_grow;
 mod impl_nvec_mut;
+#[cfg(feature = "rayon")]
+mod impl_nvec_par;
+mod impl_nvec_ref;
+mod map;
 mod nvec;
 mod nvec_aliases;
+mod nvec_all_mut;
+mod nvec_children_mut;
 mod nvec_core;
 mod nvec_core_sealed;
+mod nvec_grow;
 mod nvec_mut;
+#[cfg(feature = "rayon")]
+mod nvec_par;
+#[cfg(feature = "rayon")]
+mod nvec_par_mut;
+mod nvec_ref;
+mod permute;
+mod reduce;
+mod ref_vec;
+mod select;
+mod slice;
 mod sparse;
+mod strided;
+mod tensors;
 mod v;
+mod zip;
 
 /// Matrix representations.
 pub mod matrices;
 
+/// Reading and writing NumPy `.npy` files.
+///
+/// Two, three and four-dimensional arrays are read into flat vectors viewed as a
+/// matrix or a `D3` or `D4` tensor with the layout of the file; i.e., row-major for
+/// arrays stored in C order and col-major for arrays stored in Fortran order.
+/// Any rectangular vector can be written to an `.npy` file.
+///
+/// Requires the **npy** feature.
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+/// use orx_v::npy::*;
+///
+/// let v2 = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+///
+/// let mut bytes = vec![];
+/// write(&mut bytes, &v2).unwrap(); // or a File
+///
+/// let mat = match read_matrix::<f64>(bytes.as_slice()).unwrap() {
+///     NpyMatrix::RowMajor(mat) => mat,
+///     NpyMatrix::ColMajor(_) => unreachable!("written in C order"),
+/// };
+/// assert_eq!(mat.at([1, 2]), 6.0);
+/// ```
+#[cfg(feature = "npy")]
+pub mod npy;
+
+/// Growable vectors.
+///
+/// Unlike the other traits, [`NVecGrow`](grow::NVecGrow) is not re-exported at the crate root
+/// since its method names coincide with those of the standard `Vec` and the `Extend` trait.
+/// It must be imported explicitly with `use orx_v::grow::*`.
+pub mod grow {
+    pub use crate::nvec_grow::{GrowError, NVecGrow};
+}
+
 // pub(crate) use
 
 pub(crate) use nvec_core_sealed::NVecCoreSealed;
 
 // pub use
 
+pub use broadcast::{Broadcast, BroadcastVec, V1Broadcast};
 pub use cached::{Cache, CachedVec, DefaultCache, IntoCached};
 pub use cardinality::{
     Card, CardD1, EmptyCard, RectangularCardD2, RectangularCardD3, RectangularCardD4,
-    UnboundedCard, VariableCardD2, VariableCardD3, VariableCardD4,
+    RectangularCardD5, RectangularCardD6, UnboundedCard, VariableCardD2, VariableCardD3,
+    VariableCardD4, VariableCardD5, VariableCardD6,
 };
+pub use concat::{chain, stack, ChainVec, StackVec};
 pub use constant_vec::ConstantVec;
+pub use custom_nvec::{CustomNVec, CustomNVecMut, CustomVec};
 pub use dim::*;
+pub use dyn_dim::{DynDim, DynNVec, DynVec, IntoDyn, NVecAsDyn};
 pub use empty_vec::EmptyVec;
-pub use flat_jagged::{FlatJagged, IntoJagged};
+pub use flat_jagged::{FlatJagged, IntoJagged, UniformEndIndices};
+pub use flatten::{AsFlat, FlattenVec};
+pub use from_nvec::{FromNVec, FromNVecError, ToNestedVec, V2ToOwned};
 pub use fun::FunVec;
+pub use map::{MapVec, MapWithIdxVec};
 pub use matrices::{
-    Matrix, MatrixColMajor, MatrixColMajorMut, MatrixMut, MatrixRowMajor, MatrixRowMajorMut,
-    V1AsMatrix, V2AsMatrix,
+    Matrix, MatrixAllMut, MatrixColMajor, MatrixColMajorMut, MatrixMut, MatrixRowMajor,
+    MatrixRowMajorMut, V1AsMatrix, V2AsMatrix,
 };
+#[cfg(feature = "rayon")]
+pub use matrices::{MatrixPar, MatrixParMut};
 pub use nvec::NVec;
 pub use nvec_aliases::*;
+pub use nvec_all_mut::NVecAllMut;
+pub use nvec_children_mut::NVecChildrenMut;
 pub use nvec_core::NVecCore;
 pub use nvec_mut::NVecMut;
+#[cfg(feature = "rayon")]
+pub use nvec_par::NVecPar;
+#[cfg(feature = "rayon")]
+pub use nvec_par_mut::NVecParMut;
+pub use nvec_ref::NVecRef;
+pub use permute::{PermuteAxes, PermutedVec, Transpose};
+pub use reduce::{ArgMinMax, ReduceAxis, ReducedVec};
+pub use ref_vec::RefVec;
+pub use select::{SelectVec, SelectVecD2, V1AsSelect, V2AsSelect};
+pub use slice::{BlockVec, SliceVec, V1AsSlice, V2AsBlock};
 pub use sparse::{DefaultLookup, Lookup, SparseVec};
-pub use v::{NewV1, NewV2, NewV3, NewV4, V};
+pub use strided::{RevVec, StridedVec, V1AsStrided};
+pub use tensors::{
+    V1AsTensor, V1Tensor, V1TensorColMajor, V1TensorLayout, V1TensorLayoutColMajor,
+    V1TensorLayoutRowMajor, V1TensorRowMajor,
+};
+pub use v::{NewV1, NewV2, NewV3, NewV4, NewV5, NewV6, V};
+pub use zip::ZipWithVec;
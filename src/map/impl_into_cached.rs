@@ -0,0 +1,24 @@
+use super::{MapVec, MapWithIdxVec};
+use crate::{Dim, IntoCached, NVec};
+use core::hash::Hash;
+
+impl<D, T, U, V, F> IntoCached<D, U> for MapVec<D, T, U, V, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    F: Fn(T) -> U,
+    U: Copy,
+    D::Idx: Ord + Hash,
+{
+}
+
+impl<D, T, U, V, F> IntoCached<D, U> for MapWithIdxVec<D, T, U, V, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    F: Fn(D::Idx, T) -> U,
+    U: Copy,
+    D::Idx: Ord + Hash,
+    Self: NVec<D, U>,
+{
+}
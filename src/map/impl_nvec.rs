@@ -0,0 +1,79 @@
+use super::{MapVec, MapWithIdxVec};
+use crate::cardinality::panic_on_all_when_udd;
+use crate::common_trait_helpers::indices::*;
+use crate::{dim::*, NVec};
+
+impl<D, T, U, V, F> NVec<D, U> for MapVec<D, T, U, V, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    F: Fn(T) -> U,
+{
+    #[inline(always)]
+    fn at(&self, idx: impl IntoIdx<D>) -> U {
+        (self.f)(self.vec.at(idx))
+    }
+
+    fn child(&self, i: <D as Dim>::ChildIdx) -> impl NVec<<D as Dim>::PrevDim, U> {
+        MapVec::new(self.vec.child(i), &self.f)
+    }
+
+    fn all(&self) -> impl Iterator<Item = U> {
+        self.vec.all().map(&self.f)
+    }
+}
+
+impl<D, T, U, V, F> MapWithIdxVec<D, T, U, V, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    F: Fn(D::Idx, T) -> U,
+{
+    #[allow(clippy::type_complexity)]
+    pub(super) fn map_child(
+        &self,
+        i: D::ChildIdx,
+    ) -> MapWithIdxVec<
+        D::PrevDim,
+        T,
+        U,
+        impl NVec<D::PrevDim, T> + '_,
+        impl Fn(<D::PrevDim as Dim>::Idx, T) -> U + '_,
+    > {
+        let i: usize = i.into();
+        let f = move |idx, x| (self.f)(D::left_join_from_lower_dim(i, idx), x);
+        MapWithIdxVec::new(self.vec.child(i.into()), f)
+    }
+}
+
+macro_rules! impl_nvec_with_idx {
+    ($dim:ty, $indices:ident) => {
+        impl<T, U, V, F> NVec<$dim, U> for MapWithIdxVec<$dim, T, U, V, F>
+        where
+            V: NVec<$dim, T>,
+            F: Fn(<$dim as Dim>::Idx, T) -> U,
+        {
+            #[inline(always)]
+            fn at(&self, idx: impl IntoIdx<$dim>) -> U {
+                let idx = idx.into_idx();
+                (self.f)(idx, self.vec.at(idx))
+            }
+
+            fn child(&self, i: <$dim as Dim>::ChildIdx) -> impl NVec<<$dim as Dim>::PrevDim, U> {
+                self.map_child(i)
+            }
+
+            fn all(&self) -> impl Iterator<Item = U> {
+                panic_on_all_when_udd(!self.vec.core_is_bounded());
+                $indices(&self.vec).map(|idx| (self.f)(idx, self.vec.at(idx)))
+            }
+        }
+    };
+}
+
+impl_nvec_with_idx!(D1, indices_d1);
+impl_nvec_with_idx!(D2, indices_d2);
+impl_nvec_with_idx!(D3, indices_d3);
+impl_nvec_with_idx!(D4, indices_d4);
+impl_nvec_with_idx!(D5, indices_d5);
+impl_nvec_with_idx!(D6, indices_d6);
@@ -0,0 +1,61 @@
+use super::{MapVec, MapWithIdxVec};
+use crate::{dim::*, NVec, NVecCoreSealed};
+
+impl<D, T, U, V, F> NVecCoreSealed<D, U> for MapVec<D, T, U, V, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    F: Fn(T) -> U,
+{
+    #[inline(always)]
+    fn core_num_children(&self) -> usize {
+        self.vec.num_children()
+    }
+
+    #[inline(always)]
+    fn core_card(&self, idx: impl Into<<D as Dim>::CardIdx>) -> usize {
+        self.vec.card(idx)
+    }
+
+    fn core_child(&self, i: <D as Dim>::ChildIdx) -> impl NVecCoreSealed<<D as Dim>::PrevDim, U> {
+        MapVec::new(self.vec.child(i), &self.f)
+    }
+
+    fn core_map<M: FnMut(&U) -> O, O>(&self, idx: impl IntoIdx<D>, f: &mut M) -> O {
+        f(&(self.f)(self.vec.at(idx)))
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        self.vec.is_rectangular()
+    }
+}
+
+impl<D, T, U, V, F> NVecCoreSealed<D, U> for MapWithIdxVec<D, T, U, V, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    F: Fn(D::Idx, T) -> U,
+{
+    #[inline(always)]
+    fn core_num_children(&self) -> usize {
+        self.vec.num_children()
+    }
+
+    #[inline(always)]
+    fn core_card(&self, idx: impl Into<<D as Dim>::CardIdx>) -> usize {
+        self.vec.card(idx)
+    }
+
+    fn core_child(&self, i: <D as Dim>::ChildIdx) -> impl NVecCoreSealed<<D as Dim>::PrevDim, U> {
+        self.map_child(i)
+    }
+
+    fn core_map<M: FnMut(&U) -> O, O>(&self, idx: impl IntoIdx<D>, f: &mut M) -> O {
+        let idx = idx.into_idx();
+        f(&(self.f)(idx, self.vec.at(idx)))
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        self.vec.is_rectangular()
+    }
+}
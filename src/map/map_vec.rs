@@ -0,0 +1,88 @@
+use crate::common_trait_helpers::debug::*;
+use crate::{dim::*, NVec};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A lazy vector of dimension `D` mapping each element of the underlying vector
+/// `V` with `f: Fn(T) -> U`.
+///
+/// The mapped vector has exactly the same cardinality as the underlying vector.
+/// Elements are not stored, `f` is applied each time an element is accessed.
+///
+/// It is created by the [`map`] method of `NVec`.
+///
+/// [`map`]: crate::NVec::map
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let v2 = vec![vec![1, 2, 3], vec![4]];
+///
+/// let doubled = v2.map(|x| x * 2);
+/// assert_eq!(doubled.card([]), 2);
+/// assert_eq!(doubled.card([0]), 3);
+/// assert_eq!(doubled.at([1, 0]), 8);
+/// assert_eq!(doubled.equality(&[vec![2, 4, 6], vec![8]]), Equality::Equal);
+/// ```
+pub struct MapVec<D, T, U, V, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    F: Fn(T) -> U,
+{
+    pub(super) vec: V,
+    pub(super) f: F,
+    phantom: PhantomData<(D, T, U)>,
+}
+
+impl<D, T, U, V, F> MapVec<D, T, U, V, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    F: Fn(T) -> U,
+{
+    pub(crate) fn new(vec: V, f: F) -> Self {
+        Self {
+            vec,
+            f,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Destructs the mapped vector into its underlying vector and map function.
+    pub fn into_inner(self) -> (V, F) {
+        (self.vec, self.f)
+    }
+}
+
+macro_rules! impl_debug {
+    ($dim:ty, $dbg_fn:ident) => {
+        impl<T, U, V, F> Debug for MapVec<$dim, T, U, V, F>
+        where
+            V: NVec<$dim, T>,
+            F: Fn(T) -> U,
+            U: Debug,
+            Self: NVec<$dim, U>,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{{ kind: MapVec, dim: D{}, is_bounded: {}, values: ",
+                    <$dim as Dim>::dimension(),
+                    self.is_bounded(),
+                )?;
+                $dbg_fn(f, self)?;
+                write!(f, " }}")
+            }
+        }
+    };
+}
+
+impl_debug!(D1, dbg_values_d1);
+impl_debug!(D2, dbg_values_d2);
+impl_debug!(D3, dbg_values_d3);
+impl_debug!(D4, dbg_values_d4);
+impl_debug!(D5, dbg_values_d5);
+impl_debug!(D6, dbg_values_d6);
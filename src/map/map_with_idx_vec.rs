@@ -0,0 +1,89 @@
+use crate::common_trait_helpers::debug::*;
+use crate::{dim::*, NVec};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A lazy vector of dimension `D` mapping each element of the underlying vector
+/// `V` together with its index with `f: Fn(D::Idx, T) -> U`.
+///
+/// The mapped vector has exactly the same cardinality as the underlying vector.
+/// Elements are not stored, `f` is applied each time an element is accessed.
+///
+/// It is created by the [`map_with_idx`] method of `NVec`.
+///
+/// [`map_with_idx`]: crate::NVec::map_with_idx
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let v2 = vec![vec![1, 2, 3], vec![4]];
+///
+/// let v2 = v2.map_with_idx(|[i, j], x| x + 10 * i + 100 * j);
+/// assert_eq!(v2.card([0]), 3);
+/// assert_eq!(v2.at([0, 2]), 203);
+/// assert_eq!(v2.at([1, 0]), 14);
+/// assert_eq!(v2.child(0).at([1]), 102);
+/// assert_eq!(v2.equality(&[vec![1, 102, 203], vec![14]]), Equality::Equal);
+/// ```
+pub struct MapWithIdxVec<D, T, U, V, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    F: Fn(D::Idx, T) -> U,
+{
+    pub(super) vec: V,
+    pub(super) f: F,
+    phantom: PhantomData<(D, T, U)>,
+}
+
+impl<D, T, U, V, F> MapWithIdxVec<D, T, U, V, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    F: Fn(D::Idx, T) -> U,
+{
+    pub(crate) fn new(vec: V, f: F) -> Self {
+        Self {
+            vec,
+            f,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Destructs the mapped vector into its underlying vector and map function.
+    pub fn into_inner(self) -> (V, F) {
+        (self.vec, self.f)
+    }
+}
+
+macro_rules! impl_debug {
+    ($dim:ty, $dbg_fn:ident) => {
+        impl<T, U, V, F> Debug for MapWithIdxVec<$dim, T, U, V, F>
+        where
+            V: NVec<$dim, T>,
+            F: Fn(<$dim as Dim>::Idx, T) -> U,
+            U: Debug,
+            Self: NVec<$dim, U>,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{{ kind: MapWithIdxVec, dim: D{}, is_bounded: {}, values: ",
+                    <$dim as Dim>::dimension(),
+                    self.is_bounded(),
+                )?;
+                $dbg_fn(f, self)?;
+                write!(f, " }}")
+            }
+        }
+    };
+}
+
+impl_debug!(D1, dbg_values_d1);
+impl_debug!(D2, dbg_values_d2);
+impl_debug!(D3, dbg_values_d3);
+impl_debug!(D4, dbg_values_d4);
+impl_debug!(D5, dbg_values_d5);
+impl_debug!(D6, dbg_values_d6);
@@ -0,0 +1,8 @@
+mod impl_into_cached;
+mod impl_nvec;
+mod impl_nvec_core;
+mod map_vec;
+mod map_with_idx_vec;
+
+pub use map_vec::MapVec;
+pub use map_with_idx_vec::MapWithIdxVec;
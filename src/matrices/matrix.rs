@@ -1,4 +1,6 @@
+use super::{MatrixBlock, MatrixMut};
 use crate::{Dim, Equality, IdxLeqD1, IdxLeqD2, IntoIdx, D2};
+use core::ops::Range;
 
 /// A matrix or a matrix view over a `D2` vector with rectangular cardinality,
 /// or over a flattened representation by a `D1` vector.
@@ -81,6 +83,35 @@ pub trait Matrix<T> {
         }
     }
 
+    /// Returns the element at the given `idx` of the matrix without bounds checks.
+    ///
+    /// Matrices over storage-backed vectors skip validating the index; see
+    /// [`NVec::at_unchecked`] for details.
+    ///
+    /// [`NVec::at_unchecked`]: crate::NVec::at_unchecked
+    ///
+    /// # Safety
+    ///
+    /// The `idx` must be `in_bounds`; calling this method with an out of bounds index
+    /// is *undefined behavior*.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v1: Vec<_> = (0..6).collect();
+    /// let mat = v1.v1_as_matrix_col_major(2, 3);
+    ///
+    /// // SAFETY: [1, 2] is in bounds of the 2x3 matrix
+    /// assert_eq!(unsafe { mat.at_unchecked([1, 2]) }, 5);
+    /// ```
+    #[inline(always)]
+    unsafe fn at_unchecked(&self, idx: impl IntoIdx<D2>) -> T {
+        debug_assert!(self.in_bounds(idx.into_idx()), "index is out of bounds");
+        self.at(idx)
+    }
+
     /// Returns the equality result of comparing this matrix to the `other`.
     fn equality(&self, other: &impl Matrix<T>) -> Equality<D2>
     where
@@ -101,6 +132,107 @@ pub trait Matrix<T> {
             Equality::Equal
         }
     }
+
+    /// Converts the matrix into a zero-copy view of its block defined by the range of
+    /// `rows` and the range of `cols`; the element of the block at `[i, j]` is the
+    /// element of this matrix at `[rows.start + i, cols.start + j]`.
+    ///
+    /// The block preserves the layout of the matrix; i.e., rows of a block of a
+    /// row-major matrix and columns of a block of a col-major matrix are available.
+    ///
+    /// See [`as_block`] and [`as_block_mut`] to create blocks over a reference of
+    /// the matrix.
+    ///
+    /// [`as_block`]: crate::matrices::Matrix::as_block
+    /// [`as_block_mut`]: crate::matrices::Matrix::as_block_mut
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows` or `cols` are out of bounds of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v1: Vec<_> = (0..12).collect();
+    /// let mat = v1.v1_into_matrix(3, 4);
+    ///
+    /// let b = mat.into_block(1..3, 2..4);
+    /// assert_eq!(b.num_rows(), 2);
+    /// assert_eq!(b.row(0).equality(&[6, 7]), Equality::Equal);
+    /// ```
+    fn into_block(self, rows: Range<usize>, cols: Range<usize>) -> MatrixBlock<T, Self>
+    where
+        Self: Sized,
+    {
+        MatrixBlock::new(self, rows, cols)
+    }
+
+    /// Creates a zero-copy view of the block of the matrix defined by the range of
+    /// `rows` and the range of `cols`; the element of the block at `[i, j]` is the
+    /// element of this matrix at `[rows.start + i, cols.start + j]`.
+    ///
+    /// The block preserves the layout of the matrix; i.e., rows of a block of a
+    /// row-major matrix and columns of a block of a col-major matrix are available.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows` or `cols` are out of bounds of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v1: Vec<_> = (0..12).collect();
+    /// let mat = v1.v1_into_matrix(3, 4);
+    ///
+    /// let b = mat.as_block(1..3, 2..4);
+    /// assert_eq!(b.num_rows(), 2);
+    /// assert_eq!(b.num_cols(), 2);
+    /// assert_eq!(b.at([1, 0]), 10);
+    /// assert_eq!(b.row(0).equality(&[6, 7]), Equality::Equal);
+    /// ```
+    fn as_block(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixBlock<T, &Self>
+    where
+        Self: Sized,
+    {
+        MatrixBlock::new(self, rows, cols)
+    }
+
+    /// Creates a mutable zero-copy view of the block of the matrix defined by the range
+    /// of `rows` and the range of `cols`; the element of the block at `[i, j]` is the
+    /// element of this matrix at `[rows.start + i, cols.start + j]`.
+    ///
+    /// The block preserves the layout of the matrix; i.e., rows of a block of a
+    /// row-major matrix and columns of a block of a col-major matrix are available.
+    /// The block is a [`MatrixMut`].
+    ///
+    /// [`MatrixMut`]: crate::matrices::MatrixMut
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows` or `cols` are out of bounds of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v1: Vec<_> = (0..12).collect();
+    /// let mut mat = v1.v1_into_matrix(3, 4);
+    ///
+    /// let mut b = mat.as_block_mut(0..2, 0..2);
+    /// b.reset_all(0);
+    /// assert_eq!(mat.row(1).equality(&[0, 0, 6, 7]), Equality::Equal);
+    /// ```
+    fn as_block_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatrixBlock<T, &mut Self>
+    where
+        Self: MatrixMut<T> + Sized,
+    {
+        MatrixBlock::new(self, rows, cols)
+    }
 }
 
 // &V auto impl
@@ -118,6 +250,10 @@ impl<T, M: Matrix<T>> Matrix<T> for &M {
         <M as Matrix<T>>::at(self, idx)
     }
 
+    unsafe fn at_unchecked(&self, idx: impl IntoIdx<D2>) -> T {
+        unsafe { <M as Matrix<T>>::at_unchecked(self, idx) }
+    }
+
     fn all(&self) -> impl Iterator<Item = T> {
         <M as Matrix<T>>::all(self)
     }
@@ -138,6 +274,10 @@ impl<T, M: Matrix<T>> Matrix<T> for &mut M {
         <M as Matrix<T>>::at(self, idx)
     }
 
+    unsafe fn at_unchecked(&self, idx: impl IntoIdx<D2>) -> T {
+        unsafe { <M as Matrix<T>>::at_unchecked(self, idx) }
+    }
+
     fn all(&self) -> impl Iterator<Item = T> {
         <M as Matrix<T>>::all(self)
     }
@@ -0,0 +1,122 @@
+use super::MatrixMut;
+use crate::common_trait_helpers::all_mut::take_in_order;
+use crate::{IntoIdx, D2};
+use alloc::vec::Vec;
+
+/// A mutable matrix which can create mutable references to all of its elements at the
+/// same time since the elements are disjoint.
+///
+/// This is the matrix counterpart of [`NVecAllMut`].
+///
+/// [`NVecAllMut`]: crate::NVecAllMut
+pub trait MatrixAllMut<T>: MatrixMut<T> {
+    /// Returns an iterator of mutable references to all elements of the matrix in the
+    /// same order as yielded by [`all`].
+    ///
+    /// [`all`]: crate::Matrix::all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v1 = vec![0; 6];
+    /// let mut mat = v1.v1_as_matrix_col_major_mut(2, 3);
+    ///
+    /// for (x, y) in mat.all_mut().zip(1..) {
+    ///     *x = y;
+    /// }
+    /// assert_eq!(mat.at([1, 0]), 2);
+    /// assert_eq!(v1, vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a;
+
+    /// Returns an iterator of all elements of the matrix together with their `[i, j]`
+    /// indices in the same order as yielded by [`all_mut`].
+    ///
+    /// [`all_mut`]: crate::MatrixAllMut::all_mut
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v1 = vec![0; 6];
+    /// let mut mat = v1.v1_as_matrix_col_major_mut(2, 3);
+    ///
+    /// for ([i, j], x) in mat.enumerate_all_mut() {
+    ///     *x = 10 * i + j;
+    /// }
+    /// assert_eq!(v1, vec![0, 10, 1, 11, 2, 12]);
+    /// ```
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = ([usize; 2], &'a mut T)>
+    where
+        T: 'a;
+
+    // provided
+
+    /// Returns an iterator of mutable references to the elements at the given `indices`,
+    /// in the order of the `indices`.
+    ///
+    /// See [`NVecAllMut::all_mut_in`] for details.
+    ///
+    /// [`NVecAllMut::all_mut_in`]: crate::NVecAllMut::all_mut_in
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds or is requested more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v1 = vec![0; 6];
+    /// let mut mat = v1.v1_as_matrix_col_major_mut(2, 3);
+    ///
+    /// for (x, y) in mat.all_mut_in([[1, 2], [0, 1]].into_iter()).zip([1, 2]) {
+    ///     *x = y;
+    /// }
+    /// assert_eq!(v1, vec![0, 0, 2, 0, 0, 1]);
+    /// ```
+    fn all_mut_in<'a>(
+        &'a mut self,
+        indices: impl Iterator<Item = impl IntoIdx<D2>>,
+    ) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        let indices: Vec<_> = indices.map(|idx| idx.into_idx()).collect();
+        take_in_order(self.enumerate_all_mut(), indices)
+    }
+}
+
+// &mut V auto impl
+
+impl<T, M: MatrixAllMut<T>> MatrixAllMut<T> for &mut M {
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        <M as MatrixAllMut<T>>::all_mut(self)
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = ([usize; 2], &'a mut T)>
+    where
+        T: 'a,
+    {
+        <M as MatrixAllMut<T>>::enumerate_all_mut(self)
+    }
+
+    fn all_mut_in<'a>(
+        &'a mut self,
+        indices: impl Iterator<Item = impl IntoIdx<D2>>,
+    ) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        <M as MatrixAllMut<T>>::all_mut_in(self, indices)
+    }
+}
@@ -0,0 +1,216 @@
+use super::{
+    Matrix, MatrixAllMut, MatrixColMajor, MatrixColMajorMut, MatrixMut, MatrixRowMajor,
+    MatrixRowMajorMut,
+};
+use crate::{IntoIdx, NVec, NVecMut, V1AsSlice, D1, D2};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+/// A zero-copy view of a block of a matrix, defined by a range of its rows and a
+/// range of its columns.
+///
+/// It is created by the [`into_block`], [`as_block`] and [`as_block_mut`] methods.
+///
+/// [`into_block`]: crate::matrices::Matrix::into_block
+/// [`as_block`]: crate::matrices::Matrix::as_block
+/// [`as_block_mut`]: crate::matrices::Matrix::as_block_mut
+pub struct MatrixBlock<T, M>
+where
+    M: Matrix<T>,
+{
+    mat: M,
+    rows: Range<usize>,
+    cols: Range<usize>,
+    phantom: PhantomData<T>,
+}
+
+impl<T, M> MatrixBlock<T, M>
+where
+    M: Matrix<T>,
+{
+    pub(super) fn new(mat: M, rows: Range<usize>, cols: Range<usize>) -> Self {
+        assert!(
+            rows.start <= rows.end && rows.end <= mat.num_rows(),
+            "Row range {:?} is out of bounds of the matrix with {} rows",
+            rows,
+            mat.num_rows()
+        );
+        assert!(
+            cols.start <= cols.end && cols.end <= mat.num_cols(),
+            "Column range {:?} is out of bounds of the matrix with {} columns",
+            cols,
+            mat.num_cols()
+        );
+        Self {
+            mat,
+            rows,
+            cols,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the range of rows of the underlying matrix that the block is a view of.
+    pub fn rows_range(&self) -> Range<usize> {
+        self.rows.clone()
+    }
+
+    /// Returns the range of columns of the underlying matrix that the block is a view of.
+    pub fn cols_range(&self) -> Range<usize> {
+        self.cols.clone()
+    }
+
+    /// Destructs the block into the underlying matrix.
+    pub fn into_inner(self) -> M {
+        self.mat
+    }
+
+    #[inline(always)]
+    fn src_row(&self, i: usize) -> usize {
+        assert!(
+            i < self.num_rows(),
+            "Row {} is out of bounds of the matrix block with {} rows",
+            i,
+            self.num_rows()
+        );
+        self.rows.start + i
+    }
+
+    #[inline(always)]
+    fn src_col(&self, j: usize) -> usize {
+        assert!(
+            j < self.num_cols(),
+            "Column {} is out of bounds of the matrix block with {} columns",
+            j,
+            self.num_cols()
+        );
+        self.cols.start + j
+    }
+
+    #[inline(always)]
+    fn src_idx(&self, idx: impl IntoIdx<D2>) -> [usize; 2] {
+        let [i, j] = idx.into_idx();
+        [self.src_row(i), self.src_col(j)]
+    }
+}
+
+impl<T, M> Matrix<T> for MatrixBlock<T, M>
+where
+    M: Matrix<T>,
+{
+    #[inline(always)]
+    fn num_rows(&self) -> usize {
+        self.rows.end - self.rows.start
+    }
+
+    #[inline(always)]
+    fn num_cols(&self) -> usize {
+        self.cols.end - self.cols.start
+    }
+
+    #[inline(always)]
+    fn at(&self, idx: impl IntoIdx<D2>) -> T {
+        self.mat.at(self.src_idx(idx))
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        self.rows
+            .clone()
+            .flat_map(move |i| self.cols.clone().map(move |j| self.mat.at([i, j])))
+    }
+}
+
+impl<T, M> MatrixMut<T> for MatrixBlock<T, M>
+where
+    M: MatrixMut<T>,
+{
+    #[inline(always)]
+    fn at_mut<Idx: IntoIdx<D2>>(&mut self, idx: Idx) -> &mut T {
+        let idx = self.src_idx(idx);
+        self.mat.at_mut(idx)
+    }
+
+    fn mut_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for i in self.rows.clone() {
+            for j in self.cols.clone() {
+                f(self.mat.at_mut([i, j]));
+            }
+        }
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        self.mut_all(|x| *x = value);
+    }
+}
+
+impl<T, M> MatrixAllMut<T> for MatrixBlock<T, M>
+where
+    M: MatrixAllMut<T>,
+{
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        let indices: Vec<_> = self
+            .rows
+            .clone()
+            .flat_map(|i| self.cols.clone().map(move |j| [i, j]))
+            .collect();
+        self.mat.all_mut_in(indices.into_iter())
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = ([usize; 2], &'a mut T)>
+    where
+        T: 'a,
+    {
+        let (num_rows, num_cols) = (self.num_rows(), self.num_cols());
+        let indices = (0..num_rows).flat_map(move |i| (0..num_cols).map(move |j| [i, j]));
+        indices.zip(self.all_mut())
+    }
+}
+
+impl<T, M> MatrixRowMajor<T> for MatrixBlock<T, M>
+where
+    M: MatrixRowMajor<T>,
+{
+    fn row(&self, i: usize) -> impl NVec<D1, T> {
+        let i = self.src_row(i);
+        self.mat.row(i).v1_into_slice(self.cols.clone())
+    }
+}
+
+impl<T, M> MatrixRowMajorMut<T> for MatrixBlock<T, M>
+where
+    M: MatrixRowMajorMut<T>,
+{
+    fn row_mut(&mut self, i: usize) -> impl NVecMut<D1, T> {
+        let i = self.src_row(i);
+        self.mat.row_mut(i).v1_into_slice(self.cols.clone())
+    }
+}
+
+impl<T, M> MatrixColMajor<T> for MatrixBlock<T, M>
+where
+    M: MatrixColMajor<T>,
+{
+    fn col(&self, j: usize) -> impl NVec<D1, T> {
+        let j = self.src_col(j);
+        self.mat.col(j).v1_into_slice(self.rows.clone())
+    }
+}
+
+impl<T, M> MatrixColMajorMut<T> for MatrixBlock<T, M>
+where
+    M: MatrixColMajorMut<T>,
+{
+    fn col_mut(&mut self, j: usize) -> impl NVecMut<D1, T> {
+        let j = self.src_col(j);
+        self.mat.col_mut(j).v1_into_slice(self.rows.clone())
+    }
+}
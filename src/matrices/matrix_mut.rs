@@ -104,6 +104,24 @@ pub trait MatrixMut<T>: Matrix<T> {
             false => None,
         }
     }
+
+    /// Returns a mutable reference to the element at the given `idx` of the matrix
+    /// without bounds checks.
+    ///
+    /// Matrices over storage-backed vectors skip validating the index; see
+    /// [`NVecMut::at_mut_unchecked`] for details.
+    ///
+    /// [`NVecMut::at_mut_unchecked`]: crate::NVecMut::at_mut_unchecked
+    ///
+    /// # Safety
+    ///
+    /// The `idx` must be `in_bounds`; calling this method with an out of bounds index
+    /// is *undefined behavior*.
+    #[inline(always)]
+    unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<D2>) -> &mut T {
+        debug_assert!(self.in_bounds(idx.into_idx()), "index is out of bounds");
+        self.at_mut(idx)
+    }
 }
 
 // &mut V auto impl
@@ -113,6 +131,10 @@ impl<T, M: MatrixMut<T>> MatrixMut<T> for &mut M {
         <M as MatrixMut<T>>::at_mut(self, idx)
     }
 
+    unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<D2>) -> &mut T {
+        unsafe { <M as MatrixMut<T>>::at_mut_unchecked(self, idx) }
+    }
+
     fn mut_all<F>(&mut self, f: F)
     where
         F: FnMut(&mut T),
@@ -0,0 +1,125 @@
+use super::{Matrix, MatrixMut};
+use rayon::iter::ParallelIterator;
+
+/// A matrix whose elements can be iterated over in parallel using rayon.
+///
+/// Collecting the parallel iterators yields the elements in the same order as
+/// the sequential [`all`] method of the matrix.
+///
+/// Requires the **rayon** feature.
+///
+/// [`all`]: crate::Matrix::all
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+/// use rayon::prelude::*;
+///
+/// let v1 = vec![1, 2, 3, 4, 5, 6];
+/// let mat = v1.v1_as_matrix(2, 3);
+///
+/// let sum: i32 = mat.par_all().sum();
+/// assert_eq!(sum, 21);
+/// ```
+pub trait MatrixPar<T: Send>: Matrix<T> + Sync {
+    /// Returns a parallel iterator over all elements of the matrix.
+    ///
+    /// Collecting the iterator yields the elements in the same order as [`all`].
+    ///
+    /// [`all`]: crate::Matrix::all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let v1 = vec![1, 2, 3, 4, 5, 6];
+    /// let mat = v1.v1_as_matrix_col_major(2, 3);
+    ///
+    /// let all: Vec<_> = mat.par_all().collect();
+    /// assert_eq!(all, mat.all().collect::<Vec<_>>());
+    /// ```
+    fn par_all(&self) -> impl ParallelIterator<Item = T>;
+
+    /// Returns a parallel iterator over all elements of the matrix together with their
+    /// row and column indices.
+    ///
+    /// Collecting the iterator yields the elements in the same order as [`all`].
+    ///
+    /// [`all`]: crate::Matrix::all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let v1 = vec![1, 2, 3, 4];
+    /// let mat = v1.v1_as_matrix_col_major(2, 2);
+    ///
+    /// let all: Vec<_> = mat.par_enumerate_all().collect();
+    /// assert_eq!(all, vec![([0, 0], 1), ([1, 0], 2), ([0, 1], 3), ([1, 1], 4)]);
+    /// ```
+    fn par_enumerate_all(&self) -> impl ParallelIterator<Item = ([usize; 2], T)>;
+}
+
+/// A mutable matrix whose elements can be mutated in parallel using rayon.
+///
+/// Requires the **rayon** feature.
+pub trait MatrixParMut<T: Send>: MatrixMut<T> {
+    /// Applies the mutation `f` to all elements of the matrix in parallel.
+    ///
+    /// This is the parallel counterpart of [`mut_all`].
+    ///
+    /// [`mut_all`]: crate::MatrixMut::mut_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v1 = vec![1, 2, 3, 4, 5, 6];
+    /// let mut mat = v1.v1_as_matrix_mut(2, 3);
+    ///
+    /// mat.par_mut_all(|x| *x *= 10);
+    /// assert_eq!(v1, vec![10, 20, 30, 40, 50, 60]);
+    /// ```
+    fn par_mut_all<F>(&mut self, f: F)
+    where
+        F: Fn(&mut T) + Sync + Send;
+}
+
+// &V auto impl
+
+impl<T: Send, M: MatrixPar<T>> MatrixPar<T> for &M {
+    fn par_all(&self) -> impl ParallelIterator<Item = T> {
+        <M as MatrixPar<T>>::par_all(self)
+    }
+
+    fn par_enumerate_all(&self) -> impl ParallelIterator<Item = ([usize; 2], T)> {
+        <M as MatrixPar<T>>::par_enumerate_all(self)
+    }
+}
+
+// &mut V auto impl
+
+impl<T: Send, M: MatrixPar<T>> MatrixPar<T> for &mut M {
+    fn par_all(&self) -> impl ParallelIterator<Item = T> {
+        <M as MatrixPar<T>>::par_all(self)
+    }
+
+    fn par_enumerate_all(&self) -> impl ParallelIterator<Item = ([usize; 2], T)> {
+        <M as MatrixPar<T>>::par_enumerate_all(self)
+    }
+}
+
+impl<T: Send, M: MatrixParMut<T>> MatrixParMut<T> for &mut M {
+    fn par_mut_all<F>(&mut self, f: F)
+    where
+        F: Fn(&mut T) + Sync + Send,
+    {
+        <M as MatrixParMut<T>>::par_mut_all(self, f)
+    }
+}
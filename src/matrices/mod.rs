@@ -1,13 +1,21 @@
 mod matrix;
+mod matrix_all_mut;
+mod matrix_block;
 mod matrix_col_major;
 mod matrix_mut;
+#[cfg(feature = "rayon")]
+mod matrix_par;
 mod matrix_row_major;
 mod v1;
 mod v2;
 
 pub use matrix::Matrix;
+pub use matrix_all_mut::MatrixAllMut;
+pub use matrix_block::MatrixBlock;
 pub use matrix_col_major::{MatrixColMajor, MatrixColMajorMut};
 pub use matrix_mut::MatrixMut;
+#[cfg(feature = "rayon")]
+pub use matrix_par::{MatrixPar, MatrixParMut};
 pub use matrix_row_major::{MatrixRowMajor, MatrixRowMajorMut};
 pub use v1::{
     V1AsMatrix, V1LayoutColMajor, V1LayoutRowMajor, V1Matrix, V1MatrixColMajor, V1MatrixLayout,
@@ -1,5 +1,5 @@
 use super::layout::{V1LayoutColMajor, V1MatrixLayout};
-use crate::{Dim, IntoIdx, NVec, NVecCoreSealed, NVecMut, D1};
+use crate::{Dim, IntoIdx, NVec, NVecAllMut, NVecCoreSealed, NVecMut, D1};
 use core::marker::PhantomData;
 
 pub struct Col<T, V>
@@ -111,3 +111,23 @@ where
         }
     }
 }
+
+impl<T, V> NVecAllMut<D1, T> for Col<T, V>
+where
+    V: NVecAllMut<D1, T>,
+{
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        let (begin, len) = (self.v1_idx(0), self.core_num_children());
+        self.data.all_mut_in((begin..(begin + len)).map(|i| [i]))
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D1 as Dim>::Idx, &'a mut T)>
+    where
+        T: 'a,
+    {
+        self.all_mut().enumerate().map(|(i, x)| ([i], x))
+    }
+}
@@ -23,6 +23,12 @@ pub trait V1MatrixLayout: Clone {
     /// index for the underlying data.
     fn v1_idx(&self, i: usize, j: usize) -> usize;
 
+    /// Inverse of [`v1_idx`]; transformation of the one dimensional index of the
+    /// underlying data into the row and column indices (`i`, `j`).
+    ///
+    /// [`v1_idx`]: crate::matrices::V1MatrixLayout::v1_idx
+    fn matrix_idx(&self, v1_idx: usize) -> [usize; 2];
+
     /// Child of the matrix:
     /// * row if row-major,
     /// * column if col-major.
@@ -42,6 +48,7 @@ pub trait V1MatrixLayout: Clone {
 
 /// Row major layout.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct V1LayoutRowMajor {
     num_rows: usize,
     num_cols: usize,
@@ -79,6 +86,11 @@ impl V1MatrixLayout for V1LayoutRowMajor {
         self.num_cols * i + j
     }
 
+    #[inline(always)]
+    fn matrix_idx(&self, v1_idx: usize) -> [usize; 2] {
+        [v1_idx / self.num_cols, v1_idx % self.num_cols]
+    }
+
     fn child<T, V>(&self, data: V, first_idx: usize) -> impl NVec<D1, T>
     where
         V: NVec<D1, T>,
@@ -98,6 +110,7 @@ impl V1MatrixLayout for V1LayoutRowMajor {
 
 /// Column major layout.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct V1LayoutColMajor {
     num_rows: usize,
     num_cols: usize,
@@ -135,6 +148,11 @@ impl V1MatrixLayout for V1LayoutColMajor {
         self.num_rows * j + i
     }
 
+    #[inline(always)]
+    fn matrix_idx(&self, v1_idx: usize) -> [usize; 2] {
+        [v1_idx % self.num_rows, v1_idx / self.num_rows]
+    }
+
     fn child<T, V>(&self, data: V, first_idx: usize) -> impl NVec<D1, T>
     where
         V: NVec<D1, T>,
@@ -1,10 +1,15 @@
 use super::layout::{V1LayoutColMajor, V1LayoutRowMajor, V1MatrixLayout};
+use crate::common_trait_helpers::all_mut::panic_out_of_bounds;
+use crate::common_trait_helpers::split_mut::split_at_ends_mut;
 use crate::{
     matrices::{
-        Matrix, MatrixColMajor, MatrixColMajorMut, MatrixMut, MatrixRowMajor, MatrixRowMajorMut,
+        Matrix, MatrixAllMut, MatrixColMajor, MatrixColMajorMut, MatrixMut, MatrixRowMajor,
+        MatrixRowMajorMut,
     },
-    IntoIdx, NVec, NVecMut, D1, D2,
+    IntoIdx, NVec, NVecAllMut, NVecMut, D1, D2,
 };
+use alloc::vec;
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 
 /// A row-major matrix represented by a flat one-dimensional vector `V1`.
@@ -19,6 +24,11 @@ pub type V1MatrixColMajor<T, V> = V1Matrix<T, V, V1LayoutColMajor>;
 
 /// A matrix represented by a flat one-dimensional vector `V1`.
 #[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "V1MatrixFields<V, L>")
+)]
 pub struct V1Matrix<T, V, L>
 where
     V: NVec<D1, T>,
@@ -26,9 +36,37 @@ where
 {
     layout: L,
     data: V,
+    #[cfg_attr(feature = "serde", serde(skip))]
     phantom: PhantomData<T>,
 }
 
+/// Fields of a [`V1Matrix`] which are deserialized and validated before the matrix
+/// is created.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct V1MatrixFields<V, L> {
+    layout: L,
+    data: V,
+}
+
+#[cfg(feature = "serde")]
+impl<T, V, L> TryFrom<V1MatrixFields<V, L>> for V1Matrix<T, V, L>
+where
+    V: NVec<D1, T>,
+    L: V1MatrixLayout,
+{
+    type Error = &'static str;
+
+    fn try_from(fields: V1MatrixFields<V, L>) -> Result<Self, Self::Error> {
+        let V1MatrixFields { layout, data } = fields;
+        let len = layout.num_rows().checked_mul(layout.num_cols());
+        match len == Some(data.card([])) {
+            true => Ok(Self::new(layout, data)),
+            false => Err("number of elements of the V1Matrix data must be num_rows * num_cols"),
+        }
+    }
+}
+
 impl<T, V, L> V1Matrix<T, V, L>
 where
     V: NVec<D1, T>,
@@ -70,6 +108,14 @@ where
         self.data.at(idx)
     }
 
+    #[inline(always)]
+    unsafe fn at_unchecked(&self, idx: impl IntoIdx<D2>) -> T {
+        debug_assert!(self.in_bounds(idx.into_idx()), "index is out of bounds");
+        let [i, j] = idx.into_idx();
+        let idx = self.layout.v1_idx(i, j);
+        unsafe { self.data.at_unchecked(idx) }
+    }
+
     fn all(&self) -> impl Iterator<Item = T> {
         self.data.all()
     }
@@ -86,6 +132,13 @@ where
         self.data.at_mut(idx)
     }
 
+    unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<D2>) -> &mut T {
+        debug_assert!(self.in_bounds(idx.into_idx()), "index is out of bounds");
+        let [i, j] = idx.into_idx();
+        let idx = self.layout.v1_idx(i, j);
+        unsafe { self.data.at_mut_unchecked(idx) }
+    }
+
     fn mut_all<F>(&mut self, f: F)
     where
         F: FnMut(&mut T),
@@ -101,6 +154,53 @@ where
     }
 }
 
+impl<T, V, L> MatrixAllMut<T> for V1Matrix<T, V, L>
+where
+    V: NVecAllMut<D1, T>,
+    L: V1MatrixLayout,
+{
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        self.data.all_mut()
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = ([usize; 2], &'a mut T)>
+    where
+        T: 'a,
+    {
+        let (num_rows, num_cols) = (self.layout.num_rows(), self.layout.num_cols());
+        let mut indices = vec![[0, 0]; num_rows * num_cols];
+        for i in 0..num_rows {
+            for j in 0..num_cols {
+                indices[self.layout.v1_idx(i, j)] = [i, j];
+            }
+        }
+        indices.into_iter().zip(self.data.all_mut())
+    }
+
+    fn all_mut_in<'a>(
+        &'a mut self,
+        indices: impl Iterator<Item = impl IntoIdx<D2>>,
+    ) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        let layout = &self.layout;
+        let indices: Vec<_> = indices
+            .map(|idx| {
+                let [i, j] = idx.into_idx();
+                if i >= layout.num_rows() || j >= layout.num_cols() {
+                    panic_out_of_bounds([i, j]);
+                }
+                [layout.v1_idx(i, j)]
+            })
+            .collect();
+        self.data.all_mut_in(indices.into_iter())
+    }
+}
+
 impl<T, V> MatrixRowMajor<T> for V1Matrix<T, V, V1LayoutRowMajor>
 where
     V: NVec<D1, T>,
@@ -136,3 +236,185 @@ where
         self.layout.child_mut(&mut self.data, i)
     }
 }
+
+// par
+
+#[cfg(feature = "rayon")]
+impl<T, V, L> crate::matrices::MatrixPar<T> for V1Matrix<T, V, L>
+where
+    V: crate::NVecPar<D1, T>,
+    L: V1MatrixLayout + Sync,
+    T: Send + Sync,
+{
+    fn par_all(&self) -> impl rayon::iter::ParallelIterator<Item = T> {
+        self.data.par_all()
+    }
+
+    fn par_enumerate_all(&self) -> impl rayon::iter::ParallelIterator<Item = ([usize; 2], T)> {
+        use rayon::iter::ParallelIterator;
+        self.data
+            .par_enumerate_all()
+            .map(move |([k], x)| (self.layout.matrix_idx(k), x))
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T, V, L> crate::matrices::MatrixParMut<T> for V1Matrix<T, V, L>
+where
+    V: crate::NVecParMut<D1, T>,
+    L: V1MatrixLayout,
+    T: Send,
+{
+    fn par_mut_all<F>(&mut self, f: F)
+    where
+        F: Fn(&mut T) + Sync + Send,
+    {
+        self.data.par_mut_all(f);
+    }
+}
+
+// split
+
+impl<T, V> V1Matrix<T, V, V1LayoutRowMajor>
+where
+    V: NVecMut<D1, T> + AsMut<[T]>,
+    T: Copy,
+{
+    /// Returns an iterator of all rows of the row-major matrix as disjoint mutable
+    /// slices of the underlying data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v1 = vec![1, 2, 3, 4, 5, 6];
+    /// let mut mat = v1.v1_as_matrix_mut(2, 3);
+    ///
+    /// for (i, row) in mat.rows_mut().enumerate() {
+    ///     row.iter_mut().for_each(|x| *x *= 10 * (i + 1));
+    /// }
+    /// assert_eq!(v1, vec![10, 20, 30, 80, 100, 120]);
+    /// ```
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let num_cols = self.layout.num_cols();
+        let ends = (1..=self.layout.num_rows()).map(move |i| i * num_cols);
+        split_at_ends_mut(self.data.as_mut(), ends)
+    }
+
+    /// Splits the row-major matrix into two disjoint mutable matrices such that:
+    /// * the first one contains rows with indices in `0..i`, and
+    /// * the second one contains rows with indices in `i..num_rows`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is greater than the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v1 = vec![1, 2, 3, 4, 5, 6];
+    /// let mut mat = v1.v1_as_matrix_mut(3, 2);
+    ///
+    /// let (mut first, mut second) = mat.split_rows_mut(1);
+    /// assert_eq!(first.num_rows(), 1);
+    /// assert_eq!(second.num_rows(), 2);
+    ///
+    /// std::thread::scope(|s| {
+    ///     s.spawn(move || first.mut_all(|x| *x += 10));
+    ///     s.spawn(move || second.mut_all(|x| *x += 20));
+    /// });
+    /// assert_eq!(v1, vec![11, 12, 23, 24, 25, 26]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn split_rows_mut(
+        &mut self,
+        i: usize,
+    ) -> (V1MatrixRowMajor<T, &mut [T]>, V1MatrixRowMajor<T, &mut [T]>) {
+        let (num_rows, num_cols) = (self.layout.num_rows(), self.layout.num_cols());
+        assert!(
+            i <= num_rows,
+            "Matrix cannot be split at row {}: matrix has {} rows",
+            i,
+            num_rows
+        );
+        let (first, second) = self.data.as_mut().split_at_mut(i * num_cols);
+        (
+            V1Matrix::new(V1LayoutRowMajor::new(i, num_cols), first),
+            V1Matrix::new(V1LayoutRowMajor::new(num_rows - i, num_cols), second),
+        )
+    }
+}
+
+impl<T, V> V1Matrix<T, V, V1LayoutColMajor>
+where
+    V: NVecMut<D1, T> + AsMut<[T]>,
+    T: Copy,
+{
+    /// Returns an iterator of all columns of the col-major matrix as disjoint mutable
+    /// slices of the underlying data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v1 = vec![1, 2, 3, 4, 5, 6];
+    /// let mut mat = v1.v1_as_matrix_col_major_mut(2, 3);
+    ///
+    /// for (j, col) in mat.cols_mut().enumerate() {
+    ///     col.iter_mut().for_each(|x| *x *= 10 * (j + 1));
+    /// }
+    /// assert_eq!(v1, vec![10, 20, 60, 80, 150, 180]);
+    /// ```
+    pub fn cols_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let num_rows = self.layout.num_rows();
+        let ends = (1..=self.layout.num_cols()).map(move |j| j * num_rows);
+        split_at_ends_mut(self.data.as_mut(), ends)
+    }
+
+    /// Splits the col-major matrix into two disjoint mutable matrices such that:
+    /// * the first one contains columns with indices in `0..j`, and
+    /// * the second one contains columns with indices in `j..num_cols`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `j` is greater than the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v1 = vec![1, 2, 3, 4, 5, 6];
+    /// let mut mat = v1.v1_as_matrix_col_major_mut(2, 3);
+    ///
+    /// let (mut first, mut second) = mat.split_cols_mut(2);
+    /// assert_eq!(first.num_cols(), 2);
+    /// assert_eq!(second.num_cols(), 1);
+    ///
+    /// first.col_mut(1).set(0, 42);
+    /// second.mut_all(|x| *x = 0);
+    /// assert_eq!(v1, vec![1, 2, 42, 4, 0, 0]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn split_cols_mut(
+        &mut self,
+        j: usize,
+    ) -> (V1MatrixColMajor<T, &mut [T]>, V1MatrixColMajor<T, &mut [T]>) {
+        let (num_rows, num_cols) = (self.layout.num_rows(), self.layout.num_cols());
+        assert!(
+            j <= num_cols,
+            "Matrix cannot be split at column {}: matrix has {} columns",
+            j,
+            num_cols
+        );
+        let (first, second) = self.data.as_mut().split_at_mut(j * num_rows);
+        (
+            V1Matrix::new(V1LayoutColMajor::new(num_rows, j), first),
+            V1Matrix::new(V1LayoutColMajor::new(num_rows, num_cols - j), second),
+        )
+    }
+}
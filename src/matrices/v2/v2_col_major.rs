@@ -1,5 +1,5 @@
-use super::super::{matrix::Matrix, MatrixColMajor, MatrixColMajorMut};
-use crate::{matrices::MatrixMut, IntoIdx, NVec, NVecMut, D1, D2};
+use super::super::{matrix::Matrix, MatrixAllMut, MatrixColMajor, MatrixColMajorMut};
+use crate::{matrices::MatrixMut, IntoIdx, NVec, NVecAllMut, NVecMut, D1, D2};
 use core::marker::PhantomData;
 
 /// A column major matrix.
@@ -52,6 +52,12 @@ where
         self.data.at([j, i])
     }
 
+    #[inline(always)]
+    unsafe fn at_unchecked(&self, idx: impl IntoIdx<D2>) -> T {
+        let [i, j] = idx.into_idx();
+        unsafe { self.data.at_unchecked([j, i]) }
+    }
+
     fn all(&self) -> impl Iterator<Item = T> {
         self.data.all()
     }
@@ -67,6 +73,12 @@ where
         self.data.at_mut([j, i])
     }
 
+    #[inline(always)]
+    unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<D2>) -> &mut T {
+        let [i, j] = idx.into_idx();
+        unsafe { self.data.at_mut_unchecked([j, i]) }
+    }
+
     fn mut_all<F>(&mut self, f: F)
     where
         F: FnMut(&mut T),
@@ -82,6 +94,38 @@ where
     }
 }
 
+impl<T, V> MatrixAllMut<T> for V2MatrixColMajor<T, V>
+where
+    V: NVecAllMut<D2, T>,
+{
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        self.data.all_mut()
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = ([usize; 2], &'a mut T)>
+    where
+        T: 'a,
+    {
+        self.data.enumerate_all_mut().map(|([j, i], x)| ([i, j], x))
+    }
+
+    fn all_mut_in<'a>(
+        &'a mut self,
+        indices: impl Iterator<Item = impl IntoIdx<D2>>,
+    ) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        self.data.all_mut_in(indices.map(|idx| {
+            let [i, j] = idx.into_idx();
+            [j, i]
+        }))
+    }
+}
+
 impl<T, V> MatrixColMajor<T> for V2MatrixColMajor<T, V>
 where
     V: NVec<D2, T>,
@@ -99,3 +143,35 @@ where
         self.data.child_mut(i)
     }
 }
+
+// par
+
+#[cfg(feature = "rayon")]
+impl<T, V> crate::matrices::MatrixPar<T> for V2MatrixColMajor<T, V>
+where
+    V: crate::NVecPar<D2, T>,
+    T: Send + Sync,
+{
+    fn par_all(&self) -> impl rayon::iter::ParallelIterator<Item = T> {
+        self.data.par_all()
+    }
+
+    fn par_enumerate_all(&self) -> impl rayon::iter::ParallelIterator<Item = ([usize; 2], T)> {
+        use rayon::iter::ParallelIterator;
+        self.data.par_enumerate_all().map(|([j, i], x)| ([i, j], x))
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T, V> crate::matrices::MatrixParMut<T> for V2MatrixColMajor<T, V>
+where
+    V: crate::NVecParMut<D2, T>,
+    T: Send,
+{
+    fn par_mut_all<F>(&mut self, f: F)
+    where
+        F: Fn(&mut T) + Sync + Send,
+    {
+        self.data.par_mut_all(f);
+    }
+}
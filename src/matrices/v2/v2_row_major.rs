@@ -2,7 +2,10 @@ use super::super::{
     matrix::Matrix,
     matrix_row_major::{MatrixRowMajor, MatrixRowMajorMut},
 };
-use crate::{matrices::MatrixMut, IntoIdx, NVec, NVecMut, D1, D2};
+use crate::{
+    matrices::{MatrixAllMut, MatrixMut},
+    IntoIdx, NVec, NVecAllMut, NVecMut, D1, D2,
+};
 use core::marker::PhantomData;
 
 /// A row major matrix.
@@ -54,6 +57,11 @@ where
         self.data.at(idx)
     }
 
+    #[inline(always)]
+    unsafe fn at_unchecked(&self, idx: impl IntoIdx<D2>) -> T {
+        unsafe { self.data.at_unchecked(idx) }
+    }
+
     fn all(&self) -> impl Iterator<Item = T> {
         self.data.all()
     }
@@ -68,6 +76,11 @@ where
         self.data.at_mut(idx)
     }
 
+    #[inline(always)]
+    unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<D2>) -> &mut T {
+        unsafe { self.data.at_mut_unchecked(idx) }
+    }
+
     fn mut_all<F>(&mut self, f: F)
     where
         F: FnMut(&mut T),
@@ -83,6 +96,35 @@ where
     }
 }
 
+impl<T, V> MatrixAllMut<T> for V2MatrixRowMajor<T, V>
+where
+    V: NVecAllMut<D2, T>,
+{
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        self.data.all_mut()
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = ([usize; 2], &'a mut T)>
+    where
+        T: 'a,
+    {
+        self.data.enumerate_all_mut()
+    }
+
+    fn all_mut_in<'a>(
+        &'a mut self,
+        indices: impl Iterator<Item = impl IntoIdx<D2>>,
+    ) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        self.data.all_mut_in(indices)
+    }
+}
+
 impl<T, V> MatrixRowMajor<T> for V2MatrixRowMajor<T, V>
 where
     V: NVec<D2, T>,
@@ -100,3 +142,34 @@ where
         self.data.child_mut(i)
     }
 }
+
+// par
+
+#[cfg(feature = "rayon")]
+impl<T, V> crate::matrices::MatrixPar<T> for V2MatrixRowMajor<T, V>
+where
+    V: crate::NVecPar<D2, T>,
+    T: Send + Sync,
+{
+    fn par_all(&self) -> impl rayon::iter::ParallelIterator<Item = T> {
+        self.data.par_all()
+    }
+
+    fn par_enumerate_all(&self) -> impl rayon::iter::ParallelIterator<Item = ([usize; 2], T)> {
+        self.data.par_enumerate_all()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T, V> crate::matrices::MatrixParMut<T> for V2MatrixRowMajor<T, V>
+where
+    V: crate::NVecParMut<D2, T>,
+    T: Send,
+{
+    fn par_mut_all<F>(&mut self, f: F)
+    where
+        F: Fn(&mut T) + Sync + Send,
+    {
+        self.data.par_mut_all(f);
+    }
+}
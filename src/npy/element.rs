@@ -0,0 +1,86 @@
+use alloc::vec::Vec;
+
+/// An element type which can be read from and written to `.npy` files.
+///
+/// It is implemented for the standard numeric dtypes:
+///
+/// | Rust  | NumPy dtype | descr |
+/// |-------|-------------|-------|
+/// | `i8`  | `int8`      | `\|i1` |
+/// | `i16` | `int16`     | `<i2` |
+/// | `i32` | `int32`     | `<i4` |
+/// | `i64` | `int64`     | `<i8` |
+/// | `u8`  | `uint8`     | `\|u1` |
+/// | `u16` | `uint16`    | `<u2` |
+/// | `u32` | `uint32`    | `<u4` |
+/// | `u64` | `uint64`    | `<u8` |
+/// | `f32` | `float32`   | `<f4` |
+/// | `f64` | `float64`   | `<f8` |
+///
+/// Arrays are always written in little-endian byte order; both little and big-endian
+/// arrays can be read.
+pub trait NpyElement: Copy {
+    /// Descriptor of the dtype of the element which is written to the header of the
+    /// `.npy` file, such as `"<f8"`; composed of the byte order, kind and number of bytes.
+    const DESCR: &'static str;
+
+    /// Number of bytes of the element.
+    const NUM_BYTES: usize;
+
+    /// Creates the element from its little-endian representation.
+    ///
+    /// Length of `bytes` must be equal to [`NUM_BYTES`].
+    ///
+    /// [`NUM_BYTES`]: crate::npy::NpyElement::NUM_BYTES
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+
+    /// Creates the element from its big-endian representation.
+    ///
+    /// Length of `bytes` must be equal to [`NUM_BYTES`].
+    ///
+    /// [`NUM_BYTES`]: crate::npy::NpyElement::NUM_BYTES
+    fn from_be_bytes(bytes: &[u8]) -> Self;
+
+    /// Appends the little-endian representation of the element to the `buffer`.
+    fn extend_le_bytes(self, buffer: &mut Vec<u8>);
+}
+
+macro_rules! impl_npy_element {
+    ($t:ty, $descr:literal) => {
+        impl NpyElement for $t {
+            const DESCR: &'static str = $descr;
+
+            const NUM_BYTES: usize = core::mem::size_of::<$t>();
+
+            #[inline(always)]
+            fn from_le_bytes(bytes: &[u8]) -> Self {
+                let mut array = [0u8; core::mem::size_of::<$t>()];
+                array.copy_from_slice(bytes);
+                <$t>::from_le_bytes(array)
+            }
+
+            #[inline(always)]
+            fn from_be_bytes(bytes: &[u8]) -> Self {
+                let mut array = [0u8; core::mem::size_of::<$t>()];
+                array.copy_from_slice(bytes);
+                <$t>::from_be_bytes(array)
+            }
+
+            #[inline(always)]
+            fn extend_le_bytes(self, buffer: &mut Vec<u8>) {
+                buffer.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    };
+}
+
+impl_npy_element!(i8, "|i1");
+impl_npy_element!(i16, "<i2");
+impl_npy_element!(i32, "<i4");
+impl_npy_element!(i64, "<i8");
+impl_npy_element!(u8, "|u1");
+impl_npy_element!(u16, "<u2");
+impl_npy_element!(u32, "<u4");
+impl_npy_element!(u64, "<u8");
+impl_npy_element!(f32, "<f4");
+impl_npy_element!(f64, "<f8");
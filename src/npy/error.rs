@@ -0,0 +1,82 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+/// Error returned while reading or writing `.npy` files.
+#[derive(Debug)]
+pub enum NpyError {
+    /// An error raised by the underlying reader or writer; including the case
+    /// where the file ends before all elements of the array are read.
+    Io(std::io::Error),
+    /// The input is not a valid `.npy` file; such as when the magic string is
+    /// missing, the format version is unknown or the header cannot be parsed.
+    InvalidFormat(String),
+    /// The array is stored with a dtype which is not supported, such as a structured,
+    /// complex or string dtype.
+    UnsupportedDtype(String),
+    /// Dtype of the array does not match the element type that is requested.
+    DtypeMismatch {
+        /// Descriptor of the requested element type, such as `"<f8"`.
+        expected: &'static str,
+        /// Descriptor of the array in the file, such as `"<i4"`.
+        found: String,
+    },
+    /// Number of dimensions of the array does not match the dimension that is requested;
+    /// for instance, a three-dimensional array is read as a matrix.
+    ShapeMismatch {
+        /// Requested number of dimensions.
+        expected_dimension: usize,
+        /// Shape of the array in the file.
+        found_shape: Vec<usize>,
+    },
+    /// The vector to be written, or one of its children, is unbounded.
+    Unbounded,
+    /// The vector to be written is jagged; while `.npy` files can only store
+    /// rectangular arrays.
+    NotRectangular,
+}
+
+impl Display for NpyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "I/O error while reading or writing npy: {}", e),
+            Self::InvalidFormat(message) => write!(f, "Invalid npy format: {}", message),
+            Self::UnsupportedDtype(descr) => {
+                write!(f, "Unsupported npy dtype with descriptor {}", descr)
+            }
+            Self::DtypeMismatch { expected, found } => write!(
+                f,
+                "Dtype of the npy array {} does not match the requested element type {}",
+                found, expected
+            ),
+            Self::ShapeMismatch {
+                expected_dimension,
+                found_shape,
+            } => write!(
+                f,
+                "Npy array with shape {:?} cannot be read as a vector of dimension {}",
+                found_shape, expected_dimension
+            ),
+            Self::Unbounded => write!(
+                f,
+                "Cannot write an unbounded vector to npy; consider setting its bounds by `bounded`, `with_rectangular_bounds` or `with_variable_bounds`"
+            ),
+            Self::NotRectangular => write!(f, "Cannot write a jagged vector to npy"),
+        }
+    }
+}
+
+impl std::error::Error for NpyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for NpyError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
@@ -0,0 +1,246 @@
+use super::NpyError;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use std::io::{Read, Write};
+
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+
+/// Alignment of the total length of the preamble and header, required by the format
+/// so that the array data can be memory mapped.
+const ALIGNMENT: usize = 64;
+
+/// Header of an `.npy` file describing the array that follows.
+#[derive(Debug)]
+pub(super) struct Header {
+    pub descr: String,
+    pub fortran_order: bool,
+    pub shape: Vec<usize>,
+}
+
+impl Header {
+    /// Reads the magic string, format version and header of an `.npy` file, leaving the
+    /// `reader` at the beginning of the array data.
+    pub fn read(reader: &mut impl Read) -> Result<Self, NpyError> {
+        let mut preamble = [0u8; 8];
+        reader.read_exact(&mut preamble)?;
+        if &preamble[..6] != MAGIC {
+            return Err(invalid("missing the magic string \\x93NUMPY"));
+        }
+
+        let header_len = match preamble[6] {
+            1 => {
+                let mut len = [0u8; 2];
+                reader.read_exact(&mut len)?;
+                u16::from_le_bytes(len) as usize
+            }
+            2 | 3 => {
+                let mut len = [0u8; 4];
+                reader.read_exact(&mut len)?;
+                u32::from_le_bytes(len) as usize
+            }
+            major => {
+                return Err(NpyError::InvalidFormat(format!(
+                    "unsupported format version {}.{}",
+                    major, preamble[7]
+                )))
+            }
+        };
+
+        let mut bytes = Vec::new();
+        reader.take(header_len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() < header_len {
+            return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+        }
+        let text = core::str::from_utf8(&bytes).map_err(|_| invalid("header is not a string"))?;
+        Self::parse(text)
+    }
+
+    /// Parses the header which is a python dictionary literal such as
+    /// `{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }`.
+    fn parse(text: &str) -> Result<Self, NpyError> {
+        let mut cursor = Cursor { text };
+        let (mut descr, mut fortran_order, mut shape) = (None, None, None);
+
+        cursor.expect('{')?;
+        while !cursor.eat('}') {
+            match cursor.string()? {
+                "descr" => {
+                    cursor.expect(':')?;
+                    descr = Some(cursor.descr()?);
+                }
+                "fortran_order" => {
+                    cursor.expect(':')?;
+                    fortran_order = Some(cursor.bool()?);
+                }
+                "shape" => {
+                    cursor.expect(':')?;
+                    shape = Some(cursor.tuple()?);
+                }
+                key => return Err(NpyError::InvalidFormat(format!("unknown key '{}'", key))),
+            }
+            if !cursor.eat(',') {
+                cursor.expect('}')?;
+                break;
+            }
+        }
+
+        match (descr, fortran_order, shape) {
+            (Some(descr), Some(fortran_order), Some(shape)) => Ok(Self {
+                descr,
+                fortran_order,
+                shape,
+            }),
+            _ => Err(invalid(
+                "header must contain the keys 'descr', 'fortran_order' and 'shape'",
+            )),
+        }
+    }
+
+    /// Writes the magic string, format version and header of an `.npy` file storing an
+    /// array in C order.
+    pub fn write(writer: &mut impl Write, descr: &str, shape: &[usize]) -> std::io::Result<()> {
+        let shape = match shape {
+            [n] => format!("({},)", n),
+            _ => {
+                let lengths: Vec<_> = shape.iter().map(|n| n.to_string()).collect();
+                format!("({})", lengths.join(", "))
+            }
+        };
+        let dict = format!(
+            "{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}",
+            descr, shape
+        );
+
+        // version 1.0 stores the header length in 2 bytes, and version 2.0 in 4 bytes
+        let padded_len = |preamble_len: usize| {
+            let unpadded_len = preamble_len + dict.len() + 1;
+            unpadded_len.div_ceil(ALIGNMENT) * ALIGNMENT - preamble_len
+        };
+        let (major, preamble_len) = match padded_len(10) <= u16::MAX as usize {
+            true => (1, 10),
+            false => (2, 12),
+        };
+        let header_len = padded_len(preamble_len);
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[major, 0])?;
+        match major {
+            1 => writer.write_all(&(header_len as u16).to_le_bytes())?,
+            _ => {
+                let len = u32::try_from(header_len)
+                    .map_err(|_| std::io::Error::other("npy header is too long"))?;
+                writer.write_all(&len.to_le_bytes())?;
+            }
+        }
+        writer.write_all(dict.as_bytes())?;
+        writer.write_all(&vec![b' '; header_len - dict.len() - 1])?;
+        writer.write_all(b"\n")
+    }
+}
+
+fn invalid(message: &str) -> NpyError {
+    NpyError::InvalidFormat(message.to_string())
+}
+
+/// A minimal parser of the python literals that appear in the header.
+struct Cursor<'a> {
+    text: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    fn eat(&mut self, c: char) -> bool {
+        self.text = self.text.trim_start();
+        match self.text.strip_prefix(c) {
+            Some(rest) => {
+                self.text = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), NpyError> {
+        match self.eat(c) {
+            true => Ok(()),
+            false => Err(NpyError::InvalidFormat(format!(
+                "expected '{}' in the header",
+                c
+            ))),
+        }
+    }
+
+    fn string(&mut self) -> Result<&'a str, NpyError> {
+        self.text = self.text.trim_start();
+        let quote = match self.text.chars().next() {
+            Some(c @ ('\'' | '"')) => c,
+            _ => return Err(invalid("expected a string in the header")),
+        };
+        let rest = &self.text[1..];
+        let end = rest
+            .find(quote)
+            .ok_or_else(|| invalid("unterminated string in the header"))?;
+        self.text = &rest[end + 1..];
+        Ok(&rest[..end])
+    }
+
+    fn descr(&mut self) -> Result<String, NpyError> {
+        self.text = self.text.trim_start();
+        match self.text.starts_with('[') {
+            // structured dtypes are represented as lists of fields
+            true => {
+                let mut depth = 0;
+                let end = self.text.find(|c| {
+                    match c {
+                        '[' => depth += 1,
+                        ']' => depth -= 1,
+                        _ => {}
+                    }
+                    depth == 0
+                });
+                let descr = match end {
+                    Some(end) => &self.text[..=end],
+                    None => self.text,
+                };
+                Err(NpyError::UnsupportedDtype(descr.to_string()))
+            }
+            false => self.string().map(|x| x.to_string()),
+        }
+    }
+
+    fn bool(&mut self) -> Result<bool, NpyError> {
+        self.text = self.text.trim_start();
+        if let Some(rest) = self.text.strip_prefix("True") {
+            self.text = rest;
+            Ok(true)
+        } else if let Some(rest) = self.text.strip_prefix("False") {
+            self.text = rest;
+            Ok(false)
+        } else {
+            Err(invalid("expected True or False in the header"))
+        }
+    }
+
+    fn tuple(&mut self) -> Result<Vec<usize>, NpyError> {
+        let mut values = vec![];
+        self.expect('(')?;
+        while !self.eat(')') {
+            self.text = self.text.trim_start();
+            let end = self
+                .text
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(self.text.len());
+            let value = self.text[..end]
+                .parse()
+                .map_err(|_| invalid("expected a non-negative integer in the shape"))?;
+            values.push(value);
+            self.text = &self.text[end..];
+            if !self.eat(',') {
+                self.expect(')')?;
+                break;
+            }
+        }
+        Ok(values)
+    }
+}
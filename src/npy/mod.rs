@@ -0,0 +1,12 @@
+mod element;
+mod error;
+mod header;
+mod npy_array;
+mod read;
+mod write;
+
+pub use element::NpyElement;
+pub use error::NpyError;
+pub use npy_array::{NpyMatrix, NpyTensor};
+pub use read::{read_d3, read_d4, read_matrix};
+pub use write::{write, write_matrix};
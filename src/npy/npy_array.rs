@@ -0,0 +1,93 @@
+use super::NpyElement;
+use crate::matrices::{V1MatrixColMajor, V1MatrixRowMajor};
+use crate::{
+    Dim, V1TensorColMajor, V1TensorLayout, V1TensorLayoutColMajor, V1TensorLayoutRowMajor,
+    V1TensorRowMajor,
+};
+use alloc::vec::Vec;
+
+/// A matrix read from an `.npy` file, which is stored in a flat vector with the layout
+/// of the file:
+/// * [`RowMajor`] if the array is stored in C order,
+/// * [`ColMajor`] if the array is stored in Fortran order (`fortran_order: True`).
+///
+/// Since the layout is known only at runtime, the matrix is to be matched to
+/// access the underlying [`V1Matrix`]. Both variants implement [`Matrix`]; hence,
+/// generic algorithms can be called in both arms.
+///
+/// [`RowMajor`]: crate::npy::NpyMatrix::RowMajor
+/// [`ColMajor`]: crate::npy::NpyMatrix::ColMajor
+/// [`V1Matrix`]: crate::matrices::V1Matrix
+/// [`Matrix`]: crate::Matrix
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+/// use orx_v::npy::*;
+///
+/// fn trace(mat: &impl Matrix<f64>) -> f64 {
+///     (0..mat.num_rows()).map(|i| mat.at([i, i])).sum()
+/// }
+///
+/// let v1 = vec![1.0, 2.0, 3.0, 4.0];
+/// let mut bytes = vec![];
+/// write_matrix(&mut bytes, &v1.v1_as_matrix(2, 2)).unwrap();
+///
+/// let tr = match read_matrix(bytes.as_slice()).unwrap() {
+///     NpyMatrix::RowMajor(mat) => trace(&mat),
+///     NpyMatrix::ColMajor(mat) => trace(&mat),
+/// };
+/// assert_eq!(tr, 5.0);
+/// ```
+#[derive(Clone)]
+pub enum NpyMatrix<T: NpyElement> {
+    /// A row-major matrix read from an array stored in C order.
+    RowMajor(V1MatrixRowMajor<T, Vec<T>>),
+    /// A col-major matrix read from an array stored in Fortran order.
+    ColMajor(V1MatrixColMajor<T, Vec<T>>),
+}
+
+/// A tensor of dimension `D` read from an `.npy` file, which is stored in a flat vector
+/// with the layout of the file:
+/// * [`RowMajor`] if the array is stored in C order,
+/// * [`ColMajor`] if the array is stored in Fortran order (`fortran_order: True`).
+///
+/// Since the layout is known only at runtime, the tensor is to be matched to
+/// access the underlying [`V1Tensor`]. Both variants implement [`NVec<D, T>`];
+/// hence, generic algorithms can be called in both arms.
+///
+/// [`RowMajor`]: crate::npy::NpyTensor::RowMajor
+/// [`ColMajor`]: crate::npy::NpyTensor::ColMajor
+/// [`V1Tensor`]: crate::V1Tensor
+/// [`NVec<D, T>`]: crate::NVec
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+/// use orx_v::npy::*;
+///
+/// let v3 = vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]];
+/// let mut bytes = vec![];
+/// write(&mut bytes, &v3).unwrap();
+///
+/// let sum = match read_d3::<i32>(bytes.as_slice()).unwrap() {
+///     NpyTensor::RowMajor(tensor) => tensor.all().sum::<i32>(),
+///     NpyTensor::ColMajor(tensor) => tensor.all().sum::<i32>(),
+/// };
+/// assert_eq!(sum, 36);
+/// ```
+#[derive(Clone)]
+pub enum NpyTensor<D, T>
+where
+    D: Dim,
+    T: NpyElement,
+    V1TensorLayoutRowMajor<D>: V1TensorLayout<D>,
+    V1TensorLayoutColMajor<D>: V1TensorLayout<D>,
+{
+    /// A row-major tensor read from an array stored in C order.
+    RowMajor(V1TensorRowMajor<D, T, Vec<T>>),
+    /// A col-major tensor read from an array stored in Fortran order.
+    ColMajor(V1TensorColMajor<D, T, Vec<T>>),
+}
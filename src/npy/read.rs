@@ -0,0 +1,175 @@
+use super::header::Header;
+use super::{NpyElement, NpyError, NpyMatrix, NpyTensor};
+use crate::{V1AsMatrix, V1AsTensor, D3, D4};
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use std::io::Read;
+
+const SUPPORTED_TYPES: [&str; 10] = ["i1", "i2", "i4", "i8", "u1", "u2", "u4", "u8", "f4", "f8"];
+
+/// Reads a two-dimensional array from an `.npy` file into a matrix.
+///
+/// The matrix is row-major if the array is stored in C order, and col-major if it is
+/// stored in Fortran order; see [`NpyMatrix`].
+///
+/// [`NpyMatrix`]: crate::npy::NpyMatrix
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * the `reader` fails or ends before the entire array is read,
+/// * the input is not a valid `.npy` file,
+/// * dtype of the array is not that of `T`, or
+/// * the array is not two-dimensional.
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+/// use orx_v::npy::*;
+///
+/// let v1: Vec<u16> = (0..6).collect();
+/// let mut bytes = vec![];
+/// write_matrix(&mut bytes, &v1.v1_as_matrix(2, 3)).unwrap();
+///
+/// match read_matrix::<u16>(bytes.as_slice()).unwrap() {
+///     NpyMatrix::RowMajor(mat) => {
+///         assert_eq!((mat.num_rows(), mat.num_cols()), (2, 3));
+///         assert_eq!(mat.at([1, 0]), 3);
+///     }
+///     NpyMatrix::ColMajor(_) => unreachable!("written in C order"),
+/// }
+///
+/// // dtype and shape are validated
+/// let result = read_matrix::<f32>(bytes.as_slice());
+/// assert!(matches!(result, Err(NpyError::DtypeMismatch { .. })));
+///
+/// let result = read_d3::<u16>(bytes.as_slice());
+/// assert!(matches!(result, Err(NpyError::ShapeMismatch { .. })));
+/// ```
+pub fn read_matrix<T: NpyElement>(mut reader: impl Read) -> Result<NpyMatrix<T>, NpyError> {
+    let (header, data) = read_array::<T>(&mut reader, 2)?;
+    let (num_rows, num_cols) = (header.shape[0], header.shape[1]);
+    Ok(match header.fortran_order {
+        false => NpyMatrix::RowMajor(data.v1_into_matrix(num_rows, num_cols)),
+        true => NpyMatrix::ColMajor(data.v1_into_matrix_col_major(num_rows, num_cols)),
+    })
+}
+
+/// Reads a three-dimensional array from an `.npy` file into a `D3` tensor.
+///
+/// The tensor is row-major if the array is stored in C order, and col-major if it is
+/// stored in Fortran order; see [`NpyTensor`].
+///
+/// [`NpyTensor`]: crate::npy::NpyTensor
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * the `reader` fails or ends before the entire array is read,
+/// * the input is not a valid `.npy` file,
+/// * dtype of the array is not that of `T`, or
+/// * the array is not three-dimensional.
+pub fn read_d3<T: NpyElement>(mut reader: impl Read) -> Result<NpyTensor<D3, T>, NpyError> {
+    let (header, data) = read_array::<T>(&mut reader, 3)?;
+    let shape = [header.shape[0], header.shape[1], header.shape[2]];
+    Ok(match header.fortran_order {
+        false => NpyTensor::RowMajor(data.v1_into_d3(shape)),
+        true => NpyTensor::ColMajor(data.v1_into_d3_col_major(shape)),
+    })
+}
+
+/// Reads a four-dimensional array from an `.npy` file into a `D4` tensor.
+///
+/// The tensor is row-major if the array is stored in C order, and col-major if it is
+/// stored in Fortran order; see [`NpyTensor`].
+///
+/// [`NpyTensor`]: crate::npy::NpyTensor
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * the `reader` fails or ends before the entire array is read,
+/// * the input is not a valid `.npy` file,
+/// * dtype of the array is not that of `T`, or
+/// * the array is not four-dimensional.
+pub fn read_d4<T: NpyElement>(mut reader: impl Read) -> Result<NpyTensor<D4, T>, NpyError> {
+    let (header, data) = read_array::<T>(&mut reader, 4)?;
+    let shape = [
+        header.shape[0],
+        header.shape[1],
+        header.shape[2],
+        header.shape[3],
+    ];
+    Ok(match header.fortran_order {
+        false => NpyTensor::RowMajor(data.v1_into_d4(shape)),
+        true => NpyTensor::ColMajor(data.v1_into_d4_col_major(shape)),
+    })
+}
+
+/// Reads the header and the flat data of an array of the given `dimension`; the data is
+/// in the order of the file, which is described by `fortran_order` of the header.
+fn read_array<T: NpyElement>(
+    reader: &mut impl Read,
+    dimension: usize,
+) -> Result<(Header, Vec<T>), NpyError> {
+    let header = Header::read(reader)?;
+    if header.shape.len() != dimension {
+        return Err(NpyError::ShapeMismatch {
+            expected_dimension: dimension,
+            found_shape: header.shape,
+        });
+    }
+    let big_endian = is_big_endian::<T>(&header.descr)?;
+
+    let num_bytes = header
+        .shape
+        .iter()
+        .try_fold(T::NUM_BYTES, |n, len| n.checked_mul(*len))
+        .ok_or_else(|| NpyError::InvalidFormat("shape is too large".to_string()))?;
+    let mut bytes = Vec::new();
+    reader.take(num_bytes as u64).read_to_end(&mut bytes)?;
+    if bytes.len() < num_bytes {
+        return Err(NpyError::InvalidFormat(format!(
+            "array with shape {:?} requires {} bytes of data, but only {} bytes are found",
+            header.shape,
+            num_bytes,
+            bytes.len()
+        )));
+    }
+
+    let data = bytes
+        .chunks_exact(T::NUM_BYTES)
+        .map(|x| match big_endian {
+            true => T::from_be_bytes(x),
+            false => T::from_le_bytes(x),
+        })
+        .collect();
+    Ok((header, data))
+}
+
+/// Validates that the `descr` of the file is the dtype of `T`, and returns whether or
+/// not the data is stored in big-endian byte order.
+fn is_big_endian<T: NpyElement>(descr: &str) -> Result<bool, NpyError> {
+    let (byte_order, type_code) = match descr.chars().next() {
+        Some(c @ ('<' | '>' | '|' | '=')) => (c, &descr[1..]),
+        _ => ('=', descr),
+    };
+
+    if !SUPPORTED_TYPES.contains(&type_code) {
+        return Err(NpyError::UnsupportedDtype(descr.to_string()));
+    }
+    if type_code != &T::DESCR[1..] {
+        return Err(NpyError::DtypeMismatch {
+            expected: T::DESCR,
+            found: descr.to_string(),
+        });
+    }
+
+    Ok(match byte_order {
+        '>' => true,
+        '=' => cfg!(target_endian = "big"),
+        _ => false,
+    })
+}
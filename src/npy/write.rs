@@ -0,0 +1,125 @@
+use super::header::Header;
+use super::{NpyElement, NpyError};
+use crate::{Dim, DynNVec, IntoDyn, Matrix, NVec, NVecAsDyn};
+use alloc::vec;
+use alloc::vec::Vec;
+use std::io::Write;
+
+/// Writes the rectangular vector `vec` of dimension `D` to an `.npy` file.
+///
+/// The array is written in C order with the shape of the vector; i.e., the element
+/// at index `[i, j, k]` of a `D3` vector is at `array[i, j, k]` when loaded by NumPy.
+/// Note that the lengths following the first empty dimension are written as zero;
+/// for instance, a `D2` vector without any children is written with shape `(0, 0)`.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * the vector, or any of its children, is unbounded,
+/// * the vector is jagged, or
+/// * the `writer` fails.
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+/// use orx_v::npy::*;
+///
+/// let v2 = vec![vec![1.5f32, 2.5], vec![3.5, 4.5], vec![5.5, 6.5]];
+///
+/// let mut bytes = vec![];
+/// write(&mut bytes, &v2).unwrap();
+///
+/// match read_matrix::<f32>(bytes.as_slice()).unwrap() {
+///     NpyMatrix::RowMajor(mat) => assert_eq!(mat.equality(&v2.as_matrix()), Equality::Equal),
+///     NpyMatrix::ColMajor(_) => unreachable!("written in C order"),
+/// }
+///
+/// // any rectangular vector can be written
+/// let v3 = V.d3().fun(|[i, j, k]| (i * j * k) as u64).with_rectangular_bounds([2, 3, 4]);
+/// assert!(write(&mut vec![], &v3).is_ok());
+///
+/// let jagged = vec![vec![1, 2], vec![3]];
+/// assert!(matches!(write(&mut vec![], &jagged), Err(NpyError::NotRectangular)));
+///
+/// let unbounded = V.d2().constant(42);
+/// assert!(matches!(write(&mut vec![], &unbounded), Err(NpyError::Unbounded)));
+/// ```
+pub fn write<D, T, V>(mut writer: impl Write, vec: &V) -> Result<(), NpyError>
+where
+    D: Dim,
+    T: NpyElement,
+    V: NVec<D, T>,
+    for<'a> NVecAsDyn<D, T, &'a V>: DynNVec<T>,
+{
+    let dyn_vec = vec.as_dyn();
+    let zeros = vec![0; D::dimension()];
+    let mut shape: Vec<usize> = Vec::with_capacity(D::dimension());
+    for depth in 0..D::dimension() {
+        let n = match shape.last() {
+            Some(0) => 0,
+            _ => dyn_vec.card(&zeros[..depth]),
+        };
+        if n == usize::MAX {
+            return Err(NpyError::Unbounded);
+        }
+        shape.push(n);
+    }
+
+    if !vec.is_rectangular() {
+        return Err(NpyError::NotRectangular);
+    }
+
+    let mut data = Vec::with_capacity(shape.iter().product::<usize>() * T::NUM_BYTES);
+    for x in vec.all() {
+        x.extend_le_bytes(&mut data);
+    }
+
+    Header::write(&mut writer, T::DESCR, &shape)?;
+    writer.write_all(&data)?;
+    Ok(())
+}
+
+/// Writes the matrix `mat` to an `.npy` file.
+///
+/// The array is written in C order regardless of the layout of the matrix; i.e.,
+/// the element at `[i, j]` of the matrix is at `array[i, j]` when loaded by NumPy.
+///
+/// # Errors
+///
+/// Returns an error if the `writer` fails.
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+/// use orx_v::npy::*;
+///
+/// let v1: Vec<i64> = (0..6).collect();
+/// let mat = v1.v1_as_matrix_col_major(2, 3);
+///
+/// let mut bytes = vec![];
+/// write_matrix(&mut bytes, &mat).unwrap();
+///
+/// match read_matrix::<i64>(bytes.as_slice()).unwrap() {
+///     NpyMatrix::RowMajor(back) => assert_eq!(back.equality(&mat), Equality::Equal),
+///     NpyMatrix::ColMajor(_) => unreachable!("written in C order"),
+/// }
+/// ```
+pub fn write_matrix<T: NpyElement>(
+    mut writer: impl Write,
+    mat: &impl Matrix<T>,
+) -> Result<(), NpyError> {
+    let (num_rows, num_cols) = (mat.num_rows(), mat.num_cols());
+
+    let mut data = Vec::with_capacity(num_rows * num_cols * T::NUM_BYTES);
+    for i in 0..num_rows {
+        for j in 0..num_cols {
+            mat.at([i, j]).extend_le_bytes(&mut data);
+        }
+    }
+
+    Header::write(&mut writer, T::DESCR, &[num_rows, num_cols])?;
+    writer.write_all(&data)?;
+    Ok(())
+}
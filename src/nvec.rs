@@ -1,4 +1,4 @@
-use crate::{dim::*, nvec_core::NVecCore, NVecCoreSealed};
+use crate::{dim::*, nvec_core::NVecCore, MapVec, MapWithIdxVec, NVecCoreSealed, ZipWithVec};
 
 /// A `D` dimensional vector.
 ///
@@ -720,6 +720,89 @@ pub trait NVec<D: Dim, T>: NVecCore<D, T> {
         }
     }
 
+    /// Returns the element at the `idx`-th position of the vector if the
+    /// index is `in_bounds`; returns the [`IndexError`] describing the first
+    /// axis that is out of bounds otherwise.
+    ///
+    /// Unlike [`try_at`], the error tells where and why the index failed: the
+    /// `card_idx` of the exceeded axis, the attempted `idx` and the actual `card`.
+    ///
+    /// Note that, when the trait is in scope, `vec.get(idx)` on a `Vec` or an array
+    /// resolves to this method rather than to the slice method `get`.
+    ///
+    /// [`try_at`]: crate::NVec::try_at
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_v::*;
+    ///
+    /// let vec = vec![
+    ///     vec![0, 1, 2],
+    ///     vec![3],
+    ///     vec![4, 5],
+    /// ];
+    ///
+    /// assert_eq!(vec.get([0, 1]), Ok(1));
+    /// assert_eq!(vec.get([2, 1]), Ok(5));
+    ///
+    /// // vec.at([1, 1]); // panics!
+    /// let error = vec.get([1, 1]).unwrap_err();
+    /// assert_eq!(error.card_idx, IdxLeqD1::IdxD1([1]));
+    /// assert_eq!((error.idx, error.card), (1, 1));
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "index 1 is out of bounds at [1] with cardinality 1"
+    /// );
+    /// ```
+    fn get(&self, idx: impl IntoIdx<D>) -> Result<T, IndexError<D>> {
+        D::check_bounds(idx, self).map(|_| self.at(idx))
+    }
+
+    /// Returns the element at the `idx`-th position of the vector without
+    /// bounds checks.
+    ///
+    /// Storage-backed vectors such as `Vec`, arrays, slices or [`FlatJagged`] skip
+    /// validating the index through each level of the nested structure; hence, this
+    /// method can be used to avoid the repeated checks of [`at`] in hot loops where the
+    /// indices are known to be in bounds. The remaining vectors fall back to [`at`].
+    ///
+    /// In debug builds, the index is validated by a `debug_assert`.
+    ///
+    /// [`at`]: crate::NVec::at
+    /// [`FlatJagged`]: crate::FlatJagged
+    ///
+    /// # Safety
+    ///
+    /// The `idx` must be `in_bounds`; calling this method with an out of bounds index
+    /// is *undefined behavior*.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_v::*;
+    ///
+    /// let vec = vec![
+    ///     vec![0, 1, 2],
+    ///     vec![3],
+    ///     vec![4, 5],
+    /// ];
+    ///
+    /// let mut sum = 0;
+    /// for i in 0..vec.num_children() {
+    ///     for j in 0..vec.card([i]) {
+    ///         // SAFETY: i and j are within the cardinalities of the vector
+    ///         sum += unsafe { vec.at_unchecked([i, j]) };
+    ///     }
+    /// }
+    /// assert_eq!(sum, 15);
+    /// ```
+    #[inline(always)]
+    unsafe fn at_unchecked(&self, idx: impl IntoIdx<D>) -> T {
+        debug_assert!(D::in_bounds(idx, self), "index is out of bounds");
+        self.at(idx)
+    }
+
     /// Returns the equality of this vec with the `other`:
     /// * Returns [`Equality::Equal`] iff the cardinality of the structures as
     ///   well as all values at corresponding positions are equal.
@@ -847,6 +930,288 @@ pub trait NVec<D: Dim, T>: NVecCore<D, T> {
     fn all_in(&self, indices: impl Iterator<Item = impl IntoIdx<D>>) -> impl Iterator<Item = T> {
         indices.map(|idx| self.at(idx.into_idx()))
     }
+
+    /// Returns an iterator of all indices of the vector in lexicographic order,
+    /// which is the same order that [`all`] yields the elements.
+    ///
+    /// Both rectangular and jagged vectors are supported; the indices of
+    /// empty children are skipped.
+    ///
+    /// [`all`]: crate::NVec::all
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector [`is_unbounded`].
+    ///
+    /// [`is_unbounded`]: crate::NVec::is_unbounded
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_v::*;
+    ///
+    /// let v2 = vec![vec![3, 1], vec![], vec![4]];
+    /// assert_eq!(
+    ///     v2.indices().collect::<Vec<_>>(),
+    ///     vec![[0, 0], [0, 1], [2, 0]],
+    /// );
+    ///
+    /// let v3 = V.d3().constant(0).with_rectangular_bounds([2, 1, 2]);
+    /// assert_eq!(
+    ///     v3.indices().collect::<Vec<_>>(),
+    ///     vec![[0, 0, 0], [0, 0, 1], [1, 0, 0], [1, 0, 1]],
+    /// );
+    /// ```
+    fn indices(&self) -> impl Iterator<Item = D::Idx> {
+        crate::cardinality::panic_on_all_when_udd(self.is_unbounded());
+        D::indices(self)
+    }
+
+    /// Returns an iterator of all elements of the vector together with their
+    /// indices; i.e., `(idx, element)` pairs, in the same order as [`all`].
+    ///
+    /// [`all`]: crate::NVec::all
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector [`is_unbounded`].
+    ///
+    /// [`is_unbounded`]: crate::NVec::is_unbounded
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_v::*;
+    ///
+    /// let v2 = vec![vec![3, 1], vec![], vec![4]];
+    /// let max = v2.enumerate_all().max_by_key(|(_, x)| *x);
+    /// assert_eq!(max, Some(([2, 0], 4)));
+    ///
+    /// let v1 = V.d1().sparse(0).bounded(4);
+    /// assert_eq!(
+    ///     v1.enumerate_all().collect::<Vec<_>>(),
+    ///     vec![([0], 0), ([1], 0), ([2], 0), ([3], 0)],
+    /// );
+    /// ```
+    fn enumerate_all(&self) -> impl Iterator<Item = (D::Idx, T)> {
+        self.indices().map(|idx| (idx, self.at(idx)))
+    }
+
+    /// Collects all elements of the vector into nested standard vectors, such as
+    /// `Vec<T>` for `D1`, `Vec<Vec<T>>` for `D2`, and so on.
+    ///
+    /// This is useful to materialize a lazy vector, such as a [`FunVec`], [`CachedVec`]
+    /// or [`SparseVec`], into dense owned storage.
+    ///
+    /// Returns [`FromNVecError::Unbounded`] if the vector or any of its children is
+    /// unbounded.
+    ///
+    /// See [`FromNVec`] in order to materialize the vector into other storages.
+    /// The nested vector type of each dimension is defined by [`ToNestedVec`].
+    ///
+    /// [`FunVec`]: crate::FunVec
+    /// [`CachedVec`]: crate::CachedVec
+    /// [`SparseVec`]: crate::SparseVec
+    /// [`FromNVecError::Unbounded`]: crate::FromNVecError::Unbounded
+    /// [`FromNVec`]: crate::FromNVec
+    /// [`ToNestedVec`]: crate::ToNestedVec
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_v::*;
+    ///
+    /// let v2 = V.d2().fun(|[i, j]| 10 * i + j).with_variable_bounds(vec![2, 0, 1]);
+    /// assert_eq!(v2.to_nested_vec(), Ok(vec![vec![0, 1], vec![], vec![20]]));
+    ///
+    /// let mut v3 = V.d3().sparse(0).with_rectangular_bounds([2, 1, 2]);
+    /// v3.set([1, 0, 1], 7);
+    /// assert_eq!(
+    ///     v3.to_nested_vec(),
+    ///     Ok(vec![vec![vec![0, 0]], vec![vec![0, 7]]]),
+    /// );
+    ///
+    /// let v1 = V.d1().constant(42);
+    /// assert_eq!(v1.to_nested_vec(), Err(FromNVecError::Unbounded));
+    /// ```
+    fn to_nested_vec(&self) -> Result<D::NestedVec<T>, crate::FromNVecError>
+    where
+        D: crate::ToNestedVec,
+    {
+        <D::NestedVec<T> as crate::FromNVec<D, T>>::from_nvec(self)
+    }
+
+    /// Returns a lazy vector which maps each element of this vector with `f`.
+    ///
+    /// The mapped vector has the same cardinality as this vector; its children
+    /// are the mapped children of this vector. No element is stored; `f` is applied
+    /// every time an element is accessed. Therefore, it might be useful to call
+    /// [`into_cached`] on the mapped vector when `f` is expensive.
+    ///
+    /// The mapped vector holds a reference to this vector.
+    /// Note that types having an inherent `map` method, such as arrays, must be
+    /// called with the fully qualified syntax, as in `NVec::map(&array, f)`.
+    ///
+    /// [`into_cached`]: crate::IntoCached::into_cached
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = vec![vec![1, 2, 3], vec![], vec![4]];
+    ///
+    /// let v2_str = v2.map(|x| x.to_string().len());
+    /// assert_eq!(v2_str.card([]), 3);
+    /// assert_eq!(v2_str.card([0]), 3);
+    /// assert_eq!(v2_str.card([1]), 0);
+    /// assert_eq!(v2_str.at([2, 0]), 1);
+    ///
+    /// let squared = v2.map(|x| x * x);
+    /// assert_eq!(squared.child(0).all().collect::<Vec<_>>(), vec![1, 4, 9]);
+    /// assert_eq!(squared.all().sum::<i32>(), 1 + 4 + 9 + 16);
+    ///
+    /// let products = V.d2().fun(|[i, j]| i * j);
+    /// let expensive = products.map(|x| x + 1).into_cached();
+    /// assert_eq!(expensive.at([3, 4]), 13);
+    /// assert_eq!(expensive.cache_len(), 1);
+    /// ```
+    fn map<U, F>(&self, f: F) -> MapVec<D, T, U, &Self, F>
+    where
+        F: Fn(T) -> U,
+    {
+        MapVec::new(self, f)
+    }
+
+    /// Returns a lazy vector which maps each element of this vector together with its
+    /// index with `f`.
+    ///
+    /// The mapped vector has the same cardinality as this vector; its children
+    /// are the mapped children of this vector. No element is stored; `f` is applied
+    /// every time an element is accessed. Therefore, it might be useful to call
+    /// [`into_cached`] on the mapped vector when `f` is expensive.
+    ///
+    /// [`into_cached`]: crate::IntoCached::into_cached
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = vec![vec![1, 2, 3], vec![], vec![4]];
+    ///
+    /// let v2 = v2.map_with_idx(|[i, j], x| 100 * i + 10 * j + x);
+    /// assert_eq!(v2.card([0]), 3);
+    /// assert_eq!(v2.at([0, 2]), 23);
+    /// assert_eq!(v2.at([2, 0]), 204);
+    /// assert_eq!(v2.child(2).at([0]), 204);
+    /// assert_eq!(v2.all().collect::<Vec<_>>(), vec![1, 12, 23, 204]);
+    /// ```
+    fn map_with_idx<U, F>(&self, f: F) -> MapWithIdxVec<D, T, U, &Self, F>
+    where
+        F: Fn(D::Idx, T) -> U,
+    {
+        MapWithIdxVec::new(self, f)
+    }
+
+    /// Returns a lazy vector which combines each element of this vector with the
+    /// corresponding element of the `other` vector using `f`.
+    ///
+    /// The cardinalities of the two vectors are validated by [`card_equality`]
+    /// on construction:
+    /// * returns `Ok` of the zipped vector if the cardinalities are equal,
+    /// * returns the `Err` of [`CardEquality::Unequal`] otherwise, which reports
+    ///   the first index at which the cardinalities of the two vectors differ.
+    ///
+    /// Cardinalities cannot be validated when both vectors are unbounded; in this case,
+    /// the zipped vector is also unbounded.
+    ///
+    /// The zipped vector holds a reference to this vector. No element is stored;
+    /// `f` is applied every time an element is accessed. `all` iterates over both
+    /// vectors in lockstep.
+    ///
+    /// [`card_equality`]: crate::NVec::card_equality
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let costs = vec![vec![1, 2, 3], vec![4]];
+    ///
+    /// let capacities = vec![vec![10, 0, 30], vec![40]];
+    /// let total = costs.zip_with(&capacities, |c, q| c * q).unwrap();
+    /// assert_eq!(total.at([0, 2]), 90);
+    /// assert_eq!(total.all().collect::<Vec<_>>(), vec![10, 0, 90, 160]);
+    ///
+    /// let capacities = vec![vec![10, 0, 30], vec![40, 50]];
+    /// let total = costs.zip_with(&capacities, |c, q| c * q);
+    /// assert_eq!(
+    ///     total.map(|_| ()).unwrap_err(),
+    ///     CardEquality::Unequal(IdxLeqD1::IdxD1([1]), 1, 2)
+    /// );
+    ///
+    /// let unit_capacities = V.d2().constant(1);
+    /// let total = costs.zip_with(&unit_capacities, |c, q| c * q);
+    /// assert!(total.is_err());
+    ///
+    /// let unit_capacities = unit_capacities.with_variable_bounds(vec![3, 1]);
+    /// let total = costs.zip_with(&unit_capacities, |c, q| c * q).unwrap();
+    /// assert_eq!(total.equality(&costs), Equality::Equal);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn zip_with<U, O, W, F>(
+        &self,
+        other: W,
+        f: F,
+    ) -> Result<ZipWithVec<D, T, U, O, &Self, W, F>, CardEquality<D>>
+    where
+        W: NVec<D, U>,
+        F: Fn(T, U) -> O,
+    {
+        let both_unbounded = self.is_unbounded() && other.is_unbounded();
+        let card_equality = match both_unbounded {
+            true => CardEquality::Equal,
+            false => self.map(|_| ()).card_equality(&other.map(|_| ())),
+        };
+        match card_equality {
+            CardEquality::Equal => Ok(ZipWithVec::new(self, other, f)),
+            unequal => Err(unequal),
+        }
+    }
+
+    /// Returns a lazy vector of pairs of corresponding elements of this vector and
+    /// the `other` vector.
+    ///
+    /// This is equivalent to `zip_with(other, |a, b| (a, b))`; see [`zip_with`] for
+    /// details of cardinality validation.
+    ///
+    /// [`zip_with`]: crate::NVec::zip_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let names = vec!['a', 'b', 'c'];
+    /// let scores = vec![3, 7, 1];
+    ///
+    /// let zipped = names.zip(&scores).unwrap();
+    /// assert_eq!(zipped.at([1]), ('b', 7));
+    /// assert_eq!(zipped.all().max_by_key(|x| x.1), Some(('b', 7)));
+    ///
+    /// assert!(names.zip(&vec![3, 7]).is_err());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn zip<U, W>(
+        &self,
+        other: W,
+    ) -> Result<ZipWithVec<D, T, U, (T, U), &Self, W, fn(T, U) -> (T, U)>, CardEquality<D>>
+    where
+        W: NVec<D, U>,
+    {
+        self.zip_with(other, |a, b| (a, b))
+    }
 }
 
 // &V auto impl
@@ -857,6 +1222,11 @@ impl<T, D: Dim, V: NVec<D, T>> NVec<D, T> for &V {
         <V as NVec<D, T>>::at(self, idx)
     }
 
+    #[inline(always)]
+    unsafe fn at_unchecked(&self, idx: impl IntoIdx<D>) -> T {
+        unsafe { <V as NVec<D, T>>::at_unchecked(self, idx) }
+    }
+
     #[inline(always)]
     fn child(&self, i: <D as Dim>::ChildIdx) -> impl NVec<<D as Dim>::PrevDim, T> {
         <V as NVec<D, T>>::child(self, i)
@@ -875,6 +1245,11 @@ impl<T, D: Dim, V: NVec<D, T>> NVec<D, T> for &mut V {
         <V as NVec<D, T>>::at(self, idx)
     }
 
+    #[inline(always)]
+    unsafe fn at_unchecked(&self, idx: impl IntoIdx<D>) -> T {
+        unsafe { <V as NVec<D, T>>::at_unchecked(self, idx) }
+    }
+
     #[inline(always)]
     fn child(&self, i: <D as Dim>::ChildIdx) -> impl NVec<<D as Dim>::PrevDim, T> {
         <V as NVec<D, T>>::child(self, i)
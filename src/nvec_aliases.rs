@@ -18,6 +18,14 @@ impl<T, N: NVec<D3, T>> V3<T> for N {}
 pub trait V4<T>: NVec<D4, T> {}
 impl<T, N: NVec<D4, T>> V4<T> for N {}
 
+/// A type alias: `V5<T>` is equivalent to `NVec<D5, T>`.
+pub trait V5<T>: NVec<D5, T> {}
+impl<T, N: NVec<D5, T>> V5<T> for N {}
+
+/// A type alias: `V6<T>` is equivalent to `NVec<D6, T>`.
+pub trait V6<T>: NVec<D6, T> {}
+impl<T, N: NVec<D6, T>> V6<T> for N {}
+
 // mut
 
 /// A type alias: `V1Mut<T>` is equivalent to `NVecMut<D1, T>`.
@@ -35,3 +43,11 @@ impl<T, N: NVecMut<D3, T>> V3Mut<T> for N {}
 /// A type alias: `V4Mut<T>` is equivalent to `NVecMut<D4, T>`.
 pub trait V4Mut<T>: NVecMut<D4, T> {}
 impl<T, N: NVecMut<D4, T>> V4Mut<T> for N {}
+
+/// A type alias: `V5Mut<T>` is equivalent to `NVecMut<D5, T>`.
+pub trait V5Mut<T>: NVecMut<D5, T> {}
+impl<T, N: NVecMut<D5, T>> V5Mut<T> for N {}
+
+/// A type alias: `V6Mut<T>` is equivalent to `NVecMut<D6, T>`.
+pub trait V6Mut<T>: NVecMut<D6, T> {}
+impl<T, N: NVecMut<D6, T>> V6Mut<T> for N {}
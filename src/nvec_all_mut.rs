@@ -0,0 +1,159 @@
+use crate::common_trait_helpers::all_mut::take_in_order;
+use crate::{Dim, IntoIdx, NVecMut};
+use alloc::vec::Vec;
+
+/// A `D` dimensional mutable vector which can create mutable references to all of its
+/// elements at the same time since the elements are disjoint.
+///
+/// [`at_mut`] allows to mutate only one element at a time, while this trait allows to
+/// iterate over mutable references of all elements with [`all_mut`], or of the elements
+/// at the given indices with [`all_mut_in`].
+///
+/// Note that children created by [`child_mut`] are only guaranteed to be `NVecMut`;
+/// mutable references to the elements of a child can be created by calling
+/// [`all_mut_in`] on the parent with the indices of the child.
+///
+/// [`at_mut`]: crate::NVecMut::at_mut
+/// [`child_mut`]: crate::NVecMut::child_mut
+/// [`all_mut`]: crate::NVecAllMut::all_mut
+/// [`all_mut_in`]: crate::NVecAllMut::all_mut_in
+pub trait NVecAllMut<D: Dim, T>: NVecMut<D, T> {
+    // required
+
+    /// Returns an iterator of mutable references to all scalar elements of the vector
+    /// in the same order as yielded by [`all`]; i.e., in lexicographic order of their
+    /// indices.
+    ///
+    /// Unlike [`mut_all`], it allows early exit, zipping with other iterators or using
+    /// any of the `Iterator` combinators.
+    ///
+    /// [`all`]: crate::NVec::all
+    /// [`mut_all`]: crate::NVecMut::mut_all
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v2 = vec![vec![1, 2], vec![], vec![3, 4, 5]];
+    ///
+    /// for (x, y) in v2.all_mut().zip([10, 20, 30]) {
+    ///     *x += y;
+    /// }
+    /// assert_eq!(v2, vec![vec![11, 22], vec![], vec![33, 4, 5]]);
+    ///
+    /// if let Some(x) = v2.all_mut().find(|x| **x % 2 == 0) {
+    ///     *x = 0;
+    /// }
+    /// assert_eq!(v2, vec![vec![11, 0], vec![], vec![33, 4, 5]]);
+    /// ```
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a;
+
+    /// Returns an iterator of all scalar elements of the vector together with their
+    /// indices; i.e., `(idx, &mut element)` pairs, in lexicographic order of the indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v2 = vec![vec![0; 2], vec![], vec![0; 3]];
+    ///
+    /// for ([i, j], x) in v2.enumerate_all_mut() {
+    ///     *x = 10 * i + j;
+    /// }
+    /// assert_eq!(v2, vec![vec![0, 1], vec![], vec![20, 21, 22]]);
+    ///
+    /// let mut v1 = [3, 1, 4, 1, 5];
+    /// let ([i], x) = v1.enumerate_all_mut().find(|(_, x)| **x > 3).unwrap();
+    /// *x = 0;
+    /// assert_eq!(i, 2);
+    /// assert_eq!(v1, [3, 1, 0, 1, 5]);
+    /// ```
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (D::Idx, &'a mut T)>
+    where
+        T: 'a;
+
+    // provided
+
+    /// Returns an iterator of mutable references to the elements at the given `indices`,
+    /// in the order of the `indices`.
+    ///
+    /// This is the mutable counterpart of [`all_in`]; while all references are alive at
+    /// the same time, and hence, each index can be requested at most once.
+    ///
+    /// Elements are located by walking [`enumerate_all_mut`] until all requested indices
+    /// are found; therefore, the cost is linear in the position of the last requested
+    /// element in lexicographic order, in addition to sorting the `indices`.
+    /// Sparse vectors, on the other hand, only touch the requested indices.
+    ///
+    /// [`all_in`]: crate::NVec::all_in
+    /// [`enumerate_all_mut`]: crate::NVecAllMut::enumerate_all_mut
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds or is requested more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v2 = vec![vec![0, 1], vec![], vec![2, 3, 4]];
+    ///
+    /// for (x, y) in v2.all_mut_in([[2, 1], [0, 0]].into_iter()).zip([10, 20]) {
+    ///     *x += y;
+    /// }
+    /// assert_eq!(v2, vec![vec![20, 1], vec![], vec![2, 13, 4]]);
+    /// ```
+    fn all_mut_in<'a>(
+        &'a mut self,
+        indices: impl Iterator<Item = impl IntoIdx<D>>,
+    ) -> impl Iterator<Item = &'a mut T>
+    where
+        D::Idx: Ord,
+        T: 'a,
+    {
+        let indices: Vec<_> = indices.map(|idx| idx.into_idx()).collect();
+        take_in_order(self.enumerate_all_mut(), indices)
+    }
+}
+
+// &mut V auto impl
+
+impl<T, D: Dim, V: NVecAllMut<D, T>> NVecAllMut<D, T> for &mut V {
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        <V as NVecAllMut<D, T>>::all_mut(self)
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (D::Idx, &'a mut T)>
+    where
+        T: 'a,
+    {
+        <V as NVecAllMut<D, T>>::enumerate_all_mut(self)
+    }
+
+    fn all_mut_in<'a>(
+        &'a mut self,
+        indices: impl Iterator<Item = impl IntoIdx<D>>,
+    ) -> impl Iterator<Item = &'a mut T>
+    where
+        D::Idx: Ord,
+        T: 'a,
+    {
+        <V as NVecAllMut<D, T>>::all_mut_in(self, indices)
+    }
+}
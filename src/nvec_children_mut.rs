@@ -0,0 +1,100 @@
+use crate::{Dim, NVecMut, D2, D3, D4, D5, D6};
+use alloc::vec::Vec;
+
+/// A `D` dimensional mutable vector whose children can be borrowed mutably at the
+/// same time since they are disjoint.
+///
+/// [`child_mut`] allows to mutate only one child at a time, while this trait allows
+/// to iterate over all mutable children with [`children_mut`], or to split the vector
+/// into two disjoint mutable halves with [`split_children_mut`]. Halves can then be
+/// mutated in independent passes or threads.
+///
+/// [`child_mut`]: crate::NVecMut::child_mut
+/// [`children_mut`]: crate::NVecChildrenMut::children_mut
+/// [`split_children_mut`]: crate::NVecChildrenMut::split_children_mut
+pub trait NVecChildrenMut<D: Dim, T>: NVecMut<D, T> {
+    /// Returns an iterator of all children of the vector as mutable vectors of one
+    /// lower dimension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v2 = vec![vec![1, 2], vec![], vec![3, 4, 5]];
+    ///
+    /// for (i, mut row) in v2.children_mut().enumerate() {
+    ///     row.mut_all(|x| *x *= 10 * (i + 1));
+    /// }
+    /// assert_eq!(v2, vec![vec![10, 20], vec![], vec![90, 120, 150]]);
+    /// ```
+    fn children_mut(&mut self) -> impl Iterator<Item = impl NVecMut<D::PrevDim, T>>;
+
+    /// Splits the vector into two disjoint mutable vectors of the same dimension
+    /// such that:
+    /// * the first one contains children with indices in `0..i`, and
+    /// * the second one contains children with indices in `i..num_children`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is greater than the number of children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v2 = vec![vec![0, 1], vec![2], vec![3, 4, 5]];
+    ///
+    /// let (mut first, mut second) = v2.split_children_mut(1);
+    /// assert_eq!(first.card([]), 1);
+    /// assert_eq!(second.card([]), 2);
+    ///
+    /// std::thread::scope(|s| {
+    ///     s.spawn(move || first.mut_all(|x| *x += 10));
+    ///     s.spawn(move || second.mut_all(|x| *x += 20));
+    /// });
+    /// assert_eq!(v2, vec![vec![10, 11], vec![22], vec![23, 24, 25]]);
+    /// ```
+    fn split_children_mut(&mut self, i: usize) -> (impl NVecMut<D, T>, impl NVecMut<D, T>);
+}
+
+macro_rules! impl_children_mut {
+    ($dim:ty, [$($impl_generics:tt)*], $V:ty) => {
+        impl<$($impl_generics)*> NVecChildrenMut<$dim, T> for $V
+        where
+            C: NVecMut<<$dim as Dim>::PrevDim, T>,
+        {
+            fn children_mut(
+                &mut self,
+            ) -> impl Iterator<Item = impl NVecMut<<$dim as Dim>::PrevDim, T>> {
+                self.iter_mut()
+            }
+
+            fn split_children_mut(
+                &mut self,
+                i: usize,
+            ) -> (impl NVecMut<$dim, T>, impl NVecMut<$dim, T>) {
+                self.split_at_mut(i)
+            }
+        }
+    };
+}
+
+impl_children_mut!(D2, [T, C], Vec<C>);
+impl_children_mut!(D3, [T, C], Vec<C>);
+impl_children_mut!(D4, [T, C], Vec<C>);
+impl_children_mut!(D5, [T, C], Vec<C>);
+impl_children_mut!(D6, [T, C], Vec<C>);
+
+impl_children_mut!(D2, ['a, T, C], &'a mut [C]);
+impl_children_mut!(D3, ['a, T, C], &'a mut [C]);
+impl_children_mut!(D4, ['a, T, C], &'a mut [C]);
+impl_children_mut!(D5, ['a, T, C], &'a mut [C]);
+impl_children_mut!(D6, ['a, T, C], &'a mut [C]);
+
+impl_children_mut!(D2, [const N: usize, T, C], [C; N]);
+impl_children_mut!(D3, [const N: usize, T, C], [C; N]);
+impl_children_mut!(D4, [const N: usize, T, C], [C; N]);
+impl_children_mut!(D5, [const N: usize, T, C], [C; N]);
+impl_children_mut!(D6, [const N: usize, T, C], [C; N]);
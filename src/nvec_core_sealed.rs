@@ -1,6 +1,9 @@
 use crate::dim::*;
 use core::fmt::Debug;
 
+/// Core functionalities of a `D` dimensional vector which are required by [`NVec`].
+///
+/// [`NVec`]: crate::NVec
 pub trait NVecCoreSealed<D: Dim, T>: Sized {
     /// Returns the number of children of the vector; i.e., number of
     /// elements of the one lower dimension.
@@ -79,18 +82,23 @@ pub trait NVecCoreSealed<D: Dim, T>: Sized {
     /// ```
     fn core_card(&self, idx: impl Into<D::CardIdx>) -> usize;
 
+    /// Returns the `i`-th child of the vector.
     fn core_child(&self, i: D::ChildIdx) -> impl NVecCoreSealed<D::PrevDim, T>;
 
+    /// Applies the function `f` on a reference to the element at the `idx`-th position.
     fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D>, f: &mut F) -> O;
 
+    /// Returns whether or not the vector is rectangular.
     fn core_is_rectangular(&self) -> bool;
 
     // provided
+    /// Returns whether or not the vector is bounded.
     #[inline(always)]
     fn core_is_bounded(&self) -> bool {
         self.core_num_children() < usize::MAX
     }
 
+    /// Writes the debug representation of the element at the `idx`-th position.
     fn core_dbg_at(
         &self,
         idx: impl IntoIdx<D>,
@@ -0,0 +1,326 @@
+use crate::{Dim, NVecMut};
+use core::fmt::Display;
+
+/// Error returned by the growing methods of [`NVecGrow`] when the vector has a fixed
+/// capacity which does not allow to add more elements, or when the given index does not
+/// point to a one-dimensional vector to grow.
+///
+/// [`NVecGrow`]: crate::grow::NVecGrow
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GrowError {
+    /// The vector is full; i.e., its length is already equal to its fixed `capacity`.
+    CapacityExceeded {
+        /// Fixed capacity of the vector.
+        capacity: usize,
+    },
+    /// The index is not of dimension `D - 1`; and hence, does not point to a
+    /// one-dimensional vector whose elements can be added.
+    NotLeaf,
+}
+
+impl Display for GrowError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::CapacityExceeded { capacity } => write!(
+                f,
+                "Cannot grow the vector beyond its fixed capacity of {}",
+                capacity
+            ),
+            Self::NotLeaf => write!(
+                f,
+                "idx must be of dimension D - 1 to point to a one-dimensional vector to grow"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for GrowError {}
+
+/// A `D` dimensional mutable vector which can grow or shrink.
+///
+/// Growing methods return a [`GrowError`] when the vector, or the child to be extended,
+/// has a fixed capacity which is already full, or when the index does not point to a
+/// one-dimensional vector. Vectors with dynamic capacity, such as
+/// `Vec`, `SplitVec`, `SmallVec` or `TinyVec`, never fail to grow; while fixed capacity
+/// vectors such as `ArrayVec` or `FixedVec` fail when full.
+///
+/// Elements and children are addressed by cardinality indices, as in [`card`]:
+/// * [`push`], [`extend`], [`insert`] and [`resize`] operate on the one-dimensional
+///   vector at the given `idx`; hence, `idx` must be of dimension `D - 1` (`[]` for a
+///   `D1` vector, `[i]` for the `i`-th child of a `D2` vector, and so on);
+/// * [`truncate`] operates on the vector at the given `idx` of any dimension lower
+///   than `D`; `[]` truncates the children of the vector itself;
+/// * [`push_child`] and [`clear`] operate on the vector itself.
+///
+/// Notice that the method names coincide with the inherent methods of the standard
+/// `Vec` and the other backends. Therefore, on a concrete type, these methods must be
+/// called with the fully qualified syntax such as `NVecGrow::push(&mut vec, [i], value)`;
+/// while they can be called directly within generic code.
+///
+/// [`card`]: crate::NVec::card
+/// [`push`]: crate::grow::NVecGrow::push
+/// [`extend`]: crate::grow::NVecGrow::extend
+/// [`insert`]: crate::grow::NVecGrow::insert
+/// [`resize`]: crate::grow::NVecGrow::resize
+/// [`truncate`]: crate::grow::NVecGrow::truncate
+/// [`push_child`]: crate::grow::NVecGrow::push_child
+/// [`clear`]: crate::grow::NVecGrow::clear
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+/// use orx_v::grow::*;
+///
+/// fn add_edge<V>(adj: &mut V, from: usize, to: usize)
+/// where
+///     V: NVecGrow<D2, usize>,
+///     V::Child: Default,
+/// {
+///     while adj.card([]) <= from {
+///         adj.push_child(Default::default()).unwrap();
+///     }
+///     adj.push([from], to).unwrap();
+/// }
+///
+/// let mut adj: Vec<Vec<usize>> = vec![];
+/// add_edge(&mut adj, 0, 1);
+/// add_edge(&mut adj, 2, 0);
+/// add_edge(&mut adj, 0, 2);
+/// assert_eq!(adj, vec![vec![1, 2], vec![], vec![0]]);
+/// ```
+pub trait NVecGrow<D: Dim, T>: NVecMut<D, T> {
+    /// Type of the children of the vector, which is the scalar element type `T`
+    /// for `D1` vectors.
+    type Child;
+
+    /// Appends the `child` to the end of the vector, incrementing its number of
+    /// children by one.
+    ///
+    /// Returns an error if the vector has a fixed capacity and it is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    /// use orx_v::grow::*;
+    ///
+    /// let mut v2 = vec![vec![1]];
+    /// NVecGrow::push_child(&mut v2, vec![2, 3]).unwrap();
+    /// assert_eq!(v2, vec![vec![1], vec![2, 3]]);
+    ///
+    /// let mut v1 = vec![1];
+    /// NVecGrow::push_child(&mut v1, 2).unwrap();
+    /// assert_eq!(v1, vec![1, 2]);
+    /// ```
+    fn push_child(&mut self, child: Self::Child) -> Result<(), GrowError>;
+
+    /// Appends the `value` to the end of the one-dimensional vector at the given `idx`.
+    ///
+    /// Returns
+    /// * [`GrowError::CapacityExceeded`] if the vector at `idx` has a fixed capacity and
+    ///   it is full, and
+    /// * [`GrowError::NotLeaf`] if `idx` is not of dimension `D - 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    /// use orx_v::grow::*;
+    ///
+    /// let mut v1 = vec![1];
+    /// NVecGrow::push(&mut v1, [], 2).unwrap();
+    /// assert_eq!(v1, vec![1, 2]);
+    ///
+    /// let mut v3 = vec![vec![vec![1], vec![]]];
+    /// NVecGrow::push(&mut v3, [0, 1], 2).unwrap();
+    /// assert_eq!(v3, vec![vec![vec![1], vec![2]]]);
+    /// ```
+    fn push(&mut self, idx: impl Into<D::CardIdx>, value: T) -> Result<(), GrowError>;
+
+    /// Appends all `values` to the end of the one-dimensional vector at the given `idx`.
+    ///
+    /// Returns
+    /// * [`GrowError::CapacityExceeded`] if the vector at `idx` has a fixed capacity which
+    ///   is exceeded while extending; in this case, the values until the vector becomes
+    ///   full are already appended, and
+    /// * [`GrowError::NotLeaf`] if `idx` is not of dimension `D - 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    /// use orx_v::grow::*;
+    ///
+    /// let mut v2 = vec![vec![1], vec![]];
+    /// NVecGrow::extend(&mut v2, [1], [2, 3]).unwrap();
+    /// assert_eq!(v2, vec![vec![1], vec![2, 3]]);
+    /// ```
+    fn extend(
+        &mut self,
+        idx: impl Into<D::CardIdx>,
+        values: impl IntoIterator<Item = T>,
+    ) -> Result<(), GrowError>;
+
+    /// Inserts the `value` at the given `position` of the one-dimensional vector at the
+    /// given `idx`, shifting all elements after it to the right.
+    ///
+    /// Returns an error without modifying the vector:
+    /// * [`GrowError::CapacityExceeded`] if the vector at `idx` has a fixed capacity and
+    ///   it is full, and
+    /// * [`GrowError::NotLeaf`] if `idx` is not of dimension `D - 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `idx` is out of bounds, or
+    /// * `position` is greater than the length of the vector at `idx`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    /// use orx_v::grow::*;
+    ///
+    /// let mut v1 = vec![1, 3];
+    /// NVecGrow::insert(&mut v1, [], 1, 2).unwrap();
+    /// assert_eq!(v1, vec![1, 2, 3]);
+    ///
+    /// let mut v2 = vec![vec![1], vec![4, 6]];
+    /// NVecGrow::insert(&mut v2, [1], 1, 5).unwrap();
+    /// NVecGrow::insert(&mut v2, [1], 0, 3).unwrap();
+    /// assert_eq!(v2, vec![vec![1], vec![3, 4, 5, 6]]);
+    ///
+    /// assert_eq!(NVecGrow::insert(&mut v2, [], 0, 0), Err(GrowError::NotLeaf));
+    /// ```
+    fn insert(
+        &mut self,
+        idx: impl Into<D::CardIdx>,
+        position: usize,
+        value: T,
+    ) -> Result<(), GrowError>;
+
+    /// Shortens the vector at the given `idx`, keeping its first `len` elements or
+    /// children and dropping the rest.
+    ///
+    /// Does nothing if `len` is greater than or equal to the current cardinality at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    /// use orx_v::grow::*;
+    ///
+    /// let mut v2 = vec![vec![1, 2, 3], vec![4], vec![5, 6]];
+    ///
+    /// NVecGrow::truncate(&mut v2, [0], 1);
+    /// assert_eq!(v2, vec![vec![1], vec![4], vec![5, 6]]);
+    ///
+    /// NVecGrow::truncate(&mut v2, [], 2);
+    /// assert_eq!(v2, vec![vec![1], vec![4]]);
+    /// ```
+    fn truncate(&mut self, idx: impl Into<D::CardIdx>, len: usize);
+
+    /// Resizes the one-dimensional vector at the given `idx` so that its length is
+    /// equal to `len`:
+    /// * the vector is truncated if `len` is less than its current length,
+    /// * `value` is appended as many times as necessary otherwise.
+    ///
+    /// Returns an error without modifying the vector:
+    /// * [`GrowError::CapacityExceeded`] if the vector at `idx` has a fixed capacity which
+    ///   is less than `len`, and
+    /// * [`GrowError::NotLeaf`] if `idx` is not of dimension `D - 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    /// use orx_v::grow::*;
+    ///
+    /// let mut v2 = vec![vec![1, 2, 3], vec![4]];
+    ///
+    /// NVecGrow::resize(&mut v2, [0], 1, 0).unwrap();
+    /// NVecGrow::resize(&mut v2, [1], 3, 0).unwrap();
+    /// assert_eq!(v2, vec![vec![1], vec![4, 0, 0]]);
+    /// ```
+    fn resize(&mut self, idx: impl Into<D::CardIdx>, len: usize, value: T)
+        -> Result<(), GrowError>;
+
+    /// Removes all children of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    /// use orx_v::grow::*;
+    ///
+    /// let mut v2 = vec![vec![1, 2, 3], vec![4]];
+    /// NVecGrow::clear(&mut v2);
+    /// assert!(v2.is_empty());
+    /// ```
+    fn clear(&mut self);
+}
+
+// &mut V auto impl
+
+impl<T, D: Dim, V: NVecGrow<D, T>> NVecGrow<D, T> for &mut V {
+    type Child = V::Child;
+
+    fn push_child(&mut self, child: Self::Child) -> Result<(), GrowError> {
+        <V as NVecGrow<D, T>>::push_child(self, child)
+    }
+
+    fn push(&mut self, idx: impl Into<D::CardIdx>, value: T) -> Result<(), GrowError> {
+        <V as NVecGrow<D, T>>::push(self, idx, value)
+    }
+
+    fn extend(
+        &mut self,
+        idx: impl Into<D::CardIdx>,
+        values: impl IntoIterator<Item = T>,
+    ) -> Result<(), GrowError> {
+        <V as NVecGrow<D, T>>::extend(self, idx, values)
+    }
+
+    fn insert(
+        &mut self,
+        idx: impl Into<D::CardIdx>,
+        position: usize,
+        value: T,
+    ) -> Result<(), GrowError> {
+        <V as NVecGrow<D, T>>::insert(self, idx, position, value)
+    }
+
+    fn truncate(&mut self, idx: impl Into<D::CardIdx>, len: usize) {
+        <V as NVecGrow<D, T>>::truncate(self, idx, len);
+    }
+
+    fn resize(
+        &mut self,
+        idx: impl Into<D::CardIdx>,
+        len: usize,
+        value: T,
+    ) -> Result<(), GrowError> {
+        <V as NVecGrow<D, T>>::resize(self, idx, len, value)
+    }
+
+    fn clear(&mut self) {
+        <V as NVecGrow<D, T>>::clear(self);
+    }
+}
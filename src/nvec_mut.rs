@@ -1,5 +1,5 @@
 use crate::{
-    dim::{Dim, IntoIdx},
+    dim::{Dim, IndexError, IntoIdx},
     nvec::NVec,
 };
 
@@ -221,6 +221,71 @@ pub trait NVecMut<D: Dim, T>: NVec<D, T> {
             false => None,
         }
     }
+
+    /// Returns a mutable reference to the element at the `idx`-th
+    /// position of the vector if the index is `in_bounds`;
+    /// returns the [`IndexError`] describing the first axis that is
+    /// out of bounds otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_v::*;
+    ///
+    /// let mut vec = vec![
+    ///     vec![0, 1, 2],
+    ///     vec![3],
+    ///     vec![4, 5],
+    /// ];
+    ///
+    /// *vec.get_mut([0, 1]).unwrap() = 42;
+    /// assert_eq!(vec.at([0, 1]), 42);
+    ///
+    /// // vec.at_mut([3, 0]); // panics!
+    /// let error = vec.get_mut([3, 0]).unwrap_err();
+    /// assert_eq!(error.card_idx, IdxLeqD1::IdxD0([]));
+    /// assert_eq!((error.idx, error.card), (3, 3));
+    /// ```
+    fn get_mut(&mut self, idx: impl IntoIdx<D>) -> Result<&mut T, IndexError<D>> {
+        D::check_bounds(idx, self)?;
+        Ok(self.at_mut(idx))
+    }
+
+    /// Returns a mutable reference to the element at the `idx`-th position of the
+    /// vector without bounds checks.
+    ///
+    /// This is the mutable counterpart of [`at_unchecked`]: storage-backed vectors skip
+    /// validating the index, while the remaining vectors fall back to [`at_mut`].
+    ///
+    /// In debug builds, the index is validated by a `debug_assert`.
+    ///
+    /// [`at_unchecked`]: crate::NVec::at_unchecked
+    /// [`at_mut`]: crate::NVecMut::at_mut
+    ///
+    /// # Safety
+    ///
+    /// The `idx` must be `in_bounds`; calling this method with an out of bounds index
+    /// is *undefined behavior*.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_v::*;
+    ///
+    /// let mut vec = vec![
+    ///     vec![0, 1, 2],
+    ///     vec![3],
+    /// ];
+    ///
+    /// // SAFETY: [1, 0] is in bounds
+    /// *unsafe { vec.at_mut_unchecked([1, 0]) } = 42;
+    /// assert_eq!(vec, vec![vec![0, 1, 2], vec![42]]);
+    /// ```
+    #[inline(always)]
+    unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<D>) -> &mut T {
+        debug_assert!(D::in_bounds(idx, self), "index is out of bounds");
+        self.at_mut(idx)
+    }
 }
 
 // &mut V auto impl
@@ -230,6 +295,10 @@ impl<T, D: Dim, V: NVecMut<D, T>> NVecMut<D, T> for &mut V {
         <V as NVecMut<D, T>>::at_mut(self, idx)
     }
 
+    unsafe fn at_mut_unchecked(&mut self, idx: impl IntoIdx<D>) -> &mut T {
+        unsafe { <V as NVecMut<D, T>>::at_mut_unchecked(self, idx) }
+    }
+
     fn set<Idx: IntoIdx<D>>(&mut self, idx: Idx, value: T) {
         <V as NVecMut<D, T>>::set(self, idx, value);
     }
@@ -0,0 +1,132 @@
+use crate::{Dim, NVec};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+/// A `D` dimensional vector whose elements can be iterated over in parallel using rayon.
+///
+/// Parallelization is achieved by splitting the vector over its children; and
+/// elements of each child are then iterated over sequentially. Therefore, this trait
+/// is implemented by storage-backed vectors which are made up of independent children,
+/// such as `Vec<Vec<T>>`, `FlatJagged`, `ndarray` arrays or `SplitVec`.
+///
+/// Collecting the parallel iterators yields the elements in the same order as the
+/// sequential counterparts; i.e., `par_all` and `all` are equivalent except for the
+/// parallel execution.
+///
+/// Requires the **rayon** feature.
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+/// use rayon::prelude::*;
+///
+/// let v2 = vec![vec![1, 2], vec![], vec![3, 4, 5]];
+///
+/// let sum: i32 = v2.par_all().sum();
+/// assert_eq!(sum, 15);
+///
+/// let doubled: Vec<_> = v2.par_all().map(|x| 2 * x).collect();
+/// assert_eq!(doubled, v2.all().map(|x| 2 * x).collect::<Vec<_>>());
+/// ```
+pub trait NVecPar<D: Dim, T: Send>: NVec<D, T> + Sync {
+    /// Returns a parallel iterator of all children of the vector.
+    ///
+    /// Note that children of a `D1` vector are scalars rather than vectors;
+    /// hence, the iterator is empty for one-dimensional vectors.
+    /// [`par_all`] can be used instead to iterate over the elements.
+    ///
+    /// [`par_all`]: crate::NVecPar::par_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let v2 = vec![vec![1, 2], vec![], vec![3, 4, 5]];
+    ///
+    /// let sums: Vec<i32> = v2.par_children().map(|x| x.all().sum()).collect();
+    /// assert_eq!(sums, vec![3, 0, 12]);
+    /// ```
+    fn par_children(&self) -> impl IndexedParallelIterator<Item = impl NVec<D::PrevDim, T> + Send>;
+
+    /// Returns a parallel iterator over all scalar (D0) elements of the vector.
+    ///
+    /// Collecting the iterator yields the elements in the same order as [`all`].
+    ///
+    /// [`all`]: crate::NVec::all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let v3 = vec![vec![vec![1], vec![2, 3]], vec![vec![4, 5, 6]]];
+    ///
+    /// let all: Vec<_> = v3.par_all().collect();
+    /// assert_eq!(all, vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(v3.par_all().max(), Some(6));
+    /// ```
+    fn par_all(&self) -> impl ParallelIterator<Item = T>;
+
+    /// Returns a parallel iterator over all scalar (D0) elements of the vector
+    /// together with their indices.
+    ///
+    /// Collecting the iterator yields the elements in the same order as [`enumerate_all`].
+    ///
+    /// [`enumerate_all`]: crate::NVec::enumerate_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let v2 = vec![vec![1, 2], vec![], vec![3]];
+    ///
+    /// let all: Vec<_> = v2.par_enumerate_all().collect();
+    /// assert_eq!(all, vec![([0, 0], 1), ([0, 1], 2), ([2, 0], 3)]);
+    /// ```
+    fn par_enumerate_all(&self) -> impl ParallelIterator<Item = (D::Idx, T)>
+    where
+        D::Idx: Send;
+}
+
+// &V auto impl
+
+impl<T: Send, D: Dim, V: NVecPar<D, T>> NVecPar<D, T> for &V {
+    fn par_children(&self) -> impl IndexedParallelIterator<Item = impl NVec<D::PrevDim, T> + Send> {
+        <V as NVecPar<D, T>>::par_children(self)
+    }
+
+    fn par_all(&self) -> impl ParallelIterator<Item = T> {
+        <V as NVecPar<D, T>>::par_all(self)
+    }
+
+    fn par_enumerate_all(&self) -> impl ParallelIterator<Item = (D::Idx, T)>
+    where
+        D::Idx: Send,
+    {
+        <V as NVecPar<D, T>>::par_enumerate_all(self)
+    }
+}
+
+// &mut V auto impl
+
+impl<T: Send, D: Dim, V: NVecPar<D, T>> NVecPar<D, T> for &mut V {
+    fn par_children(&self) -> impl IndexedParallelIterator<Item = impl NVec<D::PrevDim, T> + Send> {
+        <V as NVecPar<D, T>>::par_children(self)
+    }
+
+    fn par_all(&self) -> impl ParallelIterator<Item = T> {
+        <V as NVecPar<D, T>>::par_all(self)
+    }
+
+    fn par_enumerate_all(&self) -> impl ParallelIterator<Item = (D::Idx, T)>
+    where
+        D::Idx: Send,
+    {
+        <V as NVecPar<D, T>>::par_enumerate_all(self)
+    }
+}
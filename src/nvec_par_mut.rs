@@ -0,0 +1,42 @@
+use crate::{Dim, NVecMut};
+
+/// A `D` dimensional mutable vector whose elements can be mutated in parallel using rayon.
+///
+/// Similar to [`NVecPar`], parallelization is achieved by splitting the vector over its
+/// disjoint children.
+///
+/// Requires the **rayon** feature.
+///
+/// [`NVecPar`]: crate::NVecPar
+pub trait NVecParMut<D: Dim, T: Send>: NVecMut<D, T> {
+    /// Applies the mutation `f` to all scalar elements of the vector in parallel.
+    ///
+    /// This is the parallel counterpart of [`mut_all`].
+    ///
+    /// [`mut_all`]: crate::NVecMut::mut_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v2 = vec![vec![1, 2], vec![], vec![3, 4, 5]];
+    ///
+    /// v2.par_mut_all(|x| *x *= 10);
+    /// assert_eq!(v2, vec![vec![10, 20], vec![], vec![30, 40, 50]]);
+    /// ```
+    fn par_mut_all<F>(&mut self, f: F)
+    where
+        F: Fn(&mut T) + Sync + Send;
+}
+
+// &mut V auto impl
+
+impl<T: Send, D: Dim, V: NVecParMut<D, T>> NVecParMut<D, T> for &mut V {
+    fn par_mut_all<F>(&mut self, f: F)
+    where
+        F: Fn(&mut T) + Sync + Send,
+    {
+        <V as NVecParMut<D, T>>::par_mut_all(self, f)
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{Dim, IntoIdx, NVecCore, RefVec};
+
+/// A `D` dimensional vector providing access to its elements by reference.
+///
+/// Unlike [`NVec`] which requires elements to be returned by value, and hence,
+/// is mostly convenient for `Copy` types, `NVecRef` allows to access elements of
+/// a storage of non-`Copy` types, such as `Vec<String>`, without cloning.
+///
+/// `NVecRef` is implemented by storage-backed vectors such as `Vec`, arrays, slices,
+/// `ndarray` arrays, etc.; and nested combinations of them.
+///
+/// Any `NVecRef<D, T>` can be viewed as an `NVec<D, &T>` by calling [`as_nvec_of_refs`];
+/// so that it can be used with generic algorithms defined on [`NVec`].
+///
+/// Note that, since elements are not required to be `Copy`, nested containers such as
+/// `Vec<Vec<T>>` implement both `NVecRef<D2, T>` and `NVecRef<D1, Vec<T>>`. Therefore,
+/// the dimension or the element type might need to be specified when it cannot be inferred
+/// from the context, such as `NVecRef::<D2, _>::all_ref(&vec)`.
+///
+/// [`NVec`]: crate::NVec
+/// [`as_nvec_of_refs`]: crate::NVecRef::as_nvec_of_refs
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// fn longest<'a>(words: impl NVec<D2, &'a String>) -> Option<&'a String> {
+///     words.all().max_by_key(|x| x.len())
+/// }
+///
+/// let words = vec![
+///     vec![String::from("lazy"), String::from("dog")],
+///     vec![],
+///     vec![String::from("quick")],
+/// ];
+///
+/// let word: &String = words.at_ref([0, 1]);
+/// assert_eq!(word, "dog");
+/// assert_eq!(NVecRef::<D2, _>::all_ref(&words).count(), 3);
+///
+/// assert_eq!(longest(words.as_nvec_of_refs()), Some(&words[2][0]));
+/// ```
+pub trait NVecRef<D: Dim, T>: NVecCore<D, T> {
+    /// Returns a reference to the element at the `idx`-th position of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `idx` is not `in_bounds`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v1 = vec![String::from("a"), String::from("b")];
+    /// assert_eq!(v1.at_ref([1]), "b");
+    ///
+    /// let v2 = [vec![String::from("x")], vec![String::from("y"), String::from("z")]];
+    /// let y: &String = v2.at_ref([1, 0]);
+    /// assert_eq!(y, "y");
+    /// ```
+    fn at_ref(&self, idx: impl IntoIdx<D>) -> &T;
+
+    /// Returns an iterator of references to all elements of the vector in
+    /// lexicographic order of their indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = vec![vec![String::from("a")], vec![], vec![String::from("b")]];
+    /// let joined: String = NVecRef::<D2, String>::all_ref(&v2)
+    ///     .map(|x| x.as_str())
+    ///     .collect();
+    /// assert_eq!(joined, "ab");
+    /// ```
+    fn all_ref<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a;
+
+    /// Creates a view of this vector as an `NVec<D, &T>` which can be used with any
+    /// generic algorithm defined on [`NVec`].
+    ///
+    /// [`NVec`]: crate::NVec
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// fn total_len<'a>(words: &impl V1<&'a String>) -> usize {
+    ///     words.all().map(|x| x.len()).sum()
+    /// }
+    ///
+    /// let words = vec![String::from("quick"), String::from("fox")];
+    /// let refs = words.as_nvec_of_refs();
+    ///
+    /// assert_eq!(refs.at(0), "quick");
+    /// assert_eq!(total_len(&refs), 8);
+    /// ```
+    fn as_nvec_of_refs(&self) -> RefVec<'_, D, T, Self> {
+        RefVec::new(self)
+    }
+}
+
+// &V auto impl
+
+impl<T, D: Dim, V: NVecRef<D, T>> NVecRef<D, T> for &V {
+    #[inline(always)]
+    fn at_ref(&self, idx: impl IntoIdx<D>) -> &T {
+        <V as NVecRef<D, T>>::at_ref(self, idx)
+    }
+
+    fn all_ref<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        <V as NVecRef<D, T>>::all_ref(self)
+    }
+}
+
+// &mut V auto impl
+
+impl<T, D: Dim, V: NVecRef<D, T>> NVecRef<D, T> for &mut V {
+    #[inline(always)]
+    fn at_ref(&self, idx: impl IntoIdx<D>) -> &T {
+        <V as NVecRef<D, T>>::at_ref(self, idx)
+    }
+
+    fn all_ref<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        <V as NVecRef<D, T>>::all_ref(self)
+    }
+}
@@ -0,0 +1,138 @@
+use super::PermutedVec;
+use crate::children::{ChildD2D1, ChildD3D2, ChildD4D3, ChildD5D4, ChildD6D5};
+use crate::common_trait_helpers::indices::*;
+use crate::{dim::*, Card, NVec, NVecAllMut, NVecCoreSealed, NVecMut};
+use crate::{RectangularCardD2, RectangularCardD3, RectangularCardD4};
+use crate::{RectangularCardD5, RectangularCardD6};
+use alloc::vec::Vec;
+
+macro_rules! impl_permuted {
+    ($dim:ty, $card:ident, $child:ident, $indices:ident) => {
+        impl<T, V> NVecCoreSealed<$dim, T> for PermutedVec<$dim, T, V>
+        where
+            V: NVec<$dim, T>,
+        {
+            #[inline(always)]
+            fn core_num_children(&self) -> usize {
+                self.shape[0]
+            }
+
+            #[inline(always)]
+            fn core_card(&self, idx: impl Into<<$dim as Dim>::CardIdx>) -> usize {
+                $card::from(self.shape).cardinality_of(idx)
+            }
+
+            fn core_child(
+                &self,
+                i: <$dim as Dim>::ChildIdx,
+            ) -> impl NVecCoreSealed<<$dim as Dim>::PrevDim, T> {
+                $child::<_, T> {
+                    i,
+                    parent: self,
+                    phantom: Default::default(),
+                }
+            }
+
+            fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<$dim>, f: &mut F) -> O {
+                self.vec.core_map(self.src_idx(idx.into_idx()), f)
+            }
+
+            fn core_is_rectangular(&self) -> bool {
+                true
+            }
+        }
+
+        impl<T, V> NVec<$dim, T> for PermutedVec<$dim, T, V>
+        where
+            V: NVec<$dim, T>,
+        {
+            #[inline(always)]
+            fn at(&self, idx: impl IntoIdx<$dim>) -> T {
+                self.vec.at(self.src_idx(idx.into_idx()))
+            }
+
+            fn child(&self, i: <$dim as Dim>::ChildIdx) -> impl NVec<<$dim as Dim>::PrevDim, T> {
+                $child {
+                    i,
+                    parent: self,
+                    phantom: Default::default(),
+                }
+            }
+
+            fn all(&self) -> impl Iterator<Item = T> {
+                $indices(self).map(|idx| self.at(idx))
+            }
+        }
+
+        impl<T, V> NVecMut<$dim, T> for PermutedVec<$dim, T, V>
+        where
+            V: NVecMut<$dim, T>,
+        {
+            #[inline(always)]
+            fn at_mut<Idx: IntoIdx<$dim>>(&mut self, idx: Idx) -> &mut T {
+                let idx = self.src_idx(idx.into_idx());
+                self.vec.at_mut(idx)
+            }
+
+            #[inline(always)]
+            fn set<Idx: IntoIdx<$dim>>(&mut self, idx: Idx, value: T) {
+                let idx = self.src_idx(idx.into_idx());
+                self.vec.set(idx, value);
+            }
+
+            fn child_mut(
+                &mut self,
+                i: <$dim as Dim>::ChildIdx,
+            ) -> impl NVecMut<<$dim as Dim>::PrevDim, T> {
+                $child {
+                    i,
+                    parent: self,
+                    phantom: Default::default(),
+                }
+            }
+
+            fn mut_all<F>(&mut self, f: F)
+            where
+                F: FnMut(&mut T),
+            {
+                self.vec.mut_all(f);
+            }
+
+            fn reset_all(&mut self, value: T)
+            where
+                T: PartialEq + Copy,
+            {
+                self.vec.reset_all(value);
+            }
+        }
+
+        impl<T, V> NVecAllMut<$dim, T> for PermutedVec<$dim, T, V>
+        where
+            V: NVecAllMut<$dim, T>,
+        {
+            fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+            where
+                T: 'a,
+            {
+                let indices: Vec<_> = $indices(self).map(|idx| self.src_idx(idx)).collect();
+                self.vec.all_mut_in(indices.into_iter())
+            }
+
+            fn enumerate_all_mut<'a>(
+                &'a mut self,
+            ) -> impl Iterator<Item = (<$dim as Dim>::Idx, &'a mut T)>
+            where
+                T: 'a,
+            {
+                let indices: Vec<_> = $indices(self).collect();
+                indices.into_iter().zip(self.all_mut())
+            }
+        }
+    };
+}
+
+impl_permuted!(D2, RectangularCardD2, ChildD2D1, indices_d2);
+impl_permuted!(D3, RectangularCardD3, ChildD3D2, indices_d3);
+impl_permuted!(D4, RectangularCardD4, ChildD4D3, indices_d4);
+impl_permuted!(D5, RectangularCardD5, ChildD5D4, indices_d5);
+impl_permuted!(D6, RectangularCardD6, ChildD6D5, indices_d6);
@@ -0,0 +1,7 @@
+mod impl_into_cached;
+mod impl_nvec;
+mod permute_axes;
+mod permuted_vec;
+
+pub use permute_axes::{PermuteAxes, Transpose};
+pub use permuted_vec::PermutedVec;
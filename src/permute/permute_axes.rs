@@ -0,0 +1,234 @@
+use super::PermutedVec;
+use crate::{Dim, NVec, NVecMut, D2, D3, D4, D5, D6};
+
+/// Creates lazy views of rectangular vectors with permuted axes.
+pub trait PermuteAxes<D: Dim, T>: NVec<D, T> + Sized {
+    /// Converts the vector into a lazy view such that the `q`-th axis of the view is
+    /// the `axes[q]`-th axis of this vector.
+    ///
+    /// The view does not copy the elements; every access is mapped to the
+    /// underlying vector. When the underlying vector is mutable, so is the view.
+    ///
+    /// See [`as_permuted_axes`] and [`as_permuted_axes_mut`] to create views over
+    /// a reference of the vector.
+    ///
+    /// [`as_permuted_axes`]: crate::PermuteAxes::as_permuted_axes
+    /// [`as_permuted_axes_mut`]: crate::PermuteAxes::as_permuted_axes_mut
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * the vector is not rectangular; i.e., [`is_rectangular`] is false, or
+    /// * `axes` is not a permutation of `0..D::dimension()`.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v3 = V.d3().fun(|[i, j, k]| 100 * i + 10 * j + k).with_rectangular_bounds([2, 3, 4]);
+    ///
+    /// let p3 = v3.into_permuted_axes([2, 0, 1]);
+    /// assert_eq!(p3.card([]), 4);
+    /// assert_eq!(p3.at([3, 1, 2]), 123);
+    /// ```
+    ///
+    /// Jagged vectors cannot be permuted.
+    ///
+    /// ```should_panic
+    /// use orx_v::*;
+    ///
+    /// let v3 = vec![vec![vec![0, 1], vec![2]]];
+    /// let _ = v3.into_permuted_axes([2, 1, 0]);
+    /// ```
+    fn into_permuted_axes(self, axes: D::Idx) -> PermutedVec<D, T, Self>;
+
+    /// Creates a lazy view of the vector such that the `q`-th axis of the view is
+    /// the `axes[q]`-th axis of this vector.
+    ///
+    /// The view does not copy the elements; every access is mapped to the
+    /// underlying vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * the vector is not rectangular; i.e., [`is_rectangular`] is false, or
+    /// * `axes` is not a permutation of `0..D::dimension()`.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v3 = vec![
+    ///     vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7]],
+    ///     vec![vec![8, 9, 10, 11], vec![12, 13, 14, 15]],
+    ///     vec![vec![16, 17, 18, 19], vec![20, 21, 22, 23]],
+    /// ];
+    ///
+    /// let p3 = v3.as_permuted_axes([1, 2, 0]);
+    /// assert_eq!(p3.card([]), 2);
+    /// assert_eq!(p3.card([0]), 4);
+    /// assert_eq!(p3.card([0, 0]), 3);
+    /// assert_eq!(p3.at([1, 3, 2]), v3[2][1][3]);
+    /// assert_eq!(p3.child(1).child(3).equality(&[7, 15, 23]), Equality::Equal);
+    /// ```
+    fn as_permuted_axes(&self, axes: D::Idx) -> PermutedVec<D, T, &Self>;
+
+    /// Creates a mutable lazy view of the vector such that the `q`-th axis of the view
+    /// is the `axes[q]`-th axis of this vector.
+    ///
+    /// The view does not copy the elements; every access and mutation is mapped to the
+    /// underlying vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * the vector is not rectangular; i.e., [`is_rectangular`] is false, or
+    /// * `axes` is not a permutation of `0..D::dimension()`.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v3 = vec![
+    ///     vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7]],
+    ///     vec![vec![8, 9, 10, 11], vec![12, 13, 14, 15]],
+    /// ];
+    ///
+    /// let mut p3 = v3.as_permuted_axes_mut([2, 1, 0]);
+    /// p3.set([3, 1, 0], 42);
+    /// assert_eq!(v3[0][1][3], 42);
+    /// ```
+    fn as_permuted_axes_mut(&mut self, axes: D::Idx) -> PermutedVec<D, T, &mut Self>
+    where
+        Self: NVecMut<D, T>;
+}
+
+macro_rules! impl_permute_axes {
+    ($dim:ty) => {
+        impl<T, V> PermuteAxes<$dim, T> for V
+        where
+            V: NVec<$dim, T>,
+        {
+            fn into_permuted_axes(self, axes: <$dim as Dim>::Idx) -> PermutedVec<$dim, T, Self> {
+                PermutedVec::<$dim, T, Self>::new(self, axes)
+            }
+
+            fn as_permuted_axes(&self, axes: <$dim as Dim>::Idx) -> PermutedVec<$dim, T, &Self> {
+                PermutedVec::<$dim, T, &Self>::new(self, axes)
+            }
+
+            fn as_permuted_axes_mut(
+                &mut self,
+                axes: <$dim as Dim>::Idx,
+            ) -> PermutedVec<$dim, T, &mut Self>
+            where
+                Self: NVecMut<$dim, T>,
+            {
+                PermutedVec::<$dim, T, &mut Self>::new(self, axes)
+            }
+        }
+    };
+}
+
+impl_permute_axes!(D2);
+impl_permute_axes!(D3);
+impl_permute_axes!(D4);
+impl_permute_axes!(D5);
+impl_permute_axes!(D6);
+
+/// Creates transposed views of rectangular `D2` vectors.
+pub trait Transpose<T>: NVec<D2, T> + Sized {
+    /// Converts the rectangular `D2` vector into a lazy transposed view; i.e., the
+    /// element of the view at `[i, j]` is the element of this vector at `[j, i]`.
+    ///
+    /// This is equivalent to `into_permuted_axes([1, 0])`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is not rectangular; i.e., [`is_rectangular`] is false.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = V.d2().fun(|[i, j]| 10 * i + j).with_rectangular_bounds([4, 2]);
+    ///
+    /// let t = v2.into_transposed();
+    /// assert_eq!(t.card([]), 2);
+    /// assert_eq!(t.child(1).equality(&[1, 11, 21, 31]), Equality::Equal);
+    /// ```
+    fn into_transposed(self) -> PermutedVec<D2, T, Self> {
+        PermutedVec::<D2, T, Self>::new(self, [1, 0])
+    }
+
+    /// Creates a lazy transposed view of the rectangular `D2` vector; i.e., the
+    /// element of the view at `[i, j]` is the element of this vector at `[j, i]`.
+    ///
+    /// This is equivalent to `as_permuted_axes([1, 0])`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is not rectangular; i.e., [`is_rectangular`] is false.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = vec![vec![0, 1, 2], vec![3, 4, 5]];
+    ///
+    /// let t = v2.as_transposed();
+    /// assert_eq!(t.card([]), 3);
+    /// assert_eq!(t.card([2]), 2);
+    /// assert_eq!(t.at([2, 1]), 5);
+    /// assert_eq!(t.equality(&[[0, 3], [1, 4], [2, 5]]), Equality::Equal);
+    /// ```
+    fn as_transposed(&self) -> PermutedVec<D2, T, &Self> {
+        PermutedVec::<D2, T, &Self>::new(self, [1, 0])
+    }
+
+    /// Creates a mutable lazy transposed view of the rectangular `D2` vector; i.e., the
+    /// element of the view at `[i, j]` is the element of this vector at `[j, i]`.
+    ///
+    /// This is equivalent to `as_permuted_axes_mut([1, 0])`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is not rectangular; i.e., [`is_rectangular`] is false.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v2 = vec![vec![0, 1, 2], vec![3, 4, 5]];
+    ///
+    /// let mut t = v2.as_transposed_mut();
+    /// t.child_mut(1).set([0], 10);
+    /// assert_eq!(v2[0][1], 10);
+    /// ```
+    fn as_transposed_mut(&mut self) -> PermutedVec<D2, T, &mut Self>
+    where
+        Self: NVecMut<D2, T>,
+    {
+        PermutedVec::<D2, T, &mut Self>::new(self, [1, 0])
+    }
+}
+
+impl<T, V: NVec<D2, T>> Transpose<T> for V {}
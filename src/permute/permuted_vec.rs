@@ -0,0 +1,143 @@
+use crate::common_trait_helpers::debug::*;
+use crate::common_trait_helpers::shape::rectangular_shape;
+use crate::{dim::*, IntoDyn, NVec};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A lazy view of a rectangular vector of dimension `D` with permuted axes.
+///
+/// The `q`-th axis of the permuted vector is the `axes[q]`-th axis of the
+/// underlying vector. In other words, the element of the permuted vector at
+/// `idx` is the element of the underlying vector at index `src` where
+/// `src[axes[q]] = idx[q]` for all `q`.
+///
+/// It is created by the methods of the [`Transpose`] and [`PermuteAxes`] traits.
+///
+/// [`Transpose`]: crate::Transpose
+/// [`PermuteAxes`]: crate::PermuteAxes
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let v3 = V.d3().fun(|[i, j, k]| 100 * i + 10 * j + k).with_rectangular_bounds([2, 3, 4]);
+///
+/// let p3 = v3.into_permuted_axes([2, 0, 1]);
+/// assert_eq!(p3.card([]), 4);
+/// assert_eq!(p3.card([3]), 2);
+/// assert_eq!(p3.card([3, 1]), 3);
+/// assert_eq!(p3.at([3, 1, 2]), 123);
+/// assert_eq!(p3.child(3).child(1).at([2]), 123);
+/// ```
+pub struct PermutedVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D, T>,
+{
+    pub(super) vec: V,
+    pub(super) axes: D::Idx,
+    pub(super) shape: D::Idx,
+    phantom: PhantomData<T>,
+}
+
+impl<D, T, V> PermutedVec<D, T, V>
+where
+    D: Dim,
+    V: NVec<D, T>,
+{
+    /// Destructs the permuted view into the underlying vector.
+    pub fn into_inner(self) -> V {
+        self.vec
+    }
+
+    /// Returns the permutation of the axes, such that the `q`-th axis of the
+    /// permuted vector is the `axes[q]`-th axis of the underlying vector.
+    pub fn axes(&self) -> D::Idx {
+        self.axes
+    }
+
+    /// Returns the shape of the permuted vector; i.e., number of elements along
+    /// each of its axes.
+    pub fn shape(&self) -> D::Idx {
+        self.shape
+    }
+}
+
+macro_rules! impl_new {
+    ($dim:ty, $n:expr) => {
+        impl<T, V> PermutedVec<$dim, T, V>
+        where
+            V: NVec<$dim, T>,
+        {
+            pub(super) fn new(vec: V, axes: [usize; $n]) -> Self {
+                assert!(
+                    vec.is_rectangular(),
+                    "Axes of a jagged vector cannot be permuted; vector must have rectangular cardinality."
+                );
+                let mut is_used = [false; $n];
+                for a in axes {
+                    assert!(
+                        a < $n && !is_used[a],
+                        "Axes {:?} is not a permutation of 0..{}",
+                        axes,
+                        $n
+                    );
+                    is_used[a] = true;
+                }
+
+                let src_shape: [usize; $n] = rectangular_shape(&vec.as_dyn());
+                let shape = axes.map(|a| src_shape[a]);
+
+                Self {
+                    vec,
+                    axes,
+                    shape,
+                    phantom: PhantomData,
+                }
+            }
+
+            #[inline(always)]
+            pub(super) fn src_idx(&self, idx: [usize; $n]) -> [usize; $n] {
+                let mut src = [0; $n];
+                for (q, a) in self.axes.iter().enumerate() {
+                    src[*a] = idx[q];
+                }
+                src
+            }
+        }
+    };
+}
+
+impl_new!(D2, 2);
+impl_new!(D3, 3);
+impl_new!(D4, 4);
+impl_new!(D5, 5);
+impl_new!(D6, 6);
+
+macro_rules! impl_debug {
+    ($dim:ty, $dbg_fn:ident) => {
+        impl<T, V> Debug for PermutedVec<$dim, T, V>
+        where
+            V: NVec<$dim, T>,
+            T: Debug,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{{ kind: PermutedVec, dim: D{}, axes: {:?}, values: ",
+                    <$dim as Dim>::dimension(),
+                    self.axes,
+                )?;
+                $dbg_fn(f, self)?;
+                write!(f, " }}")
+            }
+        }
+    };
+}
+
+impl_debug!(D2, dbg_values_d2);
+impl_debug!(D3, dbg_values_d3);
+impl_debug!(D4, dbg_values_d4);
+impl_debug!(D5, dbg_values_d5);
+impl_debug!(D6, dbg_values_d6);
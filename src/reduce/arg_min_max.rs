@@ -0,0 +1,121 @@
+use crate::common_trait_helpers::indices::*;
+use crate::{Dim, NVec, D1, D2, D3, D4, D5, D6};
+
+/// Finds indices of the minimum and maximum elements of vectors.
+pub trait ArgMinMax<D: Dim, T>: NVec<D, T> {
+    /// Returns the index of the minimum element of the vector; None if the vector
+    /// is empty.
+    ///
+    /// If several elements are equally minimum, index of the first one in the order
+    /// yielded by [`all`] is returned. Elements which are not comparable to
+    /// themselves, such as NaN, are ignored unless all elements are such.
+    ///
+    /// [`all`]: crate::NVec::all
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = vec![vec![4, 2, 7], vec![], vec![1, 9, 1]];
+    /// assert_eq!(v2.argmin(), Some([2, 0]));
+    /// assert_eq!(v2.argmax(), Some([2, 1]));
+    ///
+    /// let v1: Vec<f64> = vec![];
+    /// assert_eq!(v1.argmin(), None);
+    /// ```
+    fn argmin(&self) -> Option<D::Idx>
+    where
+        T: PartialOrd;
+
+    /// Returns the index of the maximum element of the vector; None if the vector
+    /// is empty.
+    ///
+    /// If several elements are equally maximum, index of the first one in the order
+    /// yielded by [`all`] is returned. Elements which are not comparable to
+    /// themselves, such as NaN, are ignored unless all elements are such.
+    ///
+    /// [`all`]: crate::NVec::all
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v3 = V.d3().fun(|[i, j, k]| (i + j) * k).with_rectangular_bounds([2, 3, 4]);
+    /// assert_eq!(v3.argmin(), Some([0, 0, 0]));
+    /// assert_eq!(v3.argmax(), Some([1, 2, 3]));
+    /// ```
+    fn argmax(&self) -> Option<D::Idx>
+    where
+        T: PartialOrd;
+}
+
+macro_rules! impl_arg_min_max {
+    ($dim:ty, $indices:ident) => {
+        impl<T, V> ArgMinMax<$dim, T> for V
+        where
+            V: NVec<$dim, T>,
+        {
+            fn argmin(&self) -> Option<<$dim as Dim>::Idx>
+            where
+                T: PartialOrd,
+            {
+                assert!(
+                    self.is_bounded(),
+                    "Cannot find argmin of an unbounded vector"
+                );
+                $indices(self)
+                    .zip(self.all())
+                    .fold(None, |min, (idx, x)| match &min {
+                        Some((_, m)) if x < *m || (is_incomparable(m) && !is_incomparable(&x)) => {
+                            Some((idx, x))
+                        }
+                        Some(_) => min,
+                        None => Some((idx, x)),
+                    })
+                    .map(|(idx, _)| idx)
+            }
+
+            fn argmax(&self) -> Option<<$dim as Dim>::Idx>
+            where
+                T: PartialOrd,
+            {
+                assert!(
+                    self.is_bounded(),
+                    "Cannot find argmax of an unbounded vector"
+                );
+                $indices(self)
+                    .zip(self.all())
+                    .fold(None, |max, (idx, x)| match &max {
+                        Some((_, m)) if x > *m || (is_incomparable(m) && !is_incomparable(&x)) => {
+                            Some((idx, x))
+                        }
+                        Some(_) => max,
+                        None => Some((idx, x)),
+                    })
+                    .map(|(idx, _)| idx)
+            }
+        }
+    };
+}
+
+#[inline(always)]
+fn is_incomparable<T: PartialOrd>(x: &T) -> bool {
+    x.partial_cmp(x).is_none()
+}
+
+impl_arg_min_max!(D1, indices_d1);
+impl_arg_min_max!(D2, indices_d2);
+impl_arg_min_max!(D3, indices_d3);
+impl_arg_min_max!(D4, indices_d4);
+impl_arg_min_max!(D5, indices_d5);
+impl_arg_min_max!(D6, indices_d6);
@@ -0,0 +1,14 @@
+use super::ReducedVec;
+use crate::{Dim, IntoCached, NVec};
+use core::hash::Hash;
+
+impl<D, T, V, O, F> IntoCached<D::PrevDim, O> for ReducedVec<D, T, V, O, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    F: Fn(O, T) -> O,
+    O: Copy,
+    <D::PrevDim as Dim>::Idx: Ord + Hash,
+    Self: NVec<D::PrevDim, O>,
+{
+}
@@ -0,0 +1,127 @@
+use super::ReducedVec;
+use crate::cardinality::IsRectangular;
+use crate::children::{ChildD2D1, ChildD3D2, ChildD4D3, ChildD5D4};
+use crate::common_trait_helpers::indices::*;
+use crate::{dim::*, NVec, NVecCoreSealed};
+
+// D2 -> D1
+
+impl<T, V, O, F> NVecCoreSealed<D1, O> for ReducedVec<D2, T, V, O, F>
+where
+    V: NVec<D2, T>,
+    F: Fn(O, T) -> O,
+    O: Clone,
+{
+    #[inline(always)]
+    fn core_num_children(&self) -> usize {
+        self.card_at(&[])
+    }
+
+    #[inline(always)]
+    fn core_card(&self, _: impl Into<<D1 as Dim>::CardIdx>) -> usize {
+        self.card_at(&[])
+    }
+
+    fn core_child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D1 as Dim>::PrevDim, O> {
+        self
+    }
+
+    fn core_map<G: FnMut(&O) -> X, X>(&self, idx: impl IntoIdx<D1>, f: &mut G) -> X {
+        f(&self.reduce(idx.into_idx()))
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        true
+    }
+}
+
+impl<T, V, O, F> NVec<D1, O> for ReducedVec<D2, T, V, O, F>
+where
+    V: NVec<D2, T>,
+    F: Fn(O, T) -> O,
+    O: Clone,
+{
+    #[inline(always)]
+    fn at(&self, idx: impl IntoIdx<D1>) -> O {
+        self.reduce(idx.into_idx())
+    }
+
+    fn child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVec<<D1 as Dim>::PrevDim, O> {
+        self
+    }
+
+    fn all(&self) -> impl Iterator<Item = O> {
+        (0..self.card_at(&[])).map(|i| self.reduce([i]))
+    }
+}
+
+// D3..D6 -> D2..D5
+
+macro_rules! impl_reduced {
+    ($src_dim:ty, $dim:ty, $child:ident, $indices:ident) => {
+        impl<T, V, O, F> NVecCoreSealed<$dim, O> for ReducedVec<$src_dim, T, V, O, F>
+        where
+            V: NVec<$src_dim, T>,
+            F: Fn(O, T) -> O,
+            O: Clone,
+        {
+            #[inline(always)]
+            fn core_num_children(&self) -> usize {
+                self.card_at(&[])
+            }
+
+            #[inline(always)]
+            fn core_card(&self, idx: impl Into<<$dim as Dim>::CardIdx>) -> usize {
+                self.card_at(idx.into().as_slice())
+            }
+
+            fn core_child(
+                &self,
+                i: <$dim as Dim>::ChildIdx,
+            ) -> impl NVecCoreSealed<<$dim as Dim>::PrevDim, O> {
+                $child::<_, O> {
+                    i,
+                    parent: self,
+                    phantom: Default::default(),
+                }
+            }
+
+            fn core_map<G: FnMut(&O) -> X, X>(&self, idx: impl IntoIdx<$dim>, f: &mut G) -> X {
+                f(&self.reduce(idx.into_idx()))
+            }
+
+            fn core_is_rectangular(&self) -> bool {
+                <$dim as IsRectangular>::is_rectangular(self)
+            }
+        }
+
+        impl<T, V, O, F> NVec<$dim, O> for ReducedVec<$src_dim, T, V, O, F>
+        where
+            V: NVec<$src_dim, T>,
+            F: Fn(O, T) -> O,
+            O: Clone,
+        {
+            #[inline(always)]
+            fn at(&self, idx: impl IntoIdx<$dim>) -> O {
+                self.reduce(idx.into_idx())
+            }
+
+            fn child(&self, i: <$dim as Dim>::ChildIdx) -> impl NVec<<$dim as Dim>::PrevDim, O> {
+                $child {
+                    i,
+                    parent: self,
+                    phantom: Default::default(),
+                }
+            }
+
+            fn all(&self) -> impl Iterator<Item = O> {
+                $indices(self).map(|idx| self.reduce(idx))
+            }
+        }
+    };
+}
+
+impl_reduced!(D3, D2, ChildD2D1, indices_d2);
+impl_reduced!(D4, D3, ChildD3D2, indices_d3);
+impl_reduced!(D5, D4, ChildD4D3, indices_d4);
+impl_reduced!(D6, D5, ChildD5D4, indices_d5);
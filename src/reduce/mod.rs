@@ -0,0 +1,9 @@
+mod arg_min_max;
+mod impl_into_cached;
+mod impl_nvec;
+mod reduce_axis;
+mod reduced_vec;
+
+pub use arg_min_max::ArgMinMax;
+pub use reduce_axis::ReduceAxis;
+pub use reduced_vec::ReducedVec;
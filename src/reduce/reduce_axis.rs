@@ -0,0 +1,435 @@
+use super::ReducedVec;
+use crate::{Dim, NVec, D2, D3, D4, D5, D6};
+use core::cmp::Ordering;
+use core::ops::Add;
+
+/// Creates lazy vectors of one lower dimension by reducing vectors along one of
+/// their axes.
+pub trait ReduceAxis<D: Dim, T>: NVec<D, T> + Sized {
+    /// Converts the vector into a lazy vector of dimension `D::PrevDim` by reducing it
+    /// along the given `axis`.
+    ///
+    /// The element of the reduced vector at `idx` is obtained by folding the elements
+    /// of this vector at indices `idx` with `k` inserted at position `axis` for all
+    /// `k` along the axis, starting from `init` and applying `f`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `axis` is not less than `D::dimension()`, or
+    /// * the vector is unbounded, or
+    /// * any of the sub-vectors at depth `axis` is jagged, in which case the reduction
+    ///   is not well-defined. The last axis of any bounded vector can be reduced, while
+    ///   the first axis can be reduced only if [`is_rectangular`] is true.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = V.d2().fun(|[i, j]| i + j).with_rectangular_bounds([2, 3]);
+    ///
+    /// let products_of_rows = v2.into_reduced_axis(1, 1, |a, b| a * b);
+    /// assert_eq!(products_of_rows.equality(&[0, 6]), Equality::Equal);
+    /// ```
+    fn into_reduced_axis<O, F>(self, axis: usize, init: O, f: F) -> ReducedVec<D, T, Self, O, F>
+    where
+        O: Clone,
+        F: Fn(O, T) -> O;
+
+    /// Creates a lazy vector of dimension `D::PrevDim` by reducing this vector along the
+    /// given `axis`.
+    ///
+    /// The element of the reduced vector at `idx` is obtained by folding the elements
+    /// of this vector at indices `idx` with `k` inserted at position `axis` for all
+    /// `k` along the axis, starting from `init` and applying `f`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `axis` is not less than `D::dimension()`, or
+    /// * the vector is unbounded, or
+    /// * any of the sub-vectors at depth `axis` is jagged, in which case the reduction
+    ///   is not well-defined. The last axis of any bounded vector can be reduced, while
+    ///   the first axis can be reduced only if [`is_rectangular`] is true.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    ///
+    /// let products_of_rows = v2.as_reduced_axis(1, 1, |a, b| a * b);
+    /// assert_eq!(products_of_rows.equality(&[6, 120]), Equality::Equal);
+    ///
+    /// let products_of_cols = v2.as_reduced_axis(0, 1, |a, b| a * b);
+    /// assert_eq!(products_of_cols.equality(&[4, 10, 18]), Equality::Equal);
+    ///
+    /// let num_odds_of_rows = v2.as_reduced_axis(1, 0, |n, x| n + x % 2);
+    /// assert_eq!(num_odds_of_rows.equality(&[2, 1]), Equality::Equal);
+    ///
+    /// // the last axis of a jagged vector can be reduced
+    /// let jagged = vec![vec![1, 2, 3], vec![], vec![4]];
+    /// let products_of_rows = jagged.as_reduced_axis(1, 1, |a, b| a * b);
+    /// assert_eq!(products_of_rows.equality(&[6, 1, 4]), Equality::Equal);
+    /// ```
+    fn as_reduced_axis<O, F>(&self, axis: usize, init: O, f: F) -> ReducedVec<D, T, &Self, O, F>
+    where
+        O: Clone,
+        F: Fn(O, T) -> O;
+
+    /// Converts the vector into a lazy vector of dimension `D::PrevDim` by summing up its
+    /// elements along the given `axis`.
+    ///
+    /// This is equivalent to `into_reduced_axis(axis, T::default(), |a, b| a + b)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `axis` is not less than `D::dimension()`, or
+    /// * the vector is unbounded, or
+    /// * any of the sub-vectors at depth `axis` is jagged, in which case the reduction
+    ///   is not well-defined. The last axis of any bounded vector can be reduced, while
+    ///   the first axis can be reduced only if [`is_rectangular`] is true.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = V.d2().fun(|[i, j]| i + j).with_rectangular_bounds([2, 3]);
+    ///
+    /// let row_sums = v2.into_sum_axis(1);
+    /// assert_eq!(row_sums.equality(&[3, 6]), Equality::Equal);
+    /// ```
+    fn into_sum_axis(self, axis: usize) -> ReducedVec<D, T, Self, T, impl Fn(T, T) -> T>
+    where
+        T: Clone + Default + Add<Output = T>;
+
+    /// Creates a lazy vector of dimension `D::PrevDim` by summing up the elements of this
+    /// vector along the given `axis`.
+    ///
+    /// This is equivalent to `as_reduced_axis(axis, T::default(), |a, b| a + b)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `axis` is not less than `D::dimension()`, or
+    /// * the vector is unbounded, or
+    /// * any of the sub-vectors at depth `axis` is jagged, in which case the reduction
+    ///   is not well-defined. The last axis of any bounded vector can be reduced, while
+    ///   the first axis can be reduced only if [`is_rectangular`] is true.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    ///
+    /// let row_sums = v2.as_sum_axis(1);
+    /// assert_eq!(row_sums.equality(&[6, 15]), Equality::Equal);
+    ///
+    /// let col_sums = v2.as_sum_axis(0);
+    /// assert_eq!(col_sums.equality(&[5, 7, 9]), Equality::Equal);
+    ///
+    /// let jagged = vec![vec![1, 2, 3], vec![], vec![4]];
+    /// let row_sums = jagged.as_sum_axis(1);
+    /// assert_eq!(row_sums.equality(&[6, 0, 4]), Equality::Equal);
+    /// ```
+    fn as_sum_axis(&self, axis: usize) -> ReducedVec<D, T, &Self, T, impl Fn(T, T) -> T>
+    where
+        T: Clone + Default + Add<Output = T>;
+
+    /// Converts the vector into a lazy vector of dimension `D::PrevDim` such that each
+    /// element is the minimum of its elements along the given `axis` with respect to
+    /// the `compare` function.
+    ///
+    /// Similar to [`Iterator::min_by`], elements of the reduced vector are None if
+    /// the axis is empty; and the first minimum element is returned if several
+    /// elements are equally minimum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `axis` is not less than `D::dimension()`, or
+    /// * the vector is unbounded, or
+    /// * any of the sub-vectors at depth `axis` is jagged, in which case the reduction
+    ///   is not well-defined. The last axis of any bounded vector can be reduced, while
+    ///   the first axis can be reduced only if [`is_rectangular`] is true.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = V.d2().fun(|[i, j]| (i + 1) * (3 - j)).with_rectangular_bounds([2, 3]);
+    ///
+    /// let min = v2.into_min_by_axis(1, |a, b| a.cmp(b));
+    /// assert_eq!(min.equality(&[Some(1), Some(2)]), Equality::Equal);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn into_min_by_axis<C>(
+        self,
+        axis: usize,
+        compare: C,
+    ) -> ReducedVec<D, T, Self, Option<T>, impl Fn(Option<T>, T) -> Option<T>>
+    where
+        T: Clone,
+        C: Fn(&T, &T) -> Ordering;
+
+    /// Creates a lazy vector of dimension `D::PrevDim` such that each element is the
+    /// minimum of the elements of this vector along the given `axis` with respect to
+    /// the `compare` function.
+    ///
+    /// Similar to [`Iterator::min_by`], elements of the reduced vector are None if
+    /// the axis is empty; and the first minimum element is returned if several
+    /// elements are equally minimum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `axis` is not less than `D::dimension()`, or
+    /// * the vector is unbounded, or
+    /// * any of the sub-vectors at depth `axis` is jagged, in which case the reduction
+    ///   is not well-defined. The last axis of any bounded vector can be reduced, while
+    ///   the first axis can be reduced only if [`is_rectangular`] is true.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let costs = vec![vec![4.0f64, 2.0, 7.0], vec![1.0, 9.0, 3.0]];
+    ///
+    /// let min_costs = costs.as_min_by_axis(1, |a, b| a.total_cmp(b));
+    /// assert_eq!(min_costs.equality(&[Some(2.0), Some(1.0)]), Equality::Equal);
+    ///
+    /// let jagged: Vec<Vec<f64>> = vec![vec![3.0, 1.0], vec![]];
+    /// let min_costs = jagged.as_min_by_axis(1, |a, b| a.total_cmp(b));
+    /// assert_eq!(min_costs.equality(&[Some(1.0), None]), Equality::Equal);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn as_min_by_axis<C>(
+        &self,
+        axis: usize,
+        compare: C,
+    ) -> ReducedVec<D, T, &Self, Option<T>, impl Fn(Option<T>, T) -> Option<T>>
+    where
+        T: Clone,
+        C: Fn(&T, &T) -> Ordering;
+
+    /// Converts the vector into a lazy vector of dimension `D::PrevDim` such that each
+    /// element is the maximum of its elements along the given `axis` with respect to
+    /// the `compare` function.
+    ///
+    /// Similar to [`Iterator::max_by`], elements of the reduced vector are None if
+    /// the axis is empty; and the last maximum element is returned if several
+    /// elements are equally maximum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `axis` is not less than `D::dimension()`, or
+    /// * the vector is unbounded, or
+    /// * any of the sub-vectors at depth `axis` is jagged, in which case the reduction
+    ///   is not well-defined. The last axis of any bounded vector can be reduced, while
+    ///   the first axis can be reduced only if [`is_rectangular`] is true.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = V.d2().fun(|[i, j]| (i + 1) * (3 - j)).with_rectangular_bounds([2, 3]);
+    ///
+    /// let max = v2.into_max_by_axis(0, |a, b| a.cmp(b));
+    /// assert_eq!(max.equality(&[Some(6), Some(4), Some(2)]), Equality::Equal);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn into_max_by_axis<C>(
+        self,
+        axis: usize,
+        compare: C,
+    ) -> ReducedVec<D, T, Self, Option<T>, impl Fn(Option<T>, T) -> Option<T>>
+    where
+        T: Clone,
+        C: Fn(&T, &T) -> Ordering;
+
+    /// Creates a lazy vector of dimension `D::PrevDim` such that each element is the
+    /// maximum of the elements of this vector along the given `axis` with respect to
+    /// the `compare` function.
+    ///
+    /// Similar to [`Iterator::max_by`], elements of the reduced vector are None if
+    /// the axis is empty; and the last maximum element is returned if several
+    /// elements are equally maximum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `axis` is not less than `D::dimension()`, or
+    /// * the vector is unbounded, or
+    /// * any of the sub-vectors at depth `axis` is jagged, in which case the reduction
+    ///   is not well-defined. The last axis of any bounded vector can be reduced, while
+    ///   the first axis can be reduced only if [`is_rectangular`] is true.
+    ///
+    /// [`is_rectangular`]: crate::NVec::is_rectangular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = vec![vec![4, 2, 7], vec![1, 9, 3]];
+    ///
+    /// let col_max = v2.as_max_by_axis(0, |a, b| a.cmp(b));
+    /// assert_eq!(col_max.equality(&[Some(4), Some(9), Some(7)]), Equality::Equal);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn as_max_by_axis<C>(
+        &self,
+        axis: usize,
+        compare: C,
+    ) -> ReducedVec<D, T, &Self, Option<T>, impl Fn(Option<T>, T) -> Option<T>>
+    where
+        T: Clone,
+        C: Fn(&T, &T) -> Ordering;
+}
+
+macro_rules! impl_reduce_axis {
+    ($dim:ty) => {
+        impl<T, V> ReduceAxis<$dim, T> for V
+        where
+            V: NVec<$dim, T>,
+        {
+            fn into_reduced_axis<O, F>(
+                self,
+                axis: usize,
+                init: O,
+                f: F,
+            ) -> ReducedVec<$dim, T, Self, O, F>
+            where
+                O: Clone,
+                F: Fn(O, T) -> O,
+            {
+                ReducedVec::<$dim, T, Self, O, F>::new(self, axis, init, f)
+            }
+
+            fn as_reduced_axis<O, F>(
+                &self,
+                axis: usize,
+                init: O,
+                f: F,
+            ) -> ReducedVec<$dim, T, &Self, O, F>
+            where
+                O: Clone,
+                F: Fn(O, T) -> O,
+            {
+                ReducedVec::<$dim, T, &Self, O, F>::new(self, axis, init, f)
+            }
+
+            fn into_sum_axis(self, axis: usize) -> ReducedVec<$dim, T, Self, T, impl Fn(T, T) -> T>
+            where
+                T: Clone + Default + Add<Output = T>,
+            {
+                self.into_reduced_axis(axis, T::default(), |a, b| a + b)
+            }
+
+            fn as_sum_axis(&self, axis: usize) -> ReducedVec<$dim, T, &Self, T, impl Fn(T, T) -> T>
+            where
+                T: Clone + Default + Add<Output = T>,
+            {
+                self.as_reduced_axis(axis, T::default(), |a, b| a + b)
+            }
+
+            fn into_min_by_axis<C>(
+                self,
+                axis: usize,
+                compare: C,
+            ) -> ReducedVec<$dim, T, Self, Option<T>, impl Fn(Option<T>, T) -> Option<T>>
+            where
+                T: Clone,
+                C: Fn(&T, &T) -> Ordering,
+            {
+                self.into_reduced_axis(axis, None, min_by(compare))
+            }
+
+            fn as_min_by_axis<C>(
+                &self,
+                axis: usize,
+                compare: C,
+            ) -> ReducedVec<$dim, T, &Self, Option<T>, impl Fn(Option<T>, T) -> Option<T>>
+            where
+                T: Clone,
+                C: Fn(&T, &T) -> Ordering,
+            {
+                self.as_reduced_axis(axis, None, min_by(compare))
+            }
+
+            fn into_max_by_axis<C>(
+                self,
+                axis: usize,
+                compare: C,
+            ) -> ReducedVec<$dim, T, Self, Option<T>, impl Fn(Option<T>, T) -> Option<T>>
+            where
+                T: Clone,
+                C: Fn(&T, &T) -> Ordering,
+            {
+                self.into_reduced_axis(axis, None, max_by(compare))
+            }
+
+            fn as_max_by_axis<C>(
+                &self,
+                axis: usize,
+                compare: C,
+            ) -> ReducedVec<$dim, T, &Self, Option<T>, impl Fn(Option<T>, T) -> Option<T>>
+            where
+                T: Clone,
+                C: Fn(&T, &T) -> Ordering,
+            {
+                self.as_reduced_axis(axis, None, max_by(compare))
+            }
+        }
+    };
+}
+
+impl_reduce_axis!(D2);
+impl_reduce_axis!(D3);
+impl_reduce_axis!(D4);
+impl_reduce_axis!(D5);
+impl_reduce_axis!(D6);
+
+fn min_by<T, C>(compare: C) -> impl Fn(Option<T>, T) -> Option<T>
+where
+    C: Fn(&T, &T) -> Ordering,
+{
+    move |min, x| match min {
+        Some(min) if compare(&x, &min) != Ordering::Less => Some(min),
+        _ => Some(x),
+    }
+}
+
+fn max_by<T, C>(compare: C) -> impl Fn(Option<T>, T) -> Option<T>
+where
+    C: Fn(&T, &T) -> Ordering,
+{
+    move |max, x| match max {
+        Some(max) if compare(&x, &max) == Ordering::Less => Some(max),
+        _ => Some(x),
+    }
+}
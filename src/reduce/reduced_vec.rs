@@ -0,0 +1,199 @@
+use crate::common_trait_helpers::debug::*;
+use crate::common_trait_helpers::shape::rectangular_shape;
+use crate::{dim::*, DynNVec, IntoDyn, NVec};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A lazy vector of dimension `D::PrevDim` obtained by reducing the vector of
+/// dimension `D` along one of its axes.
+///
+/// The element of the reduced vector at `idx` is obtained by folding the elements
+/// of the underlying vector at indices `idx` with `k` inserted at position `axis`
+/// for all `k` along the axis, starting from `init` and applying `f`.
+///
+/// The underlying vector might be jagged as long as the reduction is well-defined;
+/// i.e., all sub-vectors at depth `axis` are rectangular. For instance, the last
+/// axis of any vector can be reduced, such as the per-row sums of a jagged `D2`
+/// vector; while the first axis can be reduced only if the vector is rectangular.
+///
+/// Elements are not stored, the reduction is computed each time an element is
+/// accessed. Therefore, it might be useful to call [`into_cached`] on the reduced
+/// vector when it is accessed repeatedly.
+///
+/// It is created by the methods of the [`ReduceAxis`] trait.
+///
+/// [`into_cached`]: crate::IntoCached::into_cached
+/// [`ReduceAxis`]: crate::ReduceAxis
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let v3 = V.d3().fun(|[i, j, k]| 100 * i + 10 * j + k).with_rectangular_bounds([2, 3, 4]);
+///
+/// let v2 = v3.as_reduced_axis(1, 0, |a, b| a + b);
+/// assert_eq!(v2.shape(), [2, 4]);
+/// assert_eq!(v2.at([1, 2]), 102 + 112 + 122);
+/// assert_eq!(v2.child(1).at([2]), 102 + 112 + 122);
+/// ```
+pub struct ReducedVec<D, T, V, O, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    F: Fn(O, T) -> O,
+{
+    pub(super) vec: V,
+    pub(super) axis: usize,
+    pub(super) init: O,
+    pub(super) f: F,
+    phantom: PhantomData<(D, T)>,
+}
+
+impl<D, T, V, O, F> ReducedVec<D, T, V, O, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    F: Fn(O, T) -> O,
+{
+    /// Destructs the reduced vector into the underlying vector.
+    pub fn into_inner(self) -> V {
+        self.vec
+    }
+
+    /// Returns the axis of the underlying vector along which its elements are reduced.
+    pub fn axis(&self) -> usize {
+        self.axis
+    }
+}
+
+macro_rules! impl_new {
+    ($dim:ty, $n:expr) => {
+        impl<T, V, O, F> ReducedVec<$dim, T, V, O, F>
+        where
+            V: NVec<$dim, T>,
+            F: Fn(O, T) -> O,
+            O: Clone,
+        {
+            pub(super) fn new(vec: V, axis: usize, init: O, f: F) -> Self {
+                assert!(
+                    axis < $n,
+                    "Axis {} is out of bounds for a vector of dimension D{}",
+                    axis,
+                    $n
+                );
+                assert!(vec.is_bounded(), "An unbounded vector cannot be reduced");
+                assert!(
+                    axis == $n - 1 || is_rectangular_at_depth(&vec.as_dyn(), axis),
+                    "Axis {} of the jagged vector cannot be reduced; all sub-vectors at depth {} must have rectangular cardinality.",
+                    axis,
+                    axis
+                );
+
+                Self {
+                    vec,
+                    axis,
+                    init,
+                    f,
+                    phantom: PhantomData,
+                }
+            }
+
+            /// Returns the shape of the reduced vector; i.e., number of elements along
+            /// each of its axes.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the reduced vector is not rectangular, which is possible when
+            /// a jagged vector is reduced.
+            pub fn shape(&self) -> [usize; $n - 1] {
+                assert!(
+                    NVec::<<$dim as Dim>::PrevDim, O>::is_rectangular(self),
+                    "A jagged reduced vector does not have a shape; vector must have rectangular cardinality."
+                );
+                rectangular_shape(&self.as_dyn())
+            }
+
+            /// Returns the cardinality of the reduced vector at the given `idx`.
+            pub(super) fn card_at(&self, idx: &[usize]) -> usize {
+                let vec = self.vec.as_dyn();
+                match idx.len() < self.axis {
+                    true => vec.card(idx),
+                    false => match vec.card(&idx[..self.axis]) {
+                        0 => 0,
+                        _ => {
+                            // sub-vectors at depth axis are rectangular; hence, any k
+                            // along the axis leads to the same cardinality
+                            let mut src = [0; $n];
+                            src[..self.axis].copy_from_slice(&idx[..self.axis]);
+                            src[(self.axis + 1)..=idx.len()].copy_from_slice(&idx[self.axis..]);
+                            vec.card(&src[..=idx.len()])
+                        }
+                    },
+                }
+            }
+
+            #[inline(always)]
+            pub(super) fn reduce(&self, idx: [usize; $n - 1]) -> O {
+                let len = self.vec.as_dyn().card(&idx[..self.axis]);
+                let mut src = [0; $n];
+                src[..self.axis].copy_from_slice(&idx[..self.axis]);
+                src[(self.axis + 1)..].copy_from_slice(&idx[self.axis..]);
+                (0..len).fold(self.init.clone(), |acc, k| {
+                    src[self.axis] = k;
+                    (self.f)(acc, self.vec.at(src))
+                })
+            }
+        }
+    };
+}
+
+impl_new!(D2, 2);
+impl_new!(D3, 3);
+impl_new!(D4, 4);
+impl_new!(D5, 5);
+impl_new!(D6, 6);
+
+/// Returns whether or not all sub-vectors of the `vec` at the given `depth` are
+/// rectangular.
+fn is_rectangular_at_depth<T>(vec: &impl DynNVec<T>, depth: usize) -> bool {
+    match depth {
+        0 => vec.is_rectangular(),
+        _ => {
+            for i in 0..vec.card([]) {
+                if !is_rectangular_at_depth(&vec.child(i), depth - 1) {
+                    return false;
+                }
+            }
+            true
+        }
+    }
+}
+
+macro_rules! impl_debug {
+    ($dim:ty, $dbg_fn:ident) => {
+        impl<T, V, O, F> Debug for ReducedVec<$dim, T, V, O, F>
+        where
+            V: NVec<$dim, T>,
+            F: Fn(O, T) -> O,
+            O: Clone + Debug,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{{ kind: ReducedVec, dim: D{}, axis: {}, values: ",
+                    <$dim as Dim>::dimension() - 1,
+                    self.axis,
+                )?;
+                $dbg_fn(f, self)?;
+                write!(f, " }}")
+            }
+        }
+    };
+}
+
+impl_debug!(D2, dbg_values_d1);
+impl_debug!(D3, dbg_values_d2);
+impl_debug!(D4, dbg_values_d3);
+impl_debug!(D5, dbg_values_d4);
+impl_debug!(D6, dbg_values_d5);
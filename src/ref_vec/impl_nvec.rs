@@ -0,0 +1,120 @@
+use super::RefVec;
+use crate::children::{ChildD2D1, ChildD3D2, ChildD4D3, ChildD5D4, ChildD6D5};
+use crate::{dim::*, NVec, NVecCoreSealed, NVecRef};
+
+impl<'a, T, V> NVecCoreSealed<D1, &'a T> for RefVec<'a, D1, T, V>
+where
+    V: NVecRef<D1, T>,
+{
+    #[inline(always)]
+    fn core_num_children(&self) -> usize {
+        self.vec.core_num_children()
+    }
+
+    #[inline(always)]
+    fn core_card(&self, idx: impl Into<<D1 as Dim>::CardIdx>) -> usize {
+        self.vec.core_card(idx)
+    }
+
+    fn core_child(
+        &self,
+        _: <D1 as Dim>::ChildIdx,
+    ) -> impl NVecCoreSealed<<D1 as Dim>::PrevDim, &'a T> {
+        *self
+    }
+
+    fn core_map<F: FnMut(&&'a T) -> O, O>(&self, idx: impl IntoIdx<D1>, f: &mut F) -> O {
+        f(&self.vec.at_ref(idx))
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        true
+    }
+}
+
+impl<'a, T, V> NVec<D1, &'a T> for RefVec<'a, D1, T, V>
+where
+    V: NVecRef<D1, T>,
+{
+    #[inline(always)]
+    fn at(&self, idx: impl IntoIdx<D1>) -> &'a T {
+        self.vec.at_ref(idx)
+    }
+
+    fn child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVec<<D1 as Dim>::PrevDim, &'a T> {
+        *self
+    }
+
+    fn all(&self) -> impl Iterator<Item = &'a T> {
+        self.vec.all_ref()
+    }
+}
+
+macro_rules! impl_ref_vec {
+    ($dim:ty, $child:ident) => {
+        impl<'a, T, V> NVecCoreSealed<$dim, &'a T> for RefVec<'a, $dim, T, V>
+        where
+            V: NVecRef<$dim, T>,
+        {
+            #[inline(always)]
+            fn core_num_children(&self) -> usize {
+                self.vec.core_num_children()
+            }
+
+            #[inline(always)]
+            fn core_card(&self, idx: impl Into<<$dim as Dim>::CardIdx>) -> usize {
+                self.vec.core_card(idx)
+            }
+
+            fn core_child(
+                &self,
+                i: <$dim as Dim>::ChildIdx,
+            ) -> impl NVecCoreSealed<<$dim as Dim>::PrevDim, &'a T> {
+                $child::<_, &'a T> {
+                    i,
+                    parent: *self,
+                    phantom: Default::default(),
+                }
+            }
+
+            fn core_map<F: FnMut(&&'a T) -> O, O>(&self, idx: impl IntoIdx<$dim>, f: &mut F) -> O {
+                f(&self.vec.at_ref(idx))
+            }
+
+            fn core_is_rectangular(&self) -> bool {
+                self.vec.core_is_rectangular()
+            }
+        }
+
+        impl<'a, T, V> NVec<$dim, &'a T> for RefVec<'a, $dim, T, V>
+        where
+            V: NVecRef<$dim, T>,
+        {
+            #[inline(always)]
+            fn at(&self, idx: impl IntoIdx<$dim>) -> &'a T {
+                self.vec.at_ref(idx)
+            }
+
+            fn child(
+                &self,
+                i: <$dim as Dim>::ChildIdx,
+            ) -> impl NVec<<$dim as Dim>::PrevDim, &'a T> {
+                $child {
+                    i,
+                    parent: *self,
+                    phantom: Default::default(),
+                }
+            }
+
+            fn all(&self) -> impl Iterator<Item = &'a T> {
+                self.vec.all_ref()
+            }
+        }
+    };
+}
+
+impl_ref_vec!(D2, ChildD2D1);
+impl_ref_vec!(D3, ChildD3D2);
+impl_ref_vec!(D4, ChildD4D3);
+impl_ref_vec!(D5, ChildD5D4);
+impl_ref_vec!(D6, ChildD6D5);
@@ -0,0 +1,4 @@
+mod impl_nvec;
+mod nvec_of_refs;
+
+pub use nvec_of_refs::RefVec;
@@ -0,0 +1,95 @@
+use crate::common_trait_helpers::debug::*;
+use crate::{Dim, NVecRef, D1, D2, D3, D4, D5, D6};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A view of a vector providing access to its elements by reference, [`NVecRef<D, T>`],
+/// as a vector of references, `NVec<D, &T>`.
+///
+/// It is created by the [`as_nvec_of_refs`] method.
+///
+/// [`NVecRef<D, T>`]: crate::NVecRef
+/// [`as_nvec_of_refs`]: crate::NVecRef::as_nvec_of_refs
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let v2 = vec![vec![String::from("a")], vec![String::from("b"), String::from("c")]];
+/// let refs: RefVec<D2, _, _> = v2.as_nvec_of_refs();
+///
+/// assert_eq!(refs.card([1]), 2);
+/// assert_eq!(refs.at([1, 1]), "c");
+/// assert_eq!(refs.child(1).at([0]), "b");
+/// ```
+pub struct RefVec<'a, D, T, V>
+where
+    D: Dim,
+    V: NVecRef<D, T>,
+{
+    pub(super) vec: &'a V,
+    phantom: PhantomData<(D, T)>,
+}
+
+impl<'a, D, T, V> RefVec<'a, D, T, V>
+where
+    D: Dim,
+    V: NVecRef<D, T>,
+{
+    pub(crate) fn new(vec: &'a V) -> Self {
+        Self {
+            vec,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the underlying vector.
+    pub fn inner(&self) -> &'a V {
+        self.vec
+    }
+}
+
+impl<D, T, V> Clone for RefVec<'_, D, T, V>
+where
+    D: Dim,
+    V: NVecRef<D, T>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D, T, V> Copy for RefVec<'_, D, T, V>
+where
+    D: Dim,
+    V: NVecRef<D, T>,
+{
+}
+
+macro_rules! impl_debug {
+    ($dim:ty, $dbg_fn:ident) => {
+        impl<T, V> Debug for RefVec<'_, $dim, T, V>
+        where
+            V: NVecRef<$dim, T>,
+            T: Debug,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{{ kind: RefVec, dim: D{}, values: ",
+                    <$dim as Dim>::dimension(),
+                )?;
+                $dbg_fn(f, self.vec)?;
+                write!(f, " }}")
+            }
+        }
+    };
+}
+
+impl_debug!(D1, dbg_values_d1);
+impl_debug!(D2, dbg_values_d2);
+impl_debug!(D3, dbg_values_d3);
+impl_debug!(D4, dbg_values_d4);
+impl_debug!(D5, dbg_values_d5);
+impl_debug!(D6, dbg_values_d6);
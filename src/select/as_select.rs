@@ -0,0 +1,172 @@
+use super::{SelectVec, SelectVecD2};
+use crate::{NVec, NVecMut, D1, D2};
+
+/// Creates lazy index-remapping views of `D1` vectors.
+pub trait V1AsSelect<T>: NVec<D1, T> {
+    /// Creates a lazy view of the vector such that the `i`-th element of the view is
+    /// the `indices.at(i)`-th element of this vector; i.e., the view gathers the
+    /// elements at the given `indices`.
+    ///
+    /// The view has as many elements as `indices`, which might contain duplicates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` is unbounded.
+    ///
+    /// Accessing an element of the view panics if the corresponding index is out of
+    /// bounds of this vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// fn tour_length(positions: impl V1<f64>) -> f64 {
+    ///     let n = positions.card([]);
+    ///     (0..n)
+    ///         .map(|i| (positions.at(i) - positions.at((i + 1) % n)).abs())
+    ///         .sum()
+    /// }
+    ///
+    /// let positions = vec![0.0, 10.0, 3.0, 7.0];
+    /// let tour = vec![0, 2, 3, 1];
+    ///
+    /// let visited = positions.v1_select(&tour);
+    /// assert_eq!(visited.equality(&[0.0, 3.0, 7.0, 10.0]), Equality::Equal);
+    /// assert_eq!(tour_length(&visited), 20.0);
+    ///
+    /// // any V1<usize> can be used as indices
+    /// let odd = positions.v1_select(V.d1().fun(|[i]| 2 * i + 1).bounded(2));
+    /// assert_eq!(odd.equality(&[10.0, 7.0]), Equality::Equal);
+    /// ```
+    fn v1_select<I>(&self, indices: I) -> SelectVec<T, &Self, I>
+    where
+        I: NVec<D1, usize>,
+    {
+        SelectVec::new(self, indices)
+    }
+
+    /// Creates a lazy mutable view of the vector such that the `i`-th element of the
+    /// view is the `indices.at(i)`-th element of this vector.
+    ///
+    /// Setting the `i`-th element of the view scatters the value to the
+    /// `indices.at(i)`-th position of this vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` is unbounded.
+    ///
+    /// Accessing an element of the view panics if the corresponding index is out of
+    /// bounds of this vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v1 = vec![0; 5];
+    /// let indices = [4, 0, 2];
+    ///
+    /// let mut s = v1.v1_select_mut(&indices);
+    /// for (i, x) in [40, 1, 20].into_iter().enumerate() {
+    ///     s.set(i, x);
+    /// }
+    /// assert_eq!(v1, vec![1, 0, 20, 0, 40]);
+    ///
+    /// v1.v1_select_mut(&indices).mut_all(|x| *x += 1);
+    /// assert_eq!(v1, vec![2, 0, 21, 0, 41]);
+    /// ```
+    fn v1_select_mut<I>(&mut self, indices: I) -> SelectVec<T, &mut Self, I>
+    where
+        Self: NVecMut<D1, T>,
+        I: NVec<D1, usize>,
+    {
+        SelectVec::new(self, indices)
+    }
+}
+
+impl<T, V: NVec<D1, T>> V1AsSelect<T> for V {}
+
+/// Creates lazy index-remapping views of `D2` vectors.
+pub trait V2AsSelect<T>: NVec<D2, T> {
+    /// Creates a lazy view of the vector such that the element of the view at `[i, j]`
+    /// is the element of this vector at `[rows.at(i), cols.at(j)]`.
+    ///
+    /// The view is rectangular with as many children as `rows` and each child has as
+    /// many elements as `cols`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows` or `cols` is unbounded.
+    ///
+    /// Accessing an element of the view panics if the corresponding index is out of
+    /// bounds of this vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let distances = vec![
+    ///     vec![0, 3, 5, 9],
+    ///     vec![3, 0, 2, 4],
+    ///     vec![5, 2, 0, 1],
+    ///     vec![9, 4, 1, 0],
+    /// ];
+    ///
+    /// // distances from depots to customers
+    /// let depots = [0, 3];
+    /// let customers = [1, 2];
+    /// let sub = distances.v2_select(&depots, &customers);
+    /// assert_eq!(sub.equality(&[[3, 5], [4, 1]]), Equality::Equal);
+    ///
+    /// // the distance matrix of the tour order
+    /// let tour = vec![2, 0, 1];
+    /// let sub = distances.v2_select(&tour, &tour);
+    /// assert_eq!(sub.at([0, 1]), 5);
+    /// assert_eq!(sub.child(2).equality(&[2, 3, 0]), Equality::Equal);
+    /// ```
+    fn v2_select<R, C>(&self, rows: R, cols: C) -> SelectVecD2<T, &Self, R, C>
+    where
+        R: NVec<D1, usize>,
+        C: NVec<D1, usize>,
+    {
+        SelectVecD2::new(self, rows, cols)
+    }
+
+    /// Creates a lazy mutable view of the vector such that the element of the view at
+    /// `[i, j]` is the element of this vector at `[rows.at(i), cols.at(j)]`.
+    ///
+    /// Setting an element of the view scatters the value to the corresponding position
+    /// of this vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows` or `cols` is unbounded.
+    ///
+    /// Accessing an element of the view panics if the corresponding index is out of
+    /// bounds of this vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v2 = vec![vec![0; 3]; 3];
+    ///
+    /// let mut sub = v2.v2_select_mut([0, 2], [2, 0]);
+    /// sub.set([0, 0], 1);
+    /// sub.child_mut(1).set(1, 2);
+    /// assert_eq!(v2, vec![vec![0, 0, 1], vec![0, 0, 0], vec![2, 0, 0]]);
+    /// ```
+    fn v2_select_mut<R, C>(&mut self, rows: R, cols: C) -> SelectVecD2<T, &mut Self, R, C>
+    where
+        Self: NVecMut<D2, T>,
+        R: NVec<D1, usize>,
+        C: NVec<D1, usize>,
+    {
+        SelectVecD2::new(self, rows, cols)
+    }
+}
+
+impl<T, V: NVec<D2, T>> V2AsSelect<T> for V {}
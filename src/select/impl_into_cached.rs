@@ -0,0 +1,19 @@
+use super::{SelectVec, SelectVecD2};
+use crate::{IntoCached, NVec, D1, D2};
+
+impl<T, V, I> IntoCached<D1, T> for SelectVec<T, V, I>
+where
+    V: NVec<D1, T>,
+    I: NVec<D1, usize>,
+    T: Copy,
+{
+}
+
+impl<T, V, R, C> IntoCached<D2, T> for SelectVecD2<T, V, R, C>
+where
+    V: NVec<D2, T>,
+    R: NVec<D1, usize>,
+    C: NVec<D1, usize>,
+    T: Copy,
+{
+}
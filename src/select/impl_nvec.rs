@@ -0,0 +1,254 @@
+use super::{SelectVec, SelectVecD2};
+use crate::children::ChildD2D1;
+use crate::common_trait_helpers::indices::indices_d2;
+use crate::{dim::*, NVec, NVecAllMut, NVecCoreSealed, NVecMut};
+use alloc::vec::Vec;
+
+// d1
+
+impl<T, V, I> NVecCoreSealed<D1, T> for SelectVec<T, V, I>
+where
+    V: NVec<D1, T>,
+    I: NVec<D1, usize>,
+{
+    #[inline(always)]
+    fn core_num_children(&self) -> usize {
+        self.indices.card([])
+    }
+
+    #[inline(always)]
+    fn core_card(&self, _: impl Into<<D1 as Dim>::CardIdx>) -> usize {
+        self.indices.card([])
+    }
+
+    fn core_child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D1 as Dim>::PrevDim, T> {
+        self
+    }
+
+    #[inline(always)]
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D1>, f: &mut F) -> O {
+        self.vec.core_map([self.indices.at(idx)], f)
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        true
+    }
+}
+
+impl<T, V, I> NVec<D1, T> for SelectVec<T, V, I>
+where
+    V: NVec<D1, T>,
+    I: NVec<D1, usize>,
+{
+    #[inline(always)]
+    fn at(&self, idx: impl IntoIdx<D1>) -> T {
+        self.vec.at([self.indices.at(idx)])
+    }
+
+    fn child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVec<<D1 as Dim>::PrevDim, T> {
+        self
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        self.indices.all().map(|i| self.vec.at([i]))
+    }
+}
+
+impl<T, V, I> NVecMut<D1, T> for SelectVec<T, V, I>
+where
+    V: NVecMut<D1, T>,
+    I: NVec<D1, usize>,
+{
+    #[inline(always)]
+    fn at_mut<Idx: IntoIdx<D1>>(&mut self, idx: Idx) -> &mut T {
+        self.vec.at_mut([self.indices.at(idx)])
+    }
+
+    #[inline(always)]
+    fn set<Idx: IntoIdx<D1>>(&mut self, idx: Idx, value: T) {
+        self.vec.set([self.indices.at(idx)], value);
+    }
+
+    fn child_mut(&mut self, _: <D1 as Dim>::ChildIdx) -> impl NVecMut<<D1 as Dim>::PrevDim, T> {
+        self
+    }
+
+    fn mut_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for i in self.indices.all() {
+            f(self.vec.at_mut([i]));
+        }
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        for i in self.indices.all() {
+            self.vec.set([i], value);
+        }
+    }
+}
+
+impl<T, V, I> NVecAllMut<D1, T> for SelectVec<T, V, I>
+where
+    V: NVecAllMut<D1, T>,
+    I: NVec<D1, usize>,
+{
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        let indices: Vec<_> = self.indices.all().map(|i| [i]).collect();
+        self.vec.all_mut_in(indices.into_iter())
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D1 as Dim>::Idx, &'a mut T)>
+    where
+        T: 'a,
+    {
+        self.all_mut().enumerate().map(|(i, x)| ([i], x))
+    }
+}
+
+// d2
+
+impl<T, V, R, C> NVecCoreSealed<D2, T> for SelectVecD2<T, V, R, C>
+where
+    V: NVec<D2, T>,
+    R: NVec<D1, usize>,
+    C: NVec<D1, usize>,
+{
+    #[inline(always)]
+    fn core_num_children(&self) -> usize {
+        self.rows.card([])
+    }
+
+    #[inline(always)]
+    fn core_card(&self, idx: impl Into<<D2 as Dim>::CardIdx>) -> usize {
+        match idx.into() {
+            IdxLeqD1::IdxD0([]) => self.rows.card([]),
+            IdxLeqD1::IdxD1([i]) => {
+                let _ = self.rows.at([i]);
+                self.cols.card([])
+            }
+        }
+    }
+
+    fn core_child(&self, i: <D2 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D2 as Dim>::PrevDim, T> {
+        ChildD2D1 {
+            i,
+            parent: self,
+            phantom: Default::default(),
+        }
+    }
+
+    #[inline(always)]
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D2>, f: &mut F) -> O {
+        self.vec.core_map(self.src_idx(idx.into_idx()), f)
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        true
+    }
+}
+
+impl<T, V, R, C> NVec<D2, T> for SelectVecD2<T, V, R, C>
+where
+    V: NVec<D2, T>,
+    R: NVec<D1, usize>,
+    C: NVec<D1, usize>,
+{
+    #[inline(always)]
+    fn at(&self, idx: impl IntoIdx<D2>) -> T {
+        self.vec.at(self.src_idx(idx.into_idx()))
+    }
+
+    fn child(&self, i: <D2 as Dim>::ChildIdx) -> impl NVec<<D2 as Dim>::PrevDim, T> {
+        ChildD2D1 {
+            i,
+            parent: self,
+            phantom: Default::default(),
+        }
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        self.rows
+            .all()
+            .flat_map(move |i| self.cols.all().map(move |j| self.vec.at([i, j])))
+    }
+}
+
+impl<T, V, R, C> NVecMut<D2, T> for SelectVecD2<T, V, R, C>
+where
+    V: NVecMut<D2, T>,
+    R: NVec<D1, usize>,
+    C: NVec<D1, usize>,
+{
+    #[inline(always)]
+    fn at_mut<Idx: IntoIdx<D2>>(&mut self, idx: Idx) -> &mut T {
+        let idx = self.src_idx(idx.into_idx());
+        self.vec.at_mut(idx)
+    }
+
+    #[inline(always)]
+    fn set<Idx: IntoIdx<D2>>(&mut self, idx: Idx, value: T) {
+        let idx = self.src_idx(idx.into_idx());
+        self.vec.set(idx, value);
+    }
+
+    fn child_mut(&mut self, i: <D2 as Dim>::ChildIdx) -> impl NVecMut<<D2 as Dim>::PrevDim, T> {
+        ChildD2D1 {
+            i,
+            parent: self,
+            phantom: Default::default(),
+        }
+    }
+
+    fn mut_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for i in self.rows.all() {
+            for j in self.cols.all() {
+                f(self.vec.at_mut([i, j]));
+            }
+        }
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        for i in self.rows.all() {
+            for j in self.cols.all() {
+                self.vec.set([i, j], value);
+            }
+        }
+    }
+}
+
+impl<T, V, R, C> NVecAllMut<D2, T> for SelectVecD2<T, V, R, C>
+where
+    V: NVecAllMut<D2, T>,
+    R: NVec<D1, usize>,
+    C: NVec<D1, usize>,
+{
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        let indices: Vec<_> = indices_d2(self).map(|idx| self.src_idx(idx)).collect();
+        self.vec.all_mut_in(indices.into_iter())
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D2 as Dim>::Idx, &'a mut T)>
+    where
+        T: 'a,
+    {
+        let indices: Vec<_> = indices_d2(self).collect();
+        indices.into_iter().zip(self.all_mut())
+    }
+}
@@ -0,0 +1,9 @@
+mod as_select;
+mod impl_into_cached;
+mod impl_nvec;
+mod select_vec;
+mod select_vec_d2;
+
+pub use as_select::{V1AsSelect, V2AsSelect};
+pub use select_vec::SelectVec;
+pub use select_vec_d2::SelectVecD2;
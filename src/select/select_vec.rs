@@ -0,0 +1,69 @@
+use crate::common_trait_helpers::debug::*;
+use crate::{NVec, D1};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A lazy view of a `D1` vector such that its `i`-th element is the
+/// `indices.at(i)`-th element of the underlying vector.
+///
+/// It is created by the [`v1_select`] and [`v1_select_mut`] methods.
+///
+/// [`v1_select`]: crate::V1AsSelect::v1_select
+/// [`v1_select_mut`]: crate::V1AsSelect::v1_select_mut
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let cities = vec!['a', 'b', 'c', 'd'];
+/// let tour = vec![2, 0, 3, 1];
+///
+/// let visited = cities.v1_select(&tour);
+/// assert_eq!(visited.equality(&['c', 'a', 'd', 'b']), Equality::Equal);
+/// ```
+pub struct SelectVec<T, V, I>
+where
+    V: NVec<D1, T>,
+    I: NVec<D1, usize>,
+{
+    pub(super) vec: V,
+    pub(super) indices: I,
+    phantom: PhantomData<T>,
+}
+
+impl<T, V, I> SelectVec<T, V, I>
+where
+    V: NVec<D1, T>,
+    I: NVec<D1, usize>,
+{
+    pub(super) fn new(vec: V, indices: I) -> Self {
+        assert!(
+            indices.is_bounded(),
+            "Indices of a selection must be a bounded vector"
+        );
+        Self {
+            vec,
+            indices,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Destructs the view into the underlying vector and indices.
+    pub fn into_inner(self) -> (V, I) {
+        (self.vec, self.indices)
+    }
+}
+
+impl<T, V, I> Debug for SelectVec<T, V, I>
+where
+    V: NVec<D1, T>,
+    I: NVec<D1, usize>,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{{ kind: SelectVec, dim: D1, values: ")?;
+        dbg_values_d1(f, self)?;
+        write!(f, " }}")
+    }
+}
@@ -0,0 +1,80 @@
+use crate::common_trait_helpers::debug::*;
+use crate::{NVec, D1, D2};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A lazy view of a `D2` vector such that its element at `[i, j]` is the element of
+/// the underlying vector at `[rows.at(i), cols.at(j)]`.
+///
+/// It is created by the [`v2_select`] and [`v2_select_mut`] methods.
+///
+/// [`v2_select`]: crate::V2AsSelect::v2_select
+/// [`v2_select_mut`]: crate::V2AsSelect::v2_select_mut
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let distances = V.d2().fun(|[i, j]| 10 * i + j).with_rectangular_bounds([5, 5]);
+///
+/// let sub = distances.v2_select(&[4, 1], &[0, 2, 3]);
+/// assert_eq!(sub.card([]), 2);
+/// assert_eq!(sub.card([0]), 3);
+/// assert_eq!(sub.equality(&[[40, 42, 43], [10, 12, 13]]), Equality::Equal);
+/// ```
+pub struct SelectVecD2<T, V, R, C>
+where
+    V: NVec<D2, T>,
+    R: NVec<D1, usize>,
+    C: NVec<D1, usize>,
+{
+    pub(super) vec: V,
+    pub(super) rows: R,
+    pub(super) cols: C,
+    phantom: PhantomData<T>,
+}
+
+impl<T, V, R, C> SelectVecD2<T, V, R, C>
+where
+    V: NVec<D2, T>,
+    R: NVec<D1, usize>,
+    C: NVec<D1, usize>,
+{
+    pub(super) fn new(vec: V, rows: R, cols: C) -> Self {
+        assert!(
+            rows.is_bounded() && cols.is_bounded(),
+            "Row and column indices of a selection must be bounded vectors"
+        );
+        Self {
+            vec,
+            rows,
+            cols,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Destructs the view into the underlying vector, row indices and column indices.
+    pub fn into_inner(self) -> (V, R, C) {
+        (self.vec, self.rows, self.cols)
+    }
+
+    #[inline(always)]
+    pub(super) fn src_idx(&self, [i, j]: [usize; 2]) -> [usize; 2] {
+        [self.rows.at([i]), self.cols.at([j])]
+    }
+}
+
+impl<T, V, R, C> Debug for SelectVecD2<T, V, R, C>
+where
+    V: NVec<D2, T>,
+    R: NVec<D1, usize>,
+    C: NVec<D1, usize>,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{{ kind: SelectVecD2, dim: D2, values: ")?;
+        dbg_values_d2(f, self)?;
+        write!(f, " }}")
+    }
+}
@@ -0,0 +1,229 @@
+use super::{BlockVec, SliceVec};
+use crate::{NVec, NVecMut, D1, D2};
+use core::ops::Range;
+
+/// Creates zero-copy views of contiguous ranges of `D1` vectors.
+pub trait V1AsSlice<T>: NVec<D1, T> + Sized {
+    /// Converts the vector into a zero-copy view of its elements within the given
+    /// `range`; the `i`-th element of the slice is the `(range.start + i)`-th
+    /// element of this vector.
+    ///
+    /// See [`v1_as_slice`] and [`v1_as_slice_mut`] to create slices over a reference
+    /// of the vector.
+    ///
+    /// [`v1_as_slice`]: crate::V1AsSlice::v1_as_slice
+    /// [`v1_as_slice_mut`]: crate::V1AsSlice::v1_as_slice_mut
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `range` is out of bounds of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v1: Vec<_> = (0..100).collect();
+    ///
+    /// let s = v1.v1_into_slice(10..20);
+    /// assert_eq!(s.card([]), 10);
+    /// assert_eq!(s.at(3), 13);
+    /// assert_eq!(s.try_at(10), None);
+    ///
+    /// // slice of a slice
+    /// let ss = s.v1_into_slice(2..4);
+    /// assert_eq!(ss.equality(&[12, 13]), Equality::Equal);
+    /// ```
+    fn v1_into_slice(self, range: Range<usize>) -> SliceVec<T, Self> {
+        SliceVec::new(self, range)
+    }
+
+    /// Creates a zero-copy view of the elements of the vector within the given
+    /// `range`; the `i`-th element of the slice is the `(range.start + i)`-th
+    /// element of this vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `range` is out of bounds of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v1: Vec<_> = (0..100).collect();
+    ///
+    /// let s = v1.v1_as_slice(10..20);
+    /// assert_eq!(s.card([]), 10);
+    /// assert_eq!(s.at(3), 13);
+    /// ```
+    ///
+    /// Any `D1` vector can be sliced, including sparse or functional vectors.
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v1 = V.d1().sparse(0).bounded(100);
+    /// v1.set(15, 7);
+    ///
+    /// let s = v1.v1_as_slice(10..20);
+    /// assert_eq!(s.at(5), 7);
+    /// assert_eq!(s.all().sum::<i32>(), 7);
+    /// ```
+    fn v1_as_slice(&self, range: Range<usize>) -> SliceVec<T, &Self> {
+        SliceVec::new(self, range)
+    }
+
+    /// Creates a mutable zero-copy view of the elements of the vector within the given
+    /// `range`; the `i`-th element of the slice is the `(range.start + i)`-th
+    /// element of this vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `range` is out of bounds of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v1: Vec<_> = (0..100).collect();
+    ///
+    /// let mut s = v1.v1_as_slice_mut(50..60);
+    /// s.reset_all(0);
+    /// s.set(9, 42);
+    /// assert_eq!(&v1[48..61], &[48, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 60]);
+    /// ```
+    fn v1_as_slice_mut(&mut self, range: Range<usize>) -> SliceVec<T, &mut Self>
+    where
+        Self: NVecMut<D1, T>,
+    {
+        SliceVec::new(self, range)
+    }
+}
+
+impl<T, V: NVec<D1, T>> V1AsSlice<T> for V {}
+
+/// Creates zero-copy views of rectangular blocks of `D2` vectors.
+pub trait V2AsBlock<T>: NVec<D2, T> + Sized {
+    /// Converts the vector into a zero-copy view of its block defined by the range of
+    /// `rows` and the range of `cols`; the element of the block at `[i, j]` is the
+    /// element of this vector at `[rows.start + i, cols.start + j]`.
+    ///
+    /// The vector might be jagged; however, each child (row) within the `rows`
+    /// range must have at least `cols.end` elements. The block is always rectangular.
+    ///
+    /// See [`v2_as_block`] and [`v2_as_block_mut`] to create blocks over a reference
+    /// of the vector.
+    ///
+    /// [`v2_as_block`]: crate::V2AsBlock::v2_as_block
+    /// [`v2_as_block_mut`]: crate::V2AsBlock::v2_as_block_mut
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `rows` is out of bounds of the vector, or
+    /// * `cols` is out of bounds of any of the children within `rows`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = V.d2().fun(|[i, j]| 10 * i + j).with_rectangular_bounds([4, 4]);
+    ///
+    /// let b = v2.v2_into_block(1..3, 2..4);
+    /// assert_eq!(b.equality(&[[12, 13], [22, 23]]), Equality::Equal);
+    /// ```
+    fn v2_into_block(self, rows: Range<usize>, cols: Range<usize>) -> BlockVec<T, Self> {
+        BlockVec::new(self, rows, cols)
+    }
+
+    /// Creates a zero-copy view of the block of the vector defined by the range of
+    /// `rows` and the range of `cols`; the element of the block at `[i, j]` is the
+    /// element of this vector at `[rows.start + i, cols.start + j]`.
+    ///
+    /// The vector might be jagged; however, each child (row) within the `rows`
+    /// range must have at least `cols.end` elements. The block is always rectangular.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `rows` is out of bounds of the vector, or
+    /// * `cols` is out of bounds of any of the children within `rows`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v2 = vec![
+    ///     vec![0, 1, 2, 3],
+    ///     vec![4, 5, 6, 7],
+    ///     vec![8, 9],
+    /// ];
+    ///
+    /// let b = v2.v2_as_block(0..2, 1..4);
+    /// assert_eq!(b.card([]), 2);
+    /// assert_eq!(b.card([0]), 3);
+    /// assert!(b.is_rectangular());
+    /// assert_eq!(b.equality(&[[1, 2, 3], [5, 6, 7]]), Equality::Equal);
+    /// assert_eq!(b.child(1).at(2), 7);
+    /// ```
+    ///
+    /// Any `D2` vector can be viewed as a block, such as a flat jagged vector.
+    /// Matrices, on the other hand, provide their own [`as_block`] view.
+    ///
+    /// [`as_block`]: crate::Matrix::as_block
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v1: Vec<_> = (0..10).collect();
+    /// let jagged = v1.into_jagged_from_row_lengths(&[3, 4, 3]);
+    ///
+    /// let b = jagged.v2_as_block(1..3, 1..3);
+    /// assert_eq!(b.equality(&[[4, 5], [8, 9]]), Equality::Equal);
+    /// ```
+    fn v2_as_block(&self, rows: Range<usize>, cols: Range<usize>) -> BlockVec<T, &Self> {
+        BlockVec::new(self, rows, cols)
+    }
+
+    /// Creates a mutable zero-copy view of the block of the vector defined by the range
+    /// of `rows` and the range of `cols`; the element of the block at `[i, j]` is the
+    /// element of this vector at `[rows.start + i, cols.start + j]`.
+    ///
+    /// The vector might be jagged; however, each child (row) within the `rows`
+    /// range must have at least `cols.end` elements. The block is always rectangular.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `rows` is out of bounds of the vector, or
+    /// * `cols` is out of bounds of any of the children within `rows`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v2 = vec![
+    ///     vec![0, 1, 2, 3],
+    ///     vec![4, 5, 6, 7],
+    ///     vec![8, 9],
+    /// ];
+    ///
+    /// let mut b = v2.v2_as_block_mut(1..3, 0..2);
+    /// b.child_mut(1).set(0, 80);
+    /// b.mut_all(|x| *x *= 10);
+    /// assert_eq!(v2, vec![vec![0, 1, 2, 3], vec![40, 50, 6, 7], vec![800, 90]]);
+    /// ```
+    fn v2_as_block_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> BlockVec<T, &mut Self>
+    where
+        Self: NVecMut<D2, T>,
+    {
+        BlockVec::new(self, rows, cols)
+    }
+}
+
+impl<T, V: NVec<D2, T>> V2AsBlock<T> for V {}
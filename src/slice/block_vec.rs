@@ -0,0 +1,127 @@
+use super::panic_oob;
+use crate::common_trait_helpers::debug::*;
+use crate::{NVec, D2};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+/// A zero-copy view of a rectangular block of a `D2` vector, defined by a range
+/// of its rows and a range of columns.
+///
+/// The underlying vector might be jagged; however, every row of the block must
+/// have all columns of the block. Therefore, the block itself is always
+/// rectangular.
+///
+/// It is created by the methods of the [`V2AsBlock`] trait.
+///
+/// [`V2AsBlock`]: crate::V2AsBlock
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let v2 = vec![vec![0, 1, 2, 3], vec![4, 5, 6], vec![7, 8, 9, 10, 11]];
+///
+/// let b = v2.v2_as_block(1..3, 1..3);
+/// assert_eq!(b.card([]), 2);
+/// assert_eq!(b.card([1]), 2);
+/// assert_eq!(b.equality(&[[5, 6], [8, 9]]), Equality::Equal);
+/// ```
+pub struct BlockVec<T, V>
+where
+    V: NVec<D2, T>,
+{
+    pub(super) vec: V,
+    pub(super) rows: Range<usize>,
+    pub(super) cols: Range<usize>,
+    phantom: PhantomData<T>,
+}
+
+impl<T, V> BlockVec<T, V>
+where
+    V: NVec<D2, T>,
+{
+    pub(super) fn new(vec: V, rows: Range<usize>, cols: Range<usize>) -> Self {
+        assert!(
+            rows.start <= rows.end && rows.end <= vec.card([]),
+            "Row range {:?} is out of bounds of the D2 vector with {} children",
+            rows,
+            vec.card([])
+        );
+        assert!(cols.start <= cols.end, "Column range {:?} is invalid", cols);
+        for i in rows.clone() {
+            assert!(
+                cols.end <= vec.card([i]),
+                "Column range {:?} is out of bounds of the {}-th child of the D2 vector with {} elements",
+                cols,
+                i,
+                vec.card([i])
+            );
+        }
+
+        Self {
+            vec,
+            rows,
+            cols,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the range of rows of the underlying vector that the block is a view of.
+    pub fn rows(&self) -> Range<usize> {
+        self.rows.clone()
+    }
+
+    /// Returns the range of columns of the underlying vector that the block is a view of.
+    pub fn cols(&self) -> Range<usize> {
+        self.cols.clone()
+    }
+
+    /// Destructs the block into the underlying vector.
+    pub fn into_inner(self) -> V {
+        self.vec
+    }
+
+    #[inline(always)]
+    pub(super) fn num_rows(&self) -> usize {
+        self.rows.end - self.rows.start
+    }
+
+    #[inline(always)]
+    pub(super) fn num_cols(&self) -> usize {
+        self.cols.end - self.cols.start
+    }
+
+    #[inline(always)]
+    pub(super) fn src_row(&self, i: usize) -> usize {
+        match i < self.num_rows() {
+            true => self.rows.start + i,
+            false => panic_oob("Block row", i, self.num_rows()),
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn src_idx(&self, [i, j]: [usize; 2]) -> [usize; 2] {
+        match j < self.num_cols() {
+            true => [self.src_row(i), self.cols.start + j],
+            false => panic_oob("Block col", j, self.num_cols()),
+        }
+    }
+}
+
+impl<T, V> Debug for BlockVec<T, V>
+where
+    V: NVec<D2, T>,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{{ kind: BlockVec, dim: D2, rows: {:?}, cols: {:?}, values: ",
+            self.rows, self.cols
+        )?;
+        dbg_values_d2(f, self)?;
+        write!(f, " }}")
+    }
+}
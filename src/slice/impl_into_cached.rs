@@ -0,0 +1,16 @@
+use super::{BlockVec, SliceVec};
+use crate::{IntoCached, NVec, D1, D2};
+
+impl<T, V> IntoCached<D1, T> for SliceVec<T, V>
+where
+    V: NVec<D1, T>,
+    T: Copy,
+{
+}
+
+impl<T, V> IntoCached<D2, T> for BlockVec<T, V>
+where
+    V: NVec<D2, T>,
+    T: Copy,
+{
+}
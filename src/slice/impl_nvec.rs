@@ -0,0 +1,248 @@
+use super::{panic_oob, BlockVec, SliceVec};
+use crate::children::ChildD2D1;
+use crate::{dim::*, NVec, NVecAllMut, NVecCoreSealed, NVecMut};
+
+// slice
+
+impl<T, V> NVecCoreSealed<D1, T> for SliceVec<T, V>
+where
+    V: NVec<D1, T>,
+{
+    #[inline(always)]
+    fn core_num_children(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    fn core_card(&self, _: impl Into<<D1 as Dim>::CardIdx>) -> usize {
+        self.len
+    }
+
+    fn core_child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D1 as Dim>::PrevDim, T> {
+        self
+    }
+
+    #[inline(always)]
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D1>, f: &mut F) -> O {
+        let [i] = idx.into_idx();
+        self.vec.core_map([self.src_idx(i)], f)
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        true
+    }
+}
+
+impl<T, V> NVec<D1, T> for SliceVec<T, V>
+where
+    V: NVec<D1, T>,
+{
+    #[inline(always)]
+    fn at(&self, idx: impl IntoIdx<D1>) -> T {
+        let [i] = idx.into_idx();
+        self.vec.at([self.src_idx(i)])
+    }
+
+    fn child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVec<<D1 as Dim>::PrevDim, T> {
+        self
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        self.range().map(|i| self.vec.at([i]))
+    }
+}
+
+impl<T, V> NVecMut<D1, T> for SliceVec<T, V>
+where
+    V: NVecMut<D1, T>,
+{
+    #[inline(always)]
+    fn at_mut<Idx: IntoIdx<D1>>(&mut self, idx: Idx) -> &mut T {
+        let [i] = idx.into_idx();
+        let i = self.src_idx(i);
+        self.vec.at_mut([i])
+    }
+
+    #[inline(always)]
+    fn set<Idx: IntoIdx<D1>>(&mut self, idx: Idx, value: T) {
+        let [i] = idx.into_idx();
+        let i = self.src_idx(i);
+        self.vec.set([i], value);
+    }
+
+    fn child_mut(&mut self, _: <D1 as Dim>::ChildIdx) -> impl NVecMut<<D1 as Dim>::PrevDim, T> {
+        self
+    }
+
+    fn mut_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for i in self.range() {
+            f(self.vec.at_mut([i]));
+        }
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        for i in self.range() {
+            self.vec.set([i], value);
+        }
+    }
+}
+
+impl<T, V> NVecAllMut<D1, T> for SliceVec<T, V>
+where
+    V: NVecAllMut<D1, T>,
+{
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        let range = self.range();
+        self.vec.all_mut_in(range.map(|i| [i]))
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D1 as Dim>::Idx, &'a mut T)>
+    where
+        T: 'a,
+    {
+        self.all_mut().enumerate().map(|(i, x)| ([i], x))
+    }
+}
+
+// block
+
+impl<T, V> NVecCoreSealed<D2, T> for BlockVec<T, V>
+where
+    V: NVec<D2, T>,
+{
+    #[inline(always)]
+    fn core_num_children(&self) -> usize {
+        self.num_rows()
+    }
+
+    #[inline(always)]
+    fn core_card(&self, idx: impl Into<<D2 as Dim>::CardIdx>) -> usize {
+        match idx.into() {
+            IdxLeqD1::IdxD0([]) => self.num_rows(),
+            IdxLeqD1::IdxD1([i]) => match i < self.num_rows() {
+                true => self.num_cols(),
+                false => panic_oob("Block row", i, self.num_rows()),
+            },
+        }
+    }
+
+    fn core_child(&self, i: <D2 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D2 as Dim>::PrevDim, T> {
+        ChildD2D1 {
+            i,
+            parent: self,
+            phantom: Default::default(),
+        }
+    }
+
+    #[inline(always)]
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D2>, f: &mut F) -> O {
+        self.vec.core_map(self.src_idx(idx.into_idx()), f)
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        true
+    }
+}
+
+impl<T, V> NVec<D2, T> for BlockVec<T, V>
+where
+    V: NVec<D2, T>,
+{
+    #[inline(always)]
+    fn at(&self, idx: impl IntoIdx<D2>) -> T {
+        self.vec.at(self.src_idx(idx.into_idx()))
+    }
+
+    fn child(&self, i: <D2 as Dim>::ChildIdx) -> impl NVec<<D2 as Dim>::PrevDim, T> {
+        ChildD2D1 {
+            i,
+            parent: self,
+            phantom: Default::default(),
+        }
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        self.rows
+            .clone()
+            .flat_map(move |i| self.cols.clone().map(move |j| self.vec.at([i, j])))
+    }
+}
+
+impl<T, V> NVecMut<D2, T> for BlockVec<T, V>
+where
+    V: NVecMut<D2, T>,
+{
+    #[inline(always)]
+    fn at_mut<Idx: IntoIdx<D2>>(&mut self, idx: Idx) -> &mut T {
+        let idx = self.src_idx(idx.into_idx());
+        self.vec.at_mut(idx)
+    }
+
+    #[inline(always)]
+    fn set<Idx: IntoIdx<D2>>(&mut self, idx: Idx, value: T) {
+        let idx = self.src_idx(idx.into_idx());
+        self.vec.set(idx, value);
+    }
+
+    fn child_mut(&mut self, i: <D2 as Dim>::ChildIdx) -> impl NVecMut<<D2 as Dim>::PrevDim, T> {
+        ChildD2D1 {
+            i,
+            parent: self,
+            phantom: Default::default(),
+        }
+    }
+
+    fn mut_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for i in self.rows.clone() {
+            for j in self.cols.clone() {
+                f(self.vec.at_mut([i, j]));
+            }
+        }
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        for i in self.rows.clone() {
+            for j in self.cols.clone() {
+                self.vec.set([i, j], value);
+            }
+        }
+    }
+}
+
+impl<T, V> NVecAllMut<D2, T> for BlockVec<T, V>
+where
+    V: NVecAllMut<D2, T>,
+{
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        let (rows, cols) = (self.rows.clone(), self.cols.clone());
+        let indices = rows.flat_map(move |i| cols.clone().map(move |j| [i, j]));
+        self.vec.all_mut_in(indices)
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D2 as Dim>::Idx, &'a mut T)>
+    where
+        T: 'a,
+    {
+        let num_cols = self.num_cols();
+        let indices = (0..self.num_rows()).flat_map(move |i| (0..num_cols).map(move |j| [i, j]));
+        indices.zip(self.all_mut())
+    }
+}
@@ -0,0 +1,19 @@
+mod as_slice;
+mod block_vec;
+mod impl_into_cached;
+mod impl_nvec;
+mod slice_vec;
+
+pub use as_slice::{V1AsSlice, V2AsBlock};
+pub use block_vec::BlockVec;
+pub use slice_vec::SliceVec;
+
+// errors
+
+#[allow(clippy::panic)]
+fn panic_oob(kind: &str, idx: usize, len: usize) -> ! {
+    panic!(
+        "{} idx {} is out of bounds: view has {} elements along this dimension",
+        kind, idx, len
+    );
+}
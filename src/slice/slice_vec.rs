@@ -0,0 +1,88 @@
+use super::panic_oob;
+use crate::common_trait_helpers::debug::*;
+use crate::{NVec, D1};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+/// A zero-copy view of a contiguous range of elements of a `D1` vector.
+///
+/// It is created by the methods of the [`V1AsSlice`] trait.
+///
+/// [`V1AsSlice`]: crate::V1AsSlice
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let v1: Vec<_> = (0..100).collect();
+///
+/// let s = v1.v1_as_slice(10..20);
+/// assert_eq!(s.card([]), 10);
+/// assert_eq!(s.at(3), 13);
+/// assert_eq!(s.all().sum::<usize>(), (10..20).sum());
+/// ```
+pub struct SliceVec<T, V>
+where
+    V: NVec<D1, T>,
+{
+    pub(super) vec: V,
+    pub(super) begin: usize,
+    pub(super) len: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T, V> SliceVec<T, V>
+where
+    V: NVec<D1, T>,
+{
+    pub(super) fn new(vec: V, range: Range<usize>) -> Self {
+        assert!(
+            range.start <= range.end && range.end <= vec.card([]),
+            "Range {:?} is out of bounds of the D1 vector with {} elements",
+            range,
+            vec.card([])
+        );
+        Self {
+            vec,
+            begin: range.start,
+            len: range.end - range.start,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the range of the underlying vector that the slice is a view of.
+    pub fn range(&self) -> Range<usize> {
+        self.begin..(self.begin + self.len)
+    }
+
+    /// Destructs the slice into the underlying vector.
+    pub fn into_inner(self) -> V {
+        self.vec
+    }
+
+    #[inline(always)]
+    pub(super) fn src_idx(&self, i: usize) -> usize {
+        match i < self.len {
+            true => self.begin + i,
+            false => panic_oob("Slice", i, self.len),
+        }
+    }
+}
+
+impl<T, V> Debug for SliceVec<T, V>
+where
+    V: NVec<D1, T>,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{{ kind: SliceVec, dim: D1, range: {:?}, values: ",
+            self.range()
+        )?;
+        dbg_values_d1(f, self)?;
+        write!(f, " }}")
+    }
+}
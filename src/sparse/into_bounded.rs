@@ -1,7 +1,8 @@
 use super::sparse_vec::SparseVec;
 use crate::{
-    dim::*, CardD1, Lookup, RectangularCardD2, RectangularCardD3, RectangularCardD4, UnboundedCard,
-    VariableCardD2, VariableCardD3, VariableCardD4, V1, V2, V3,
+    dim::*, CardD1, Lookup, RectangularCardD2, RectangularCardD3, RectangularCardD4,
+    RectangularCardD5, RectangularCardD6, UnboundedCard, VariableCardD2, VariableCardD3,
+    VariableCardD4, VariableCardD5, VariableCardD6, V1, V2, V3, V4, V5,
 };
 
 // D1
@@ -429,3 +430,146 @@ where
         self.with_bounds(cardinality.into())
     }
 }
+
+// D5
+
+impl<T, L> SparseVec<D5, T, UnboundedCard<D5>, L>
+where
+    T: Copy,
+    L: Lookup<<D5 as Dim>::Idx, T>,
+{
+    /// Converts an unbounded sparse vector into one with rectangular bounds as in
+    /// multi-dimensional matrices. The matrix has
+    /// `dimensions[0]` x `dimensions[1]` x `dimensions[2]` x `dimensions[3]` x `dimensions[4]` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v5 = V.d5().sparse(42).with_rectangular_bounds([2, 1, 3, 1, 2]);
+    ///
+    /// assert!(v5.is_rectangular());
+    /// assert_eq!(v5.card([]), 2);
+    /// assert_eq!(v5.card([0, 0, 0, 0]), 2);
+    /// assert_eq!(v5.all().count(), 12);
+    /// v5.set([1, 0, 0, 0, 0], 7);
+    /// assert_eq!(v5.at([1, 0, 0, 0, 0]), 7);
+    /// assert_eq!(v5.at([0, 0, 0, 0, 0]), 42);
+    ///
+    /// assert_eq!(v5.try_at([7, 7, 7, 7, 7]), None);
+    /// ```
+    pub fn with_rectangular_bounds(
+        self,
+        dimensions: [usize; 5],
+    ) -> SparseVec<D5, T, RectangularCardD5, L> {
+        self.with_bounds(dimensions.into())
+    }
+
+    /// Converts an unbounded sparse vector into one with variable bounds as in
+    /// jagged arrays:
+    /// * the vector has `cardinality.card([])` children, and
+    /// * `[i]`-th child has `cardinality.card([i])` children, and
+    /// * `[i, j]`-th child has `cardinality.card([i, j])` children, and
+    /// * `[i, j, k]`-th child has `cardinality.card([i, j, k])` children, and
+    /// * `[i, j, k, l]`-th child has `cardinality.at([i, j, k, l])` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let cardinality = vec![vec![vec![vec![2, 3]]], vec![vec![vec![1]]]];
+    /// let mut v5 = V.d5().sparse(42).with_variable_bounds(&cardinality);
+    ///
+    /// assert!(!v5.is_rectangular());
+    /// assert_eq!(v5.card([]), 2);
+    /// assert_eq!(v5.card([0, 0, 0]), 2);
+    /// assert_eq!(v5.card([0, 0, 0, 1]), 3);
+    /// assert_eq!(v5.card([1, 0, 0, 0]), 1);
+    /// assert_eq!(v5.all().count(), 6);
+    /// v5.set([1, 0, 0, 0, 0], 7);
+    /// assert_eq!(v5.at([1, 0, 0, 0, 0]), 7);
+    /// assert_eq!(v5.at([0, 0, 0, 0, 0]), 42);
+    ///
+    /// assert_eq!(v5.try_at([7, 7, 7, 7, 7]), None);
+    /// ```
+    pub fn with_variable_bounds<C>(self, cardinality: C) -> SparseVec<D5, T, VariableCardD5<C>, L>
+    where
+        C: V4<usize>,
+    {
+        self.with_bounds(cardinality.into())
+    }
+}
+
+// D6
+
+impl<T, L> SparseVec<D6, T, UnboundedCard<D6>, L>
+where
+    T: Copy,
+    L: Lookup<<D6 as Dim>::Idx, T>,
+{
+    /// Converts an unbounded sparse vector into one with rectangular bounds as in
+    /// multi-dimensional matrices. The matrix has
+    /// `dimensions[0]` x `dimensions[1]` x `dimensions[2]` x `dimensions[3]` x `dimensions[4]` x `dimensions[5]` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v6 = V.d6().sparse(42).with_rectangular_bounds([2, 1, 3, 1, 2, 2]);
+    ///
+    /// assert!(v6.is_rectangular());
+    /// assert_eq!(v6.card([]), 2);
+    /// assert_eq!(v6.card([0, 0, 0, 0, 0]), 2);
+    /// assert_eq!(v6.all().count(), 24);
+    /// v6.set([1, 0, 0, 0, 0, 0], 7);
+    /// assert_eq!(v6.at([1, 0, 0, 0, 0, 0]), 7);
+    /// assert_eq!(v6.at([0, 0, 0, 0, 0, 0]), 42);
+    ///
+    /// assert_eq!(v6.try_at([7, 7, 7, 7, 7, 7]), None);
+    /// ```
+    pub fn with_rectangular_bounds(
+        self,
+        dimensions: [usize; 6],
+    ) -> SparseVec<D6, T, RectangularCardD6, L> {
+        self.with_bounds(dimensions.into())
+    }
+
+    /// Converts an unbounded sparse vector into one with variable bounds as in
+    /// jagged arrays:
+    /// * the vector has `cardinality.card([])` children, and
+    /// * `[i]`-th child has `cardinality.card([i])` children, and
+    /// * `[i, j]`-th child has `cardinality.card([i, j])` children, and
+    /// * `[i, j, k]`-th child has `cardinality.card([i, j, k])` children, and
+    /// * `[i, j, k, l]`-th child has `cardinality.card([i, j, k, l])` children, and
+    /// * `[i, j, k, l, m]`-th child has `cardinality.at([i, j, k, l, m])` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let cardinality = vec![vec![vec![vec![vec![2, 3]]]], vec![vec![vec![vec![1]]]]];
+    /// let mut v6 = V.d6().sparse(42).with_variable_bounds(&cardinality);
+    ///
+    /// assert!(!v6.is_rectangular());
+    /// assert_eq!(v6.card([]), 2);
+    /// assert_eq!(v6.card([0, 0, 0, 0]), 2);
+    /// assert_eq!(v6.card([0, 0, 0, 0, 1]), 3);
+    /// assert_eq!(v6.card([1, 0, 0, 0, 0]), 1);
+    /// assert_eq!(v6.all().count(), 6);
+    /// v6.set([1, 0, 0, 0, 0, 0], 7);
+    /// assert_eq!(v6.at([1, 0, 0, 0, 0, 0]), 7);
+    /// assert_eq!(v6.at([0, 0, 0, 0, 0, 0]), 42);
+    ///
+    /// assert_eq!(v6.try_at([7, 7, 7, 7, 7, 7]), None);
+    /// ```
+    pub fn with_variable_bounds<C>(self, cardinality: C) -> SparseVec<D6, T, VariableCardD6<C>, L>
+    where
+        C: V5<usize>,
+    {
+        self.with_bounds(cardinality.into())
+    }
+}
@@ -0,0 +1,32 @@
+//! Serializes lookups of sparse vectors as sequences of `(idx, value)` pairs, rather than as
+//! maps, since indices of sparse vectors are arrays which cannot be used as keys of maps in
+//! many formats such as JSON.
+
+use crate::Lookup;
+use alloc::vec::Vec;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub(crate) fn serialize<L, Idx, T, S>(lookup: &L, serializer: S) -> Result<S::Ok, S::Error>
+where
+    for<'a> &'a L: IntoIterator<Item = (&'a Idx, &'a T)>,
+    Idx: Serialize,
+    T: Serialize,
+    S: Serializer,
+{
+    serializer.collect_seq(lookup)
+}
+
+pub(crate) fn deserialize<'de, L, Idx, T, De>(deserializer: De) -> Result<L, De::Error>
+where
+    L: Lookup<Idx, T>,
+    Idx: Deserialize<'de>,
+    T: Deserialize<'de>,
+    De: Deserializer<'de>,
+{
+    let pairs = Vec::<(Idx, T)>::deserialize(deserializer)?;
+    let mut lookup = L::default();
+    for (idx, value) in pairs {
+        lookup.insert(idx, value);
+    }
+    Ok(lookup)
+}
@@ -1,11 +1,15 @@
 mod impl_lookup;
 mod into_bounded;
 mod lookup;
+#[cfg(feature = "serde")]
+mod lookup_serde;
 mod sparse_vec;
 mod sparse_vec_d1;
 mod sparse_vec_d2;
 mod sparse_vec_d3;
 mod sparse_vec_d4;
+mod sparse_vec_d5;
+mod sparse_vec_d6;
 
 pub use lookup::{DefaultLookup, Lookup};
 pub use sparse_vec::SparseVec;
@@ -1,6 +1,8 @@
 use super::DefaultLookup;
+use crate::common_trait_helpers::all_mut::{panic_out_of_bounds, take_in_order};
 use crate::common_trait_helpers::debug::*;
-use crate::{Card, Dim, IntoIdx, LeqIdx, Lookup, NVec, D1, D2, D3, D4};
+use crate::{Card, Dim, IntoIdx, LeqIdx, Lookup, NVec, D1, D2, D3, D4, D5, D6};
+use alloc::vec::Vec;
 use core::fmt::Debug;
 use core::marker::PhantomData;
 
@@ -25,6 +27,16 @@ use core::marker::PhantomData;
 /// `with_rectangular_bounds` or `with_variable_bounds` transformations.
 ///
 /// [`UnboundedCard`]: crate::UnboundedCard
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "T: serde::Serialize, C: serde::Serialize, D::Idx: serde::Serialize, \
+                     for<'a> &'a L: IntoIterator<Item = (&'a D::Idx, &'a T)>",
+        deserialize = "T: serde::Deserialize<'de>, C: serde::Deserialize<'de>, \
+                       D::Idx: serde::Deserialize<'de>"
+    ))
+)]
 pub struct SparseVec<D, T, C, L = DefaultLookup<D, T>>
 where
     D: Dim,
@@ -32,9 +44,11 @@ where
     L: Lookup<D::Idx, T>,
     C: Card<D>,
 {
+    #[cfg_attr(feature = "serde", serde(with = "super::lookup_serde"))]
     pub(super) lookup: L,
     pub(super) default_value: T,
     pub(super) card: C,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(super) phantom: PhantomData<D>,
 }
 
@@ -67,6 +81,8 @@ impl_debug!(D1, dbg_values_d1);
 impl_debug!(D2, dbg_values_d2);
 impl_debug!(D3, dbg_values_d3);
 impl_debug!(D4, dbg_values_d4);
+impl_debug!(D5, dbg_values_d5);
+impl_debug!(D6, dbg_values_d6);
 
 impl<D, T, L, C> SparseVec<D, T, C, L>
 where
@@ -164,6 +180,24 @@ where
         self.lookup.insert(idx.into_idx(), value);
     }
 
+    pub(super) fn sparse_all_mut<'a>(
+        &'a mut self,
+        indices: Vec<D::Idx>,
+    ) -> impl Iterator<Item = &'a mut T>
+    where
+        D::Idx: Ord,
+        T: 'a,
+        Self: NVec<D, T>,
+    {
+        if let Some(idx) = indices.iter().find(|idx| !self.in_bounds(**idx)) {
+            panic_out_of_bounds(*idx);
+        }
+        for idx in indices.iter() {
+            _ = self.lookup.entry_or_insert(*idx, self.default_value);
+        }
+        take_in_order(self.lookup.iter_mut().map(|(idx, x)| (*idx, x)), indices)
+    }
+
     pub(super) fn sparse_mut_all<F>(&mut self, mut f: F)
     where
         F: FnMut(&mut T),
@@ -264,3 +298,49 @@ impl Fill<D4> for D4 {
         }
     }
 }
+impl Fill<D5> for D5 {
+    fn fill<T, L, C>(sparse_vec: &mut SparseVec<D5, T, C, L>)
+    where
+        T: Copy,
+        L: Lookup<<D5 as Dim>::Idx, T>,
+        C: Card<D5>,
+    {
+        for i in 0..sparse_vec.card.cardinality_of([]) {
+            for j in 0..sparse_vec.card.cardinality_of([i]) {
+                for k in 0..sparse_vec.card.cardinality_of([i, j]) {
+                    for l in 0..sparse_vec.card.cardinality_of([i, j, k]) {
+                        for m in 0..sparse_vec.card.cardinality_of([i, j, k, l]) {
+                            _ = sparse_vec
+                                .lookup
+                                .entry_or_insert([i, j, k, l, m], sparse_vec.default_value)
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+impl Fill<D6> for D6 {
+    fn fill<T, L, C>(sparse_vec: &mut SparseVec<D6, T, C, L>)
+    where
+        T: Copy,
+        L: Lookup<<D6 as Dim>::Idx, T>,
+        C: Card<D6>,
+    {
+        for i in 0..sparse_vec.card.cardinality_of([]) {
+            for j in 0..sparse_vec.card.cardinality_of([i]) {
+                for k in 0..sparse_vec.card.cardinality_of([i, j]) {
+                    for l in 0..sparse_vec.card.cardinality_of([i, j, k]) {
+                        for m in 0..sparse_vec.card.cardinality_of([i, j, k, l]) {
+                            for n in 0..sparse_vec.card.cardinality_of([i, j, k, l, m]) {
+                                _ = sparse_vec
+                                    .lookup
+                                    .entry_or_insert([i, j, k, l, m, n], sparse_vec.default_value)
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use super::sparse_vec::SparseVec;
-use crate::{Card, Dim, IntoIdx, Lookup, NVec, NVecCoreSealed, NVecMut, D1};
+use crate::cardinality::panic_on_all_when_udd;
+use crate::common_trait_helpers::indices::indices_d1;
+use crate::{Card, Dim, IntoIdx, Lookup, NVec, NVecAllMut, NVecCoreSealed, NVecMut, D1};
+use alloc::vec::Vec;
 
 impl<T, L, C> NVecCoreSealed<D1, T> for SparseVec<D1, T, C, L>
 where
@@ -94,3 +97,42 @@ where
         self.sparse_reset_all(value);
     }
 }
+
+impl<T, L, C> NVecAllMut<D1, T> for SparseVec<D1, T, C, L>
+where
+    T: Copy + Default,
+    L: Lookup<<D1 as Dim>::Idx, T>,
+    C: Card<D1>,
+{
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        panic_on_all_when_udd(!self.core_is_bounded());
+        let indices: Vec<_> = indices_d1(self).collect();
+        self.sparse_all_mut(indices)
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D1 as Dim>::Idx, &'a mut T)>
+    where
+        T: 'a,
+    {
+        panic_on_all_when_udd(!self.core_is_bounded());
+        let indices: Vec<_> = indices_d1(self).collect();
+        indices
+            .clone()
+            .into_iter()
+            .zip(self.sparse_all_mut(indices))
+    }
+
+    fn all_mut_in<'a>(
+        &'a mut self,
+        indices: impl Iterator<Item = impl IntoIdx<D1>>,
+    ) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        let indices: Vec<_> = indices.map(|idx| idx.into_idx()).collect();
+        self.sparse_all_mut(indices)
+    }
+}
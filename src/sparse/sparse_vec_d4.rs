@@ -1,5 +1,10 @@
 use super::sparse_vec::SparseVec;
-use crate::{children::ChildD4D3, Card, Dim, IntoIdx, Lookup, NVec, NVecCoreSealed, NVecMut, D4};
+use crate::cardinality::panic_on_all_when_udd;
+use crate::common_trait_helpers::indices::indices_d4;
+use crate::{
+    children::ChildD4D3, Card, Dim, IntoIdx, Lookup, NVec, NVecAllMut, NVecCoreSealed, NVecMut, D4,
+};
+use alloc::vec::Vec;
 
 impl<T, L, C> NVecCoreSealed<D4, T> for SparseVec<D4, T, C, L>
 where
@@ -103,3 +108,42 @@ where
         self.sparse_reset_all(value);
     }
 }
+
+impl<T, L, C> NVecAllMut<D4, T> for SparseVec<D4, T, C, L>
+where
+    T: Copy,
+    L: Lookup<<D4 as Dim>::Idx, T>,
+    C: Card<D4>,
+{
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        panic_on_all_when_udd(!self.core_is_bounded());
+        let indices: Vec<_> = indices_d4(self).collect();
+        self.sparse_all_mut(indices)
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D4 as Dim>::Idx, &'a mut T)>
+    where
+        T: 'a,
+    {
+        panic_on_all_when_udd(!self.core_is_bounded());
+        let indices: Vec<_> = indices_d4(self).collect();
+        indices
+            .clone()
+            .into_iter()
+            .zip(self.sparse_all_mut(indices))
+    }
+
+    fn all_mut_in<'a>(
+        &'a mut self,
+        indices: impl Iterator<Item = impl IntoIdx<D4>>,
+    ) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        let indices: Vec<_> = indices.map(|idx| idx.into_idx()).collect();
+        self.sparse_all_mut(indices)
+    }
+}
@@ -0,0 +1,149 @@
+use super::sparse_vec::SparseVec;
+use crate::cardinality::panic_on_all_when_udd;
+use crate::common_trait_helpers::indices::indices_d5;
+use crate::{
+    children::ChildD5D4, Card, Dim, IntoIdx, Lookup, NVec, NVecAllMut, NVecCoreSealed, NVecMut, D5,
+};
+use alloc::vec::Vec;
+
+impl<T, L, C> NVecCoreSealed<D5, T> for SparseVec<D5, T, C, L>
+where
+    T: Copy,
+    L: Lookup<<D5 as Dim>::Idx, T>,
+    C: Card<D5>,
+{
+    fn core_num_children(&self) -> usize {
+        self.sparse_num_children()
+    }
+
+    fn core_card(&self, idx: impl Into<<D5 as Dim>::CardIdx>) -> usize {
+        self.sparse_card(idx)
+    }
+
+    fn core_child(&self, i: <D5 as Dim>::ChildIdx) -> impl NVecCoreSealed<<D5 as Dim>::PrevDim, T> {
+        ChildD5D4::<_, T> {
+            i,
+            parent: self,
+            phantom: Default::default(),
+        }
+    }
+
+    fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D5>, f: &mut F) -> O {
+        f(&self.sparse_at(idx))
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        self.sparse_cardinality().is_rectangular()
+    }
+}
+
+// nvec
+
+impl<T, L, C> NVec<D5, T> for SparseVec<D5, T, C, L>
+where
+    T: Copy,
+    L: Lookup<<D5 as Dim>::Idx, T>,
+    C: Card<D5>,
+{
+    #[inline(always)]
+    fn at(&self, idx: impl IntoIdx<D5>) -> T {
+        self.sparse_at(idx)
+    }
+
+    #[inline(always)]
+    fn in_bounds(&self, idx: impl Into<<D5 as Dim>::LeqIdx>) -> bool {
+        self.sparse_in_bounds(idx)
+    }
+
+    fn child(&self, i: <D5 as Dim>::ChildIdx) -> impl NVec<<D5 as Dim>::PrevDim, T> {
+        ChildD5D4 {
+            i,
+            parent: self,
+            phantom: Default::default(),
+        }
+    }
+
+    fn all(&self) -> impl Iterator<Item = T> {
+        self.sparse_cardinality().vec_all(self)
+    }
+}
+
+// nvec-mut
+
+impl<T, L, C> NVecMut<D5, T> for SparseVec<D5, T, C, L>
+where
+    T: Copy,
+    L: Lookup<<D5 as Dim>::Idx, T>,
+    C: Card<D5>,
+{
+    #[inline(always)]
+    fn at_mut<Idx: IntoIdx<D5>>(&mut self, idx: Idx) -> &mut T {
+        self.sparse_at_mut(idx)
+    }
+
+    #[inline(always)]
+    fn set<Idx: IntoIdx<D5>>(&mut self, idx: Idx, value: T) {
+        self.sparse_set(idx, value);
+    }
+
+    fn child_mut(&mut self, i: <D5 as Dim>::ChildIdx) -> impl NVecMut<<D5 as Dim>::PrevDim, T> {
+        ChildD5D4 {
+            i,
+            parent: self,
+            phantom: Default::default(),
+        }
+    }
+
+    fn mut_all<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.sparse_mut_all(f);
+    }
+
+    fn reset_all(&mut self, value: T)
+    where
+        T: PartialEq + Copy,
+    {
+        self.sparse_reset_all(value);
+    }
+}
+
+impl<T, L, C> NVecAllMut<D5, T> for SparseVec<D5, T, C, L>
+where
+    T: Copy,
+    L: Lookup<<D5 as Dim>::Idx, T>,
+    C: Card<D5>,
+{
+    fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        panic_on_all_when_udd(!self.core_is_bounded());
+        let indices: Vec<_> = indices_d5(self).collect();
+        self.sparse_all_mut(indices)
+    }
+
+    fn enumerate_all_mut<'a>(&'a mut self) -> impl Iterator<Item = (<D5 as Dim>::Idx, &'a mut T)>
+    where
+        T: 'a,
+    {
+        panic_on_all_when_udd(!self.core_is_bounded());
+        let indices: Vec<_> = indices_d5(self).collect();
+        indices
+            .clone()
+            .into_iter()
+            .zip(self.sparse_all_mut(indices))
+    }
+
+    fn all_mut_in<'a>(
+        &'a mut self,
+        indices: impl Iterator<Item = impl IntoIdx<D5>>,
+    ) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        let indices: Vec<_> = indices.map(|idx| idx.into_idx()).collect();
+        self.sparse_all_mut(indices)
+    }
+}
@@ -0,0 +1,266 @@
+use super::{RevVec, StridedVec};
+use crate::{NVec, NVecMut, D1};
+
+/// Creates reversed and strided views of `D1` vectors.
+///
+/// Each view is available in three flavors:
+/// * `v1_into_rev`, `v1_into_step_by` and `v1_into_strided` convert the vector into
+///   the view,
+/// * `v1_as_rev`, `v1_as_step_by` and `v1_as_strided` create views over a reference
+///   of the vector,
+/// * `v1_as_rev_mut`, `v1_as_step_by_mut` and `v1_as_strided_mut` create views over a
+///   mutable reference of the vector, which additionally implement [`NVecMut`].
+///
+/// Notice that the methods are prefixed by `v1_` so that iterators which are also `D1`
+/// vectors, such as `Range<usize>`, keep using the `rev` and `step_by` methods of the
+/// `Iterator` trait.
+///
+/// [`NVecMut`]: crate::NVecMut
+pub trait V1AsStrided<T>: NVec<D1, T> {
+    /// Converts the bounded vector into a view with its elements in reverse order.
+    ///
+    /// When the underlying vector is mutable, so is the view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut r = vec![0, 1, 2, 3].v1_into_rev();
+    /// r.set(0, 30);
+    /// assert_eq!(r.equality(&[30, 2, 1, 0]), Equality::Equal);
+    /// assert_eq!(r.into_inner(), vec![0, 1, 2, 30]);
+    /// ```
+    fn v1_into_rev(self) -> RevVec<T, Self>
+    where
+        Self: Sized,
+    {
+        RevVec::new(self)
+    }
+
+    /// Creates a view of the bounded vector with its elements in reverse order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// fn first_non_zero(v: impl V1<i32>) -> Option<i32> {
+    ///     v.all().find(|x| *x != 0)
+    /// }
+    ///
+    /// let v1 = vec![0, 1, 2, 0];
+    /// assert_eq!(first_non_zero(&v1), Some(1));
+    /// assert_eq!(first_non_zero(v1.v1_as_rev()), Some(2));
+    /// ```
+    fn v1_as_rev(&self) -> RevVec<T, &Self> {
+        RevVec::new(self)
+    }
+
+    /// Creates a mutable view of the bounded vector with its elements in reverse order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v1 = vec![0, 1, 2, 3];
+    ///
+    /// let mut r = v1.v1_as_rev_mut();
+    /// r.set(0, 30);
+    /// *r.at_mut(3) = 10;
+    /// assert_eq!(v1, vec![10, 1, 2, 30]);
+    /// ```
+    fn v1_as_rev_mut(&mut self) -> RevVec<T, &mut Self>
+    where
+        Self: NVecMut<D1, T>,
+    {
+        RevVec::new(self)
+    }
+
+    /// Converts the vector into a view of every `step`-th element of it, starting from
+    /// the first element; i.e., the `i`-th element of the view is the `(step * i)`-th
+    /// element of the vector.
+    ///
+    /// When the underlying vector is mutable, so is the view.
+    /// The view of an unbounded vector is unbounded as well; see [`v1_as_step_by`].
+    ///
+    /// [`v1_as_step_by`]: crate::V1AsStrided::v1_as_step_by
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let s = V.d1().fun(|[i]| 10 * i).v1_into_step_by(3);
+    /// assert_eq!(s.at(2), 60);
+    /// assert!(s.is_unbounded());
+    /// ```
+    fn v1_into_step_by(self, step: usize) -> StridedVec<T, Self>
+    where
+        Self: Sized,
+    {
+        StridedVec::new_step_by(self, step)
+    }
+
+    /// Creates a view of every `step`-th element of the vector, starting from the first
+    /// element; i.e., the `i`-th element of the view is the `(step * i)`-th element of
+    /// the vector.
+    ///
+    /// The view of an unbounded vector is unbounded as well; hence, calling [`all`] on it
+    /// panics as for any other unbounded vector.
+    ///
+    /// [`all`]: crate::NVec::all
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let v1: Vec<_> = (0..10).collect();
+    ///
+    /// let s = v1.v1_as_step_by(4);
+    /// assert_eq!(s.card([]), 3);
+    /// assert_eq!(s.equality(&[0, 4, 8]), Equality::Equal);
+    ///
+    /// // `Iterator::step_by` is still available on ranges
+    /// let s: Vec<_> = (0..10).step_by(4).collect();
+    /// assert_eq!(s, vec![0, 4, 8]);
+    /// ```
+    fn v1_as_step_by(&self, step: usize) -> StridedVec<T, &Self> {
+        StridedVec::new_step_by(self, step)
+    }
+
+    /// Creates a mutable view of every `step`-th element of the vector, starting from
+    /// the first element; i.e., the `i`-th element of the view is the `(step * i)`-th
+    /// element of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut v1 = vec![1; 7];
+    /// v1.v1_as_step_by_mut(3).reset_all(0);
+    /// assert_eq!(v1, vec![0, 1, 1, 0, 1, 1, 0]);
+    /// ```
+    fn v1_as_step_by_mut(&mut self, step: usize) -> StridedVec<T, &mut Self>
+    where
+        Self: NVecMut<D1, T>,
+    {
+        StridedVec::new_step_by(self, step)
+    }
+
+    /// Converts the vector into a view of `len` elements of it such that the `i`-th
+    /// element of the view is the `(offset + stride * i)`-th element of the vector.
+    ///
+    /// When the underlying vector is mutable, so is the view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the last element of the view, `offset + stride * (len - 1)`, is out of
+    /// bounds of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let samples = vec![1.0, -1.0, 2.0, -2.0, 3.0, -3.0];
+    ///
+    /// let mut left = samples.v1_into_strided(0, 2, 3);
+    /// left.set(1, 20.0);
+    /// assert_eq!(left.equality(&[1.0, 20.0, 3.0]), Equality::Equal);
+    /// ```
+    fn v1_into_strided(self, offset: usize, stride: usize, len: usize) -> StridedVec<T, Self>
+    where
+        Self: Sized,
+    {
+        StridedVec::new(self, offset, stride, len)
+    }
+
+    /// Creates a view of `len` elements of the vector such that the `i`-th element of
+    /// the view is the `(offset + stride * i)`-th element of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the last element of the view, `offset + stride * (len - 1)`, is out of
+    /// bounds of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// // interleaved stereo samples: [l0, r0, l1, r1, ...]
+    /// let samples = vec![1.0, -1.0, 2.0, -2.0, 3.0, -3.0];
+    ///
+    /// fn energy(v: impl V1<f64>) -> f64 {
+    ///     v.all().map(|x| x * x).sum()
+    /// }
+    ///
+    /// let right = samples.v1_as_strided(1, 2, 3);
+    /// assert_eq!(right.equality(&[-1.0, -2.0, -3.0]), Equality::Equal);
+    /// assert_eq!(energy(&right), 14.0);
+    /// ```
+    fn v1_as_strided(&self, offset: usize, stride: usize, len: usize) -> StridedVec<T, &Self> {
+        StridedVec::new(self, offset, stride, len)
+    }
+
+    /// Creates a mutable view of `len` elements of the vector such that the `i`-th
+    /// element of the view is the `(offset + stride * i)`-th element of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the last element of the view, `offset + stride * (len - 1)`, is out of
+    /// bounds of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut samples = vec![1.0, -1.0, 2.0, -2.0, 3.0, -3.0];
+    ///
+    /// let mut left = samples.v1_as_strided_mut(0, 2, 3);
+    /// left.mut_all(|x| *x *= 10.0);
+    /// assert_eq!(samples, vec![10.0, -1.0, 20.0, -2.0, 30.0, -3.0]);
+    /// ```
+    fn v1_as_strided_mut(
+        &mut self,
+        offset: usize,
+        stride: usize,
+        len: usize,
+    ) -> StridedVec<T, &mut Self>
+    where
+        Self: NVecMut<D1, T>,
+    {
+        StridedVec::new(self, offset, stride, len)
+    }
+}
+
+impl<T, V: NVec<D1, T>> V1AsStrided<T> for V {}
@@ -0,0 +1,16 @@
+use super::{RevVec, StridedVec};
+use crate::{IntoCached, NVec, D1};
+
+impl<T, V> IntoCached<D1, T> for RevVec<T, V>
+where
+    V: NVec<D1, T>,
+    T: Copy,
+{
+}
+
+impl<T, V> IntoCached<D1, T> for StridedVec<T, V>
+where
+    V: NVec<D1, T>,
+    T: Copy,
+{
+}
@@ -0,0 +1,134 @@
+use super::{RevVec, StridedVec};
+use crate::cardinality::panic_on_all_when_udd;
+use crate::{dim::*, NVec, NVecAllMut, NVecCoreSealed, NVecMut};
+use alloc::vec::Vec;
+
+macro_rules! impl_v1_view {
+    ($V:ident) => {
+        impl<T, V> NVecCoreSealed<D1, T> for $V<T, V>
+        where
+            V: NVec<D1, T>,
+        {
+            #[inline(always)]
+            fn core_num_children(&self) -> usize {
+                self.len
+            }
+
+            #[inline(always)]
+            fn core_card(&self, _: impl Into<<D1 as Dim>::CardIdx>) -> usize {
+                self.len
+            }
+
+            fn core_child(
+                &self,
+                _: <D1 as Dim>::ChildIdx,
+            ) -> impl NVecCoreSealed<<D1 as Dim>::PrevDim, T> {
+                self
+            }
+
+            #[inline(always)]
+            fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<D1>, f: &mut F) -> O {
+                let [i] = idx.into_idx();
+                self.vec.core_map([self.src_idx(i)], f)
+            }
+
+            fn core_is_rectangular(&self) -> bool {
+                true
+            }
+        }
+
+        impl<T, V> NVec<D1, T> for $V<T, V>
+        where
+            V: NVec<D1, T>,
+        {
+            #[inline(always)]
+            fn at(&self, idx: impl IntoIdx<D1>) -> T {
+                let [i] = idx.into_idx();
+                self.vec.at([self.src_idx(i)])
+            }
+
+            fn child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVec<<D1 as Dim>::PrevDim, T> {
+                self
+            }
+
+            fn all(&self) -> impl Iterator<Item = T> {
+                panic_on_all_when_udd(!self.core_is_bounded());
+                (0..self.len).map(|i| self.vec.at([self.src_idx(i)]))
+            }
+        }
+
+        impl<T, V> NVecMut<D1, T> for $V<T, V>
+        where
+            V: NVecMut<D1, T>,
+        {
+            #[inline(always)]
+            fn at_mut<Idx: IntoIdx<D1>>(&mut self, idx: Idx) -> &mut T {
+                let [i] = idx.into_idx();
+                let i = self.src_idx(i);
+                self.vec.at_mut([i])
+            }
+
+            #[inline(always)]
+            fn set<Idx: IntoIdx<D1>>(&mut self, idx: Idx, value: T) {
+                let [i] = idx.into_idx();
+                let i = self.src_idx(i);
+                self.vec.set([i], value);
+            }
+
+            fn child_mut(
+                &mut self,
+                _: <D1 as Dim>::ChildIdx,
+            ) -> impl NVecMut<<D1 as Dim>::PrevDim, T> {
+                self
+            }
+
+            fn mut_all<F>(&mut self, mut f: F)
+            where
+                F: FnMut(&mut T),
+            {
+                panic_on_all_when_udd(!self.core_is_bounded());
+                for i in 0..self.len {
+                    let i = self.src_idx(i);
+                    f(self.vec.at_mut([i]));
+                }
+            }
+
+            fn reset_all(&mut self, value: T)
+            where
+                T: PartialEq + Copy,
+            {
+                panic_on_all_when_udd(!self.core_is_bounded());
+                for i in 0..self.len {
+                    let i = self.src_idx(i);
+                    self.vec.set([i], value);
+                }
+            }
+        }
+
+        impl<T, V> NVecAllMut<D1, T> for $V<T, V>
+        where
+            V: NVecAllMut<D1, T>,
+        {
+            fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+            where
+                T: 'a,
+            {
+                panic_on_all_when_udd(!self.core_is_bounded());
+                let indices: Vec<_> = (0..self.len).map(|i| [self.src_idx(i)]).collect();
+                self.vec.all_mut_in(indices.into_iter())
+            }
+
+            fn enumerate_all_mut<'a>(
+                &'a mut self,
+            ) -> impl Iterator<Item = (<D1 as Dim>::Idx, &'a mut T)>
+            where
+                T: 'a,
+            {
+                self.all_mut().enumerate().map(|(i, x)| ([i], x))
+            }
+        }
+    };
+}
+
+impl_v1_view!(RevVec);
+impl_v1_view!(StridedVec);
@@ -0,0 +1,19 @@
+mod as_strided;
+mod impl_into_cached;
+mod impl_nvec;
+mod rev_vec;
+mod strided_vec;
+
+pub use as_strided::V1AsStrided;
+pub use rev_vec::RevVec;
+pub use strided_vec::StridedVec;
+
+// errors
+
+#[allow(clippy::panic)]
+fn panic_oob(kind: &str, i: usize, len: usize) -> ! {
+    panic!(
+        "{} idx {} is out of bounds: view has {} elements",
+        kind, i, len
+    );
+}
@@ -0,0 +1,74 @@
+use super::panic_oob;
+use crate::common_trait_helpers::debug::*;
+use crate::{NVec, D1};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A view of a bounded `D1` vector with `n` elements such that its `i`-th
+/// element is the `(n - 1 - i)`-th element of the underlying vector.
+///
+/// It is created by the [`v1_into_rev`], [`v1_as_rev`] and [`v1_as_rev_mut`] methods.
+///
+/// [`v1_into_rev`]: crate::V1AsStrided::v1_into_rev
+/// [`v1_as_rev`]: crate::V1AsStrided::v1_as_rev
+/// [`v1_as_rev_mut`]: crate::V1AsStrided::v1_as_rev_mut
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let v1 = vec![1, 2, 3];
+///
+/// let r = v1.v1_as_rev();
+/// assert_eq!(r.at(0), 3);
+/// assert_eq!(r.equality(&[3, 2, 1]), Equality::Equal);
+/// ```
+pub struct RevVec<T, V>
+where
+    V: NVec<D1, T>,
+{
+    pub(super) vec: V,
+    pub(super) len: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T, V> RevVec<T, V>
+where
+    V: NVec<D1, T>,
+{
+    pub(super) fn new(vec: V) -> Self {
+        assert!(vec.is_bounded(), "An unbounded vector cannot be reversed");
+        let len = vec.card([]);
+        Self {
+            vec,
+            len,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Destructs the view into the underlying vector.
+    pub fn into_inner(self) -> V {
+        self.vec
+    }
+
+    #[inline(always)]
+    pub(super) fn src_idx(&self, i: usize) -> usize {
+        match i < self.len {
+            true => self.len - 1 - i,
+            false => panic_oob("Reversed", i, self.len),
+        }
+    }
+}
+
+impl<T, V> Debug for RevVec<T, V>
+where
+    V: NVec<D1, T>,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{{ kind: RevVec, dim: D1, values: ")?;
+        dbg_values_d1(f, self)?;
+        write!(f, " }}")
+    }
+}
@@ -0,0 +1,120 @@
+use super::panic_oob;
+use crate::common_trait_helpers::debug::*;
+use crate::{NVec, D1};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A view of a `D1` vector such that its `i`-th element is the
+/// `(offset + stride * i)`-th element of the underlying vector.
+///
+/// It is created by the [`v1_as_step_by`] and [`v1_as_strided`] methods, and their
+/// `into` and `_mut` versions.
+///
+/// [`v1_as_step_by`]: crate::V1AsStrided::v1_as_step_by
+/// [`v1_as_strided`]: crate::V1AsStrided::v1_as_strided
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let v1: Vec<_> = (0..10).collect();
+///
+/// let s = v1.v1_as_strided(1, 3, 3);
+/// assert_eq!(s.card([]), 3);
+/// assert_eq!(s.equality(&[1, 4, 7]), Equality::Equal);
+/// ```
+pub struct StridedVec<T, V>
+where
+    V: NVec<D1, T>,
+{
+    pub(super) vec: V,
+    pub(super) offset: usize,
+    pub(super) stride: usize,
+    pub(super) len: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T, V> StridedVec<T, V>
+where
+    V: NVec<D1, T>,
+{
+    pub(super) fn new(vec: V, offset: usize, stride: usize, len: usize) -> Self {
+        if len > 0 {
+            let last = stride
+                .checked_mul(len - 1)
+                .and_then(|x| x.checked_add(offset));
+            assert!(
+                matches!(last, Some(last) if last < vec.card([])),
+                "Strided view with offset {}, stride {} and length {} is out of bounds of the D1 vector with {} elements",
+                offset,
+                stride,
+                len,
+                vec.card([])
+            );
+        }
+
+        Self {
+            vec,
+            offset,
+            stride,
+            len,
+            phantom: PhantomData,
+        }
+    }
+
+    pub(super) fn new_step_by(vec: V, step: usize) -> Self {
+        assert!(step > 0, "Step of a strided view must be positive");
+        let len = match vec.card([]) {
+            usize::MAX => usize::MAX,
+            n => n.div_ceil(step),
+        };
+        Self {
+            vec,
+            offset: 0,
+            stride: step,
+            len,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the position of the first element of the view in the underlying vector.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the distance between positions of two consecutive elements of the view
+    /// in the underlying vector.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Destructs the view into the underlying vector.
+    pub fn into_inner(self) -> V {
+        self.vec
+    }
+
+    #[inline(always)]
+    pub(super) fn src_idx(&self, i: usize) -> usize {
+        match i < self.len {
+            true => self.offset + self.stride * i,
+            false => panic_oob("Strided", i, self.len),
+        }
+    }
+}
+
+impl<T, V> Debug for StridedVec<T, V>
+where
+    V: NVec<D1, T>,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{{ kind: StridedVec, dim: D1, offset: {}, stride: {}, values: ",
+            self.offset, self.stride
+        )?;
+        dbg_values_d1(f, self)?;
+        write!(f, " }}")
+    }
+}
@@ -0,0 +1,14 @@
+use super::{V1Tensor, V1TensorLayout};
+use crate::{Dim, IntoCached, NVec, D1};
+use core::hash::Hash;
+
+impl<D, T, V, L> IntoCached<D, T> for V1Tensor<D, T, V, L>
+where
+    D: Dim,
+    V: NVec<D1, T>,
+    L: V1TensorLayout<D>,
+    T: Copy,
+    D::Idx: Ord + Hash,
+    Self: NVec<D, T>,
+{
+}
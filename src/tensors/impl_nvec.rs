@@ -0,0 +1,140 @@
+use super::{V1Tensor, V1TensorLayout};
+use crate::children::{ChildD3D2, ChildD4D3};
+use crate::common_trait_helpers::indices::*;
+use crate::{
+    dim::*, Card, NVec, NVecAllMut, NVecCoreSealed, NVecMut, RectangularCardD3, RectangularCardD4,
+};
+use alloc::vec::Vec;
+
+macro_rules! impl_tensor {
+    ($dim:ty, $card:ident, $child:ident, $indices:ident) => {
+        impl<T, V, L> NVecCoreSealed<$dim, T> for V1Tensor<$dim, T, V, L>
+        where
+            V: NVec<D1, T>,
+            L: V1TensorLayout<$dim>,
+        {
+            #[inline(always)]
+            fn core_num_children(&self) -> usize {
+                self.layout.shape()[0]
+            }
+
+            #[inline(always)]
+            fn core_card(&self, idx: impl Into<<$dim as Dim>::CardIdx>) -> usize {
+                $card::from(self.layout.shape()).cardinality_of(idx)
+            }
+
+            fn core_child(
+                &self,
+                i: <$dim as Dim>::ChildIdx,
+            ) -> impl NVecCoreSealed<<$dim as Dim>::PrevDim, T> {
+                $child::<_, T> {
+                    i,
+                    parent: self,
+                    phantom: Default::default(),
+                }
+            }
+
+            #[inline(always)]
+            fn core_map<F: FnMut(&T) -> O, O>(&self, idx: impl IntoIdx<$dim>, f: &mut F) -> O {
+                self.data.core_map([self.layout.v1_idx(idx.into_idx())], f)
+            }
+
+            fn core_is_rectangular(&self) -> bool {
+                true
+            }
+        }
+
+        impl<T, V, L> NVec<$dim, T> for V1Tensor<$dim, T, V, L>
+        where
+            V: NVec<D1, T>,
+            L: V1TensorLayout<$dim>,
+        {
+            #[inline(always)]
+            fn at(&self, idx: impl IntoIdx<$dim>) -> T {
+                self.data.at([self.layout.v1_idx(idx.into_idx())])
+            }
+
+            fn child(&self, i: <$dim as Dim>::ChildIdx) -> impl NVec<<$dim as Dim>::PrevDim, T> {
+                $child {
+                    i,
+                    parent: self,
+                    phantom: Default::default(),
+                }
+            }
+
+            fn all(&self) -> impl Iterator<Item = T> {
+                self.layout.all(&self.data)
+            }
+        }
+
+        impl<T, V, L> NVecMut<$dim, T> for V1Tensor<$dim, T, V, L>
+        where
+            V: NVecMut<D1, T>,
+            L: V1TensorLayout<$dim>,
+        {
+            #[inline(always)]
+            fn at_mut<Idx: IntoIdx<$dim>>(&mut self, idx: Idx) -> &mut T {
+                self.data.at_mut([self.layout.v1_idx(idx.into_idx())])
+            }
+
+            #[inline(always)]
+            fn set<Idx: IntoIdx<$dim>>(&mut self, idx: Idx, value: T) {
+                self.data.set([self.layout.v1_idx(idx.into_idx())], value);
+            }
+
+            fn child_mut(
+                &mut self,
+                i: <$dim as Dim>::ChildIdx,
+            ) -> impl NVecMut<<$dim as Dim>::PrevDim, T> {
+                $child {
+                    i,
+                    parent: self,
+                    phantom: Default::default(),
+                }
+            }
+
+            fn mut_all<F>(&mut self, f: F)
+            where
+                F: FnMut(&mut T),
+            {
+                self.data.mut_all(f);
+            }
+
+            fn reset_all(&mut self, value: T)
+            where
+                T: PartialEq + Copy,
+            {
+                self.data.reset_all(value);
+            }
+        }
+
+        impl<T, V, L> NVecAllMut<$dim, T> for V1Tensor<$dim, T, V, L>
+        where
+            V: NVecAllMut<D1, T>,
+            L: V1TensorLayout<$dim>,
+        {
+            fn all_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T>
+            where
+                T: 'a,
+            {
+                let indices: Vec<_> = $indices(self)
+                    .map(|idx| [self.layout.v1_idx(idx)])
+                    .collect();
+                self.data.all_mut_in(indices.into_iter())
+            }
+
+            fn enumerate_all_mut<'a>(
+                &'a mut self,
+            ) -> impl Iterator<Item = (<$dim as Dim>::Idx, &'a mut T)>
+            where
+                T: 'a,
+            {
+                let indices: Vec<_> = $indices(self).collect();
+                indices.into_iter().zip(self.all_mut())
+            }
+        }
+    };
+}
+
+impl_tensor!(D3, RectangularCardD3, ChildD3D2, indices_d3);
+impl_tensor!(D4, RectangularCardD4, ChildD4D3, indices_d4);
@@ -0,0 +1,187 @@
+use super::panic_oob;
+use crate::{Dim, NVec, D1, D3, D4};
+
+/// Layout for tensors, or multi-dimensional rectangular vectors, with an underlying
+/// flat vector of `D1`.
+pub trait V1TensorLayout<D: Dim>: Clone {
+    /// Shape of the tensor; i.e., number of elements along each dimension.
+    fn shape(&self) -> D::Idx;
+
+    /// Number of elements of the tensor; i.e., product of its shape.
+    fn num_elements(&self) -> usize;
+
+    /// Transformation of the tensor index `idx` into a one dimensional index for the
+    /// underlying data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds of the shape.
+    fn v1_idx(&self, idx: D::Idx) -> usize;
+
+    /// Returns an iterator of all elements of the tensor stored in `data` in
+    /// lexicographic order of their tensor indices.
+    fn all<'a, T, V>(&'a self, data: &'a V) -> impl Iterator<Item = T> + 'a
+    where
+        V: NVec<D1, T> + 'a,
+        T: 'a;
+}
+
+// row major
+
+/// Row-major, or C-order, layout of a tensor with an underlying flat vector, where
+/// the last index changes fastest.
+///
+/// For instance, the element at `[i, j, k]` of a `D3` tensor with shape
+/// `[n0, n1, n2]` is stored at position `(i * n1 + j) * n2 + k` of the flat vector.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct V1TensorLayoutRowMajor<D: Dim> {
+    shape: D::Idx,
+}
+
+impl<D: Dim> V1TensorLayoutRowMajor<D> {
+    pub(super) fn new(shape: D::Idx) -> Self {
+        Self { shape }
+    }
+}
+
+impl V1TensorLayout<D3> for V1TensorLayoutRowMajor<D3> {
+    #[inline(always)]
+    fn shape(&self) -> [usize; 3] {
+        self.shape
+    }
+
+    #[inline(always)]
+    fn num_elements(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    #[inline(always)]
+    fn v1_idx(&self, [i, j, k]: [usize; 3]) -> usize {
+        let [n0, n1, n2] = self.shape;
+        match i < n0 && j < n1 && k < n2 {
+            true => (i * n1 + j) * n2 + k,
+            false => panic_oob([i, j, k], self.shape),
+        }
+    }
+
+    fn all<'a, T, V>(&'a self, data: &'a V) -> impl Iterator<Item = T> + 'a
+    where
+        V: NVec<D1, T> + 'a,
+        T: 'a,
+    {
+        data.all()
+    }
+}
+
+impl V1TensorLayout<D4> for V1TensorLayoutRowMajor<D4> {
+    #[inline(always)]
+    fn shape(&self) -> [usize; 4] {
+        self.shape
+    }
+
+    #[inline(always)]
+    fn num_elements(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    #[inline(always)]
+    fn v1_idx(&self, [i, j, k, l]: [usize; 4]) -> usize {
+        let [n0, n1, n2, n3] = self.shape;
+        match i < n0 && j < n1 && k < n2 && l < n3 {
+            true => ((i * n1 + j) * n2 + k) * n3 + l,
+            false => panic_oob([i, j, k, l], self.shape),
+        }
+    }
+
+    fn all<'a, T, V>(&'a self, data: &'a V) -> impl Iterator<Item = T> + 'a
+    where
+        V: NVec<D1, T> + 'a,
+        T: 'a,
+    {
+        data.all()
+    }
+}
+
+// col major
+
+/// Column-major, or Fortran-order, layout of a tensor with an underlying flat
+/// vector, where the first index changes fastest.
+///
+/// For instance, the element at `[i, j, k]` of a `D3` tensor with shape
+/// `[n0, n1, n2]` is stored at position `i + n0 * (j + n1 * k)` of the flat vector.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct V1TensorLayoutColMajor<D: Dim> {
+    shape: D::Idx,
+}
+
+impl<D: Dim> V1TensorLayoutColMajor<D> {
+    pub(super) fn new(shape: D::Idx) -> Self {
+        Self { shape }
+    }
+}
+
+impl V1TensorLayout<D3> for V1TensorLayoutColMajor<D3> {
+    #[inline(always)]
+    fn shape(&self) -> [usize; 3] {
+        self.shape
+    }
+
+    #[inline(always)]
+    fn num_elements(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    #[inline(always)]
+    fn v1_idx(&self, [i, j, k]: [usize; 3]) -> usize {
+        let [n0, n1, n2] = self.shape;
+        match i < n0 && j < n1 && k < n2 {
+            true => i + n0 * (j + n1 * k),
+            false => panic_oob([i, j, k], self.shape),
+        }
+    }
+
+    fn all<'a, T, V>(&'a self, data: &'a V) -> impl Iterator<Item = T> + 'a
+    where
+        V: NVec<D1, T> + 'a,
+        T: 'a,
+    {
+        let [n0, n1, n2] = self.shape;
+        (0..n0).flat_map(move |i| {
+            (0..n1).flat_map(move |j| (0..n2).map(move |k| data.at(self.v1_idx([i, j, k]))))
+        })
+    }
+}
+
+impl V1TensorLayout<D4> for V1TensorLayoutColMajor<D4> {
+    #[inline(always)]
+    fn shape(&self) -> [usize; 4] {
+        self.shape
+    }
+
+    #[inline(always)]
+    fn num_elements(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    #[inline(always)]
+    fn v1_idx(&self, [i, j, k, l]: [usize; 4]) -> usize {
+        let [n0, n1, n2, n3] = self.shape;
+        match i < n0 && j < n1 && k < n2 && l < n3 {
+            true => i + n0 * (j + n1 * (k + n2 * l)),
+            false => panic_oob([i, j, k, l], self.shape),
+        }
+    }
+
+    fn all<'a, T, V>(&'a self, data: &'a V) -> impl Iterator<Item = T> + 'a
+    where
+        V: NVec<D1, T> + 'a,
+        T: 'a,
+    {
+        let [n0, n1, n2, n3] = self.shape;
+        (0..n0).flat_map(move |i| {
+            (0..n1).flat_map(move |j| {
+                (0..n2).flat_map(move |k| (0..n3).map(move |l| data.at(self.v1_idx([i, j, k, l]))))
+            })
+        })
+    }
+}
@@ -0,0 +1,19 @@
+mod impl_into_cached;
+mod impl_nvec;
+mod layout;
+mod v1_as_tensor;
+mod v1_tensor;
+
+pub use layout::{V1TensorLayout, V1TensorLayoutColMajor, V1TensorLayoutRowMajor};
+pub use v1_as_tensor::V1AsTensor;
+pub use v1_tensor::{V1Tensor, V1TensorColMajor, V1TensorRowMajor};
+
+// errors
+
+#[allow(clippy::panic)]
+fn panic_oob<Idx: core::fmt::Debug>(idx: Idx, shape: Idx) -> ! {
+    panic!(
+        "Index {:?} is out of bounds of the tensor with shape {:?}",
+        idx, shape
+    );
+}
@@ -0,0 +1,295 @@
+use super::{
+    layout::{V1TensorLayoutColMajor, V1TensorLayoutRowMajor},
+    v1_tensor::V1Tensor,
+};
+use crate::{NVec, NVecMut, D1, D3, D4};
+
+/// Creates tensor views, or multi-dimensional rectangular vector views, of a flat
+/// `D1` vector.
+///
+/// Each view is defined by a shape and a layout:
+/// * in a row-major, or C-order, layout, the last index changes fastest; i.e., the
+///   element at `[i, j, k]` of a tensor with shape `[n0, n1, n2]` is at position
+///   `(i * n1 + j) * n2 + k` of the flat vector;
+/// * in a col-major, or Fortran-order, layout, the first index changes fastest; i.e.,
+///   the same element is at position `i + n0 * (j + n1 * k)`.
+///
+/// Regardless of the layout, the tensors are `NVec`s; hence, `child`, `card` and
+/// `all` follow the tensor indices. For instance, `all` returns the elements in
+/// lexicographic order of their tensor indices.
+pub trait V1AsTensor<T> {
+    /// Converts the flat `D1` vector into a row-major `D3` tensor with the given `shape`.
+    ///
+    /// See [`v1_as_d3`] for details and examples.
+    ///
+    /// [`v1_as_d3`]: crate::V1AsTensor::v1_as_d3
+    fn v1_into_d3(self, shape: [usize; 3]) -> V1Tensor<D3, T, Self, V1TensorLayoutRowMajor<D3>>
+    where
+        Self: NVec<D1, T>,
+    {
+        V1Tensor::new(V1TensorLayoutRowMajor::new(shape), self)
+    }
+
+    /// Creates a row-major, or C-order, `D3` tensor view with the given `shape`
+    /// over the flat `D1` vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if cardinality of the `D1` vector is not equal to the product of
+    /// the `shape`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// // volumetric data stored as a flat vector
+    /// let data: Vec<f32> = (0..24).map(|x| x as f32).collect();
+    ///
+    /// let v3 = data.v1_as_d3([2, 3, 4]);
+    /// assert_eq!(v3.card([]), 2);
+    /// assert_eq!(v3.card([1]), 3);
+    /// assert_eq!(v3.card([1, 2]), 4);
+    /// assert_eq!(v3.at([1, 2, 3]), 23.0);
+    /// assert_eq!(v3.child(1).child(0).equality(&[12.0, 13.0, 14.0, 15.0]), Equality::Equal);
+    ///
+    /// // elements are stored in lexicographic order of indices
+    /// assert!(v3.all().eq(data.iter().copied()));
+    /// ```
+    fn v1_as_d3(&self, shape: [usize; 3]) -> V1Tensor<D3, T, &Self, V1TensorLayoutRowMajor<D3>>
+    where
+        Self: NVec<D1, T>,
+    {
+        V1Tensor::new(V1TensorLayoutRowMajor::new(shape), self)
+    }
+
+    /// Creates a mutable row-major, or C-order, `D3` tensor view with the given
+    /// `shape` over the flat `D1` vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if cardinality of the `D1` vector is not equal to the product of
+    /// the `shape`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut data = vec![0; 8];
+    ///
+    /// let mut v3 = data.v1_as_d3_mut([2, 2, 2]);
+    /// v3.set([1, 0, 1], 5);
+    /// v3.child_mut(0).set([1, 1], 3);
+    /// assert_eq!(data, vec![0, 0, 0, 3, 0, 5, 0, 0]);
+    /// ```
+    fn v1_as_d3_mut(
+        &mut self,
+        shape: [usize; 3],
+    ) -> V1Tensor<D3, T, &mut Self, V1TensorLayoutRowMajor<D3>>
+    where
+        Self: NVecMut<D1, T>,
+    {
+        V1Tensor::new(V1TensorLayoutRowMajor::new(shape), self)
+    }
+
+    /// Converts the flat `D1` vector into a col-major `D3` tensor with the given `shape`.
+    ///
+    /// See [`v1_as_d3_col_major`] for details and examples.
+    ///
+    /// [`v1_as_d3_col_major`]: crate::V1AsTensor::v1_as_d3_col_major
+    fn v1_into_d3_col_major(
+        self,
+        shape: [usize; 3],
+    ) -> V1Tensor<D3, T, Self, V1TensorLayoutColMajor<D3>>
+    where
+        Self: NVec<D1, T>,
+    {
+        V1Tensor::new(V1TensorLayoutColMajor::new(shape), self)
+    }
+
+    /// Creates a col-major, or Fortran-order, `D3` tensor view with the given `shape`
+    /// over the flat `D1` vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if cardinality of the `D1` vector is not equal to the product of
+    /// the `shape`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let data: Vec<_> = (0..24).collect();
+    ///
+    /// let v3 = data.v1_as_d3_col_major([2, 3, 4]);
+    /// assert_eq!(v3.card([1, 2]), 4);
+    /// assert_eq!(v3.at([1, 0, 0]), 1);
+    /// assert_eq!(v3.at([0, 1, 0]), 2);
+    /// assert_eq!(v3.at([0, 0, 1]), 6);
+    /// assert_eq!(v3.child(1).child(2).equality(&[5, 11, 17, 23]), Equality::Equal);
+    ///
+    /// // all yields elements in lexicographic order of indices, not storage order
+    /// assert_eq!(v3.all().take(4).collect::<Vec<_>>(), vec![0, 6, 12, 18]);
+    /// ```
+    fn v1_as_d3_col_major(
+        &self,
+        shape: [usize; 3],
+    ) -> V1Tensor<D3, T, &Self, V1TensorLayoutColMajor<D3>>
+    where
+        Self: NVec<D1, T>,
+    {
+        V1Tensor::new(V1TensorLayoutColMajor::new(shape), self)
+    }
+
+    /// Creates a mutable col-major, or Fortran-order, `D3` tensor view with the given
+    /// `shape` over the flat `D1` vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if cardinality of the `D1` vector is not equal to the product of
+    /// the `shape`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_v::*;
+    ///
+    /// let mut data = vec![0; 8];
+    ///
+    /// let mut v3 = data.v1_as_d3_col_major_mut([2, 2, 2]);
+    /// v3.set([1, 0, 1], 5);
+    /// v3.child_mut(0).set([1, 1], 3);
+    /// assert_eq!(data, vec![0, 0, 0, 0, 0, 5, 3, 0]);
+    /// ```
+    fn v1_as_d3_col_major_mut(
+        &mut self,
+        shape: [usize; 3],
+    ) -> V1Tensor<D3, T, &mut Self, V1TensorLayoutColMajor<D3>>
+    where
+        Self: NVecMut<D1, T>,
+    {
+        V1Tensor::new(V1TensorLayoutColMajor::new(shape), self)
+    }
+
+    /// Converts the flat `D1` vector into a row-major `D4` tensor with the given `shape`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if cardinality of the `D1` vector is not equal to the product of
+    /// the `shape`.
+    ///
+    /// See [`v1_into_d3`] for examples of the `D3` counterpart.
+    ///
+    /// [`v1_into_d3`]: crate::V1AsTensor::v1_into_d3
+    fn v1_into_d4(self, shape: [usize; 4]) -> V1Tensor<D4, T, Self, V1TensorLayoutRowMajor<D4>>
+    where
+        Self: NVec<D1, T>,
+    {
+        V1Tensor::new(V1TensorLayoutRowMajor::new(shape), self)
+    }
+
+    /// Creates a row-major `D4` tensor view with the given
+    /// `shape` over the flat `D1` vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if cardinality of the `D1` vector is not equal to the product of
+    /// the `shape`.
+    ///
+    /// See [`v1_as_d3`] for examples of the `D3` counterpart.
+    ///
+    /// [`v1_as_d3`]: crate::V1AsTensor::v1_as_d3
+    fn v1_as_d4(&self, shape: [usize; 4]) -> V1Tensor<D4, T, &Self, V1TensorLayoutRowMajor<D4>>
+    where
+        Self: NVec<D1, T>,
+    {
+        V1Tensor::new(V1TensorLayoutRowMajor::new(shape), self)
+    }
+
+    /// Creates a mutable row-major `D4` tensor view with the given
+    /// `shape` over the flat `D1` vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if cardinality of the `D1` vector is not equal to the product of
+    /// the `shape`.
+    ///
+    /// See [`v1_as_d3_mut`] for examples of the `D3` counterpart.
+    ///
+    /// [`v1_as_d3_mut`]: crate::V1AsTensor::v1_as_d3_mut
+    fn v1_as_d4_mut(
+        &mut self,
+        shape: [usize; 4],
+    ) -> V1Tensor<D4, T, &mut Self, V1TensorLayoutRowMajor<D4>>
+    where
+        Self: NVecMut<D1, T>,
+    {
+        V1Tensor::new(V1TensorLayoutRowMajor::new(shape), self)
+    }
+
+    /// Converts the flat `D1` vector into a col-major `D4` tensor with the given `shape`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if cardinality of the `D1` vector is not equal to the product of
+    /// the `shape`.
+    ///
+    /// See [`v1_into_d3_col_major`] for examples of the `D3` counterpart.
+    ///
+    /// [`v1_into_d3_col_major`]: crate::V1AsTensor::v1_into_d3_col_major
+    fn v1_into_d4_col_major(
+        self,
+        shape: [usize; 4],
+    ) -> V1Tensor<D4, T, Self, V1TensorLayoutColMajor<D4>>
+    where
+        Self: NVec<D1, T>,
+    {
+        V1Tensor::new(V1TensorLayoutColMajor::new(shape), self)
+    }
+
+    /// Creates a col-major `D4` tensor view with the given
+    /// `shape` over the flat `D1` vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if cardinality of the `D1` vector is not equal to the product of
+    /// the `shape`.
+    ///
+    /// See [`v1_as_d3_col_major`] for examples of the `D3` counterpart.
+    ///
+    /// [`v1_as_d3_col_major`]: crate::V1AsTensor::v1_as_d3_col_major
+    fn v1_as_d4_col_major(
+        &self,
+        shape: [usize; 4],
+    ) -> V1Tensor<D4, T, &Self, V1TensorLayoutColMajor<D4>>
+    where
+        Self: NVec<D1, T>,
+    {
+        V1Tensor::new(V1TensorLayoutColMajor::new(shape), self)
+    }
+
+    /// Creates a mutable col-major `D4` tensor view with the given
+    /// `shape` over the flat `D1` vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if cardinality of the `D1` vector is not equal to the product of
+    /// the `shape`.
+    ///
+    /// See [`v1_as_d3_col_major_mut`] for examples of the `D3` counterpart.
+    ///
+    /// [`v1_as_d3_col_major_mut`]: crate::V1AsTensor::v1_as_d3_col_major_mut
+    fn v1_as_d4_col_major_mut(
+        &mut self,
+        shape: [usize; 4],
+    ) -> V1Tensor<D4, T, &mut Self, V1TensorLayoutColMajor<D4>>
+    where
+        Self: NVecMut<D1, T>,
+    {
+        V1Tensor::new(V1TensorLayoutColMajor::new(shape), self)
+    }
+}
+
+impl<T, V: NVec<D1, T>> V1AsTensor<T> for V {}
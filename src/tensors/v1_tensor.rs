@@ -0,0 +1,91 @@
+use super::layout::{V1TensorLayout, V1TensorLayoutColMajor, V1TensorLayoutRowMajor};
+use crate::common_trait_helpers::debug::*;
+use crate::{Dim, NVec, D1, D3, D4};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A row-major tensor of dimension `D` represented by a flat one-dimensional vector `V1`.
+///
+/// Type alias for [`V1Tensor<D, T, V, V1TensorLayoutRowMajor<D>>`].
+pub type V1TensorRowMajor<D, T, V> = V1Tensor<D, T, V, V1TensorLayoutRowMajor<D>>;
+
+/// A col-major tensor of dimension `D` represented by a flat one-dimensional vector `V1`.
+///
+/// Type alias for [`V1Tensor<D, T, V, V1TensorLayoutColMajor<D>>`].
+pub type V1TensorColMajor<D, T, V> = V1Tensor<D, T, V, V1TensorLayoutColMajor<D>>;
+
+/// A rectangular vector of dimension `D`, or a tensor, represented by a flat
+/// one-dimensional vector `V1` and a layout `L` which maps tensor indices to
+/// positions of the flat vector.
+///
+/// It is created by the methods of the [`V1AsTensor`] trait.
+///
+/// [`V1AsTensor`]: crate::V1AsTensor
+#[derive(Clone)]
+pub struct V1Tensor<D, T, V, L>
+where
+    D: Dim,
+    V: NVec<D1, T>,
+    L: V1TensorLayout<D>,
+{
+    pub(super) layout: L,
+    pub(super) data: V,
+    phantom: PhantomData<(D, T)>,
+}
+
+impl<D, T, V, L> V1Tensor<D, T, V, L>
+where
+    D: Dim,
+    V: NVec<D1, T>,
+    L: V1TensorLayout<D>,
+{
+    pub(super) fn new(layout: L, data: V) -> Self {
+        assert_eq!(
+            layout.num_elements(),
+            data.card([]),
+            "Number of elements of the shape {:?} must be equal to the cardinality of the D1 vector",
+            layout.shape()
+        );
+
+        Self {
+            layout,
+            data,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Shape of the tensor; i.e., number of elements along each dimension.
+    pub fn shape(&self) -> D::Idx {
+        self.layout.shape()
+    }
+
+    /// Destructs the tensor into its underlying flat vector.
+    pub fn into_inner(self) -> V {
+        self.data
+    }
+}
+
+macro_rules! impl_debug {
+    ($dim:ty, $dbg_fn:ident) => {
+        impl<T, V, L> Debug for V1Tensor<$dim, T, V, L>
+        where
+            V: NVec<D1, T>,
+            L: V1TensorLayout<$dim>,
+            T: Debug,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{{ kind: V1Tensor, dim: D{}, shape: {:?}, values: ",
+                    <$dim as Dim>::dimension(),
+                    self.shape(),
+                )?;
+                $dbg_fn(f, self)?;
+                write!(f, " }}")
+            }
+        }
+    };
+}
+
+impl_debug!(D3, dbg_values_d3);
+impl_debug!(D4, dbg_values_d4);
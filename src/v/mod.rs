@@ -2,11 +2,15 @@ mod new_v1;
 mod new_v2;
 mod new_v3;
 mod new_v4;
+mod new_v5;
+mod new_v6;
 
 pub use new_v1::NewV1;
 pub use new_v2::NewV2;
 pub use new_v3::NewV3;
 pub use new_v4::NewV4;
+pub use new_v5::NewV5;
+pub use new_v6::NewV6;
 
 /// Builders for multi-dimensional vectors.
 pub struct V;
@@ -31,4 +35,14 @@ impl V {
     pub fn d4(self) -> NewV4 {
         NewV4
     }
+
+    /// `V5<T>`` (`NVec<D5, T>``) builder.
+    pub fn d5(self) -> NewV5 {
+        NewV5
+    }
+
+    /// `V6<T>`` (`NVec<D6, T>``) builder.
+    pub fn d6(self) -> NewV6 {
+        NewV6
+    }
 }
@@ -0,0 +1,14 @@
+use super::ZipWithVec;
+use crate::{Dim, IntoCached, NVec};
+use core::hash::Hash;
+
+impl<D, T, U, O, V, W, F> IntoCached<D, O> for ZipWithVec<D, T, U, O, V, W, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    W: NVec<D, U>,
+    F: Fn(T, U) -> O,
+    O: Copy,
+    D::Idx: Ord + Hash,
+{
+}
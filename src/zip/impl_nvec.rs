@@ -0,0 +1,27 @@
+use super::ZipWithVec;
+use crate::{dim::*, NVec};
+
+impl<D, T, U, O, V, W, F> NVec<D, O> for ZipWithVec<D, T, U, O, V, W, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    W: NVec<D, U>,
+    F: Fn(T, U) -> O,
+{
+    #[inline(always)]
+    fn at(&self, idx: impl IntoIdx<D>) -> O {
+        let idx = idx.into_idx();
+        (self.f)(self.vec1.at(idx), self.vec2.at(idx))
+    }
+
+    fn child(&self, i: <D as Dim>::ChildIdx) -> impl NVec<<D as Dim>::PrevDim, O> {
+        ZipWithVec::new(self.vec1.child(i), self.vec2.child(i), &self.f)
+    }
+
+    fn all(&self) -> impl Iterator<Item = O> {
+        self.vec1
+            .all()
+            .zip(self.vec2.all())
+            .map(|(a, b)| (self.f)(a, b))
+    }
+}
@@ -0,0 +1,33 @@
+use super::ZipWithVec;
+use crate::{dim::*, NVec, NVecCoreSealed};
+
+impl<D, T, U, O, V, W, F> NVecCoreSealed<D, O> for ZipWithVec<D, T, U, O, V, W, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    W: NVec<D, U>,
+    F: Fn(T, U) -> O,
+{
+    #[inline(always)]
+    fn core_num_children(&self) -> usize {
+        self.vec1.num_children()
+    }
+
+    #[inline(always)]
+    fn core_card(&self, idx: impl Into<<D as Dim>::CardIdx>) -> usize {
+        self.vec1.card(idx)
+    }
+
+    fn core_child(&self, i: <D as Dim>::ChildIdx) -> impl NVecCoreSealed<<D as Dim>::PrevDim, O> {
+        ZipWithVec::new(self.vec1.child(i), self.vec2.child(i), &self.f)
+    }
+
+    fn core_map<M: FnMut(&O) -> X, X>(&self, idx: impl IntoIdx<D>, f: &mut M) -> X {
+        let idx = idx.into_idx();
+        f(&(self.f)(self.vec1.at(idx), self.vec2.at(idx)))
+    }
+
+    fn core_is_rectangular(&self) -> bool {
+        self.vec1.is_rectangular()
+    }
+}
@@ -0,0 +1,6 @@
+mod impl_into_cached;
+mod impl_nvec;
+mod impl_nvec_core;
+mod zip_with_vec;
+
+pub use zip_with_vec::ZipWithVec;
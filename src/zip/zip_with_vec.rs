@@ -0,0 +1,94 @@
+use crate::common_trait_helpers::debug::*;
+use crate::{dim::*, NVec};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A lazy vector of dimension `D` combining corresponding elements of two vectors
+/// `V` and `W` having the same cardinality with `f: Fn(T, U) -> O`.
+///
+/// The zipped vector has exactly the same cardinality as its underlying vectors.
+/// Elements are not stored, `f` is applied each time an element is accessed.
+///
+/// It is created by the [`zip_with`] and [`zip`] methods of `NVec`.
+///
+/// [`zip_with`]: crate::NVec::zip_with
+/// [`zip`]: crate::NVec::zip
+///
+/// # Examples
+///
+/// ```
+/// use orx_v::*;
+///
+/// let costs = vec![vec![1, 2, 3], vec![4]];
+/// let capacities = vec![vec![10, 0, 30], vec![40]];
+///
+/// let total = costs.zip_with(&capacities, |c, q| c * q).unwrap();
+/// assert_eq!(total.card([0]), 3);
+/// assert_eq!(total.at([0, 2]), 90);
+/// assert_eq!(total.equality(&[vec![10, 0, 90], vec![160]]), Equality::Equal);
+/// ```
+pub struct ZipWithVec<D, T, U, O, V, W, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    W: NVec<D, U>,
+    F: Fn(T, U) -> O,
+{
+    pub(super) vec1: V,
+    pub(super) vec2: W,
+    pub(super) f: F,
+    phantom: PhantomData<(D, T, U, O)>,
+}
+
+impl<D, T, U, O, V, W, F> ZipWithVec<D, T, U, O, V, W, F>
+where
+    D: Dim,
+    V: NVec<D, T>,
+    W: NVec<D, U>,
+    F: Fn(T, U) -> O,
+{
+    pub(crate) fn new(vec1: V, vec2: W, f: F) -> Self {
+        Self {
+            vec1,
+            vec2,
+            f,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Destructs the zipped vector into its underlying vectors and zip function.
+    pub fn into_inner(self) -> (V, W, F) {
+        (self.vec1, self.vec2, self.f)
+    }
+}
+
+macro_rules! impl_debug {
+    ($dim:ty, $dbg_fn:ident) => {
+        impl<T, U, O, V, W, F> Debug for ZipWithVec<$dim, T, U, O, V, W, F>
+        where
+            V: NVec<$dim, T>,
+            W: NVec<$dim, U>,
+            F: Fn(T, U) -> O,
+            O: Debug,
+            Self: NVec<$dim, O>,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{{ kind: ZipWithVec, dim: D{}, is_bounded: {}, values: ",
+                    <$dim as Dim>::dimension(),
+                    self.is_bounded(),
+                )?;
+                $dbg_fn(f, self)?;
+                write!(f, " }}")
+            }
+        }
+    };
+}
+
+impl_debug!(D1, dbg_values_d1);
+impl_debug!(D2, dbg_values_d2);
+impl_debug!(D3, dbg_values_d3);
+impl_debug!(D4, dbg_values_d4);
+impl_debug!(D5, dbg_values_d5);
+impl_debug!(D6, dbg_values_d6);
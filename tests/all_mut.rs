@@ -0,0 +1,296 @@
+use orx_v::*;
+
+fn add_position<V: NVecAllMut<D2, usize>>(v: &mut V) {
+    for ([i, j], x) in v.enumerate_all_mut() {
+        *x += 10 * i + j;
+    }
+}
+
+#[test]
+fn all_mut_vec_array_slice() {
+    let mut v1 = vec![1, 2, 3, 4];
+    for (x, y) in v1.all_mut().zip([10, 20]) {
+        *x += y;
+    }
+    assert_eq!(v1, vec![11, 22, 3, 4]);
+
+    let mut v1 = [1, 2, 3, 4];
+    let mut s = v1.as_mut_slice();
+    for ([i], x) in s.enumerate_all_mut().skip(2) {
+        *x = i;
+    }
+    assert_eq!(v1, [1, 2, 2, 3]);
+
+    let mut v2 = vec![vec![0, 0], vec![], vec![0, 0, 0]];
+    add_position(&mut v2);
+    assert_eq!(v2, vec![vec![0, 1], vec![], vec![20, 21, 22]]);
+    assert_eq!(v2.all_mut().count(), 5);
+
+    let mut v3 = [[[0usize; 2]; 2]; 2];
+    for ([i, j, k], x) in NVecAllMut::<D3, _>::enumerate_all_mut(&mut v3) {
+        *x = 100 * i + 10 * j + k;
+    }
+    assert_eq!(v3, [[[0, 1], [10, 11]], [[100, 101], [110, 111]]]);
+}
+
+#[test]
+fn all_mut_early_exit() {
+    let mut v2 = vec![vec![3, 1], vec![4, 1, 5], vec![9]];
+
+    if let Some(x) = v2.all_mut().find(|x| **x > 3) {
+        *x = 0;
+    }
+    assert_eq!(v2, vec![vec![3, 1], vec![0, 1, 5], vec![9]]);
+
+    let ([i, j], x) = v2.enumerate_all_mut().find(|(_, x)| **x > 3).unwrap();
+    *x = 0;
+    assert_eq!([i, j], [1, 2]);
+    assert_eq!(v2, vec![vec![3, 1], vec![0, 1, 0], vec![9]]);
+}
+
+#[test]
+fn all_mut_children() {
+    let mut v3 = vec![vec![vec![0, 0], vec![0]], vec![vec![0], vec![0, 0]]];
+
+    let child = [[1, 0, 0], [1, 1, 0], [1, 1, 1]];
+    for (x, [_, j, k]) in v3.all_mut_in(child.into_iter()).zip(child) {
+        *x = 10 * j + k + 1;
+    }
+    assert_eq!(v3[1][0].all_mut().count(), 1);
+    assert_eq!(
+        v3,
+        vec![vec![vec![0, 0], vec![0]], vec![vec![1], vec![11, 12]]]
+    );
+}
+
+#[test]
+fn all_mut_flat_jagged() {
+    let mut jagged = vec![0; 6].into_jagged(vec![2, 2, 6]);
+    add_position(&mut jagged);
+    assert_eq!(jagged.all().collect::<Vec<_>>(), vec![0, 1, 20, 21, 22, 23]);
+
+    let row = (0..4).map(|j| [2, j]);
+    for (j, x) in jagged.all_mut_in(row).enumerate() {
+        *x += j;
+    }
+    assert_eq!(
+        jagged.child(2).all().collect::<Vec<_>>(),
+        vec![20, 22, 24, 26]
+    );
+}
+
+#[test]
+fn all_mut_sparse() {
+    let mut v2 = V.d2().sparse(1).with_rectangular_bounds([2, 3]);
+    v2.set([1, 1], 5);
+
+    for (x, y) in v2.all_mut().zip(0..) {
+        *x += y;
+    }
+    assert_eq!(v2.all().collect::<Vec<_>>(), vec![1, 2, 3, 4, 9, 6]);
+
+    let mut v1 = V.d1().sparse(0).bounded(4);
+    for ([i], x) in v1.enumerate_all_mut() {
+        *x = i * i;
+    }
+    assert_eq!(v1.lookup_len(), 4);
+    assert_eq!(v1.all().collect::<Vec<_>>(), vec![0, 1, 4, 9]);
+}
+
+#[test]
+fn all_mut_sparse_views() {
+    let mut v1 = V.d1().sparse(0).bounded(1000);
+    v1.set([2], 20);
+    v1.set([3], 30);
+    assert_eq!(v1.lookup_len(), 2);
+
+    v1.v1_as_slice_mut(2..4).all_mut().for_each(|x| *x += 1);
+    assert_eq!(v1.lookup_len(), 2);
+    assert_eq!(v1.all_in(2..4).collect::<Vec<_>>(), vec![21, 31]);
+
+    for ([i], x) in v1.v1_as_strided_mut(990, 3, 3).enumerate_all_mut() {
+        *x = i + 1;
+    }
+    assert_eq!(v1.lookup_len(), 5);
+    assert_eq!(
+        v1.all_in([990, 993, 996].into_iter()).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+
+    let mut v2 = V.d2().sparse(0).with_rectangular_bounds([100, 100]);
+    v2.v2_as_block_mut(10..12, 20..22)
+        .all_mut()
+        .for_each(|x| *x = 7);
+    assert_eq!(v2.lookup_len(), 4);
+    assert_eq!(v2.at([11, 21]), 7);
+}
+
+#[test]
+fn all_mut_in() {
+    let mut v2 = vec![vec![0, 1], vec![], vec![2, 3, 4]];
+    for (x, y) in v2.all_mut_in([[2, 2], [0, 1]].into_iter()).zip([10, 20]) {
+        *x += y;
+    }
+    assert_eq!(v2, vec![vec![0, 21], vec![], vec![2, 3, 14]]);
+
+    let mut v1 = V.d1().sparse(0).bounded(10);
+    assert_eq!(v1.all_mut_in([7, 3].into_iter()).count(), 2);
+    assert_eq!(v1.lookup_len(), 2);
+}
+
+#[test]
+#[should_panic]
+fn all_mut_in_duplicate_indices() {
+    let mut v1 = vec![0; 4];
+    let _ = v1.all_mut_in([1, 2, 1].into_iter()).count();
+}
+
+#[test]
+#[should_panic]
+fn all_mut_in_out_of_bounds() {
+    let mut v1 = vec![0; 4];
+    let _ = v1.all_mut_in([1, 4].into_iter()).count();
+}
+
+#[test]
+#[should_panic]
+fn all_mut_in_sparse_out_of_bounds() {
+    let mut v1 = V.d1().sparse(0).bounded(3);
+    let _ = v1.all_mut_in([[5]].into_iter()).count();
+}
+
+#[test]
+fn all_mut_in_sparse_out_of_bounds_does_not_insert() {
+    let mut v2 = V.d2().sparse(0).with_rectangular_bounds([2, 3]);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _ = v2.all_mut_in([[0, 0], [0, 3]].into_iter()).count();
+    }));
+    assert!(result.is_err());
+    assert_eq!(v2.lookup_len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn all_mut_in_matrix_out_of_bounds() {
+    let mut v1: Vec<_> = (0..6).collect();
+    let mut mat = v1.v1_as_matrix_mut(2, 3);
+    let _ = mat.all_mut_in([[0, 4]].into_iter()).count();
+}
+
+#[test]
+#[should_panic]
+fn all_mut_sparse_unbounded() {
+    let mut v1 = V.d1().sparse(0);
+    let _ = v1.all_mut().next();
+}
+
+#[test]
+fn all_mut_views() {
+    let mut v1: Vec<_> = (0..8).collect();
+
+    v1.v1_as_slice_mut(2..5).all_mut().for_each(|x| *x *= 10);
+    assert_eq!(v1, vec![0, 1, 20, 30, 40, 5, 6, 7]);
+
+    for ([i], x) in v1.v1_as_rev_mut().enumerate_all_mut().take(2) {
+        *x = i;
+    }
+    assert_eq!(v1, vec![0, 1, 20, 30, 40, 5, 1, 0]);
+
+    v1.v1_as_strided_mut(1, 3, 3).all_mut().for_each(|x| *x = 0);
+    assert_eq!(v1, vec![0, 0, 20, 30, 0, 5, 1, 0]);
+
+    let indices = vec![7, 2, 4];
+    for (x, y) in v1.v1_select_mut(&indices).all_mut().zip([1, 2, 3]) {
+        *x = y;
+    }
+    assert_eq!(v1, vec![0, 0, 2, 30, 3, 5, 1, 1]);
+
+    let mut v2 = vec![vec![0; 3]; 2];
+    for ([i, j], x) in v2.as_transposed_mut().enumerate_all_mut() {
+        *x = 10 * i + j;
+    }
+    assert_eq!(v2, vec![vec![0, 10, 20], vec![1, 11, 21]]);
+
+    let mut b = v2.v2_as_block_mut(0..2, 1..3);
+    for ([i, j], x) in b.enumerate_all_mut() {
+        *x = 100 + 10 * i + j;
+    }
+    assert_eq!(v2, vec![vec![0, 100, 101], vec![1, 110, 111]]);
+
+    for (k, x) in v2.as_flat_mut().all_mut().enumerate() {
+        *x = k;
+    }
+    assert_eq!(v2, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+}
+
+#[test]
+#[should_panic]
+fn all_mut_select_duplicate_indices() {
+    let mut v1 = vec![0; 4];
+    let indices = vec![1, 2, 1];
+    let _ = v1.v1_select_mut(&indices).all_mut().count();
+}
+
+#[test]
+fn all_mut_tensors() {
+    let mut data = vec![0; 12];
+
+    let mut v3 = data.v1_as_d3_col_major_mut([2, 3, 2]);
+    for ([i, j, k], x) in v3.enumerate_all_mut() {
+        *x = 100 * i + 10 * j + k;
+    }
+    assert_eq!(v3.at([1, 2, 0]), 120);
+    assert_eq!(v3.at([0, 1, 1]), 11);
+    assert_eq!(data[..4], [0, 100, 10, 110]);
+}
+
+#[test]
+fn all_mut_matrices() {
+    let mut v1 = vec![0; 6];
+    let mut mat = v1.v1_as_matrix_col_major_mut(2, 3);
+    for ([i, j], x) in mat.enumerate_all_mut() {
+        *x = 10 * i + j;
+    }
+    assert_eq!(mat.at([1, 2]), 12);
+    assert_eq!(v1, vec![0, 10, 1, 11, 2, 12]);
+
+    let mut v2 = vec![vec![0; 3]; 2];
+    let mut mat = v2.as_matrix_col_major_mut();
+    for ([i, j], x) in mat.enumerate_all_mut() {
+        *x = 10 * i + j;
+    }
+    assert_eq!(v2, vec![vec![0, 10, 20], vec![1, 11, 21]]);
+
+    let mut v1 = vec![0; 12];
+    let mut mat = v1.v1_as_matrix_mut(3, 4);
+    let mut block = mat.as_block_mut(1..3, 1..3);
+    for (x, y) in block.all_mut().zip(1..) {
+        *x = y;
+    }
+    assert_eq!(v1, vec![0, 0, 0, 0, 0, 1, 2, 0, 0, 3, 4, 0]);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn all_mut_ndarray() {
+    use ndarray::Array2;
+
+    let mut arr = Array2::<usize>::zeros((2, 3));
+    add_position(&mut arr);
+    assert_eq!(arr.all().collect::<Vec<_>>(), vec![0, 1, 2, 10, 11, 12]);
+}
+
+#[cfg(feature = "orx-split-vec")]
+#[test]
+fn all_mut_split_vec() {
+    use orx_split_vec::SplitVec;
+
+    let mut v1: SplitVec<_> = (0..10).collect();
+    for (x, y) in v1.all_mut().zip(0..).filter(|(_, y)| y % 3 == 0) {
+        *x = 100 + y;
+    }
+    assert_eq!(
+        v1.all().collect::<Vec<_>>(),
+        vec![100, 1, 2, 103, 4, 5, 106, 7, 8, 109]
+    );
+}
@@ -0,0 +1,88 @@
+use orx_v::*;
+
+fn weighted_sum(v: impl V2<i32>, w: impl V2<i32>) -> i32 {
+    v.all().zip(w.all()).map(|(x, y)| x * y).sum()
+}
+
+#[test]
+fn broadcast_rows_and_cols() {
+    let v2 = vec![vec![1, 2, 3], vec![4, 5, 6]];
+
+    let row_weights = vec![10, 100];
+    let w = row_weights.as_broadcast_rows(3);
+    assert_eq!(w.shape(), [2, 3]);
+    assert_eq!(w.card([]), 2);
+    assert_eq!(w.card([1]), 3);
+    assert!(w.is_rectangular());
+    assert_eq!(w.try_at([1, 3]), None);
+    assert_eq!(weighted_sum(&v2, &w), 10 * 6 + 100 * 15);
+
+    let col_weights = vec![1, 10, 100];
+    let w = col_weights.as_broadcast_cols(2);
+    assert_eq!(w.shape(), [2, 3]);
+    assert_eq!(w.try_at([2, 0]), None);
+    assert_eq!(w.child(1).equality(&col_weights), Equality::Equal);
+    assert_eq!(weighted_sum(&v2, &w), 5 + 70 + 900);
+
+    let w = V.d1().fun(|[i]| i as i32).bounded(3).into_broadcast_cols(0);
+    assert_eq!(w.card([]), 0);
+    assert_eq!(w.all().count(), 0);
+}
+
+#[test]
+fn broadcast_axis_d2_to_d3() {
+    let v2 = V
+        .d2()
+        .fun(|[i, j]| 10 * i + j)
+        .with_rectangular_bounds([2, 3]);
+
+    for axis in 0..3 {
+        let v3 = v2.as_broadcast_axis(axis, 4);
+        assert_eq!(v3.axis(), axis);
+        assert_eq!(v3.all().count(), 24);
+        for i in 0..2 {
+            for j in 0..3 {
+                for k in 0..4 {
+                    let idx = match axis {
+                        0 => [k, i, j],
+                        1 => [i, k, j],
+                        _ => [i, j, k],
+                    };
+                    assert_eq!(v3.at(idx), 10 * i + j);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn broadcast_axis_higher_dims() {
+    let v3 = vec![vec![vec![1, 2]], vec![vec![3, 4]]];
+    let v4 = v3.as_broadcast_axis(3, 2);
+    assert_eq!(v4.shape(), [2, 1, 2, 2]);
+    assert_eq!(v4.at([1, 0, 1, 0]), 4);
+    assert_eq!(v4.at([1, 0, 1, 1]), 4);
+
+    let v5 = v4.into_broadcast_axis(0, 3);
+    assert_eq!(v5.shape(), [3, 2, 1, 2, 2]);
+    assert_eq!(v5.at([2, 1, 0, 1, 1]), 4);
+    assert_eq!(v5.all().sum::<i32>(), 3 * 2 * 10);
+
+    let v6 = v5.into_broadcast_axis(5, 1);
+    assert_eq!(v6.card([0, 0, 0, 0, 0]), 1);
+    assert_eq!(v6.into_inner().into_inner().into_inner(), &v3);
+}
+
+#[test]
+#[should_panic]
+fn broadcast_axis_out_of_bounds() {
+    let v2 = vec![vec![0, 1], vec![2, 3]];
+    let _ = v2.into_broadcast_axis(3, 2);
+}
+
+#[test]
+#[should_panic]
+fn broadcast_unbounded() {
+    let v1 = V.d1().constant(1);
+    let _ = v1.into_broadcast_rows(2);
+}
@@ -0,0 +1,149 @@
+use orx_v::*;
+
+fn scale_rows(v: &mut impl NVecChildrenMut<D2, i32>) {
+    for (i, mut row) in v.children_mut().enumerate() {
+        row.mut_all(|x| *x *= i as i32 + 1);
+    }
+}
+
+#[test]
+fn children_mut_vec_slice_array() {
+    let mut v2 = vec![vec![1, 1], vec![], vec![1, 1, 1]];
+    scale_rows(&mut v2);
+    assert_eq!(v2, vec![vec![1, 1], vec![], vec![3, 3, 3]]);
+
+    let mut v2 = [[1, 1], [1, 1], [1, 1]];
+    scale_rows(&mut v2);
+    assert_eq!(v2, [[1, 1], [2, 2], [3, 3]]);
+
+    let mut v2 = vec![vec![1], vec![1], vec![1]];
+    let mut slice = v2.as_mut_slice();
+    scale_rows(&mut slice);
+    assert_eq!(v2, vec![vec![1], vec![2], vec![3]]);
+
+    let mut v3 = vec![vec![vec![0, 0], vec![0]], vec![vec![0]]];
+    for (i, mut c) in v3.children_mut().enumerate() {
+        for j in 0..c.num_children() {
+            c.child_mut(j).mut_all(|x| *x = 10 * i + j);
+        }
+    }
+    assert_eq!(v3, vec![vec![vec![0, 0], vec![1]], vec![vec![10]]]);
+}
+
+#[test]
+fn split_children_mut_vec() {
+    let mut v2 = vec![vec![0, 1], vec![2], vec![3, 4, 5]];
+
+    for i in 0..=3 {
+        let (first, second) = v2.split_children_mut(i);
+        assert_eq!(first.card([]), i);
+        assert_eq!(second.card([]), 3 - i);
+    }
+
+    {
+        let (mut first, mut second) = v2.split_children_mut(2);
+        first.set([1, 0], 20);
+        second.set([0, 2], 50);
+    }
+    assert_eq!(v2, vec![vec![0, 1], vec![20], vec![3, 4, 50]]);
+}
+
+#[test]
+fn split_children_mut_threads() {
+    let mut v3 = vec![vec![vec![0; 4]; 3]; 8];
+
+    let (mut first, mut second) = v3.split_children_mut(5);
+    std::thread::scope(|s| {
+        s.spawn(move || first.mut_all(|x| *x = 1));
+        s.spawn(move || second.mut_all(|x| *x = 2));
+    });
+
+    assert_eq!(v3.all().filter(|x| *x == 1).count(), 5 * 3 * 4);
+    assert_eq!(v3.all().filter(|x| *x == 2).count(), 3 * 3 * 4);
+}
+
+#[test]
+fn children_mut_flat_jagged() {
+    let mut jagged = vec![1, 1, 1, 1, 1, 1].into_jagged(vec![1, 1, 4, 6]);
+    scale_rows(&mut jagged);
+    assert_eq!(
+        jagged.equality(&[vec![1], vec![], vec![3, 3, 3], vec![4, 4]]),
+        Equality::Equal
+    );
+
+    let mut storage = [0, 1, 2, 3, 4, 5];
+    let row_lengths = [2, 0, 3, 1];
+    let mut jagged = storage.as_jagged_mut_from_row_lengths(&row_lengths);
+    let rows: Vec<_> = jagged
+        .children_mut()
+        .map(|row| row.all().collect::<Vec<_>>())
+        .collect();
+    assert_eq!(rows, vec![vec![0, 1], vec![], vec![2, 3, 4], vec![5]]);
+}
+
+#[test]
+fn split_children_mut_flat_jagged() {
+    let mut jagged = vec![0, 1, 2, 3, 4, 5].into_jagged(vec![2, 2, 5, 6]);
+
+    for i in 0..=4 {
+        let (first, second) = jagged.split_children_mut(i);
+        assert_eq!(first.card([]), i);
+        assert_eq!(second.card([]), 4 - i);
+        let mut all: Vec<_> = first.all().collect();
+        all.extend(second.all());
+        assert_eq!(all, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    let (mut first, mut second) = jagged.split_children_mut(2);
+    assert_eq!(first.card([1]), 0);
+    assert_eq!(second.card([0]), 3);
+    assert_eq!(second.card([1]), 1);
+    assert_eq!(second.at([1, 0]), 5);
+
+    std::thread::scope(|s| {
+        s.spawn(move || first.mut_all(|x| *x += 10));
+        s.spawn(move || second.set([0, 1], 30));
+    });
+    assert_eq!(
+        jagged.equality(&[vec![10, 11], vec![], vec![2, 30, 4], vec![5]]),
+        Equality::Equal
+    );
+}
+
+#[test]
+#[should_panic]
+fn split_children_mut_out_of_bounds() {
+    let mut jagged = vec![0, 1, 2].into_jagged(vec![1, 3]);
+    let _ = jagged.split_children_mut(3);
+}
+
+#[test]
+fn matrix_rows_and_cols_mut() {
+    let mut v1: Vec<_> = (0..12).collect();
+
+    let mut mat = v1.v1_as_matrix_mut(3, 4);
+    assert_eq!(mat.rows_mut().count(), 3);
+    for row in mat.rows_mut() {
+        row.reverse();
+    }
+    assert_eq!(mat.row(1).equality(&[7, 6, 5, 4]), Equality::Equal);
+
+    let (mut first, mut second) = mat.split_rows_mut(1);
+    first.mut_all(|x| *x = 0);
+    second.row_mut(1).set(0, 42);
+    assert_eq!(mat.row(0).equality(&[0, 0, 0, 0]), Equality::Equal);
+    assert_eq!(mat.at([2, 0]), 42);
+
+    let mut v1: Vec<_> = (0..6).collect();
+    let mut mat = v1.v1_as_matrix_col_major_mut(2, 3);
+    assert_eq!(mat.cols_mut().count(), 3);
+    let (first, mut second) = mat.split_cols_mut(1);
+    assert_eq!(first.num_cols(), 1);
+    second.col_mut(0).set(1, 30);
+    assert_eq!(mat.at([1, 1]), 30);
+
+    let mut v1: Vec<i32> = vec![];
+    let mut mat = v1.v1_as_matrix_mut(3, 0);
+    assert_eq!(mat.rows_mut().count(), 3);
+    assert!(mat.rows_mut().all(|r| r.is_empty()));
+}
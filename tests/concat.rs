@@ -0,0 +1,93 @@
+use orx_v::*;
+
+#[test]
+fn chain_d1() {
+    let a = vec![0, 1, 2];
+    let b = V.d1().fun(|[i]| 10 * i).bounded(2);
+
+    let c = chain(&a, &b);
+    assert_eq!(c.card([]), 5);
+    assert!(c.is_bounded());
+    assert_eq!(c.all().collect::<Vec<_>>(), vec![0, 1, 2, 0, 10]);
+    assert_eq!(c.at(2), 2);
+    assert_eq!(c.at(4), 10);
+    assert_eq!(c.try_at(5), None);
+
+    let e: Vec<usize> = vec![];
+    let c = chain(&e, &a);
+    assert_eq!(c.equality(&a), Equality::Equal);
+    let c = chain(&a, &e);
+    assert_eq!(c.equality(&a), Equality::Equal);
+
+    let c = chain(&a, V.d1().constant(7));
+    assert!(c.is_unbounded());
+    assert_eq!(c.at(100), 7);
+}
+
+#[test]
+fn chain_mut() {
+    let mut a = vec![0, 1, 2];
+    let mut b = V.d1().sparse(0).bounded(3);
+
+    let mut c = chain(&mut a, &mut b);
+    *c.at_mut(1) = 10;
+    c.set(4, 40);
+    assert_eq!(c.all().collect::<Vec<_>>(), vec![0, 10, 2, 0, 40, 0]);
+    c.reset_all(5);
+    assert_eq!(a, vec![5, 5, 5]);
+    assert_eq!(b.lookup_len(), 3);
+}
+
+#[test]
+fn stack_d1() {
+    let a = vec![0, 1, 2];
+    let b = [3, 4, 5];
+
+    let s = stack([a.as_slice(), &b]);
+    assert!(s.is_rectangular());
+    assert_eq!(s.len(), 2);
+    assert_eq!(s.card([]), 2);
+    assert_eq!(s.card([1]), 3);
+    assert_eq!(s.at([1, 2]), 5);
+    assert_eq!(s.child(0).equality(&a), Equality::Equal);
+
+    let s = stack(vec![chain(&a, &b), chain(&a, &b)]);
+    assert_eq!(s.card([1]), 6);
+    assert_eq!(s.at([1, 4]), 4);
+    assert_eq!(s.all().count(), 12);
+
+    let mat = s.into_matrix();
+    assert_eq!(mat.num_rows(), 2);
+    assert_eq!(mat.at([0, 5]), 5);
+}
+
+#[test]
+fn stack_d2_mut() {
+    let mut a = vec![vec![0, 1], vec![2]];
+    let mut b = vec![vec![3, 4, 5]];
+
+    let mut s = stack([&mut a, &mut b]);
+    assert_eq!(s.card([]), 2);
+    assert_eq!(s.card([0]), 2);
+    assert_eq!(s.card([1, 0]), 3);
+    assert!(!s.is_rectangular());
+
+    s.set([0, 1, 0], 20);
+    s.child_mut(1).child_mut(0).set([2], 50);
+    s.mut_all(|x| *x += 1);
+    assert_eq!(a, vec![vec![1, 2], vec![21]]);
+    assert_eq!(b, vec![vec![4, 5, 51]]);
+}
+
+#[test]
+fn stack_empty() {
+    let s = stack(Vec::<Vec<i32>>::new());
+    assert!(s.is_empty());
+    assert_eq!(NVec::<D2, _>::card(&s, []), 0);
+}
+
+#[test]
+#[should_panic]
+fn chain_unbounded_first() {
+    let _ = chain(V.d1().constant(7), vec![1]);
+}
@@ -0,0 +1,154 @@
+mod std_order_vec;
+use orx_v::*;
+
+/// A one dimensional vector storing its elements in chunks of equal length
+/// except for the last chunk.
+struct Chunked {
+    chunks: Vec<Vec<usize>>,
+    chunk_len: usize,
+}
+
+impl Chunked {
+    fn new(begin: usize, len: usize, chunk_len: usize) -> Self {
+        let all: Vec<_> = (begin..(begin + len)).collect();
+        let chunks = all.chunks(chunk_len).map(|x| x.to_vec()).collect();
+        Self { chunks, chunk_len }
+    }
+}
+
+impl CustomNVec<D1, usize> for Chunked {
+    fn custom_num_children(&self) -> usize {
+        self.chunks.iter().map(|x| x.len()).sum()
+    }
+
+    fn custom_at(&self, idx: impl IntoIdx<D1>) -> usize {
+        let [i] = idx.into_idx();
+        self.chunks[i / self.chunk_len][i % self.chunk_len]
+    }
+
+    fn custom_child(&self, _: <D1 as Dim>::ChildIdx) -> impl NVec<<D1 as Dim>::PrevDim, usize> {
+        CustomVec::new(self)
+    }
+
+    fn custom_all(&self) -> impl Iterator<Item = usize> {
+        self.chunks.iter().flat_map(|x| x.iter().copied())
+    }
+}
+
+/// A two dimensional jagged vector storing its rows as boxed slices.
+struct Rows<T> {
+    rows: Vec<Box<[T]>>,
+}
+
+impl Rows<usize> {
+    fn new(begin: usize, row_lengths: &[usize]) -> Self {
+        let mut x = begin;
+        let rows = row_lengths
+            .iter()
+            .map(|n| {
+                let row: Box<[usize]> = (x..(x + n)).collect();
+                x += n;
+                row
+            })
+            .collect();
+        Self { rows }
+    }
+}
+
+impl<T: Copy> CustomNVec<D2, T> for Rows<T> {
+    fn custom_num_children(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn custom_at(&self, idx: impl IntoIdx<D2>) -> T {
+        let [i, j] = idx.into_idx();
+        self.rows[i][j]
+    }
+
+    fn custom_child(&self, i: <D2 as Dim>::ChildIdx) -> impl NVec<<D2 as Dim>::PrevDim, T> {
+        &self.rows[i][..]
+    }
+
+    fn custom_all(&self) -> impl Iterator<Item = T> {
+        self.rows.iter().flat_map(|x| x.iter().copied())
+    }
+}
+
+impl<T: Copy> CustomNVecMut<D2, T> for Rows<T> {
+    fn custom_at_mut(&mut self, idx: impl IntoIdx<D2>) -> &mut T {
+        let [i, j] = idx.into_idx();
+        &mut self.rows[i][j]
+    }
+
+    fn custom_child_mut(
+        &mut self,
+        i: <D2 as Dim>::ChildIdx,
+    ) -> impl NVecMut<<D2 as Dim>::PrevDim, T> {
+        &mut self.rows[i][..]
+    }
+
+    fn custom_mut_all<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.rows.iter_mut().flat_map(|x| x.iter_mut()).for_each(f);
+    }
+}
+
+#[test]
+fn custom_d1() {
+    let vec = CustomVec::new(Chunked::new(7, 20, 6));
+    std_order_vec::assert_std_order_v1(&vec, 7);
+
+    let std_vec: Vec<_> = (7..27).collect();
+    assert_eq!(vec.card([]), std_vec.card([]));
+    assert!(vec.is_rectangular());
+    assert!(vec.is_bounded());
+    assert_eq!(vec.card_equality(&std_vec), CardEquality::Equal);
+    assert_eq!(vec.equality(&std_vec), Equality::Equal);
+    assert_eq!(vec.try_at(20), None);
+    assert!(!vec.in_bounds(20));
+}
+
+#[test]
+fn custom_d2() {
+    let row_lengths = [3, 0, 1, 5, 2];
+    let vec = CustomVec::new(Rows::new(10, &row_lengths));
+    std_order_vec::assert_std_order_v2(&vec, 10);
+
+    let std_vec = vec![
+        vec![10, 11, 12],
+        vec![],
+        vec![13],
+        vec![14, 15, 16, 17, 18],
+        vec![19, 20],
+    ];
+    assert_eq!(vec.card([]), std_vec.card([]));
+    for i in 0..row_lengths.len() {
+        assert_eq!(vec.card([i]), std_vec.card([i]));
+    }
+    assert!(!vec.is_rectangular());
+    assert_eq!(vec.card_equality(&std_vec), CardEquality::Equal);
+    assert_eq!(vec.equality(&std_vec), Equality::Equal);
+    assert_eq!(vec.try_at([1, 0]), None);
+    assert_eq!(vec.try_at([3, 4]), Some(18));
+
+    let rectangular = CustomVec::new(Rows::new(0, &[4, 4, 4]));
+    assert!(rectangular.is_rectangular());
+}
+
+#[test]
+fn custom_d2_mut() {
+    let vec = CustomVec::new(Rows::new(100, &[10, 20, 0, 15, 30]));
+    std_order_vec::assert_std_order_v2_mut(vec, 100);
+
+    let mut vec = CustomVec::new(Rows::new(0, &[2, 3]));
+    vec.set([1, 2], 42);
+    assert_eq!(vec.at([1, 2]), 42);
+    vec.reset_all(7);
+    assert!(vec.all().all(|x| x == 7));
+}
+
+#[test]
+fn custom_children() {
+    let vec = CustomVec::new(Rows::new(0, &[2, 1]));
+    let vec_as_std: Vec<Vec<usize>> = vec.children().map(|x| x.all().collect()).collect();
+    assert_eq!(vec_as_std, vec![vec![0, 1], vec![2]]);
+}
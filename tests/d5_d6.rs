@@ -0,0 +1,122 @@
+use orx_v::*;
+
+fn sum5([i, j, k, l, m]: [usize; 5]) -> usize {
+    i + j + k + l + m
+}
+
+fn sum6([i, j, k, l, m, n]: [usize; 6]) -> usize {
+    i + j + k + l + m + n
+}
+
+#[test]
+fn vec_d5() {
+    let mut v5 = vec![vec![vec![vec![vec![0usize; 3]; 2]; 2]; 1]; 2];
+
+    assert_eq!(v5.card([]), 2);
+    assert_eq!(v5.card([0, 0, 1, 0]), 3);
+    assert!(v5.is_rectangular());
+    assert_eq!(v5.all().count(), 2 * 2 * 2 * 3);
+
+    v5.set([1, 0, 1, 1, 2], 42);
+    assert_eq!(v5.at([1, 0, 1, 1, 2]), 42);
+    assert_eq!(v5.child(1).at([0, 1, 1, 2]), 42);
+    assert_eq!(v5.child(1).child(0).child(1).child(1).at([2]), 42);
+    assert_eq!(v5.try_at([1, 0, 1, 1, 3]), None);
+
+    v5[0][0][0][0].push(1);
+    assert!(!v5.is_rectangular());
+    assert_eq!(v5.card([0, 0, 0, 0]), 4);
+}
+
+#[test]
+fn vec_d6() {
+    let mut v6 = vec![vec![vec![vec![vec![vec![1usize; 2]; 1]; 3]; 1]; 2]; 2];
+
+    assert_eq!(v6.card([]), 2);
+    assert_eq!(v6.card([1, 1, 0, 2, 0]), 2);
+    assert!(v6.is_rectangular());
+    assert_eq!(v6.all().sum::<usize>(), 2 * 2 * 3 * 2);
+
+    *v6.at_mut([1, 1, 0, 2, 0, 1]) = 7;
+    assert_eq!(v6.at([1, 1, 0, 2, 0, 1]), 7);
+    assert_eq!(v6.child(1).child(1).at([0, 2, 0, 1]), 7);
+
+    v6.reset_all(3);
+    assert!(v6.all().all(|x| x == 3));
+
+    v6[1].pop();
+    assert!(!v6.is_rectangular());
+}
+
+#[test]
+fn fun_d5_d6() {
+    let v5 = V.d5().fun(sum5).with_rectangular_bounds([2, 1, 3, 1, 2]);
+    let vec5: Vec<_> = v5.all().collect();
+    assert_eq!(vec5, vec![0, 1, 1, 2, 2, 3, 1, 2, 2, 3, 3, 4]);
+    assert_eq!(v5.child(1).child(0).at([2, 0, 1]), 4);
+
+    let v6 = V.d6().fun(sum6).with_rectangular_bounds([1, 1, 1, 1, 2, 2]);
+    let vec6: Vec<_> = v6.all().collect();
+    assert_eq!(vec6, vec![0, 1, 1, 2]);
+
+    let num_children = vec![vec![vec![vec![2, 3]]], vec![vec![vec![1]]]];
+    let v5 = V.d5().fun(sum5).with_variable_bounds(&num_children);
+    assert!(!v5.is_rectangular());
+    assert_eq!(v5.card([0, 0, 0, 1]), 3);
+    assert_eq!(v5.all().count(), 6);
+    assert_eq!(v5.try_at([1, 0, 0, 0, 1]), None);
+}
+
+#[test]
+fn sparse_d5_d6() {
+    let mut v5 = V.d5().sparse(0).with_rectangular_bounds([2, 2, 2, 2, 2]);
+    v5.set([1, 1, 1, 1, 1], 5);
+    assert_eq!(v5.lookup_len(), 1);
+    assert_eq!(v5.all().sum::<i32>(), 5);
+
+    v5.reset_all(1);
+    assert_eq!(v5.lookup_len(), 32);
+    assert_eq!(v5.all().sum::<i32>(), 32);
+
+    let mut v6 = V.d6().sparse(0).with_rectangular_bounds([1, 2, 1, 2, 1, 2]);
+    *v6.child_mut(0).child_mut(1).at_mut([0, 1, 0, 1]) = 6;
+    assert_eq!(v6.at([0, 1, 0, 1, 0, 1]), 6);
+    assert_eq!(v6.all().filter(|x| *x == 6).count(), 1);
+}
+
+#[test]
+fn constant_d5_d6() {
+    let v5 = V.d5().constant(4).with_rectangular_bounds([1, 2, 3, 4, 5]);
+    assert_eq!(v5.all().count(), 120);
+    assert!(v5.all().all(|x| x == 4));
+
+    let v6 = V.d6().constant(4);
+    assert!(v6.is_unbounded());
+    assert_eq!(v6.at([100, 1, 2, 3, 4, 5]), 4);
+}
+
+#[test]
+fn equality_d5_d6() {
+    let a = vec![vec![vec![vec![vec![1, 2], vec![3]]]]];
+
+    let b = V
+        .d5()
+        .fun(|[_, _, _, k, l]| 1 + 2 * k + l)
+        .with_rectangular_bounds([1, 1, 1, 2, 2]);
+    assert!(matches!(
+        a.card_equality(&b),
+        CardEquality::Unequal(IdxLeqD4::IdxD4([0, 0, 0, 1]), 1, 2)
+    ));
+
+    let num_children = vec![vec![vec![vec![2, 1]]]];
+    let c = V
+        .d5()
+        .fun(|[_, _, _, k, l]| 1 + 2 * k + l)
+        .with_variable_bounds(&num_children);
+    assert!(matches!(a.card_equality(&c), CardEquality::Equal));
+    assert!(matches!(a.equality(&c), Equality::Equal));
+
+    let d6 = vec![a.clone()];
+    assert_eq!(d6.card([0, 0, 0, 0, 1]), 1);
+    assert!(matches!(d6.child(0).equality(&a), Equality::Equal));
+}
@@ -252,3 +252,107 @@ fn cached() {
         "{ kind: CachedVec, dim: D2, is_bounded: true, cache_len: 2, values: [[0, 1, 2], [1, 2, 3]] }"
     );
 }
+
+#[test]
+fn map_vec() {
+    let v2 = vec![vec![1, 2], vec![3]];
+    let m2 = v2.map(|x| x * 10);
+    assert_eq!(
+        dbg_str(&m2).as_str(),
+        "{ kind: MapVec, dim: D2, is_bounded: true, values: [[10, 20], [30]] }"
+    );
+
+    let m2 = v2.map_with_idx(|[i, j], x| (i, j, x));
+    assert_eq!(
+        dbg_str(&m2).as_str(),
+        "{ kind: MapWithIdxVec, dim: D2, is_bounded: true, values: [[(0, 0, 1), (0, 1, 2)], [(1, 0, 3)]] }"
+    );
+}
+
+#[test]
+fn permuted_vec() {
+    let v2 = vec![vec![0, 1, 2], vec![3, 4, 5]];
+    let t = v2.into_transposed();
+    assert_eq!(
+        format!("{:?}", t),
+        "{ kind: PermutedVec, dim: D2, axes: [1, 0], values: [[0, 3], [1, 4], [2, 5]] }"
+    );
+}
+
+#[test]
+fn slice_and_block() {
+    let v1 = vec![0, 1, 2, 3, 4];
+    assert_eq!(
+        format!("{:?}", v1.v1_into_slice(1..3)),
+        "{ kind: SliceVec, dim: D1, range: 1..3, values: [1, 2] }"
+    );
+
+    let v2 = vec![vec![0, 1, 2], vec![3, 4, 5]];
+    assert_eq!(
+        format!("{:?}", v2.v2_into_block(1..2, 0..2)),
+        "{ kind: BlockVec, dim: D2, rows: 1..2, cols: 0..2, values: [[3, 4]] }"
+    );
+}
+
+#[test]
+fn rev_and_strided() {
+    let v1 = vec![0, 1, 2, 3, 4];
+    assert_eq!(
+        format!("{:?}", v1.v1_as_rev()),
+        "{ kind: RevVec, dim: D1, values: [4, 3, 2, 1, 0] }"
+    );
+    assert_eq!(
+        format!("{:?}", v1.v1_as_step_by(2)),
+        "{ kind: StridedVec, dim: D1, offset: 0, stride: 2, values: [0, 2, 4] }"
+    );
+}
+
+#[test]
+fn chain_and_stack() {
+    let a = vec![0, 1];
+    let b = vec![2];
+    assert_eq!(
+        format!("{:?}", chain(&a, &b)),
+        "{ kind: ChainVec, dim: D1, values: [0, 1, 2] }"
+    );
+    assert_eq!(
+        format!("{:?}", stack([&a, &b])),
+        "{ kind: StackVec, dim: D2, values: [[0, 1], [2]] }"
+    );
+}
+
+#[test]
+fn v1_tensor() {
+    let data: Vec<_> = (0..8).collect();
+    assert_eq!(
+        format!("{:?}", data.v1_as_d3([2, 2, 2])),
+        "{ kind: V1Tensor, dim: D3, shape: [2, 2, 2], values: [[[0, 1], [2, 3]], [[4, 5], [6, 7]]] }"
+    );
+}
+
+#[test]
+fn flatten() {
+    let v2 = vec![vec![0, 1], vec![], vec![2]];
+    assert_eq!(
+        format!("{:?}", v2.as_flat()),
+        "{ kind: FlattenVec, dim: D1, source_dim: D2, values: [0, 1, 2] }"
+    );
+}
+
+#[test]
+fn broadcast() {
+    let v1 = vec![0, 1];
+    assert_eq!(
+        format!("{:?}", v1.as_broadcast_rows(3)),
+        "{ kind: BroadcastVec, dim: D2, axis: 1, values: [[0, 0, 0], [1, 1, 1]] }"
+    );
+}
+
+#[test]
+fn reduced() {
+    let v2 = vec![vec![0, 1, 2], vec![3, 4, 5]];
+    assert_eq!(
+        format!("{:?}", v2.as_sum_axis(0)),
+        "{ kind: ReducedVec, dim: D1, axis: 0, values: [3, 5, 7] }"
+    );
+}
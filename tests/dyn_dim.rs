@@ -0,0 +1,110 @@
+mod std_order_vec;
+use orx_v::*;
+
+fn read_tensor(rank: usize) -> DynVec<usize> {
+    let shape: Vec<_> = (0..rank).map(|r| r + 2).collect();
+    let len = shape.iter().product();
+    DynVec::new(shape, (0..len).collect())
+}
+
+fn sum_dyn(v: &impl DynNVec<usize>) -> usize {
+    match v.rank() {
+        1 => (0..v.num_children()).map(|i| v.at([i])).sum(),
+        _ => (0..v.num_children()).map(|i| sum_dyn(&v.child(i))).sum(),
+    }
+}
+
+#[test]
+fn dyn_vec_recursive_dispatch() {
+    for rank in 1..=5 {
+        let v = read_tensor(rank);
+        let len: usize = v.shape().iter().product();
+        assert_eq!(v.dim(), DynDim::new(rank));
+        assert_eq!(sum_dyn(&v), (0..len).sum());
+        assert_eq!(v.all().collect::<Vec<_>>(), (0..len).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn dyn_vec_idx_types() {
+    let v = read_tensor(3);
+    let idx_arr = [1, 2, 3];
+    let idx_vec = vec![1, 2, 3];
+    let idx_slice: &[usize] = &idx_vec;
+
+    let expected = (3 * 4) + (2 * 4) + 3;
+    assert_eq!(v.at(idx_arr), expected);
+    assert_eq!(v.at(&idx_vec), expected);
+    assert_eq!(v.at(idx_slice), expected);
+    assert_eq!(v.child(1).child(2).at([3]), expected);
+
+    assert!(v.in_bounds([1, 2]));
+    assert!(!v.in_bounds([1, 3]));
+    assert_eq!(v.try_at([0, 0, 4]), None);
+    assert_eq!(v.try_at([0, 0]), None);
+}
+
+#[test]
+#[should_panic]
+fn dyn_vec_at_wrong_rank() {
+    let v = read_tensor(3);
+    let _ = v.at([0, 0]);
+}
+
+#[test]
+#[should_panic]
+fn dyn_vec_child_of_rank1() {
+    let v = read_tensor(1);
+    let _ = v.child(0).num_children();
+}
+
+#[test]
+fn dyn_to_static() {
+    let v = read_tensor(1);
+    std_order_vec::assert_std_order_v1(v.as_d1().unwrap(), 0);
+    assert!(v.as_d2().is_none());
+
+    let v = read_tensor(2);
+    std_order_vec::assert_std_order_v2(v.as_d2().unwrap(), 0);
+    assert!(v.as_d1().is_none());
+
+    let v = read_tensor(3);
+    let v3 = v.as_d3().unwrap();
+    assert_eq!(v3.card([1, 2]), 4);
+    assert_eq!(v3.child(1).child(2).at([3]), v.at([1, 2, 3]));
+    assert!(v3.is_rectangular());
+
+    let v = read_tensor(4);
+    let v4 = v.as_d4().unwrap();
+    assert_eq!(v4.all().count(), 2 * 3 * 4 * 5);
+    assert_eq!(v4.at([1, 2, 3, 4]), v.at([1, 2, 3, 4]));
+    assert_eq!(v4.child(1).at([2, 3, 4]), v.at([1, 2, 3, 4]));
+}
+
+#[test]
+fn static_to_dyn() {
+    let v2 = vec![vec![0, 1, 2], vec![3], vec![]];
+    let v = v2.as_dyn();
+    assert_eq!(v.rank(), 2);
+    assert_eq!(v.card([]), 3);
+    assert_eq!(v.card([0]), 3);
+    assert_eq!(v.card([2]), 0);
+    assert!(!v.is_rectangular());
+    assert_eq!(sum_dyn(&v), 6);
+    assert_eq!(v.try_at([1, 1]), None);
+    assert_eq!(v.as_d2().unwrap().equality(&v2), Equality::Equal);
+
+    let v4 = V
+        .d4()
+        .fun(|[i, j, k, l]| i * 1000 + j * 100 + k * 10 + l)
+        .with_rectangular_bounds([2, 3, 4, 5]);
+    let v = v4.into_dyn();
+    assert_eq!(v.card([1, 2, 3]), 5);
+    assert_eq!(v.at([1, 2, 3, 4]), 1234);
+    assert_eq!(v.child(1).child(2).child(3).at([4]), 1234);
+
+    let v = DynVec::from_rectangular(&v).unwrap();
+    assert_eq!(v.shape(), &[2, 3, 4, 5]);
+    assert_eq!(v.at([1, 2, 3, 4]), 1234);
+    assert_eq!(v.as_d4().unwrap().equality(&v4), Equality::Equal);
+}
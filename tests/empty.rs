@@ -1,3 +1,4 @@
+#![allow(clippy::bool_assert_comparison)]
 use orx_v::*;
 
 #[test]
@@ -0,0 +1,121 @@
+use orx_v::*;
+
+fn sum(v: impl V1<usize>) -> usize {
+    v.all().sum()
+}
+
+fn assert_flat<D: Dim>(v: &impl NVec<D, usize>, flat: impl V1<usize>)
+where
+    D::Idx: Ord,
+{
+    let expected: Vec<_> = v.all().collect();
+    assert_eq!(flat.card([]), expected.len());
+    assert_eq!(flat.all().collect::<Vec<_>>(), expected);
+    for (k, x) in expected.iter().enumerate() {
+        assert_eq!(flat.at(k), *x);
+    }
+    assert_eq!(flat.try_at(expected.len()), None);
+}
+
+#[test]
+fn flatten_d2() {
+    let v2 = vec![vec![0, 1, 2], vec![3, 4, 5]];
+    assert_flat(&v2, v2.as_flat());
+    assert_eq!(sum(v2.as_flat()), 15);
+
+    let v2 = vec![vec![], vec![0, 1], vec![], vec![2], vec![3, 4, 5], vec![]];
+    assert_flat(&v2, v2.as_flat());
+
+    let v2: Vec<Vec<usize>> = vec![vec![], vec![]];
+    assert_flat(&v2, v2.as_flat());
+
+    let v2 = V
+        .d2()
+        .fun(|[i, j]| 10 * i + j)
+        .with_rectangular_bounds([3, 4]);
+    assert_flat(&v2, v2.as_flat());
+    assert_eq!(v2.as_flat().at(6), 12);
+
+    let v2 = (0..5).collect::<Vec<usize>>().into_jagged(vec![3, 3, 5]);
+    assert_flat(&v2, v2.as_flat());
+}
+
+#[test]
+fn flatten_d3_d4() {
+    let v3 = vec![
+        vec![vec![0, 1], vec![]],
+        vec![],
+        vec![vec![2], vec![3, 4], vec![5]],
+    ];
+    assert_flat(&v3, v3.as_flat());
+
+    let v3 = V
+        .d3()
+        .fun(|[i, j, k]| 100 * i + 10 * j + k)
+        .with_rectangular_bounds([2, 3, 4]);
+    assert_flat(&v3, v3.as_flat());
+    assert_eq!(v3.as_flat().at(23), 123);
+
+    let v4 = vec![vec![vec![vec![1, 2], vec![3]], vec![vec![4, 5, 6]]]];
+    assert_flat(&v4, v4.as_flat());
+
+    let v4 = V.d4().constant(7).with_rectangular_bounds([2, 0, 3, 4]);
+    assert_flat(&v4, v4.as_flat());
+}
+
+#[test]
+fn flatten_mut() {
+    let mut v2 = vec![vec![0, 1], vec![], vec![2, 3, 4]];
+
+    let mut v1 = v2.as_flat_mut();
+    v1.set(1, 10);
+    *v1.at_mut(4) = 40;
+    assert_eq!(v2, vec![vec![0, 10], vec![], vec![2, 3, 40]]);
+
+    let mut v1 = v2.as_flat_mut();
+    v1.mut_all(|x| *x += 1);
+    assert_eq!(v2, vec![vec![1, 11], vec![], vec![3, 4, 41]]);
+
+    let mut v2 = V.d2().sparse(0).with_rectangular_bounds([2, 3]);
+    let mut v1 = v2.as_flat_mut();
+    v1.set(4, 7);
+    assert_eq!(v2.at([1, 1]), 7);
+    assert_eq!(v2.lookup_len(), 1);
+}
+
+#[test]
+fn flatten_owned() {
+    let v2 = vec![vec![0, 1], vec![], vec![2, 3, 4]];
+    let mut v1 = v2.into_flat();
+    assert_eq!(v1.card([]), 5);
+    v1.set(3, 30);
+    assert_eq!(v1.into_inner(), vec![vec![0, 1], vec![], vec![2, 30, 4]]);
+
+    let v3 = V
+        .d3()
+        .fun(|[i, j, k]| 100 * i + 10 * j + k)
+        .with_rectangular_bounds([2, 2, 2]);
+    let v1 = v3.into_flat();
+    assert_eq!(v1.at(5), 101);
+}
+
+#[test]
+fn flatten_into_inner() {
+    let v2 = vec![vec![0, 1], vec![2]];
+    let v1 = v2.as_flat();
+    assert_eq!(v1.into_inner(), &v2);
+}
+
+#[test]
+#[should_panic]
+fn flatten_unbounded() {
+    let v2 = V.d2().constant(0);
+    let _ = v2.as_flat();
+}
+
+#[test]
+#[should_panic]
+fn flatten_out_of_bounds() {
+    let v2 = vec![vec![0, 1], vec![2]];
+    let _ = v2.as_flat().at(3);
+}
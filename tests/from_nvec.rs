@@ -0,0 +1,97 @@
+use orx_v::matrices::V1MatrixColMajor;
+use orx_v::*;
+
+#[test]
+fn to_nested_vec_d1_d4() {
+    let v1 = V.d1().fun(|[i]| i * i).bounded(4);
+    assert_eq!(v1.to_nested_vec(), Ok(vec![0, 1, 4, 9]));
+
+    let v2 = V.d2().constant(1).with_variable_bounds(vec![1, 0, 2]);
+    assert_eq!(v2.to_nested_vec(), Ok(vec![vec![1], vec![], vec![1, 1]]));
+
+    let v3 = V
+        .d3()
+        .fun(|[i, j, k]| 100 * i + 10 * j + k)
+        .with_rectangular_bounds([2, 1, 2]);
+    assert_eq!(
+        v3.to_nested_vec(),
+        Ok(vec![vec![vec![0, 1]], vec![vec![100, 101]]])
+    );
+
+    let mut v4 = V.d4().sparse(0).with_rectangular_bounds([1, 2, 1, 2]);
+    v4.set([0, 1, 0, 1], 5);
+    assert_eq!(
+        v4.to_nested_vec(),
+        Ok(vec![vec![vec![vec![0, 0]], vec![vec![0, 5]]]])
+    );
+}
+
+#[test]
+fn to_nested_vec_cached() {
+    let v2 = V
+        .d2()
+        .fun(|[i, j]| i * j)
+        .with_rectangular_bounds([2, 3])
+        .into_cached();
+    assert_eq!(v2.to_nested_vec(), Ok(vec![vec![0, 0, 0], vec![0, 1, 2]]));
+}
+
+#[test]
+fn to_nested_vec_unbounded() {
+    let v2 = V.d2().fun(|[i, j]| i + j);
+    assert_eq!(v2.to_nested_vec(), Err(FromNVecError::Unbounded));
+
+    // bounded rows containing an unbounded child
+    let v2 = vec![V.d1().constant(1), V.d1().constant(2)];
+    assert_eq!(
+        NVec::<D2, _>::to_nested_vec(&v2),
+        Err(FromNVecError::Unbounded)
+    );
+}
+
+#[test]
+fn to_flat_jagged() {
+    let mut v2 = V.d2().sparse(0).with_variable_bounds(vec![3, 0, 1]);
+    v2.set([0, 2], 2);
+    v2.set([2, 0], 7);
+
+    let jagged = v2.to_flat_jagged().unwrap();
+    assert_eq!(jagged.equality(&v2), Equality::Equal);
+    let (flat_vec, row_end_indices) = jagged.into_inner();
+    assert_eq!(flat_vec, vec![0, 0, 2, 7]);
+    assert_eq!(row_end_indices, vec![3, 3, 4]);
+
+    let empty = V.d2().empty::<i32>();
+    assert_eq!(empty.to_flat_jagged().unwrap().card([]), 0);
+
+    let v2 = V.d2().constant(0);
+    assert_eq!(v2.to_flat_jagged().err(), Some(FromNVecError::Unbounded));
+}
+
+#[test]
+fn to_matrix() {
+    let v2 = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let mat = v2.to_matrix().unwrap();
+    assert_eq!((mat.num_rows(), mat.num_cols()), (2, 3));
+    assert_eq!(mat.equality(&v2.as_matrix()), Equality::Equal);
+
+    let v2 = V.d2().constant(0).with_variable_bounds(vec![2, 2, 1]);
+    assert_eq!(v2.to_matrix().err(), Some(FromNVecError::NotRectangular));
+
+    let v2 = V.d2().constant(0).with_rectangular_bounds([0, 0]);
+    assert_eq!(v2.to_matrix().unwrap().num_rows(), 0);
+
+    let v2 = V.d2().constant(0);
+    assert_eq!(v2.to_matrix().err(), Some(FromNVecError::Unbounded));
+}
+
+#[test]
+fn from_nvec_col_major_matrix() {
+    let v2 = V
+        .d2()
+        .fun(|[i, j]| 10 * i + j)
+        .with_rectangular_bounds([2, 3]);
+    let mat = V1MatrixColMajor::<usize, Vec<usize>>::from_nvec(&v2).unwrap();
+    assert_eq!(mat.at([1, 2]), 12);
+    assert_eq!(mat.col(1).all().collect::<Vec<_>>(), vec![1, 11]);
+}
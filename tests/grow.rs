@@ -0,0 +1,225 @@
+use orx_v::grow::*;
+use orx_v::*;
+
+fn add_edge<V: NVecGrow<D2, usize>>(adj: &mut V, from: usize, to: usize) -> Result<(), GrowError>
+where
+    V::Child: Default,
+{
+    while adj.card([]) <= from {
+        adj.push_child(Default::default())?;
+    }
+    adj.push([from], to)
+}
+
+fn build_adjacency<V: NVecGrow<D2, usize>>(adj: &mut V, edges: &[(usize, usize)])
+where
+    V::Child: Default,
+{
+    for (from, to) in edges.iter().copied() {
+        add_edge(adj, from, to).unwrap();
+    }
+}
+
+const EDGES: [(usize, usize); 5] = [(0, 1), (2, 0), (0, 2), (3, 1), (2, 3)];
+
+fn assert_adjacency(adj: &impl NVec<D2, usize>) {
+    assert_eq!(
+        adj.equality(&[vec![1, 2], vec![], vec![0, 3], vec![1]]),
+        Equality::Equal
+    );
+}
+
+#[test]
+fn grow_adjacency_vec() {
+    let mut adj: Vec<Vec<usize>> = vec![];
+    build_adjacency(&mut adj, &EDGES);
+    assert_adjacency(&adj);
+}
+
+#[test]
+fn grow_d1() {
+    let mut v1 = vec![1, 2];
+    NVecGrow::push(&mut v1, [], 3).unwrap();
+    NVecGrow::push_child(&mut v1, 4).unwrap();
+    NVecGrow::extend(&mut v1, [], [5, 6]).unwrap();
+    assert_eq!(v1, vec![1, 2, 3, 4, 5, 6]);
+
+    NVecGrow::insert(&mut v1, [], 0, 0).unwrap();
+    NVecGrow::insert(&mut v1, [], 7, 7).unwrap();
+    assert_eq!(v1, vec![0, 1, 2, 3, 4, 5, 6, 7]);
+
+    NVecGrow::truncate(&mut v1, [], 5);
+    assert_eq!(v1, vec![0, 1, 2, 3, 4]);
+
+    NVecGrow::resize(&mut v1, [], 7, 0).unwrap();
+    assert_eq!(v1, vec![0, 1, 2, 3, 4, 0, 0]);
+    NVecGrow::resize(&mut v1, [], 2, 0).unwrap();
+    assert_eq!(v1, vec![0, 1]);
+
+    NVecGrow::clear(&mut v1);
+    assert!(v1.is_empty());
+}
+
+#[test]
+fn grow_d3() {
+    let mut v3: Vec<Vec<Vec<i32>>> = vec![];
+    NVecGrow::push_child(&mut v3, vec![vec![], vec![1]]).unwrap();
+    NVecGrow::push(&mut v3, [0, 0], 2).unwrap();
+    NVecGrow::extend(&mut v3, [0, 1], [3, 4]).unwrap();
+    assert_eq!(v3, vec![vec![vec![2], vec![1, 3, 4]]]);
+
+    NVecGrow::truncate(&mut v3, [0, 1], 1);
+    assert_eq!(v3, vec![vec![vec![2], vec![1]]]);
+
+    NVecGrow::resize(&mut v3, [0, 0], 3, 7).unwrap();
+    assert_eq!(v3, vec![vec![vec![2, 7, 7], vec![1]]]);
+
+    NVecGrow::insert(&mut v3, [0, 1], 0, 5).unwrap();
+    assert_eq!(v3, vec![vec![vec![2, 7, 7], vec![5, 1]]]);
+
+    NVecGrow::truncate(&mut v3, [0], 1);
+    assert_eq!(v3, vec![vec![vec![2, 7, 7]]]);
+
+    NVecGrow::truncate(&mut v3, [], 0);
+    assert!(v3.is_empty());
+}
+
+#[test]
+fn grow_through_mut_ref() {
+    fn push_twice(mut v: impl NVecGrow<D2, i32>) {
+        v.push([0], 1).unwrap();
+        v.push([0], 2).unwrap();
+    }
+
+    let mut v2 = vec![vec![0]];
+    push_twice(&mut v2);
+    assert_eq!(v2, vec![vec![0, 1, 2]]);
+}
+
+#[test]
+fn grow_non_leaf() {
+    let mut v3 = vec![vec![vec![1]]];
+    assert_eq!(NVecGrow::push(&mut v3, [0], 2), Err(GrowError::NotLeaf));
+    assert_eq!(NVecGrow::extend(&mut v3, [], [2]), Err(GrowError::NotLeaf));
+    assert_eq!(
+        NVecGrow::insert(&mut v3, [0], 0, 2),
+        Err(GrowError::NotLeaf)
+    );
+    assert_eq!(
+        NVecGrow::resize(&mut v3, [0], 3, 2),
+        Err(GrowError::NotLeaf)
+    );
+    assert_eq!(v3, vec![vec![vec![1]]]);
+}
+
+#[test]
+#[should_panic]
+fn grow_push_out_of_bounds() {
+    let mut v2 = vec![vec![1]];
+    let _ = NVecGrow::push(&mut v2, [1], 2);
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn grow_arrayvec_capacity() {
+    use arrayvec::ArrayVec;
+
+    let mut v1: ArrayVec<i32, 3> = ArrayVec::new();
+    assert_eq!(
+        NVecGrow::extend(&mut v1, [], [1, 2, 3, 4, 5]),
+        Err(GrowError::CapacityExceeded { capacity: 3 })
+    );
+    assert_eq!(v1.as_slice(), &[1, 2, 3]);
+
+    assert_eq!(
+        NVecGrow::push(&mut v1, [], 4),
+        Err(GrowError::CapacityExceeded { capacity: 3 })
+    );
+    assert_eq!(
+        NVecGrow::insert(&mut v1, [], 0, 4),
+        Err(GrowError::CapacityExceeded { capacity: 3 })
+    );
+    assert_eq!(v1.as_slice(), &[1, 2, 3]);
+
+    NVecGrow::truncate(&mut v1, [], 1);
+    assert_eq!(
+        NVecGrow::resize(&mut v1, [], 4, 0),
+        Err(GrowError::CapacityExceeded { capacity: 3 })
+    );
+    assert_eq!(v1.as_slice(), &[1]);
+    NVecGrow::resize(&mut v1, [], 3, 0).unwrap();
+    assert_eq!(v1.as_slice(), &[1, 0, 0]);
+
+    let mut adj: Vec<ArrayVec<usize, 2>> = vec![];
+    build_adjacency(&mut adj, &EDGES);
+    assert_adjacency(&adj);
+    assert_eq!(
+        add_edge(&mut adj, 0, 3),
+        Err(GrowError::CapacityExceeded { capacity: 2 })
+    );
+
+    let mut adj: ArrayVec<Vec<usize>, 3> = ArrayVec::new();
+    assert_eq!(
+        add_edge(&mut adj, 3, 0),
+        Err(GrowError::CapacityExceeded { capacity: 3 })
+    );
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn grow_adjacency_smallvec() {
+    use smallvec::SmallVec;
+
+    let mut adj: Vec<SmallVec<[usize; 1]>> = vec![];
+    build_adjacency(&mut adj, &EDGES);
+    assert_adjacency(&adj);
+}
+
+#[cfg(feature = "tinyvec")]
+#[test]
+fn grow_tinyvec() {
+    use tinyvec::{ArrayVec, TinyVec};
+
+    let mut adj: Vec<TinyVec<[usize; 1]>> = vec![];
+    build_adjacency(&mut adj, &EDGES);
+    assert_adjacency(&adj);
+
+    let mut v1: ArrayVec<[i32; 2]> = ArrayVec::new();
+    NVecGrow::push(&mut v1, [], 1).unwrap();
+    NVecGrow::push(&mut v1, [], 2).unwrap();
+    assert_eq!(
+        NVecGrow::push(&mut v1, [], 3),
+        Err(GrowError::CapacityExceeded { capacity: 2 })
+    );
+}
+
+#[cfg(feature = "orx-split-vec")]
+#[test]
+fn grow_adjacency_split_vec() {
+    use orx_split_vec::SplitVec;
+
+    let mut adj: SplitVec<SplitVec<usize>> = SplitVec::new();
+    build_adjacency(&mut adj, &EDGES);
+    assert_adjacency(&adj);
+
+    NVecGrow::clear(&mut adj);
+    assert_eq!(adj.card([]), 0);
+}
+
+#[cfg(feature = "orx-fixed-vec")]
+#[test]
+fn grow_fixed_vec_capacity() {
+    use orx_fixed_vec::FixedVec;
+
+    let mut v1: FixedVec<i32> = FixedVec::new(2);
+    NVecGrow::extend(&mut v1, [], [1, 2]).unwrap();
+    assert_eq!(
+        NVecGrow::push(&mut v1, [], 3),
+        Err(GrowError::CapacityExceeded { capacity: 2 })
+    );
+    assert_eq!(
+        NVecGrow::insert(&mut v1, [], 0, 3),
+        Err(GrowError::CapacityExceeded { capacity: 2 })
+    );
+    assert_eq!(v1.card([]), 2);
+}
@@ -0,0 +1,95 @@
+use orx_v::*;
+
+#[test]
+fn get_d1() {
+    let v1 = vec![1, 2, 3];
+    assert_eq!(v1.get(2), Ok(3));
+    assert_eq!(v1.get([0]), Ok(1));
+    assert_eq!(
+        v1.get(3),
+        Err(IndexError {
+            card_idx: IdxLeqD0::IdxD0([]),
+            idx: 3,
+            card: 3
+        })
+    );
+    assert_eq!([1, 2, 3].get(5).map_err(|e| e.card), Err(3));
+}
+
+#[test]
+fn get_d3_axes() {
+    let v3 = vec![
+        vec![vec![0, 1], vec![2]],
+        vec![],
+        vec![vec![], vec![3, 4, 5]],
+    ];
+
+    assert_eq!(v3.get([2, 1, 2]), Ok(5));
+
+    let error = |idx: [usize; 3]| {
+        let e = v3.get(idx).unwrap_err();
+        (e.card_idx, e.idx, e.card)
+    };
+    assert_eq!(error([3, 0, 0]), (IdxLeqD2::IdxD0([]), 3, 3));
+    assert_eq!(error([1, 0, 0]), (IdxLeqD2::IdxD1([1]), 0, 0));
+    assert_eq!(error([0, 2, 0]), (IdxLeqD2::IdxD1([0]), 2, 2));
+    assert_eq!(error([0, 1, 1]), (IdxLeqD2::IdxD2([0, 1]), 1, 1));
+    assert_eq!(error([2, 0, 0]), (IdxLeqD2::IdxD2([2, 0]), 0, 0));
+
+    // card at card_idx is the cardinality reported by the error
+    for idx in [[3, 0, 0], [1, 0, 0], [0, 2, 0], [0, 1, 1], [2, 0, 0]] {
+        let e = v3.get(idx).unwrap_err();
+        assert_eq!(v3.card(e.card_idx), e.card);
+        assert_eq!(v3.try_at(idx), None);
+    }
+}
+
+#[test]
+fn get_d6() {
+    let v6 = V
+        .d6()
+        .constant(7)
+        .with_rectangular_bounds([2, 2, 2, 2, 3, 2]);
+    assert_eq!(v6.get([1, 1, 1, 1, 2, 1]), Ok(7));
+    let e = v6.get([1, 0, 1, 0, 3, 0]).unwrap_err();
+    assert_eq!(e.card_idx, IdxLeqD5::IdxD4([1, 0, 1, 0]));
+    assert_eq!((e.idx, e.card), (3, 3));
+}
+
+#[test]
+fn get_flat_jagged() {
+    let jagged = vec![1, 2, 3, 4, 5].into_jagged_from_row_lengths(&[3, 0, 2]);
+    assert_eq!(jagged.get([2, 1]), Ok(5));
+    let e = jagged.get([1, 0]).unwrap_err();
+    assert_eq!(e.card_idx, IdxLeqD1::IdxD1([1]));
+    assert_eq!((e.idx, e.card), (0, 0));
+}
+
+#[test]
+fn get_mut() {
+    let mut v2 = vec![vec![0, 1], vec![2]];
+    *v2.get_mut([1, 0]).unwrap() = 42;
+    assert_eq!(v2, vec![vec![0, 1], vec![42]]);
+
+    let e = v2.get_mut([1, 1]).unwrap_err();
+    assert_eq!(e.card_idx, IdxLeqD1::IdxD1([1]));
+    assert_eq!((e.idx, e.card), (1, 1));
+}
+
+#[test]
+fn index_error_display() {
+    fn first(v: &impl V2<i32>) -> Result<i32, Box<dyn core::error::Error>> {
+        Ok(v.get([0, 0])?)
+    }
+
+    let v2: Vec<Vec<i32>> = vec![vec![]];
+    let e = first(&v2).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "index 0 is out of bounds at [0] with cardinality 0"
+    );
+    assert_eq!(
+        format!("{:?}", v2.get([2, 0]).unwrap_err()),
+        "IndexError { card_idx: [], idx: 2, card: 1 }"
+    );
+}
@@ -0,0 +1,103 @@
+use orx_v::*;
+
+fn assert_consistent_with_all<D: Dim, T: PartialEq + core::fmt::Debug>(vec: &impl NVec<D, T>)
+where
+    D::Idx: PartialEq,
+{
+    assert_eq!(vec.indices().count(), vec.all().count());
+
+    for ((idx, x), (idx2, y)) in vec.enumerate_all().zip(vec.indices().zip(vec.all())) {
+        assert_eq!(idx, idx2);
+        assert_eq!(x, y);
+        assert_eq!(vec.at(idx), x);
+    }
+}
+
+#[test]
+fn indices_d1() {
+    let v1 = vec![3, 1, 4];
+    assert_eq!(v1.indices().collect::<Vec<_>>(), vec![[0], [1], [2]]);
+    assert_eq!(
+        v1.enumerate_all().collect::<Vec<_>>(),
+        vec![([0], 3), ([1], 1), ([2], 4)]
+    );
+    assert_consistent_with_all(&v1);
+
+    let empty: Vec<i32> = vec![];
+    assert_eq!(empty.indices().count(), 0);
+    assert_eq!(empty.enumerate_all().count(), 0);
+
+    let v1 = V.d1().fun(|[i]| i * i).bounded(4);
+    assert_eq!(
+        v1.enumerate_all().collect::<Vec<_>>(),
+        vec![([0], 0), ([1], 1), ([2], 4), ([3], 9)]
+    );
+}
+
+#[test]
+fn indices_jagged() {
+    let v2 = vec![vec![0, 1], vec![], vec![2], vec![]];
+    assert_eq!(
+        v2.indices().collect::<Vec<_>>(),
+        vec![[0, 0], [0, 1], [2, 0]]
+    );
+    assert_consistent_with_all(&v2);
+
+    let jagged = (0..6).collect::<Vec<_>>().into_jagged(vec![1, 1, 4, 6]);
+    assert_eq!(
+        jagged.indices().collect::<Vec<_>>(),
+        vec![[0, 0], [2, 0], [2, 1], [2, 2], [3, 0], [3, 1]]
+    );
+    assert_consistent_with_all(&jagged);
+
+    let v3 = vec![vec![vec![1], vec![]], vec![], vec![vec![2, 3]]];
+    assert_eq!(
+        v3.enumerate_all().collect::<Vec<_>>(),
+        vec![([0, 0, 0], 1), ([2, 0, 0], 2), ([2, 0, 1], 3)]
+    );
+    assert_consistent_with_all(&v3);
+}
+
+#[test]
+fn indices_rectangular() {
+    let v2 = V
+        .d2()
+        .fun(|[i, j]| 10 * i + j)
+        .with_rectangular_bounds([2, 3]);
+    assert_eq!(
+        v2.indices().collect::<Vec<_>>(),
+        vec![[0, 0], [0, 1], [0, 2], [1, 0], [1, 1], [1, 2]]
+    );
+    assert_consistent_with_all(&v2);
+
+    let v4 = V.d4().constant(7).with_rectangular_bounds([2, 1, 3, 2]);
+    assert_eq!(v4.indices().count(), 12);
+    assert_eq!(v4.indices().last(), Some([1, 0, 2, 1]));
+    assert_consistent_with_all(&v4);
+
+    let data: Vec<_> = (0..24).collect();
+    let v3 = data.v1_as_d3_col_major([2, 3, 4]);
+    assert_consistent_with_all(&v3);
+
+    let mut v2 = V.d2().sparse(0).with_variable_bounds(vec![1, 0, 2]);
+    v2.set([2, 1], 21);
+    assert_eq!(
+        v2.enumerate_all().collect::<Vec<_>>(),
+        vec![([0, 0], 0), ([2, 0], 0), ([2, 1], 21)]
+    );
+    assert_eq!(v2.lookup_len(), 1);
+}
+
+#[test]
+#[should_panic]
+fn indices_unbounded() {
+    let v2 = V.d2().constant(42);
+    let _ = v2.indices().next();
+}
+
+#[test]
+#[should_panic]
+fn enumerate_all_unbounded() {
+    let v1 = V.d1().fun(|[i]| i);
+    let _ = v1.enumerate_all().next();
+}
@@ -0,0 +1,102 @@
+mod std_order_vec;
+use orx_v::*;
+
+#[test]
+fn map_d1() {
+    let v1: Vec<_> = (0..10).map(|x| x * 2).collect();
+    let mapped = v1.map(|x| x / 2);
+    std_order_vec::assert_std_order_v1(mapped, 0);
+
+    let array = [0, 2, 4, 6];
+    let mapped = NVec::map(&array, |x| x / 2);
+    std_order_vec::assert_std_order_v1(mapped, 0);
+
+    let fun = V.d1().fun(|[i]| i).bounded(5);
+    let mapped = fun.map(|x| x + 3);
+    std_order_vec::assert_std_order_v1(&mapped, 3);
+    assert!(mapped.is_bounded());
+}
+
+#[test]
+fn map_d2() {
+    let v2 = vec![vec![0, 2, 4], vec![], vec![6, 8]];
+    let mapped = v2.map(|x| x as f32 / 2.0);
+
+    assert_eq!(mapped.card([]), 3);
+    assert_eq!(mapped.card([0]), 3);
+    assert_eq!(mapped.card([1]), 0);
+    assert_eq!(mapped.card([2]), 2);
+    assert!(!mapped.is_rectangular());
+    assert_eq!(mapped.card_equality(&v2.map(|_| 0.0)), CardEquality::Equal);
+    assert_eq!(mapped.at([2, 1]), 4.0);
+    assert_eq!(mapped.child(2).at([0]), 3.0);
+    assert_eq!(mapped.try_at([1, 0]), None);
+    assert_eq!(
+        mapped.all().collect::<Vec<_>>(),
+        vec![0.0, 1.0, 2.0, 3.0, 4.0]
+    );
+
+    let squared = mapped.map(|x| x * x);
+    assert_eq!(squared.at([2, 1]), 16.0);
+}
+
+#[test]
+fn map_d4_sparse() {
+    let mut sparse = V.d4().sparse(0).with_rectangular_bounds([2, 3, 4, 5]);
+    sparse.set([1, 2, 3, 4], 7);
+
+    let mapped = sparse.map(|x| x > 0);
+    assert_eq!(mapped.card([1, 2, 3]), 5);
+    assert_eq!(mapped.all().filter(|x| *x).count(), 1);
+    assert!(mapped.at([1, 2, 3, 4]));
+    assert!(!mapped.child(0).at([2, 3, 4]));
+}
+
+#[test]
+fn map_with_idx_d3() {
+    let v3 = vec![vec![vec![1], vec![2, 3]], vec![], vec![vec![], vec![4]]];
+    let mapped = v3.map_with_idx(|[i, j, k], x| (i, j, k, x));
+
+    assert_eq!(mapped.card([0, 1]), 2);
+    assert_eq!(mapped.at([0, 1, 1]), (0, 1, 1, 3));
+    assert_eq!(mapped.child(2).at([1, 0]), (2, 1, 0, 4));
+    assert_eq!(mapped.child(2).child(1).at([0]), (2, 1, 0, 4));
+    assert_eq!(
+        mapped.all().collect::<Vec<_>>(),
+        vec![(0, 0, 0, 1), (0, 1, 0, 2), (0, 1, 1, 3), (2, 1, 0, 4)]
+    );
+    assert_eq!(
+        mapped.child(0).all().collect::<Vec<_>>(),
+        vec![(0, 0, 0, 1), (0, 1, 0, 2), (0, 1, 1, 3)]
+    );
+}
+
+#[test]
+#[should_panic]
+fn map_with_idx_all_unbounded() {
+    let v2 = V.d2().fun(|[i, j]| i + j);
+    let mapped = v2.map_with_idx(|[i, _], x| x * i);
+    let _ = mapped.all().take(3).count();
+}
+
+#[test]
+fn map_into_cached() {
+    let distances = V
+        .d2()
+        .fun(|[i, j]| i.abs_diff(j))
+        .with_rectangular_bounds([4, 4]);
+    let durations = distances
+        .map_with_idx(|[i, _], d| d * (i + 1))
+        .into_cached();
+
+    assert_eq!(durations.cache_len(), 0);
+    assert_eq!(durations.at([2, 0]), 6);
+    assert_eq!(durations.at([2, 0]), 6);
+    assert_eq!(durations.cache_len(), 1);
+    assert_eq!(durations.card([1]), 4);
+
+    let v1 = vec![1, 2, 3];
+    let cached = v1.map(|x| x * 10).into_cached();
+    assert_eq!(cached.all().collect::<Vec<_>>(), vec![10, 20, 30]);
+    assert_eq!(cached.cache_len(), 3);
+}
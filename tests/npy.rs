@@ -0,0 +1,355 @@
+#![cfg(feature = "npy")]
+
+use orx_v::npy::*;
+use orx_v::*;
+
+/// Bytes of an `.npy` file with the given format version, header dictionary and data,
+/// laid out as numpy does.
+fn npy_bytes(major: u8, dict: &str, data: &[u8]) -> Vec<u8> {
+    let preamble_len = match major {
+        1 => 10,
+        _ => 12,
+    };
+    let mut header = dict.to_string();
+    while !(preamble_len + header.len() + 1).is_multiple_of(64) {
+        header.push(' ');
+    }
+    header.push('\n');
+
+    let mut bytes = b"\x93NUMPY".to_vec();
+    bytes.extend_from_slice(&[major, 0]);
+    match major {
+        1 => bytes.extend_from_slice(&(header.len() as u16).to_le_bytes()),
+        _ => bytes.extend_from_slice(&(header.len() as u32).to_le_bytes()),
+    }
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+fn le_bytes_i32(values: impl IntoIterator<Item = i32>) -> Vec<u8> {
+    values.into_iter().flat_map(|x| x.to_le_bytes()).collect()
+}
+
+fn le_bytes_f64(values: impl IntoIterator<Item = f64>) -> Vec<u8> {
+    values.into_iter().flat_map(|x| x.to_le_bytes()).collect()
+}
+
+#[test]
+fn npy_read_matrix() {
+    // np.arange(6, dtype=np.int32).reshape(2, 3)
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '<i4', 'fortran_order': False, 'shape': (2, 3), }",
+        &le_bytes_i32(0..6),
+    );
+    match read_matrix::<i32>(bytes.as_slice()).unwrap() {
+        NpyMatrix::RowMajor(mat) => {
+            assert_eq!((mat.num_rows(), mat.num_cols()), (2, 3));
+            assert_eq!(mat.at([0, 2]), 2);
+            assert_eq!(mat.at([1, 0]), 3);
+            assert_eq!(mat.all().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+        }
+        NpyMatrix::ColMajor(_) => panic!("expected a row-major matrix"),
+    }
+
+    // np.asfortranarray(np.arange(6, dtype=np.float64).reshape(2, 3))
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '<f8', 'fortran_order': True, 'shape': (2, 3), }",
+        &le_bytes_f64([0.0, 3.0, 1.0, 4.0, 2.0, 5.0]),
+    );
+    match read_matrix::<f64>(bytes.as_slice()).unwrap() {
+        NpyMatrix::ColMajor(mat) => {
+            assert_eq!((mat.num_rows(), mat.num_cols()), (2, 3));
+            for i in 0..2 {
+                for j in 0..3 {
+                    assert_eq!(mat.at([i, j]), (3 * i + j) as f64);
+                }
+            }
+        }
+        NpyMatrix::RowMajor(_) => panic!("expected a col-major matrix"),
+    }
+}
+
+#[test]
+fn npy_read_byte_orders_and_versions() {
+    // np.arange(4, dtype='>u2').reshape(2, 2)
+    let data: Vec<u8> = (0u16..4).flat_map(|x| x.to_be_bytes()).collect();
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '>u2', 'fortran_order': False, 'shape': (2, 2), }",
+        &data,
+    );
+    let NpyMatrix::RowMajor(mat) = read_matrix::<u16>(bytes.as_slice()).unwrap() else {
+        panic!("expected a row-major matrix");
+    };
+    assert_eq!(mat.all().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+
+    // single byte types & version 2.0
+    let bytes = npy_bytes(
+        2,
+        "{'descr': '|i1', 'fortran_order': False, 'shape': (1, 3), }",
+        &[255, 0, 127],
+    );
+    let NpyMatrix::RowMajor(mat) = read_matrix::<i8>(bytes.as_slice()).unwrap() else {
+        panic!("expected a row-major matrix");
+    };
+    assert_eq!(mat.all().collect::<Vec<_>>(), vec![-1, 0, 127]);
+
+    // version 3.0 with double quotes & no trailing comma
+    let bytes = npy_bytes(
+        3,
+        "{\"descr\": \"<f4\", \"shape\": (1, 2), \"fortran_order\": False}",
+        &[1.5f32.to_le_bytes(), 2.5f32.to_le_bytes()].concat(),
+    );
+    let NpyMatrix::RowMajor(mat) = read_matrix::<f32>(bytes.as_slice()).unwrap() else {
+        panic!("expected a row-major matrix");
+    };
+    assert_eq!(mat.all().collect::<Vec<_>>(), vec![1.5, 2.5]);
+}
+
+#[test]
+fn npy_read_tensors() {
+    let expected_d3 = V.d3().fun(|[i, j, k]| (100 * i + 10 * j + k) as i32);
+
+    // np.arange(24).reshape(2, 3, 4) mapped as expected_d3
+    let data = le_bytes_i32(
+        (0..2).flat_map(|i| (0..3).flat_map(move |j| (0..4).map(move |k| 100 * i + 10 * j + k))),
+    );
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '<i4', 'fortran_order': False, 'shape': (2, 3, 4), }",
+        &data,
+    );
+    let NpyTensor::RowMajor(tensor) = read_d3::<i32>(bytes.as_slice()).unwrap() else {
+        panic!("expected a row-major tensor");
+    };
+    assert_eq!(tensor.shape(), [2, 3, 4]);
+    assert!(tensor
+        .enumerate_all()
+        .all(|(idx, x)| x == expected_d3.at(idx)));
+
+    let data = le_bytes_i32(
+        (0..4).flat_map(|k| (0..3).flat_map(move |j| (0..2).map(move |i| 100 * i + 10 * j + k))),
+    );
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '<i4', 'fortran_order': True, 'shape': (2, 3, 4), }",
+        &data,
+    );
+    let NpyTensor::ColMajor(tensor) = read_d3::<i32>(bytes.as_slice()).unwrap() else {
+        panic!("expected a col-major tensor");
+    };
+    assert_eq!(tensor.shape(), [2, 3, 4]);
+    assert!(tensor
+        .enumerate_all()
+        .all(|(idx, x)| x == expected_d3.at(idx)));
+
+    let expected_d4 = V
+        .d4()
+        .fun(|[i, j, k, l]| (1000 * i + 100 * j + 10 * k + l) as f64);
+    let data = le_bytes_f64((0..2).flat_map(|l| {
+        (0..2).flat_map(move |k| {
+            (0..3).flat_map(move |j| (0..2).map(move |i| (1000 * i + 100 * j + 10 * k + l) as f64))
+        })
+    }));
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '<f8', 'fortran_order': True, 'shape': (2, 3, 2, 2), }",
+        &data,
+    );
+    let NpyTensor::ColMajor(tensor) = read_d4::<f64>(bytes.as_slice()).unwrap() else {
+        panic!("expected a col-major tensor");
+    };
+    assert_eq!(tensor.shape(), [2, 3, 2, 2]);
+    assert!(tensor
+        .enumerate_all()
+        .all(|(idx, x)| x == expected_d4.at(idx)));
+}
+
+#[test]
+fn npy_read_errors() {
+    let matrix = |dict: &str, data: &[u8]| read_matrix::<i32>(npy_bytes(1, dict, data).as_slice());
+
+    let result = read_matrix::<i32>(&b"not an npy file"[..]);
+    assert!(matches!(result, Err(NpyError::InvalidFormat(_))));
+
+    let mut bytes = npy_bytes(
+        1,
+        "{'descr': '<i4', 'fortran_order': False, 'shape': (1, 1), }",
+        &[0; 4],
+    );
+    bytes[6] = 4;
+    let result = read_matrix::<i32>(bytes.as_slice());
+    assert!(matches!(result, Err(NpyError::InvalidFormat(_))));
+
+    let mut bytes = b"\x93NUMPY\x02\x00".to_vec();
+    bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+    bytes.extend_from_slice(b"{'descr': '<i4', ");
+    let result = read_matrix::<i32>(bytes.as_slice());
+    assert!(matches!(result, Err(NpyError::Io(_))));
+
+    let result = matrix("{'descr': '<i4', 'shape': (1, 1), }", &[0; 4]);
+    assert!(matches!(result, Err(NpyError::InvalidFormat(_))));
+
+    let result = matrix(
+        "{'descr': '<i8', 'fortran_order': False, 'shape': (1, 1), }",
+        &[0; 8],
+    );
+    match result {
+        Err(NpyError::DtypeMismatch { expected, found }) => {
+            assert_eq!((expected, found.as_str()), ("<i4", "<i8"));
+        }
+        _ => panic!("expected a dtype mismatch"),
+    }
+
+    let result = matrix(
+        "{'descr': '<c16', 'fortran_order': False, 'shape': (1, 1), }",
+        &[0; 16],
+    );
+    assert!(matches!(result, Err(NpyError::UnsupportedDtype(d)) if d == "<c16"));
+
+    let result = matrix(
+        "{'descr': [('x', '<i4'), ('y', '<i4')], 'fortran_order': False, 'shape': (1, 1), }",
+        &[0; 8],
+    );
+    assert!(
+        matches!(result, Err(NpyError::UnsupportedDtype(d)) if d == "[('x', '<i4'), ('y', '<i4')]")
+    );
+
+    let result = matrix(
+        "{'descr': '<i4', 'fortran_order': False, 'shape': (2, 3, 4), }",
+        &[0; 96],
+    );
+    match result {
+        Err(NpyError::ShapeMismatch {
+            expected_dimension,
+            found_shape,
+        }) => assert_eq!((expected_dimension, found_shape), (2, vec![2, 3, 4])),
+        _ => panic!("expected a shape mismatch"),
+    }
+
+    let result = matrix(
+        "{'descr': '<i4', 'fortran_order': False, 'shape': (2, 3), }",
+        &[0; 20],
+    );
+    assert!(matches!(result, Err(NpyError::InvalidFormat(_))));
+
+    let error = matrix(
+        "{'descr': '<u4', 'fortran_order': False, 'shape': (2, 3), }",
+        &[0; 24],
+    )
+    .err()
+    .unwrap();
+    assert_eq!(
+        error.to_string(),
+        "Dtype of the npy array <u4 does not match the requested element type <i4"
+    );
+}
+
+#[test]
+fn npy_write() {
+    // np.save of np.arange(6, dtype=np.int32).reshape(2, 3)
+    let expected = npy_bytes(
+        1,
+        "{'descr': '<i4', 'fortran_order': False, 'shape': (2, 3), }",
+        &le_bytes_i32(0..6),
+    );
+
+    let mut bytes = vec![];
+    write(&mut bytes, &vec![vec![0, 1, 2], vec![3, 4, 5]]).unwrap();
+    assert_eq!(bytes, expected);
+
+    let mut bytes = vec![];
+    write(
+        &mut bytes,
+        &V.d2()
+            .fun(|[i, j]| (3 * i + j) as i32)
+            .with_rectangular_bounds([2, 3]),
+    )
+    .unwrap();
+    assert_eq!(bytes, expected);
+
+    let mut bytes = vec![];
+    let v1: Vec<i32> = vec![0, 3, 1, 4, 2, 5];
+    write_matrix(&mut bytes, &v1.v1_as_matrix_col_major(2, 3)).unwrap();
+    assert_eq!(bytes, expected);
+
+    // np.arange(3, dtype=np.float64)
+    let mut bytes = vec![];
+    write(&mut bytes, &vec![0.0, 1.0, 2.0]).unwrap();
+    let expected = npy_bytes(
+        1,
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (3,), }",
+        &le_bytes_f64([0.0, 1.0, 2.0]),
+    );
+    assert_eq!(bytes, expected);
+
+    // empty
+    let mut bytes = vec![];
+    write(&mut bytes, &Vec::<Vec<u8>>::new()).unwrap();
+    let expected = npy_bytes(
+        1,
+        "{'descr': '|u1', 'fortran_order': False, 'shape': (0, 0), }",
+        &[],
+    );
+    assert_eq!(bytes, expected);
+}
+
+#[test]
+fn npy_write_errors() {
+    let jagged = vec![vec![1i64, 2], vec![3]];
+    assert!(matches!(
+        write(&mut vec![], &jagged),
+        Err(NpyError::NotRectangular)
+    ));
+
+    let unbounded = V.d3().constant(1u32);
+    assert!(matches!(
+        write(&mut vec![], &unbounded),
+        Err(NpyError::Unbounded)
+    ));
+
+    let unbounded_rows = V.d2().constant(1u32).with_rectangular_bounds([2, 3]);
+    assert!(write(&mut vec![], &unbounded_rows).is_ok());
+}
+
+#[test]
+fn npy_round_trip() {
+    let v3: Vec<Vec<Vec<u64>>> = (0..3)
+        .map(|i| {
+            (0..4)
+                .map(|j| (0..5).map(|k| (i * j * k) as u64).collect())
+                .collect()
+        })
+        .collect();
+    let mut bytes = vec![];
+    write(&mut bytes, &v3).unwrap();
+    let NpyTensor::RowMajor(tensor) = read_d3::<u64>(bytes.as_slice()).unwrap() else {
+        panic!("expected a row-major tensor");
+    };
+    assert_eq!(tensor.equality(&v3), Equality::Equal);
+
+    let v1: Vec<f32> = (0..120).map(|x| x as f32 / 3.0).collect();
+    let tensor = v1.v1_as_d4_col_major([2, 3, 4, 5]);
+    let mut bytes = vec![];
+    write(&mut bytes, &tensor).unwrap();
+    let NpyTensor::RowMajor(back) = read_d4::<f32>(bytes.as_slice()).unwrap() else {
+        panic!("expected a row-major tensor");
+    };
+    assert_eq!(back.shape(), [2, 3, 4, 5]);
+    assert_eq!(back.equality(&tensor), Equality::Equal);
+
+    // file
+    let path = std::env::temp_dir().join("orx_v_npy_round_trip.npy");
+    let mat = (0..12).map(|x| x as i16).collect::<Vec<_>>();
+    let mat = mat.v1_as_matrix(3, 4);
+    write_matrix(std::fs::File::create(&path).unwrap(), &mat).unwrap();
+    let back = read_matrix::<i16>(std::fs::File::open(&path).unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let NpyMatrix::RowMajor(back) = back else {
+        panic!("expected a row-major matrix");
+    };
+    assert_eq!(back.equality(&mat), Equality::Equal);
+}
@@ -0,0 +1,127 @@
+use orx_v::*;
+
+#[derive(Debug, PartialEq)]
+struct Big {
+    id: usize,
+    name: String,
+}
+
+fn big(id: usize) -> Big {
+    Big {
+        id,
+        name: format!("big-{}", id),
+    }
+}
+
+fn sum_ids<'a, D: Dim>(vec: &impl NVec<D, &'a Big>) -> usize {
+    vec.all().map(|x| x.id).sum()
+}
+
+#[test]
+fn at_ref_d1() {
+    let v1 = vec![String::from("a"), String::from("bc")];
+    assert_eq!(v1.at_ref([0]), "a");
+    assert_eq!(v1.at_ref(1), "bc");
+    assert_eq!(v1.all_ref().map(|x| x.len()).sum::<usize>(), 3);
+
+    let arr = [big(1), big(2), big(3)];
+    assert_eq!(arr.at_ref([2]).name, "big-3");
+    assert_eq!(arr.as_slice().at_ref([1]), &arr[1]);
+    assert_eq!(sum_ids(&arr.as_nvec_of_refs()), 6);
+}
+
+#[test]
+fn at_ref_nested() {
+    let v2 = vec![vec![big(1)], vec![], vec![big(2), big(3)]];
+    let x: &Big = v2.at_ref([2, 1]);
+    assert_eq!(x, &big(3));
+    assert_eq!(
+        NVecRef::<D2, _>::all_ref(&v2)
+            .map(|x| x.id)
+            .collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+
+    let v3 = vec![
+        vec![vec![String::from("x")]],
+        vec![vec![], vec![String::from("y")]],
+    ];
+    assert_eq!(NVecRef::<D3, String>::at_ref(&v3, [1, 1, 0]), "y");
+    assert_eq!(NVecRef::<D3, String>::all_ref(&v3).count(), 2);
+}
+
+#[test]
+fn as_nvec_of_refs() {
+    let v2 = vec![vec![big(1)], vec![], vec![big(2), big(3)]];
+    let refs: RefVec<D2, _, _> = v2.as_nvec_of_refs();
+
+    assert_eq!(refs.card([]), 3);
+    assert_eq!(refs.card([2]), 2);
+    assert_eq!(refs.at([2, 0]).id, 2);
+    assert_eq!(refs.child(2).at([1]).id, 3);
+    assert_eq!(refs.try_at([1, 0]), None);
+    assert_eq!(sum_ids(&refs), 6);
+    assert_eq!(
+        refs.enumerate_all()
+            .map(|(idx, x)| (idx, x.id))
+            .collect::<Vec<_>>(),
+        vec![([0, 0], 1), ([2, 0], 2), ([2, 1], 3)]
+    );
+
+    // the view is Copy and outlives its borrows
+    let max = {
+        let r = refs;
+        r.all().max_by_key(|x| x.id)
+    };
+    assert_eq!(max, Some(&v2[2][1]));
+}
+
+#[test]
+fn as_nvec_of_refs_with_algorithms() {
+    let v2 = vec![
+        vec![String::from("a"), String::from("bb")],
+        vec![String::from("ccc"), String::from("d")],
+    ];
+    let refs: RefVec<D2, _, _> = v2.as_nvec_of_refs();
+
+    let lengths = refs.map(|x| x.len());
+    assert_eq!(lengths.at([1, 0]), 3);
+
+    let mat = refs.as_matrix();
+    assert_eq!(mat.at([0, 1]), "bb");
+    assert_eq!(mat.row(1).all().collect::<Vec<_>>(), vec!["ccc", "d"]);
+
+    let transposed = refs.into_transposed();
+    assert_eq!(transposed.at([0, 1]), "ccc");
+}
+
+#[test]
+fn debug_ref_vec() {
+    let v1 = vec![String::from("a")];
+    let refs = v1.as_nvec_of_refs();
+    assert_eq!(
+        format!("{:?}", refs),
+        "{ kind: RefVec, dim: D1, values: [\"a\"] }"
+    );
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn at_ref_ndarray() {
+    use ndarray::Array2;
+
+    let arr = Array2::from_shape_fn((2, 3), |(i, j)| format!("{}{}", i, j));
+    assert_eq!(arr.at_ref([1, 2]), "12");
+    assert_eq!(arr.all_ref().count(), 6);
+    assert_eq!(arr.as_nvec_of_refs().child(1).at([0]), "10");
+}
+
+#[cfg(feature = "orx-split-vec")]
+#[test]
+fn at_ref_split_vec() {
+    use orx_split_vec::SplitVec;
+
+    let v1: SplitVec<_> = (0..10).map(big).collect();
+    assert_eq!(v1.at_ref([7]).id, 7);
+    assert_eq!(sum_ids(&v1.as_nvec_of_refs()), 45);
+}
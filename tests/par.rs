@@ -0,0 +1,179 @@
+#![cfg(feature = "rayon")]
+
+use orx_v::*;
+use rayon::prelude::*;
+
+fn jagged_d2(n: usize) -> Vec<Vec<usize>> {
+    (0..n)
+        .map(|i| (0..(i % 7)).map(|j| 10 * i + j).collect())
+        .collect()
+}
+
+fn assert_par_matches_seq<D, V>(vec: &V)
+where
+    D: Dim,
+    D::Idx: Send + PartialEq,
+    V: NVecPar<D, usize>,
+{
+    let par: Vec<_> = vec.par_all().collect();
+    let seq: Vec<_> = vec.all().collect();
+    assert_eq!(par, seq);
+
+    let par: Vec<_> = vec.par_enumerate_all().collect();
+    let seq: Vec<_> = vec.enumerate_all().collect();
+    assert_eq!(par, seq);
+
+    if D::dimension() > 1 {
+        let par: Vec<usize> = vec.par_children().map(|c| c.all().sum()).collect();
+        let seq: Vec<usize> = vec.children().map(|c| c.all().sum()).collect();
+        assert_eq!(par, seq);
+    }
+}
+
+fn assert_par_mut_matches_seq<D, V>(mut par: V)
+where
+    D: Dim,
+    V: NVecParMut<D, usize> + Clone,
+{
+    let mut seq = par.clone();
+    par.par_mut_all(|x| *x = 3 * *x + 1);
+    seq.mut_all(|x| *x = 3 * *x + 1);
+    assert_eq!(par.all().collect::<Vec<_>>(), seq.all().collect::<Vec<_>>());
+}
+
+#[test]
+fn par_vec() {
+    let v1: Vec<usize> = (0..1000).collect();
+    assert_par_matches_seq(&v1);
+    assert_par_matches_seq(&v1.as_slice());
+    assert_eq!(v1.par_children().count(), 0);
+    assert_par_mut_matches_seq(v1);
+
+    let v2 = jagged_d2(1000);
+    assert_par_matches_seq(&v2);
+    assert_par_mut_matches_seq(v2.clone());
+
+    let v3: Vec<_> = (0..100).map(|i| jagged_d2(i % 13)).collect();
+    assert_par_matches_seq(&v3);
+    assert_par_mut_matches_seq(v3);
+
+    let arr = [[1, 2, 3], [4, 5, 6]];
+    assert_par_matches_seq::<D2, _>(&arr);
+    assert_par_mut_matches_seq::<D2, _>(arr);
+}
+
+#[test]
+fn par_vec_mut_slice() {
+    let mut v2 = jagged_d2(100);
+    let expected: Vec<Vec<_>> = v2
+        .iter()
+        .map(|x| x.iter().map(|x| x * 2).collect())
+        .collect();
+
+    v2.as_mut_slice().par_mut_all(|x| *x *= 2);
+    assert_eq!(v2, expected);
+}
+
+#[test]
+fn par_flat_jagged() {
+    let v2 = jagged_d2(1000);
+    let row_lengths: Vec<_> = v2.iter().map(|x| x.len()).collect();
+    let flat: Vec<_> = v2.iter().flat_map(|x| x.iter().copied()).collect();
+
+    let mut jagged = flat.into_jagged_from_row_lengths(&row_lengths);
+    assert_par_matches_seq(&jagged);
+    assert_eq!(
+        jagged.par_enumerate_all().collect::<Vec<_>>(),
+        v2.enumerate_all().collect::<Vec<_>>()
+    );
+
+    jagged.par_mut_all(|x| *x += 1);
+    let expected: Vec<Vec<_>> = v2
+        .iter()
+        .map(|x| x.iter().map(|x| x + 1).collect())
+        .collect();
+    assert_eq!(jagged.equality(&expected), Equality::Equal);
+}
+
+#[test]
+fn par_matrices() {
+    let mut v1: Vec<usize> = (0..600).collect();
+
+    let mat = v1.v1_as_matrix(20, 30);
+    assert_eq!(
+        mat.par_all().collect::<Vec<_>>(),
+        mat.all().collect::<Vec<_>>()
+    );
+    for (idx, x) in mat.par_enumerate_all().collect::<Vec<_>>() {
+        assert_eq!(mat.at(idx), x);
+    }
+
+    let mat = v1.v1_as_matrix_col_major(20, 30);
+    assert_eq!(
+        mat.par_all().collect::<Vec<_>>(),
+        mat.all().collect::<Vec<_>>()
+    );
+    for (idx, x) in mat.par_enumerate_all().collect::<Vec<_>>() {
+        assert_eq!(mat.at(idx), x);
+    }
+
+    v1.v1_as_matrix_col_major_mut(20, 30)
+        .par_mut_all(|x| *x *= 2);
+    assert_eq!(v1, (0..600).map(|x| x * 2).collect::<Vec<_>>());
+
+    let mut v2: Vec<Vec<usize>> = (0..20)
+        .map(|i| (0..30).map(|j| 30 * i + j).collect())
+        .collect();
+
+    let mat = v2.as_matrix();
+    assert_eq!(
+        mat.par_all().collect::<Vec<_>>(),
+        mat.all().collect::<Vec<_>>()
+    );
+    for (idx, x) in mat.par_enumerate_all().collect::<Vec<_>>() {
+        assert_eq!(mat.at(idx), x);
+    }
+
+    let mut mat = v2.as_matrix_col_major_mut();
+    for (idx, x) in mat.par_enumerate_all().collect::<Vec<_>>() {
+        assert_eq!(mat.at(idx), x);
+    }
+    mat.par_mut_all(|x| *x += 1);
+    assert_eq!(v2[19][29], 600);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn par_ndarray() {
+    use ndarray::{Array, Array1, Ix2, Ix3};
+
+    let v1: Array1<usize> = Array::from_vec((0..1000).collect());
+    assert_par_matches_seq(&v1);
+    assert_par_mut_matches_seq(v1);
+
+    let v2: Array<usize, Ix2> = Array::from_shape_vec((100, 7), (0..700).collect()).unwrap();
+    assert_par_matches_seq(&v2);
+    assert_par_mut_matches_seq(v2);
+
+    let v3: Array<usize, Ix3> = Array::from_shape_vec((10, 4, 3), (0..120).collect()).unwrap();
+    assert_par_matches_seq(&v3);
+    assert_par_mut_matches_seq(v3);
+
+    let v2: Array1<Vec<usize>> = Array::from_vec(jagged_d2(100));
+    assert_par_matches_seq(&v2);
+    assert_par_mut_matches_seq(v2);
+}
+
+#[cfg(feature = "orx-split-vec")]
+#[test]
+fn par_split_vec() {
+    use orx_split_vec::SplitVec;
+
+    let v1: SplitVec<usize> = (0..1000).collect();
+    assert_par_matches_seq(&v1);
+    assert_par_mut_matches_seq(v1);
+
+    let v2: SplitVec<Vec<usize>> = jagged_d2(1000).into_iter().collect();
+    assert_par_matches_seq(&v2);
+    assert_par_mut_matches_seq(v2);
+}
@@ -0,0 +1,127 @@
+use orx_v::*;
+
+fn v3() -> Vec<Vec<Vec<usize>>> {
+    (0..2)
+        .map(|i| {
+            (0..3)
+                .map(|j| (0..4).map(|k| 100 * i + 10 * j + k).collect())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn transpose_d2() {
+    let mut v2 = vec![vec![0, 1, 2], vec![3, 4, 5]];
+
+    let t = v2.as_transposed();
+    assert!(t.is_rectangular());
+    assert_eq!(t.num_children(), 3);
+    for i in 0..3 {
+        assert_eq!(t.card([i]), 2);
+        for (j, row) in v2.iter().enumerate() {
+            assert_eq!(t.at([i, j]), row[i]);
+        }
+    }
+    assert_eq!(t.all().collect::<Vec<_>>(), vec![0, 3, 1, 4, 2, 5]);
+    assert_eq!(t.try_at([1, 2]), None);
+    assert_eq!(t.as_transposed().equality(&v2), Equality::Equal);
+
+    let mut t = v2.as_transposed_mut();
+    *t.at_mut([2, 0]) = 20;
+    t.child_mut(0).set([1], 30);
+    assert_eq!(v2, vec![vec![0, 1, 20], vec![30, 4, 5]]);
+
+    let m = V
+        .d2()
+        .fun(|[i, j]| 10 * i + j)
+        .with_rectangular_bounds([4, 2]);
+    let t = m.into_transposed();
+    assert_eq!(t.card([]), 2);
+    assert_eq!(t.card([1]), 4);
+    assert_eq!(t.child(1).equality(&[1, 11, 21, 31]), Equality::Equal);
+}
+
+#[test]
+fn permute_axes_d3_card() {
+    let v3 = v3();
+    let shape = [2, 3, 4];
+
+    let perms = [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ];
+    for axes in perms {
+        let p = v3.as_permuted_axes(axes);
+        let s = axes.map(|a| shape[a]);
+        assert_eq!(p.shape(), s);
+        assert_eq!(p.card([]), s[0]);
+        for i in 0..s[0] {
+            assert_eq!(p.card([i]), s[1]);
+            for j in 0..s[1] {
+                assert_eq!(p.card([i, j]), s[2]);
+                for k in 0..s[2] {
+                    let mut src = [0; 3];
+                    for (q, a) in axes.iter().enumerate() {
+                        src[*a] = [i, j, k][q];
+                    }
+                    assert_eq!(p.at([i, j, k]), v3[src[0]][src[1]][src[2]]);
+                    assert_eq!(p.child(i).child(j).at([k]), p.at([i, j, k]));
+                }
+            }
+        }
+        assert_eq!(p.all().count(), 24);
+        assert!(!p.in_bounds([s[0], 0]));
+    }
+}
+
+#[test]
+fn permute_axes_d4() {
+    let mut v4 = V.d4().sparse(0).with_rectangular_bounds([1, 2, 3, 4]);
+    let mut p = v4.as_permuted_axes_mut([3, 1, 0, 2]);
+    assert_eq!(p.card([]), 4);
+    assert_eq!(p.card([3]), 2);
+    assert_eq!(p.card([3, 1]), 1);
+    assert_eq!(p.card([3, 1, 0]), 3);
+
+    p.set([3, 1, 0, 2], 7);
+    p.child_mut(2).child_mut(0).set([0, 1], 5);
+    assert_eq!(v4.at([0, 1, 2, 3]), 7);
+    assert_eq!(v4.at([0, 0, 1, 2]), 5);
+
+    let p = v4.into_permuted_axes([3, 1, 0, 2]);
+    assert_eq!(p.all().sum::<i32>(), 12);
+    assert_eq!(p.into_inner().lookup_len(), 2);
+}
+
+#[test]
+fn permute_axes_empty() {
+    let v3: Vec<Vec<Vec<i32>>> = vec![vec![], vec![]];
+    let p = v3.into_permuted_axes([2, 0, 1]);
+    assert_eq!(p.shape(), [0, 2, 0]);
+    assert_eq!(p.all().count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn transpose_jagged() {
+    let v2 = vec![vec![0, 1, 2], vec![3]];
+    let _ = v2.into_transposed();
+}
+
+#[test]
+#[should_panic]
+fn permute_axes_jagged() {
+    let v3 = vec![vec![vec![0, 1]], vec![vec![2, 3], vec![4]]];
+    let _ = v3.into_permuted_axes([1, 0, 2]);
+}
+
+#[test]
+#[should_panic]
+fn permute_axes_invalid() {
+    let _ = v3().into_permuted_axes([0, 2, 2]);
+}
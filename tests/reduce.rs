@@ -0,0 +1,199 @@
+use orx_v::*;
+
+fn total(v: impl V1<i64>) -> i64 {
+    v.all().sum()
+}
+
+#[test]
+fn reduce_axis_d2() {
+    let v2 = vec![vec![1i64, 2, 3], vec![4, 5, 6]];
+
+    let rows = v2.as_sum_axis(1);
+    assert_eq!(rows.shape(), [2]);
+    assert_eq!(rows.axis(), 1);
+    assert_eq!(rows.card([]), 2);
+    assert_eq!(rows.try_at(2), None);
+    assert_eq!(rows.equality(&[6, 15]), Equality::Equal);
+    assert_eq!(total(&rows), 21);
+
+    let cols = v2.as_sum_axis(0);
+    assert_eq!(cols.equality(&[5, 7, 9]), Equality::Equal);
+    assert_eq!(total(&cols), 21);
+
+    let products = v2.as_reduced_axis(0, 1, |a, b| a * b);
+    assert_eq!(products.equality(&[4, 10, 18]), Equality::Equal);
+
+    let as_strings = v2.as_reduced_axis(1, String::new(), |s, x| format!("{}{}", s, x));
+    assert_eq!(as_strings.at(1), "456");
+}
+
+#[test]
+fn reduce_axis_d3() {
+    let v3 = V
+        .d3()
+        .fun(|[i, j, k]| 100 * i + 10 * j + k)
+        .with_rectangular_bounds([2, 3, 4]);
+
+    for axis in 0..3 {
+        let v2 = v3.as_sum_axis(axis);
+        let expected_shape = match axis {
+            0 => [3, 4],
+            1 => [2, 4],
+            _ => [2, 3],
+        };
+        assert_eq!(v2.shape(), expected_shape);
+        assert_eq!(v2.all().sum::<usize>(), v3.all().sum::<usize>());
+
+        for a in 0..expected_shape[0] {
+            for b in 0..expected_shape[1] {
+                let expected: usize = match axis {
+                    0 => (0..2).map(|i| v3.at([i, a, b])).sum(),
+                    1 => (0..3).map(|j| v3.at([a, j, b])).sum(),
+                    _ => (0..4).map(|k| v3.at([a, b, k])).sum(),
+                };
+                assert_eq!(v2.at([a, b]), expected);
+                assert_eq!(v2.child(a).at([b]), expected);
+            }
+        }
+    }
+
+    let v4 = vec![vec![vec![vec![1, 2], vec![3, 4]]]; 2];
+    let v3 = v4.as_sum_axis(0);
+    assert_eq!(v3.shape(), [1, 2, 2]);
+    assert_eq!(v3.equality(&[[[2, 4], [6, 8]]]), Equality::Equal);
+}
+
+#[test]
+fn min_max_by_axis() {
+    let costs = vec![vec![4.0f64, 2.0, 7.0], vec![1.0, 9.0, 3.0]];
+
+    let min_costs = costs.as_min_by_axis(1, |a, b| a.total_cmp(b));
+    assert_eq!(min_costs.equality(&[Some(2.0), Some(1.0)]), Equality::Equal);
+
+    let max_costs = costs.as_max_by_axis(0, |a, b| a.total_cmp(b));
+    assert_eq!(
+        max_costs.equality(&[Some(4.0), Some(9.0), Some(7.0)]),
+        Equality::Equal
+    );
+
+    // ties: first minimum & last maximum as in Iterator::min_by & max_by
+    let pairs = vec![vec![(1, 'a'), (0, 'b'), (0, 'c'), (1, 'd')]];
+    let min = pairs.as_min_by_axis(1, |a, b| a.0.cmp(&b.0));
+    assert_eq!(min.at(0), Some((0, 'b')));
+    let max = pairs.as_max_by_axis(1, |a, b| a.0.cmp(&b.0));
+    assert_eq!(max.at(0), Some((1, 'd')));
+
+    let empty: Vec<Vec<i32>> = vec![vec![], vec![]];
+    let min = empty.as_min_by_axis(1, |a, b| a.cmp(b));
+    assert_eq!(min.all().collect::<Vec<_>>(), vec![None, None]);
+}
+
+#[test]
+fn reduced_into_cached() {
+    let v2 = V
+        .d2()
+        .fun(|[i, j]| (i * j) as i64)
+        .with_rectangular_bounds([10, 10]);
+    let rows = v2.as_sum_axis(1).into_cached();
+    assert_eq!(rows.at(3), 3 * 45);
+    assert_eq!(rows.at(3), 3 * 45);
+    assert_eq!(rows.cache_len(), 1);
+}
+
+#[test]
+fn argmin_argmax() {
+    let v1 = vec![3, 1, 4, 1, 5, 9, 2, 6];
+    assert_eq!(v1.argmin(), Some([1]));
+    assert_eq!(v1.argmax(), Some([5]));
+
+    let v2 = vec![vec![4, 2, 7], vec![], vec![1, 9, 1]];
+    assert_eq!(v2.argmin(), Some([2, 0]));
+    assert_eq!(v2.argmax(), Some([2, 1]));
+
+    let v3 = V
+        .d3()
+        .fun(|[i, j, k]| ((i + j) * k) as f64)
+        .with_rectangular_bounds([2, 3, 4]);
+    assert_eq!(v3.argmin(), Some([0, 0, 0]));
+    assert_eq!(v3.argmax(), Some([1, 2, 3]));
+
+    let v1 = vec![f64::NAN, 2.0, f64::NAN, 1.0];
+    assert_eq!(v1.argmin(), Some([3]));
+    assert_eq!(v1.argmax(), Some([1]));
+
+    let v1 = vec![f64::NAN, f64::NAN];
+    assert_eq!(v1.argmin(), Some([0]));
+
+    let v2: Vec<Vec<i32>> = vec![vec![], vec![]];
+    assert_eq!(v2.argmin(), None);
+    assert_eq!(v2.argmax(), None);
+}
+
+#[test]
+fn reduce_jagged_last_axis() {
+    let v2 = vec![vec![1, 2], vec![], vec![3, 4, 5]];
+    let row_sums = v2.as_sum_axis(1);
+    assert_eq!(row_sums.card([]), 3);
+    assert!(row_sums.is_rectangular());
+    assert_eq!(row_sums.equality(&[3, 0, 12]), Equality::Equal);
+
+    let v3 = vec![vec![vec![1, 2], vec![3]], vec![], vec![vec![4, 5, 6]]];
+    let sums = v3.as_sum_axis(2);
+    assert_eq!(sums.card([]), 3);
+    assert_eq!(sums.card([0]), 2);
+    assert_eq!(sums.card([1]), 0);
+    assert!(!sums.is_rectangular());
+    assert_eq!(
+        sums.equality(&vec![vec![3, 3], vec![], vec![15]]),
+        Equality::Equal
+    );
+    assert_eq!(sums.child(2).at(0), 15);
+    assert_eq!(sums.all().collect::<Vec<_>>(), vec![3, 3, 15]);
+}
+
+#[test]
+fn reduce_jagged_rectangular_sub_vectors() {
+    // each child is rectangular while children have different shapes
+    let v3 = vec![
+        vec![vec![1, 2, 3], vec![4, 5, 6]],
+        vec![vec![7], vec![8], vec![9]],
+        vec![],
+    ];
+    let sums = v3.as_sum_axis(1);
+    assert_eq!(sums.card([]), 3);
+    assert_eq!(sums.card([0]), 3);
+    assert_eq!(sums.card([1]), 1);
+    assert_eq!(sums.card([2]), 0);
+    assert_eq!(
+        sums.equality(&vec![vec![5, 7, 9], vec![24], vec![]]),
+        Equality::Equal
+    );
+}
+
+#[test]
+#[should_panic]
+fn reduce_jagged_ill_defined_axis() {
+    let v2 = vec![vec![0, 1], vec![2]];
+    let _ = v2.into_sum_axis(0);
+}
+
+#[test]
+#[should_panic]
+fn reduce_jagged_ill_defined_inner_axis() {
+    let v3 = vec![vec![vec![1, 2], vec![3]]];
+    let _ = v3.into_sum_axis(1);
+}
+
+#[test]
+#[should_panic]
+fn reduce_jagged_shape() {
+    let v3 = vec![vec![vec![1, 2], vec![3]], vec![]];
+    let _ = v3.as_sum_axis(2).shape();
+}
+
+#[test]
+#[should_panic]
+fn reduce_axis_out_of_bounds() {
+    let v2 = vec![vec![0, 1], vec![2, 3]];
+    let _ = v2.into_sum_axis(2);
+}
@@ -0,0 +1,96 @@
+use orx_v::*;
+
+#[test]
+fn select_d1() {
+    let v1: Vec<_> = (0..10).map(|x| x * 10).collect();
+
+    let s = v1.v1_select([3, 3, 0, 9]);
+    assert_eq!(s.card([]), 4);
+    assert_eq!(s.all().collect::<Vec<_>>(), vec![30, 30, 0, 90]);
+    assert_eq!(s.try_at(4), None);
+
+    let e = v1.v1_select(Vec::<usize>::new());
+    assert_eq!(e.all().count(), 0);
+
+    let rev = v1.v1_select(V.d1().fun(|[i]| 9 - i).bounded(10));
+    assert_eq!(rev.equality(&v1.v1_as_rev()), Equality::Equal);
+
+    let s = v1.v1_select(0..3);
+    assert_eq!(s.equality(&[0, 10, 20]), Equality::Equal);
+}
+
+#[test]
+fn select_mut_d1_scatter() {
+    let mut v1 = V.d1().sparse(0).bounded(8);
+    let indices = vec![7, 1, 4];
+    let values = [70, 10, 40];
+
+    let mut s = v1.v1_select_mut(&indices);
+    for (i, x) in values.iter().enumerate() {
+        s.set(i, *x);
+    }
+    *s.at_mut(1) += 1;
+    assert_eq!(v1.lookup_len(), 3);
+    assert_eq!(v1.at(1), 11);
+    assert_eq!(v1.at(4), 40);
+    assert_eq!(v1.at(7), 70);
+
+    v1.v1_select_mut(&indices).reset_all(0);
+    assert_eq!(v1.all().sum::<i32>(), 0);
+}
+
+#[test]
+fn select_d2() {
+    let v2 = V
+        .d2()
+        .fun(|[i, j]| 10 * i + j)
+        .with_rectangular_bounds([6, 6]);
+
+    let rows = vec![5, 0];
+    let cols = [1, 1, 4];
+    let sub = v2.v2_select(&rows, &cols);
+    assert!(sub.is_rectangular());
+    assert_eq!(sub.card([]), 2);
+    assert_eq!(sub.card([1]), 3);
+    assert_eq!(sub.all().collect::<Vec<_>>(), vec![51, 51, 54, 1, 1, 4]);
+    assert_eq!(sub.child(0).equality(&[51, 51, 54]), Equality::Equal);
+    assert_eq!(sub.try_at([2, 0]), None);
+
+    let jagged = vec![vec![0, 1, 2], vec![3], vec![4, 5]];
+    let sub = jagged.v2_select([2, 0], [0, 1]);
+    assert_eq!(sub.equality(&[[4, 5], [0, 1]]), Equality::Equal);
+}
+
+#[test]
+fn select_mut_d2() {
+    let mut v2 = vec![vec![0; 4]; 4];
+    let tour = [3, 1];
+
+    let mut sub = v2.v2_select_mut(tour, tour);
+    sub.mut_all(|x| *x += 1);
+    sub.set([0, 1], 7);
+    assert_eq!(
+        v2,
+        vec![
+            vec![0, 0, 0, 0],
+            vec![0, 1, 0, 1],
+            vec![0, 0, 0, 0],
+            vec![0, 7, 0, 1],
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn select_out_of_bounds() {
+    let v1 = vec![0, 1, 2];
+    let s = v1.v1_select([0, 3]);
+    let _ = s.at(1);
+}
+
+#[test]
+#[should_panic]
+fn select_unbounded_indices() {
+    let v1 = vec![0, 1, 2];
+    let _ = v1.v1_select(V.d1().constant(0));
+}
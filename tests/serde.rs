@@ -0,0 +1,213 @@
+#![cfg(feature = "serde")]
+
+use orx_v::matrices::{V1MatrixColMajor, V1MatrixRowMajor};
+use orx_v::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+fn json_round_trip<V: Serialize + DeserializeOwned>(vec: &V) -> V {
+    let json = serde_json::to_string(vec).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+fn binary_round_trip<V: Serialize + DeserializeOwned>(vec: &V) -> V {
+    let mut bytes = vec![];
+    ciborium::into_writer(vec, &mut bytes).unwrap();
+    ciborium::from_reader(bytes.as_slice()).unwrap()
+}
+
+fn assert_round_trips<D, T, V>(vec: &V)
+where
+    D: Dim,
+    T: PartialEq + core::fmt::Debug,
+    V: NVec<D, T> + Serialize + DeserializeOwned,
+{
+    let expected: Vec<_> = vec.all().collect();
+    assert_eq!(json_round_trip(vec).all().collect::<Vec<_>>(), expected);
+    assert_eq!(binary_round_trip(vec).all().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn serde_cardinalities() {
+    let card = CardD1::from(3);
+    assert_eq!(serde_json::to_string(&card).unwrap(), "3");
+    assert_eq!(json_round_trip(&card).cardinality_of([]), 3);
+    assert_eq!(binary_round_trip(&card).cardinality_of([]), 3);
+
+    let card = RectangularCardD2::from([2, 3]);
+    let json = json_round_trip(&card);
+    assert_eq!((json.cardinality_of([]), json.cardinality_of([1])), (2, 3));
+    let bin = binary_round_trip(&card);
+    assert_eq!((bin.cardinality_of([]), bin.cardinality_of([1])), (2, 3));
+
+    let card = RectangularCardD3::from([2, 3, 4]);
+    assert_eq!(json_round_trip(&card).cardinality_of([1, 2]), 4);
+    assert_eq!(binary_round_trip(&card).cardinality_of([1, 2]), 4);
+
+    let card = RectangularCardD4::from([2, 3, 4, 5]);
+    assert_eq!(json_round_trip(&card).cardinality_of([1, 2, 3]), 5);
+    assert_eq!(binary_round_trip(&card).cardinality_of([1, 2, 3]), 5);
+
+    let card = VariableCardD2::from(vec![2, 0, 1]);
+    let json = json_round_trip(&card);
+    assert_eq!(
+        (
+            json.cardinality_of([]),
+            json.cardinality_of([0]),
+            json.cardinality_of([2])
+        ),
+        (3, 2, 1)
+    );
+    let bin = binary_round_trip(&card);
+    assert_eq!(
+        (
+            bin.cardinality_of([]),
+            bin.cardinality_of([0]),
+            bin.cardinality_of([2])
+        ),
+        (3, 2, 1)
+    );
+
+    let card = VariableCardD3::from(vec![vec![1, 2], vec![3]]);
+    assert_eq!(json_round_trip(&card).cardinality_of([0, 1]), 2);
+    assert_eq!(binary_round_trip(&card).cardinality_of([1, 0]), 3);
+
+    let card = VariableCardD4::from(vec![vec![vec![1, 2]], vec![vec![3]]]);
+    assert_eq!(json_round_trip(&card).cardinality_of([0, 0, 1]), 2);
+    assert_eq!(binary_round_trip(&card).cardinality_of([1, 0, 0]), 3);
+}
+
+#[test]
+fn serde_flat_jagged() {
+    let jagged = vec![1, 2, 3, 4, 5, 6].into_jagged_from_row_lengths(&[0, 3, 2, 1]);
+    assert_round_trips(&jagged);
+    let back: FlatJagged<Vec<i32>, Vec<usize>, i32> = json_round_trip(&jagged);
+    assert_eq!(
+        back.equality(&[vec![], vec![1, 2, 3], vec![4, 5], vec![6]]),
+        Equality::Equal
+    );
+
+    let uniform = vec![1, 2, 3, 4, 5, 6, 7, 8].into_jagged_with_uniform_lengths(3);
+    assert_round_trips(&uniform);
+    let back: FlatJagged<Vec<i32>, UniformEndIndices, i32> = binary_round_trip(&uniform);
+    assert_eq!(
+        back.equality(&[vec![1, 2, 3], vec![4, 5, 6], vec![7, 8]]),
+        Equality::Equal
+    );
+}
+
+#[test]
+fn serde_sparse() {
+    let mut sparse = V.d2().sparse(0).with_rectangular_bounds([3, 4]);
+    sparse.set([0, 1], 7);
+    sparse.set([2, 3], 42);
+    assert_round_trips(&sparse);
+
+    let back = json_round_trip(&sparse);
+    assert_eq!(back.at([1, 1]), 0);
+    assert_eq!(back.lookup_len(), 2);
+    assert_eq!(back.card([]), 3);
+
+    let mut unbounded = V.d1().sparse(-1);
+    unbounded.set([1000], 3);
+    let back = binary_round_trip(&unbounded);
+    assert_eq!(back.at([1000]), 3);
+    assert_eq!(back.at([1]), -1);
+    assert!(back.is_unbounded());
+
+    let mut variable = V.d2().sparse(1).with_variable_bounds(vec![2, 0, 1]);
+    variable.set([2, 0], 5);
+    assert_round_trips(&variable);
+}
+
+#[test]
+fn serde_constant() {
+    let constant = V.d2().constant(7).with_rectangular_bounds([2, 3]);
+    assert_round_trips(&constant);
+
+    let unbounded = V.d3().constant(1.5);
+    let back = json_round_trip(&unbounded);
+    assert_eq!(back.at([100, 200, 300]), 1.5);
+    assert!(back.is_unbounded());
+}
+
+#[test]
+fn serde_v1_matrix() {
+    let row_major = (0..6).collect::<Vec<_>>().v1_into_matrix(2, 3);
+    let back = json_round_trip(&row_major);
+    assert_eq!((back.num_rows(), back.num_cols()), (2, 3));
+    assert_eq!(back.equality(&row_major), Equality::Equal);
+    assert_eq!(
+        binary_round_trip(&row_major).equality(&row_major),
+        Equality::Equal
+    );
+
+    let col_major = (0..6).collect::<Vec<_>>().v1_into_matrix_col_major(2, 3);
+    let back = binary_round_trip(&col_major);
+    assert_eq!(back.at([1, 0]), 1);
+    assert_eq!(back.equality(&col_major), Equality::Equal);
+    assert_eq!(
+        json_round_trip(&col_major).equality(&col_major),
+        Equality::Equal
+    );
+}
+
+#[test]
+fn serde_phantom_is_skipped() {
+    let row_major = vec![1, 2].v1_into_matrix(1, 2);
+    let json = serde_json::to_string(&row_major).unwrap();
+    assert!(!json.contains("phantom"));
+
+    let jagged = vec![1, 2, 3].into_jagged_from_row_lengths(&[1, 2]);
+    let json = serde_json::to_string(&jagged).unwrap();
+    assert!(!json.contains("phantom"));
+
+    let constant = V.d1().constant(7).bounded(2);
+    let json = serde_json::to_string(&constant).unwrap();
+    assert!(!json.contains("phantom"));
+}
+
+#[test]
+fn serde_v1_matrix_invalid_len() {
+    let json = r#"{"layout":{"num_rows":2,"num_cols":2},"data":[1]}"#;
+    let result: Result<V1MatrixRowMajor<i32, Vec<i32>>, _> = serde_json::from_str(json);
+    assert!(result.is_err());
+
+    let json = r#"{"layout":{"num_rows":1,"num_cols":2},"data":[1,2,3]}"#;
+    let result: Result<V1MatrixColMajor<i32, Vec<i32>>, _> = serde_json::from_str(json);
+    assert!(result.is_err());
+
+    let json = format!(
+        r#"{{"layout":{{"num_rows":{},"num_cols":2}},"data":[]}}"#,
+        usize::MAX
+    );
+    let result: Result<V1MatrixRowMajor<i32, Vec<i32>>, _> = serde_json::from_str(&json);
+    assert!(result.is_err());
+
+    let json = r#"{"layout":{"num_rows":2,"num_cols":1},"data":[1,2]}"#;
+    let mat: V1MatrixRowMajor<i32, Vec<i32>> = serde_json::from_str(json).unwrap();
+    assert_eq!(mat.at([1, 0]), 2);
+}
+
+#[test]
+fn serde_flat_jagged_invalid_row_end_indices() {
+    type Jagged = FlatJagged<Vec<i32>, Vec<usize>, i32>;
+
+    let decreasing = r#"{"flat_vec":[1,2,3],"row_end_indices":[2,1,3]}"#;
+    assert!(serde_json::from_str::<Jagged>(decreasing).is_err());
+
+    let beyond_len = r#"{"flat_vec":[1,2,3],"row_end_indices":[1,4]}"#;
+    assert!(serde_json::from_str::<Jagged>(beyond_len).is_err());
+
+    let shorter = r#"{"flat_vec":[1,2,3],"row_end_indices":[0,2]}"#;
+    let jagged = serde_json::from_str::<Jagged>(shorter).unwrap();
+    assert_eq!(jagged.equality(&[vec![], vec![1, 2]]), Equality::Equal);
+
+    let mut bytes = vec![];
+    let invalid = (vec![1, 2, 3], vec![3usize, 2]);
+    ciborium::into_writer(
+        &serde_json::json!({"flat_vec": invalid.0, "row_end_indices": invalid.1}),
+        &mut bytes,
+    )
+    .unwrap();
+    assert!(ciborium::from_reader::<Jagged, _>(bytes.as_slice()).is_err());
+}
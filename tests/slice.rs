@@ -0,0 +1,127 @@
+use orx_v::*;
+
+#[test]
+fn slice_vec() {
+    let mut v1: Vec<_> = (0..20).collect();
+
+    let s = v1.v1_as_slice(5..10);
+    assert_eq!(s.card([]), 5);
+    assert_eq!(s.range(), 5..10);
+    assert_eq!(s.all().collect::<Vec<_>>(), vec![5, 6, 7, 8, 9]);
+    assert_eq!(s.try_at(5), None);
+    assert_eq!(s.v1_into_slice(1..3).equality(&[6, 7]), Equality::Equal);
+
+    let e = v1.v1_as_slice(20..20);
+    assert_eq!(e.card([]), 0);
+    assert_eq!(e.all().count(), 0);
+
+    let mut s = v1.v1_as_slice_mut(15..20);
+    *s.at_mut(0) = 0;
+    s.mut_all(|x| *x += 100);
+    assert_eq!(&v1[14..], &[14, 100, 116, 117, 118, 119]);
+}
+
+#[test]
+fn slice_sparse() {
+    let mut v1 = V.d1().sparse(0).bounded(10);
+    let mut s = v1.v1_as_slice_mut(2..6);
+    s.set(3, 4);
+    s.reset_all(1);
+    assert_eq!(v1.lookup_len(), 4);
+    assert_eq!(
+        v1.all().collect::<Vec<_>>(),
+        vec![0, 0, 1, 1, 1, 1, 0, 0, 0, 0]
+    );
+
+    let c = V.d1().constant(3);
+    assert_eq!(c.v1_into_slice(100..104).all().sum::<i32>(), 12);
+}
+
+#[test]
+fn block_jagged() {
+    let v1: Vec<_> = (0..10).collect();
+    let row_lengths = vec![3, 4, 1, 2];
+    let mut jagged = v1.into_jagged_from_row_lengths(&row_lengths);
+
+    let b = jagged.v2_as_block(0..2, 1..3);
+    assert!(b.is_rectangular());
+    assert_eq!(b.card([]), 2);
+    assert_eq!(b.card([1]), 2);
+    assert_eq!(b.equality(&[[1, 2], [4, 5]]), Equality::Equal);
+    assert_eq!(b.try_at([0, 2]), None);
+    assert_eq!(b.try_at([2, 0]), None);
+
+    let b = jagged.v2_as_block(1..4, 0..1);
+    assert_eq!(b.all().collect::<Vec<_>>(), vec![3, 7, 8]);
+
+    let mut b = jagged.v2_as_block_mut(1..2, 1..4);
+    b.child_mut(0).set(2, 60);
+    b.reset_all(0);
+    assert_eq!(jagged.child(1).equality(&[3, 0, 0, 0]), Equality::Equal);
+}
+
+#[test]
+fn block_matrix_and_sparse() {
+    let v1: Vec<_> = (0..12).collect();
+    let mut mat = v1.v1_into_matrix_col_major(3, 4);
+    let b = mat.as_block(1..3, 0..2);
+    assert_eq!(b.num_rows(), 2);
+    assert_eq!(b.num_cols(), 2);
+    assert_eq!(b.all().collect::<Vec<_>>(), vec![1, 4, 2, 5]);
+    assert_eq!(b.col(1).equality(&[4, 5]), Equality::Equal);
+    assert_eq!(b.try_at([2, 0]), None);
+
+    let mut b = mat.as_block_mut(0..3, 3..4);
+    b.col_mut(0).set(2, 0);
+    assert_eq!(mat.at([2, 3]), 0);
+
+    let mut v2 = V.d2().sparse(0).with_rectangular_bounds([4, 4]);
+    let mut b = v2.v2_as_block_mut(2..4, 2..4);
+    b.reset_all(1);
+    assert_eq!(v2.lookup_len(), 4);
+    assert_eq!(v2.at([3, 3]), 1);
+    assert_eq!(v2.at([1, 3]), 0);
+
+    let v2 = v2.v2_into_block(1..4, 1..4).into_inner();
+    let s = v2.child(2).v1_into_slice(1..4);
+    assert_eq!(s.all().collect::<Vec<_>>(), vec![0, 1, 1]);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn slice_and_block_ndarray() {
+    use ndarray::{Array1, Array2};
+
+    let mut arr = Array1::from_iter(0..10);
+    let mut s = arr.v1_as_slice_mut(3..6);
+    s.set(0, 30);
+    assert_eq!(arr.v1_as_slice(2..5).equality(&[2, 30, 4]), Equality::Equal);
+
+    let mut arr = Array2::from_shape_fn((3, 3), |(i, j)| 3 * i + j);
+    let mut b = arr.v2_as_block_mut(1..3, 1..3);
+    b.set([1, 1], 0);
+    assert_eq!(arr.at([2, 2]), 0);
+    assert_eq!(arr.v2_as_block(0..2, 0..3).all().sum::<usize>(), 15);
+}
+
+#[test]
+#[should_panic]
+fn slice_out_of_bounds() {
+    let v1 = vec![0, 1, 2];
+    let _ = v1.v1_into_slice(1..4);
+}
+
+#[test]
+#[should_panic]
+fn block_cols_out_of_bounds_of_jagged_row() {
+    let v2 = vec![vec![0, 1, 2], vec![3], vec![4, 5, 6]];
+    let _ = v2.v2_into_block(0..3, 0..2);
+}
+
+#[test]
+#[should_panic]
+fn block_at_out_of_bounds() {
+    let v2 = vec![vec![0, 1, 2], vec![3, 4, 5]];
+    let b = v2.v2_into_block(0..2, 0..2);
+    let _ = b.at([0, 2]);
+}
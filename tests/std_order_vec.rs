@@ -1,4 +1,4 @@
-#![allow(dead_code)]
+#![allow(dead_code, clippy::explicit_counter_loop)]
 use orx_v::*;
 
 pub fn assert_std_order_v1<V: V1<usize>>(v: V, begin: usize) {
@@ -0,0 +1,126 @@
+use orx_v::*;
+
+fn sum(v: impl V1<i32>) -> i32 {
+    v.all().sum()
+}
+
+#[test]
+fn rev() {
+    let mut v1 = vec![1, 2, 3, 4];
+
+    let r = v1.v1_as_rev();
+    assert_eq!(r.card([]), 4);
+    assert_eq!(r.all().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+    assert_eq!(r.try_at(4), None);
+    assert_eq!(r.v1_as_rev().equality(&v1), Equality::Equal);
+    assert_eq!(sum(&r), 10);
+
+    let mut r = v1.v1_as_rev_mut();
+    r.set(1, 30);
+    *r.at_mut(3) = 10;
+    assert_eq!(v1, vec![10, 2, 30, 4]);
+
+    let e: Vec<i32> = vec![];
+    assert_eq!(e.v1_as_rev().all().count(), 0);
+}
+
+#[test]
+fn into_views() {
+    let mut r = vec![1, 2, 3].v1_into_rev();
+    r.set(0, 30);
+    assert_eq!(r.into_inner(), vec![1, 2, 30]);
+
+    let s = (0..10).collect::<Vec<_>>().v1_into_step_by(3);
+    assert_eq!(s.equality(&[0, 3, 6, 9]), Equality::Equal);
+
+    let mut s = vec![0; 6].v1_into_strided(1, 2, 3);
+    s.mut_all(|x| *x = 1);
+    assert_eq!(s.into_inner(), vec![0, 1, 0, 1, 0, 1]);
+}
+
+#[test]
+fn step_by() {
+    let v1: Vec<_> = (0..10).collect();
+    for step in 1..12 {
+        let s = v1.v1_as_step_by(step);
+        let expected: Vec<_> = (0..10).step_by(step).collect();
+        assert_eq!(s.card([]), expected.len());
+        assert_eq!(s.all().collect::<Vec<_>>(), expected);
+    }
+
+    let c = V.d1().constant(2);
+    let s = c.v1_as_step_by(3);
+    assert!(s.is_unbounded());
+    assert_eq!(s.at(1000), 2);
+
+    let mut v1 = V.d1().sparse(0).bounded(10);
+    v1.v1_as_step_by_mut(5).reset_all(1);
+    assert_eq!(v1.lookup_len(), 2);
+    assert_eq!(v1.at(5), 1);
+}
+
+#[test]
+fn strided() {
+    let v1: Vec<_> = (0..12).collect();
+    let mat = v1.v1_as_matrix(3, 4);
+
+    // the second column of the row-major matrix
+    let col = v1.v1_as_strided(1, 4, 3);
+    assert_eq!(col.offset(), 1);
+    assert_eq!(col.stride(), 4);
+    for i in 0..3 {
+        assert_eq!(col.at(i), mat.at([i, 1]));
+    }
+
+    // stride of zero repeats the element
+    let rep = v1.v1_as_strided(5, 0, 3);
+    assert_eq!(rep.equality(&[5, 5, 5]), Equality::Equal);
+
+    // composition
+    let r = v1.v1_as_rev();
+    let s = r.v1_as_step_by(3);
+    let s = s.v1_as_rev();
+    assert_eq!(s.all().collect::<Vec<_>>(), vec![2, 5, 8, 11]);
+
+    let mut v1 = vec![0; 6];
+    let mut s = v1.v1_as_strided_mut(1, 2, 3);
+    s.mut_all(|x| *x += 1);
+    *s.at_mut(2) = 7;
+    assert_eq!(v1, vec![0, 1, 0, 1, 0, 7]);
+}
+
+#[test]
+#[should_panic]
+fn strided_out_of_bounds() {
+    let v1 = vec![0, 1, 2, 3, 4];
+    let _ = v1.v1_as_strided(1, 2, 3);
+}
+
+#[test]
+#[should_panic]
+fn step_by_zero() {
+    let v1 = vec![0, 1, 2, 3, 4];
+    let _ = v1.v1_as_step_by(0);
+}
+
+#[test]
+#[should_panic]
+fn rev_unbounded() {
+    let v1 = V.d1().fun(|[i]| i);
+    let _ = v1.v1_as_rev();
+}
+
+#[test]
+#[should_panic]
+fn step_by_unbounded_all() {
+    let v1 = V.d1().fun(|[i]| i);
+    let _ = v1.v1_as_step_by(2).all().next();
+}
+
+#[test]
+fn step_by_unbounded_at() {
+    let v1 = V.d1().fun(|[i]| i);
+    let s = v1.v1_as_step_by(2);
+    assert!(s.is_unbounded());
+    assert_eq!(s.at([1000]), 2000);
+}
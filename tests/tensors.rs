@@ -0,0 +1,132 @@
+use orx_v::*;
+
+fn lexicographic_d3(shape: [usize; 3]) -> Vec<[usize; 3]> {
+    let [n0, n1, n2] = shape;
+    let mut indices = vec![];
+    for i in 0..n0 {
+        for j in 0..n1 {
+            for k in 0..n2 {
+                indices.push([i, j, k]);
+            }
+        }
+    }
+    indices
+}
+
+#[test]
+fn d3_row_major() {
+    let shape = [2, 3, 4];
+    let data: Vec<_> = (0..24).collect();
+    let v3 = data.v1_as_d3(shape);
+
+    assert_eq!(v3.shape(), shape);
+    assert!(v3.is_rectangular());
+    assert_eq!(v3.card([]), 2);
+    assert_eq!(v3.card([1]), 3);
+    assert_eq!(v3.card([1, 2]), 4);
+
+    for (p, idx) in lexicographic_d3(shape).into_iter().enumerate() {
+        assert_eq!(v3.at(idx), p);
+        let [i, j, k] = idx;
+        assert_eq!(v3.child(i).child(j).at([k]), p);
+    }
+    assert_eq!(v3.all().collect::<Vec<_>>(), data);
+    assert_eq!(v3.try_at([0, 3, 0]), None);
+
+    let expected = vec![
+        vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8, 9, 10, 11]],
+        vec![
+            vec![12, 13, 14, 15],
+            vec![16, 17, 18, 19],
+            vec![20, 21, 22, 23],
+        ],
+    ];
+    assert_eq!(v3.equality(&expected), Equality::Equal);
+}
+
+#[test]
+fn d3_col_major() {
+    let shape = [2, 3, 4];
+    let data: Vec<_> = (0..24).collect();
+    let v3 = data.v1_as_d3_col_major(shape);
+
+    assert_eq!(v3.card([]), 2);
+    assert_eq!(v3.card([0]), 3);
+    assert_eq!(v3.card([0, 1]), 4);
+
+    for [i, j, k] in lexicographic_d3(shape) {
+        let p = i + 2 * (j + 3 * k);
+        assert_eq!(v3.at([i, j, k]), p);
+        assert_eq!(v3.child(i).child(j).at([k]), p);
+    }
+
+    let all: Vec<_> = v3.all().collect();
+    let expected: Vec<_> = lexicographic_d3(shape)
+        .into_iter()
+        .map(|[i, j, k]| i + 2 * (j + 3 * k))
+        .collect();
+    assert_eq!(all, expected);
+
+    // transpose of the row-major layout of the reversed shape
+    let t = data.v1_as_d3([4, 3, 2]);
+    let p = t.into_permuted_axes([2, 1, 0]);
+    assert_eq!(p.equality(&v3), Equality::Equal);
+}
+
+#[test]
+fn d4_layouts() {
+    let shape = [2, 1, 3, 2];
+    let data: Vec<_> = (0..12).collect();
+
+    let row = data.v1_as_d4(shape);
+    assert_eq!(row.card([1, 0, 2]), 2);
+    assert_eq!(row.at([1, 0, 2, 1]), 11);
+    assert_eq!(row.child(1).at([0, 1, 0]), 8);
+    assert_eq!(row.all().collect::<Vec<_>>(), data);
+
+    let col = data.v1_as_d4_col_major(shape);
+    assert_eq!(col.at([1, 0, 2, 1]), 11);
+    assert_eq!(col.at([1, 0, 0, 0]), 1);
+    assert_eq!(col.at([0, 0, 1, 0]), 2);
+    assert_eq!(col.at([0, 0, 0, 1]), 6);
+    assert_eq!(col.all().take(4).collect::<Vec<_>>(), vec![0, 6, 2, 8]);
+}
+
+#[test]
+fn mutable_tensors() {
+    let mut data = vec![0; 24];
+
+    let mut v3 = data.v1_as_d3_mut([2, 3, 4]);
+    v3.set([1, 2, 3], 1);
+    *v3.child_mut(0).child_mut(1).at_mut([2]) = 2;
+    assert_eq!(data[23], 1);
+    assert_eq!(data[6], 2);
+
+    let mut v4 = data.v1_as_d4_col_major_mut([2, 3, 2, 2]);
+    v4.set([1, 0, 0, 1], 3);
+    assert_eq!(data[1 + 2 * 3 * 2], 3);
+
+    let mut v3 = (&mut data).v1_into_d3_col_major([4, 3, 2]);
+    v3.reset_all(7);
+    assert!(data.iter().all(|x| *x == 7));
+
+    let sparse = V.d1().sparse(0).bounded(8);
+    let mut v3 = sparse.v1_into_d3([2, 2, 2]);
+    v3.set([1, 1, 1], 5);
+    assert_eq!(v3.into_inner().lookup_len(), 1);
+}
+
+#[test]
+#[should_panic]
+fn shape_mismatch() {
+    let data = vec![0; 10];
+    let _ = data.v1_as_d3([2, 2, 2]);
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds_of_shape() {
+    let data: Vec<_> = (0..8).collect();
+    let v3 = data.v1_as_d3([2, 2, 2]);
+    let _ = v3.at([0, 0, 2]);
+}
@@ -0,0 +1,129 @@
+use orx_v::*;
+
+fn assert_unchecked_matches_at<D: Dim, T: PartialEq + core::fmt::Debug>(vec: &impl NVec<D, T>) {
+    for idx in vec.indices() {
+        assert_eq!(unsafe { vec.at_unchecked(idx) }, vec.at(idx));
+    }
+}
+
+fn assert_matrix_unchecked_matches_at<T: PartialEq + core::fmt::Debug>(mat: &impl Matrix<T>) {
+    for i in 0..mat.num_rows() {
+        for j in 0..mat.num_cols() {
+            assert_eq!(unsafe { mat.at_unchecked([i, j]) }, mat.at([i, j]));
+        }
+    }
+}
+
+fn double_all<D: Dim>(vec: &mut impl NVecMut<D, i32>) {
+    let indices: Vec<_> = vec.indices().collect();
+    for idx in indices {
+        *unsafe { vec.at_mut_unchecked(idx) } *= 2;
+    }
+}
+
+#[test]
+fn unchecked_std() {
+    let v1 = vec![1, 2, 3];
+    assert_unchecked_matches_at(&v1);
+    assert_unchecked_matches_at(&v1.as_slice());
+    assert_unchecked_matches_at(&[4, 5]);
+
+    let v3 = vec![vec![vec![1], vec![]], vec![vec![2, 3], vec![4]]];
+    assert_unchecked_matches_at(&v3);
+    assert_unchecked_matches_at::<D2, _>(&[[1, 2], [3, 4]]);
+    assert_unchecked_matches_at(&v3.child(1));
+
+    let mut v3 = v3;
+    double_all(&mut v3);
+    assert_eq!(v3, vec![vec![vec![2], vec![]], vec![vec![4, 6], vec![8]]]);
+
+    let mut arr = [[1, 2], [3, 4]];
+    double_all(&mut arr.as_mut_slice());
+    assert_eq!(arr, [[2, 4], [6, 8]]);
+}
+
+#[test]
+fn unchecked_fallback() {
+    let fun = V
+        .d2()
+        .fun(|[i, j]| 10 * i + j)
+        .with_variable_bounds([2, 3, 1]);
+    assert_unchecked_matches_at(&fun);
+    assert_unchecked_matches_at(&V.d2().constant(7).with_rectangular_bounds([2, 3]));
+
+    let mut sparse = V.d2().sparse(0).with_rectangular_bounds([2, 2]);
+    double_all(&mut sparse);
+    sparse.set([1, 1], 3);
+    double_all(&mut sparse);
+    assert_eq!(sparse.at([1, 1]), 6);
+}
+
+#[test]
+fn unchecked_stack_and_jagged() {
+    let a = vec![1, 2, 3];
+    let b = [4];
+    let stacked = stack([a.as_slice(), &b]);
+    assert_unchecked_matches_at(&stacked);
+
+    let mut jagged = vec![1, 2, 3, 4, 5, 6].into_jagged_from_row_lengths(&[0, 3, 2, 1]);
+    assert_unchecked_matches_at(&jagged);
+    assert_eq!(unsafe { jagged.at_unchecked([2, 1]) }, 5);
+
+    double_all(&mut jagged);
+    assert_eq!(
+        jagged.equality(&[vec![], vec![2, 4, 6], vec![8, 10], vec![12]]),
+        Equality::Equal
+    );
+}
+
+#[test]
+fn unchecked_matrices() {
+    let mut v1: Vec<_> = (0..6).collect();
+    assert_matrix_unchecked_matches_at(&v1.v1_as_matrix(2, 3));
+    assert_matrix_unchecked_matches_at(&v1.v1_as_matrix_col_major(2, 3));
+
+    let mut mat = v1.v1_as_matrix_col_major_mut(2, 3);
+    *unsafe { mat.at_mut_unchecked([1, 0]) } = 42;
+    assert_eq!(v1, vec![0, 42, 2, 3, 4, 5]);
+
+    let mut v2 = vec![vec![0, 1, 2], vec![3, 4, 5]];
+    assert_matrix_unchecked_matches_at(&v2.as_matrix());
+    assert_matrix_unchecked_matches_at(&v2.as_matrix_col_major());
+
+    let mut mat = v2.as_matrix_col_major_mut();
+    *unsafe { mat.at_mut_unchecked([2, 1]) } = 42;
+    assert_eq!(v2, vec![vec![0, 1, 2], vec![3, 4, 42]]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic]
+fn unchecked_out_of_bounds_debug_assert() {
+    let v2 = vec![vec![1, 2], vec![3]];
+    let _ = unsafe { v2.at_unchecked([1, 1]) };
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn unchecked_ndarray() {
+    use ndarray::{Array, Array1, Ix2};
+
+    let v1: Array1<i32> = Array::from_vec(vec![1, 2, 3]);
+    assert_unchecked_matches_at(&v1);
+
+    let mut v2: Array<i32, Ix2> = Array::from_shape_vec((2, 3), (0..6).collect()).unwrap();
+    assert_unchecked_matches_at(&v2);
+    double_all(&mut v2);
+    assert_eq!(unsafe { v2.at_unchecked([1, 2]) }, 10);
+}
+
+#[cfg(feature = "orx-split-vec")]
+#[test]
+fn unchecked_split_vec() {
+    use orx_split_vec::SplitVec;
+
+    let mut v2: SplitVec<Vec<i32>> = (0..40).map(|i| vec![i; i as usize % 3]).collect();
+    assert_unchecked_matches_at(&v2);
+    double_all(&mut v2);
+    assert_eq!(unsafe { v2.at_unchecked([38, 1]) }, 76);
+}
@@ -0,0 +1,90 @@
+mod std_order_vec;
+use orx_v::*;
+
+#[test]
+fn zip_with_d1() {
+    let a: Vec<_> = (0..10).collect();
+    let b = V.d1().fun(|[i]| 10 * i).bounded(10);
+
+    let zipped = a.zip_with(&b, |x, y| y - x).unwrap();
+    let expected = V.d1().fun(|[i]| 9 * i).bounded(10);
+    assert_eq!(zipped.equality(&expected), Equality::Equal);
+
+    let halves = zipped.zip_with(&a, |z, x| z / 9 - x).unwrap();
+    assert!(halves.all().all(|x| x == 0));
+
+    let short = vec![1, 2, 3];
+    assert_eq!(
+        a.zip_with(&short, |x, y| x + y).map(|_| ()),
+        Err(CardEquality::Unequal(IdxLeqD0::IdxD0([]), 10, 3))
+    );
+}
+
+#[test]
+fn zip_with_d2_cost_capacity() {
+    let costs = vec![vec![1.0, 2.0], vec![], vec![3.0, 4.0, 5.0]];
+    let capacities = costs.map(|c| c > 2.5);
+
+    let zipped = costs
+        .zip_with(&capacities, |c, has_cap| match has_cap {
+            true => c,
+            false => f64::INFINITY,
+        })
+        .unwrap();
+
+    assert_eq!(zipped.card([]), 3);
+    assert_eq!(zipped.card([1]), 0);
+    assert_eq!(zipped.card([2]), 3);
+    assert_eq!(zipped.at([0, 1]), f64::INFINITY);
+    assert_eq!(zipped.child(2).at([2]), 5.0);
+    assert_eq!(
+        zipped.all().filter(|x| x.is_finite()).collect::<Vec<_>>(),
+        vec![3.0, 4.0, 5.0]
+    );
+
+    let jagged = vec![vec![1.0, 2.0], vec![7.0], vec![3.0, 4.0, 5.0]];
+    let result = costs.zip_with(&jagged, |a, b| a + b);
+    assert_eq!(
+        result.map(|_| ()),
+        Err(CardEquality::Unequal(IdxLeqD1::IdxD1([1]), 0, 1))
+    );
+}
+
+#[test]
+fn zip_d3() {
+    let a = V
+        .d3()
+        .fun(|[i, j, k]| i + j + k)
+        .with_rectangular_bounds([2, 3, 4]);
+    let b = vec![vec![vec!['x'; 4]; 3]; 2];
+
+    let zipped = a.zip(&b).unwrap();
+    assert_eq!(zipped.at([1, 2, 3]), (6, 'x'));
+    assert_eq!(zipped.child(1).child(2).at([3]), (6, 'x'));
+    assert_eq!(zipped.all().count(), 24);
+
+    let mut c = b.clone();
+    c[1][2].pop();
+    assert_eq!(
+        a.zip(&c).map(|_| ()),
+        Err(CardEquality::Unequal(IdxLeqD2::IdxD2([1, 2]), 4, 3))
+    );
+}
+
+#[test]
+fn zip_unbounded() {
+    let a = V.d2().constant(2);
+    let b = V.d2().fun(|[i, j]| i * j);
+
+    let zipped = a.zip_with(&b, |x, y| x * y).unwrap();
+    assert!(zipped.is_unbounded());
+    assert_eq!(zipped.at([3, 4]), 24);
+
+    let bounded = b.with_rectangular_bounds([2, 2]);
+    assert!(a.zip_with(&bounded, |x, y| x * y).is_err());
+
+    let a = a.with_rectangular_bounds([2, 2]);
+    let cached = bounded.zip_with(&a, |x, y| x * y).unwrap().into_cached();
+    assert_eq!(cached.at([1, 1]), 2);
+    assert_eq!(cached.cache_len(), 1);
+}